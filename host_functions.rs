@@ -64,8 +64,25 @@ extern "ExtismHost" {
     fn unsafe_get_env_var(var: String) -> String;
     fn unsafe_set_env_var(var: String, value: String) -> bool;
     fn call_plugin_func(func: String, plugin_name: String) -> bool;
+    fn list_playlists() -> PluginPlaylists;
+    // Returns the paths of the songs in play order, empty if the playlist does not exist
+    fn get_playlist_tracks(playlist: String) -> PlaylistTracks;
     fn create_playlist(name: String) -> bool;
+    fn rename_playlist(name: String, new_name: String) -> bool;
+    // Only removes the playlist, never the songs in it
+    fn delete_playlist(name: String) -> bool;
+    fn duplicate_playlist(name: String, new_name: String) -> bool;
+    // Returns false if the song is already in the playlist
     fn add_to_playlist(song_path: String, playlist: String) -> bool;
+    // Indices are positions as returned by get_playlist_tracks
+    fn remove_from_playlist(playlist: String, indices: PlaylistIndices) -> bool;
+    fn move_playlist_entry(playlist: String, from: u64, to: u64) -> bool;
+    // Lists every current position once, in the order they should end up in
+    fn reorder_playlist(playlist: String, order: PlaylistIndices) -> bool;
+    // Appends the sources to target, creating it if needed
+    fn merge_playlists(sources: PlaylistNames, target: String) -> bool;
+    // key is one of title, artist, album, duration, file_name or path
+    fn sort_playlist(playlist: String, key: String, descending: bool) -> bool;
 }
 
 #[derive(Serialize, Deserialize, ToBytes, FromBytes)]
@@ -104,6 +121,38 @@ pub struct SongMetadata {
     pub genre: String,
 }
 
+#[derive(Serialize, Deserialize, ToBytes, FromBytes)]
+#[encoding(Json)]
+pub struct PluginPlaylist {
+    pub name: String,
+    pub track_count: u32,
+    pub is_smart: bool,
+}
+
+#[derive(Serialize, Deserialize, ToBytes, FromBytes)]
+#[encoding(Json)]
+pub struct PluginPlaylists {
+    pub playlists: Vec<PluginPlaylist>,
+}
+
+#[derive(Serialize, Deserialize, ToBytes, FromBytes)]
+#[encoding(Json)]
+pub struct PlaylistTracks {
+    pub tracks: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, ToBytes, FromBytes)]
+#[encoding(Json)]
+pub struct PlaylistIndices {
+    pub indices: Vec<u32>,
+}
+
+#[derive(Serialize, Deserialize, ToBytes, FromBytes)]
+#[encoding(Json)]
+pub struct PlaylistNames {
+    pub names: Vec<String>,
+}
+
 #[derive(Serialize, Deserialize, ToBytes, FromBytes)]
 #[encoding(Json)]
 pub struct CommandTR {
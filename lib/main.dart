@@ -3,6 +3,7 @@ import 'dart:io';
 import 'package:adiman/src/rust/api/music_handler.dart' as rust_api;
 import 'package:adiman/src/rust/api/value_store.dart' as value_store;
 import 'package:adiman/src/rust/api/plugin_man.dart' as plugin_api;
+import 'package:adiman/src/rust/api/play_stats.dart' as play_stats;
 import 'package:adiman/src/rust/frb_generated.dart';
 import 'package:flutter/material.dart';
 import 'package:adiman/services/prefs_service.dart';
@@ -55,6 +56,48 @@ class Song {
       genre: metadata.genre as String,
     );
   }
+  // Size of the covers song lists load, the player loads the full cover
+  static const int listArtSize = 256;
+  // Covers loaded at once, so a big library does not start a load per song
+  static const int _artLoadBatch = 16;
+
+  Song _withArt(Uint8List art) => Song(
+        title: title,
+        artist: artist,
+        artists: artists,
+        album: album,
+        path: path,
+        albumArt: art,
+        duration: duration,
+        genre: genre,
+      );
+
+  // Metadata from Rust has no art, a thumbnail comes from the Rust art cache
+  static Future<Song> loadFromMetadata(dynamic metadata,
+      {List<String>? artists}) async {
+    final song = Song.fromMetadata(metadata, artists: artists);
+    if (song.albumArt != null) return song;
+    final art = await rust_api.getCachedAlbumArtSized(
+        path: song.path, size: listArtSize);
+    return art == null ? song : song._withArt(art);
+  }
+
+  static Future<List<Song>> loadAllFromMetadata(List<dynamic> metadata) async {
+    final songs = <Song>[];
+    for (var i = 0; i < metadata.length; i += _artLoadBatch) {
+      final batch = metadata.skip(i).take(_artLoadBatch);
+      songs.addAll(
+          await Future.wait(batch.map((m) => Song.loadFromMetadata(m))));
+    }
+    return songs;
+  }
+
+  // The full size cover, for the now playing view
+  Future<Song> withFullArt() async {
+    final art = await rust_api.getCachedAlbumArt(path: path);
+    return art == null ? this : _withArt(art);
+  }
+
   rust_api.SongMetadata toMetadata() {
     return rust_api.SongMetadata(
       title: title,
@@ -183,6 +226,8 @@ void syncRust() async {
   await updater.setPluginRwDir(folder: pluginRwDir);
   await updater.setUnsafeApis(
       value: SharedPreferencesService.instance.getBool('unsafeAPIs') ?? false);
+  await updater.setAcoustidApiKey(
+      key: SharedPreferencesService.instance.getString('acoustidApiKey') ?? '');
   await updater.apply();
 }
 
@@ -194,9 +239,15 @@ class Adiman extends StatefulWidget {
 }
 
 class _AdimanState extends State<Adiman> {
+  late final AppLifecycleListener _lifecycleListener;
+
   @override
   void initState() {
     super.initState();
+    _lifecycleListener = AppLifecycleListener(
+      onDetach: play_stats.flushPlayStats,
+      onExitRequested: _onExitRequested,
+    );
     if (SharedPreferencesService.instance.getBool('enableAutoUpdater') ??
         false) {
       WidgetsFlutterBinding.ensureInitialized();
@@ -204,6 +255,19 @@ class _AdimanState extends State<Adiman> {
     }
   }
 
+  // Plays are saved a while after they happen, write the ones still waiting
+  Future<AppExitResponse> _onExitRequested() async {
+    await play_stats.flushPlayStats();
+    return AppExitResponse.exit;
+  }
+
+  @override
+  void dispose() {
+    play_stats.flushPlayStats();
+    _lifecycleListener.dispose();
+    super.dispose();
+  }
+
   void runUpdater() async {
     final updater = await AdimanUpdater.initialize();
     updater.checkUpdate(context);
@@ -88,7 +88,7 @@ class _DownloadScreenState extends State<DownloadScreen>
       );
 
       if (metadata.isNotEmpty) {
-        _tempSong = Song.fromMetadata(metadata.first);
+        _tempSong = await Song.loadFromMetadata(metadata.first);
 
         if (_tempSong!.albumArt != null) {
           try {
@@ -114,8 +114,8 @@ class _DownloadScreenState extends State<DownloadScreen>
               onReloadLibrary: widget.onReloadLibrary,
               musicFolder: widget.musicFolder,
               service: widget.service,
-              song: Song.fromMetadata(metadata.first),
-              songList: [Song.fromMetadata(metadata.first)],
+              song: _tempSong!,
+              songList: [_tempSong!],
               currentIndex: 0,
               isTemp: true,
               tempPath: downloadedPath,
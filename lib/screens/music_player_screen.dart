@@ -1201,7 +1201,17 @@ class _MusicPlayerScreenState extends State<MusicPlayerScreen>
     }
   }
 
+  // Song lists only hold thumbnails, the player shows the full size cover
+  Future<void> _loadFullArt() async {
+    final song = currentSong;
+    final full = await song.withFullArt();
+    if (mounted && currentSong.path == song.path) {
+      setState(() => currentSong = full);
+    }
+  }
+
   Future<void> _updateDominantColor() async {
+    await _loadFullArt();
     final useDom = useDominantColorsNotifier.value;
     if (currentSong.albumArt == null || !useDom) {
       if (mounted) {
@@ -1538,18 +1548,22 @@ class _MusicPlayerScreenState extends State<MusicPlayerScreen>
     );
   }
 
+  // Every download gets a folder of its own, which goes along with the song
+  void _deleteTempDownload() {
+    if (!widget.isTemp || widget.tempPath == null) return;
+    final dir = Directory(path.dirname(widget.tempPath!));
+    if (dir.existsSync()) {
+      dir.deleteSync(recursive: true);
+    }
+  }
+
   Future<void> _handleSearchAnother() async {
-    if (widget.isTemp && widget.tempPath != null) {
-      try {
-        final file = File(widget.tempPath!);
-        if (await file.exists()) {
-          await file.delete();
-        }
-      } catch (e) {
-        AdiSnackbar(
-            backgroundColor: dominantColor,
-            content: 'Error deleting temp file: $e');
-      }
+    try {
+      _deleteTempDownload();
+    } catch (e) {
+      AdiSnackbar(
+          backgroundColor: dominantColor,
+          content: 'Error deleting temp file: $e');
     }
     await rust_api.pauseSong();
     widget.service.playbackStateController.add(false);
@@ -1583,7 +1597,7 @@ class _MusicPlayerScreenState extends State<MusicPlayerScreen>
       final destPath = path.join(expandedPath, fileName);
 
       await sourceFile.copy(destPath);
-      await sourceFile.delete();
+      _deleteTempDownload();
 
       final newSong = Song(
         title: currentSong.title,
@@ -1625,15 +1639,10 @@ class _MusicPlayerScreenState extends State<MusicPlayerScreen>
     _lyricsAnimationController.dispose();
     defaultThemeColorNotifier.removeListener(_handleThemeColorChange);
     useDominantColorsNotifier.removeListener(_useDominantColorsListener);
-    if (widget.isTemp && widget.tempPath != null) {
-      try {
-        final file = File(widget.tempPath!);
-        if (file.existsSync()) {
-          file.deleteSync();
-        }
-      } catch (e) {
-        AdiSnackbar(content: 'Error deleting temp file: $e');
-      }
+    try {
+      _deleteTempDownload();
+    } catch (e) {
+      AdiSnackbar(content: 'Error deleting temp file: $e');
     }
     super.dispose();
   }
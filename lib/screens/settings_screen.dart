@@ -73,6 +73,7 @@ class _SettingsScreenState extends State<SettingsScreen> {
   bool _clearMp3Cache = false;
   bool _vimKeybindings = false;
   String _ytdlpFlags = '';
+  String _acoustidApiKey = '';
   String _pluginDir = '';
   String _pluginRwDir = '';
   bool _fadeIn = false;
@@ -223,6 +224,8 @@ class _SettingsScreenState extends State<SettingsScreen> {
               false;
       _ytdlpFlags =
           SharedPreferencesService.instance.getString('ytdlpFlags') ?? '';
+      _acoustidApiKey =
+          SharedPreferencesService.instance.getString('acoustidApiKey') ?? '';
       _pluginDir = SharedPreferencesService.instance.getString('pluginDir') ??
           '~/AdiPlugins';
       _pluginRwDir =
@@ -393,6 +396,15 @@ class _SettingsScreenState extends State<SettingsScreen> {
     setState(() => _ytdlpFlags = flags);
   }
 
+  // An empty key goes back to the one compiled in
+  Future<void> _saveAcoustidApiKey(String key) async {
+    await SharedPreferencesService.instance.setString('acoustidApiKey', key);
+    final updater = await value_store.updateStore();
+    await updater.setAcoustidApiKey(key: key);
+    await updater.apply();
+    setState(() => _acoustidApiKey = key);
+  }
+
   Future<void> _saveEdgeBreathe(bool value) async {
     await SharedPreferencesService.instance.setBool('edgeBreathe', value);
     setState(() => _edgeBreathe = value);
@@ -1914,6 +1926,15 @@ class _SettingsScreenState extends State<SettingsScreen> {
                                   title: 'Enable Auto Updater',
                                   value: _enableAutoUpdater,
                                   onChanged: _saveEnableAutoUpdater),
+                              SettingsTextField(
+                                title:
+                                    'AcoustID API Key (used to identify songs, leave empty for the built in key)',
+                                initialValue: _acoustidApiKey,
+                                hintText: 'Enter AcoustID API key...',
+                                onChanged: _saveAcoustidApiKey,
+                                icon: Broken.key,
+                                dominantColor: _currentColor,
+                              ),
                             ]),
 			    if (_settingsPluginButtons.isNotEmpty) ...[ 
 			      _buildSettingsExpansionTile(
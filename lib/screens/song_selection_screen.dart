@@ -333,8 +333,7 @@ class _SongSelectionScreenState extends State<SongSelectionScreen>
           SharedPreferencesService.instance.getBool('autoConvert') ?? false,
     );
 
-    List<Song> playlistSongs =
-        metadata.map((m) => Song.fromMetadata(m)).toList();
+    List<Song> playlistSongs = await Song.loadAllFromMetadata(metadata);
 
     // Get the stored order if available
     List<String> orderedPaths =
@@ -1068,7 +1067,7 @@ class _SongSelectionScreenState extends State<SongSelectionScreen>
           autoConvert:
               SharedPreferencesService.instance.getBool('autoConvert') ?? false,
         );
-        loadedSongs = metadata.map((m) => Song.fromMetadata(m)).toList();
+        loadedSongs = await Song.loadAllFromMetadata(metadata);
         currentCount = loadedSongs.length;
 
         // Sort the songs
@@ -2381,7 +2380,17 @@ class _SongSelectionScreenState extends State<SongSelectionScreen>
                           icon: Broken.global_search,
                           label: 'Find metadata',
                           onTap: () async {
-                            final meta = await acoustid.lookup(path: song.path);
+                            final rust_api.SongMetadata? meta;
+                            try {
+                              meta = await acoustid.lookup(path: song.path);
+                            } catch (e) {
+                              ScaffoldMessenger.of(context).showSnackBar(
+                                  AdiSnackbar(
+                                      content:
+                                          "Failed to find metadata for song: $e"));
+                              Navigator.pop(context);
+                              return;
+                            }
                             if (meta == null) {
                               ScaffoldMessenger.of(context).showSnackBar(
                                   AdiSnackbar(
@@ -7,9 +7,105 @@ import '../frb_generated.dart';
 import 'music_handler.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `calc_fingerprint`, `get_duration`, `lookup_metadata`, `lookup_rs`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AcoustIdArtist`, `AcoustIdRecording`, `AcoustIdReleaseGroup`, `AcoustIdResponse`, `AcoustIdResult`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `acoustid_endpoint`, `emit_progress`, `lookup_metadata`, `lookup_rs`, `recording_to_metadata`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AcoustIdArtist`, `AcoustIdRecording`, `AcoustIdReleaseGroup`, `AcoustIdResponse`, `AcoustIdResult`, `IdentifyBatches`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `calc_fingerprint`, `get_duration`
+
+Future<bool> hasAcoustidKey() =>
+    RustLib.instance.api.crateApiAcoustidHasAcoustidKey();
 
 Future<SongMetadata?> lookup({required String path}) =>
     RustLib.instance.api.crateApiAcoustidLookup(path: path);
+
+Future<List<AcoustIdCandidate>> lookupCandidates(
+        {required String path, required int topN}) =>
+    RustLib.instance.api
+        .crateApiAcoustidLookupCandidates(path: path, topN: topN);
+
+Future<BigInt> identifyBatch(
+        {required List<String> paths, required int topN, int? workers}) =>
+    RustLib.instance.api.crateApiAcoustidIdentifyBatch(
+        paths: paths, topN: topN, workers: workers);
+
+Stream<IdentifyProgress> watchIdentify() =>
+    RustLib.instance.api.crateApiAcoustidWatchIdentify();
+
+Future<bool> cancelIdentify({required BigInt batchId}) =>
+    RustLib.instance.api.crateApiAcoustidCancelIdentify(batchId: batchId);
+
+class AcoustIdCandidate {
+  final double score;
+  final String? recordingId;
+  final SongMetadata metadata;
+
+  const AcoustIdCandidate({
+    required this.score,
+    this.recordingId,
+    required this.metadata,
+  });
+
+  @override
+  int get hashCode => score.hashCode ^ recordingId.hashCode ^ metadata.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AcoustIdCandidate &&
+          runtimeType == other.runtimeType &&
+          score == other.score &&
+          recordingId == other.recordingId &&
+          metadata == other.metadata;
+}
+
+class IdentifyProgress {
+  final BigInt batchId;
+  final int completed;
+  final int total;
+  final IdentifyResult result;
+
+  const IdentifyProgress({
+    required this.batchId,
+    required this.completed,
+    required this.total,
+    required this.result,
+  });
+
+  @override
+  int get hashCode =>
+      batchId.hashCode ^ completed.hashCode ^ total.hashCode ^ result.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IdentifyProgress &&
+          runtimeType == other.runtimeType &&
+          batchId == other.batchId &&
+          completed == other.completed &&
+          total == other.total &&
+          result == other.result;
+}
+
+class IdentifyResult {
+  final String path;
+  final List<AcoustIdCandidate> candidates;
+  final String? error;
+
+  const IdentifyResult({
+    required this.path,
+    required this.candidates,
+    this.error,
+  });
+
+  @override
+  int get hashCode => path.hashCode ^ candidates.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IdentifyResult &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          candidates == other.candidates &&
+          error == other.error;
+}
@@ -0,0 +1,20 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `clear`, `default`, `evict`, `find_folder_art`, `get`, `get_art_cache_dir`, `has_original`, `hash_bytes`, `index_insert`, `index_lookup`, `insert`, `load_original`, `load_thumbnail`, `make_thumbnail`, `original_path`, `remove`, `snap_size`, `store_original`, `thumbnail_path`, `track_art_hash`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ArtLru`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `album_art_for`, `register_track_art`
+
+Future<void> setArtCacheMemoryLimit({required BigInt bytes}) =>
+    RustLib.instance.api.crateApiArtCacheSetArtCacheMemoryLimit(bytes: bytes);
+
+Future<BigInt> getArtCacheMemoryUsage() =>
+    RustLib.instance.api.crateApiArtCacheGetArtCacheMemoryUsage();
+
+Future<bool> clearArtCache() =>
+    RustLib.instance.api.crateApiArtCacheClearArtCache();
@@ -0,0 +1,71 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `book_duration`, `default`, `load_books`, `save_position`, `store_path`, `track_book`, `with_books`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BookStore`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+Future<bool> isAudiobook({required String path}) =>
+    RustLib.instance.api.crateApiAudiobooksIsAudiobook(path: path);
+
+Future<void> setAudiobook({required String path, required bool enabled}) =>
+    RustLib.instance.api
+        .crateApiAudiobooksSetAudiobook(path: path, enabled: enabled);
+
+Future<BookProgress?> getBookProgress({required String path}) =>
+    RustLib.instance.api.crateApiAudiobooksGetBookProgress(path: path);
+
+Future<List<BookProgress>> listBooksInProgress() =>
+    RustLib.instance.api.crateApiAudiobooksListBooksInProgress();
+
+Future<void> setBookPosition(
+        {required String path, required double positionSecs}) =>
+    RustLib.instance.api.crateApiAudiobooksSetBookPosition(
+        path: path, positionSecs: positionSecs);
+
+Future<void> markBookFinished({required String path, required bool finished}) =>
+    RustLib.instance.api
+        .crateApiAudiobooksMarkBookFinished(path: path, finished: finished);
+
+Future<void> forgetBookProgress({required String path}) =>
+    RustLib.instance.api.crateApiAudiobooksForgetBookProgress(path: path);
+
+class BookProgress {
+  final String path;
+  final double positionSecs;
+  final double durationSecs;
+  final bool finished;
+  final PlatformInt64 updated;
+
+  const BookProgress({
+    required this.path,
+    required this.positionSecs,
+    required this.durationSecs,
+    required this.finished,
+    required this.updated,
+  });
+
+  @override
+  int get hashCode =>
+      path.hashCode ^
+      positionSecs.hashCode ^
+      durationSecs.hashCode ^
+      finished.hashCode ^
+      updated.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BookProgress &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          positionSecs == other.positionSecs &&
+          durationSecs == other.durationSecs &&
+          finished == other.finished &&
+          updated == other.updated;
+}
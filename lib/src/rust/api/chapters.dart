@@ -0,0 +1,54 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `be_u32`, `be_u64`, `build_chapters`, `chapter_at`, `cue_chapters`, `find_box`, `id3_chapters`, `mp4_chapters`, `mvhd_duration`, `parse_chpl`, `playing_chapters`, `read_at`, `seek_to`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `media_duration`, `read_chapters`
+
+Future<List<Chapter>> chapterList({required String path}) =>
+    RustLib.instance.api.crateApiChaptersChapterList(path: path);
+
+Future<Chapter?> currentChapter() =>
+    RustLib.instance.api.crateApiChaptersCurrentChapter();
+
+Future<bool> nextChapter() =>
+    RustLib.instance.api.crateApiChaptersNextChapter();
+
+Future<bool> previousChapter() =>
+    RustLib.instance.api.crateApiChaptersPreviousChapter();
+
+Future<bool> seekToChapter({required int index}) =>
+    RustLib.instance.api.crateApiChaptersSeekToChapter(index: index);
+
+class Chapter {
+  final int index;
+  final String title;
+  final double startSecs;
+  final double endSecs;
+
+  const Chapter({
+    required this.index,
+    required this.title,
+    required this.startSecs,
+    required this.endSecs,
+  });
+
+  @override
+  int get hashCode =>
+      index.hashCode ^ title.hashCode ^ startSecs.hashCode ^ endSecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Chapter &&
+          runtimeType == other.runtimeType &&
+          index == other.index &&
+          title == other.title &&
+          startSecs == other.startSecs &&
+          endSecs == other.endSecs;
+}
@@ -0,0 +1,141 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `emit`, `import_all`, `import_file`, `resolve`, `tag_track`, `update`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Collections`, `FlatEntry`, `FlatInfo`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+Future<CollectionDownload> downloadCollection(
+        {required String url, required CollectionOptions options}) =>
+    RustLib.instance.api.crateApiCollectionDownloadDownloadCollection(
+        url: url, options: options);
+
+Stream<CollectionDownload> watchCollectionDownloads() =>
+    RustLib.instance.api.crateApiCollectionDownloadWatchCollectionDownloads();
+
+Future<List<CollectionDownload>> listCollectionDownloads() =>
+    RustLib.instance.api.crateApiCollectionDownloadListCollectionDownloads();
+
+Future<int> clearFinishedCollections() =>
+    RustLib.instance.api.crateApiCollectionDownloadClearFinishedCollections();
+
+class CollectionDownload {
+  final BigInt id;
+  final String url;
+  final String title;
+  final String folder;
+  final List<CollectionTrack> tracks;
+  final String? playlist;
+  final bool finished;
+
+  const CollectionDownload({
+    required this.id,
+    required this.url,
+    required this.title,
+    required this.folder,
+    required this.tracks,
+    this.playlist,
+    required this.finished,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      url.hashCode ^
+      title.hashCode ^
+      folder.hashCode ^
+      tracks.hashCode ^
+      playlist.hashCode ^
+      finished.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CollectionDownload &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          url == other.url &&
+          title == other.title &&
+          folder == other.folder &&
+          tracks == other.tracks &&
+          playlist == other.playlist &&
+          finished == other.finished;
+}
+
+class CollectionOptions {
+  final String? flags;
+  final String? folder;
+  final bool createPlaylist;
+  final String? playlistName;
+  final bool postProcess;
+
+  const CollectionOptions({
+    this.flags,
+    this.folder,
+    required this.createPlaylist,
+    this.playlistName,
+    required this.postProcess,
+  });
+
+  static Future<CollectionOptions> default_() =>
+      RustLib.instance.api.crateApiCollectionDownloadCollectionOptionsDefault();
+
+  @override
+  int get hashCode =>
+      flags.hashCode ^
+      folder.hashCode ^
+      createPlaylist.hashCode ^
+      playlistName.hashCode ^
+      postProcess.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CollectionOptions &&
+          runtimeType == other.runtimeType &&
+          flags == other.flags &&
+          folder == other.folder &&
+          createPlaylist == other.createPlaylist &&
+          playlistName == other.playlistName &&
+          postProcess == other.postProcess;
+}
+
+class CollectionTrack {
+  final int position;
+  final String? title;
+  final BigInt jobId;
+  final String? path;
+  final String? error;
+
+  const CollectionTrack({
+    required this.position,
+    this.title,
+    required this.jobId,
+    this.path,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      position.hashCode ^
+      title.hashCode ^
+      jobId.hashCode ^
+      path.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CollectionTrack &&
+          runtimeType == other.runtimeType &&
+          position == other.position &&
+          title == other.title &&
+          jobId == other.jobId &&
+          path == other.path &&
+          error == other.error;
+}
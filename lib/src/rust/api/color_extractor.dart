@@ -6,9 +6,227 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `calculate_color_score`, `quantize_color`, `rgb_to_argb`, `rgb_to_hsl`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ColorScore`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`
+// These functions are ignored because they are not marked as `pub`: `argb_channels`, `argb_to_lab`, `best_text_color`, `calculate_color_score`, `cluster_colors`, `delta_e`, `ensure_contrast`, `lab_f`, `lab_f_inv`, `lab_to_argb`, `lab_to_lch`, `lch_to_argb`, `linear_to_srgb`, `pick_role`, `quantize_color`, `relative_luminance`, `rgb_to_argb`, `rgb_to_hsl`, `srgb_to_linear`, `to_swatch`, `tone`, `tone_to_y`, `y_to_tone`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ColorScore`, `Lab`, `RoleTarget`, `TonalPalette`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 
 Future<int?> getDominantColor({required List<int> data}) =>
     RustLib.instance.api.crateApiColorExtractorGetDominantColor(data: data);
+
+Future<double> contrastRatio({required int a, required int b}) =>
+    RustLib.instance.api.crateApiColorExtractorContrastRatio(a: a, b: b);
+
+Future<Palette?> extractPalette({required List<int> data, required int n}) =>
+    RustLib.instance.api.crateApiColorExtractorExtractPalette(data: data, n: n);
+
+Future<MaterialScheme> generateColorScheme(
+        {required int seed, required bool dark}) =>
+    RustLib.instance.api
+        .crateApiColorExtractorGenerateColorScheme(seed: seed, dark: dark);
+
+Future<MaterialScheme> schemeFromArt(
+        {required List<int> data, required bool dark}) =>
+    RustLib.instance.api
+        .crateApiColorExtractorSchemeFromArt(data: data, dark: dark);
+
+class MaterialScheme {
+  final bool isDark;
+  final int seed;
+  final int primary;
+  final int onPrimary;
+  final int primaryContainer;
+  final int onPrimaryContainer;
+  final int secondary;
+  final int onSecondary;
+  final int secondaryContainer;
+  final int onSecondaryContainer;
+  final int tertiary;
+  final int onTertiary;
+  final int tertiaryContainer;
+  final int onTertiaryContainer;
+  final int error;
+  final int onError;
+  final int errorContainer;
+  final int onErrorContainer;
+  final int background;
+  final int onBackground;
+  final int surface;
+  final int onSurface;
+  final int surfaceVariant;
+  final int onSurfaceVariant;
+  final int surfaceContainer;
+  final int outline;
+  final int outlineVariant;
+  final int inverseSurface;
+  final int inverseOnSurface;
+  final int inversePrimary;
+
+  const MaterialScheme({
+    required this.isDark,
+    required this.seed,
+    required this.primary,
+    required this.onPrimary,
+    required this.primaryContainer,
+    required this.onPrimaryContainer,
+    required this.secondary,
+    required this.onSecondary,
+    required this.secondaryContainer,
+    required this.onSecondaryContainer,
+    required this.tertiary,
+    required this.onTertiary,
+    required this.tertiaryContainer,
+    required this.onTertiaryContainer,
+    required this.error,
+    required this.onError,
+    required this.errorContainer,
+    required this.onErrorContainer,
+    required this.background,
+    required this.onBackground,
+    required this.surface,
+    required this.onSurface,
+    required this.surfaceVariant,
+    required this.onSurfaceVariant,
+    required this.surfaceContainer,
+    required this.outline,
+    required this.outlineVariant,
+    required this.inverseSurface,
+    required this.inverseOnSurface,
+    required this.inversePrimary,
+  });
+
+  @override
+  int get hashCode =>
+      isDark.hashCode ^
+      seed.hashCode ^
+      primary.hashCode ^
+      onPrimary.hashCode ^
+      primaryContainer.hashCode ^
+      onPrimaryContainer.hashCode ^
+      secondary.hashCode ^
+      onSecondary.hashCode ^
+      secondaryContainer.hashCode ^
+      onSecondaryContainer.hashCode ^
+      tertiary.hashCode ^
+      onTertiary.hashCode ^
+      tertiaryContainer.hashCode ^
+      onTertiaryContainer.hashCode ^
+      error.hashCode ^
+      onError.hashCode ^
+      errorContainer.hashCode ^
+      onErrorContainer.hashCode ^
+      background.hashCode ^
+      onBackground.hashCode ^
+      surface.hashCode ^
+      onSurface.hashCode ^
+      surfaceVariant.hashCode ^
+      onSurfaceVariant.hashCode ^
+      surfaceContainer.hashCode ^
+      outline.hashCode ^
+      outlineVariant.hashCode ^
+      inverseSurface.hashCode ^
+      inverseOnSurface.hashCode ^
+      inversePrimary.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MaterialScheme &&
+          runtimeType == other.runtimeType &&
+          isDark == other.isDark &&
+          seed == other.seed &&
+          primary == other.primary &&
+          onPrimary == other.onPrimary &&
+          primaryContainer == other.primaryContainer &&
+          onPrimaryContainer == other.onPrimaryContainer &&
+          secondary == other.secondary &&
+          onSecondary == other.onSecondary &&
+          secondaryContainer == other.secondaryContainer &&
+          onSecondaryContainer == other.onSecondaryContainer &&
+          tertiary == other.tertiary &&
+          onTertiary == other.onTertiary &&
+          tertiaryContainer == other.tertiaryContainer &&
+          onTertiaryContainer == other.onTertiaryContainer &&
+          error == other.error &&
+          onError == other.onError &&
+          errorContainer == other.errorContainer &&
+          onErrorContainer == other.onErrorContainer &&
+          background == other.background &&
+          onBackground == other.onBackground &&
+          surface == other.surface &&
+          onSurface == other.onSurface &&
+          surfaceVariant == other.surfaceVariant &&
+          onSurfaceVariant == other.onSurfaceVariant &&
+          surfaceContainer == other.surfaceContainer &&
+          outline == other.outline &&
+          outlineVariant == other.outlineVariant &&
+          inverseSurface == other.inverseSurface &&
+          inverseOnSurface == other.inverseOnSurface &&
+          inversePrimary == other.inversePrimary;
+}
+
+class Palette {
+  final List<Swatch> swatches;
+  final Swatch? vibrant;
+  final Swatch? lightVibrant;
+  final Swatch? darkVibrant;
+  final Swatch? muted;
+  final Swatch? lightMuted;
+  final Swatch? darkMuted;
+
+  const Palette({
+    required this.swatches,
+    this.vibrant,
+    this.lightVibrant,
+    this.darkVibrant,
+    this.muted,
+    this.lightMuted,
+    this.darkMuted,
+  });
+
+  @override
+  int get hashCode =>
+      swatches.hashCode ^
+      vibrant.hashCode ^
+      lightVibrant.hashCode ^
+      darkVibrant.hashCode ^
+      muted.hashCode ^
+      lightMuted.hashCode ^
+      darkMuted.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Palette &&
+          runtimeType == other.runtimeType &&
+          swatches == other.swatches &&
+          vibrant == other.vibrant &&
+          lightVibrant == other.lightVibrant &&
+          darkVibrant == other.darkVibrant &&
+          muted == other.muted &&
+          lightMuted == other.lightMuted &&
+          darkMuted == other.darkMuted;
+}
+
+class Swatch {
+  final int color;
+  final int population;
+  final int textColor;
+
+  const Swatch({
+    required this.color,
+    required this.population,
+    required this.textColor,
+  });
+
+  @override
+  int get hashCode => color.hashCode ^ population.hashCode ^ textColor.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Swatch &&
+          runtimeType == other.runtimeType &&
+          color == other.color &&
+          population == other.population &&
+          textColor == other.textColor;
+}
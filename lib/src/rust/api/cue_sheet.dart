@@ -0,0 +1,85 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `names_file`, `parse_cue_time`, `push_track`, `track_song`, `words`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `VirtualTrack`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `cue_for_audio`, `parse_cue`, `read_cue`, `reject_virtual_track`, `resolve_virtual_track`, `songs_in_file`, `split_virtual_track`, `virtual_track_metadata`, `virtual_track_path`
+
+class CueSheet {
+  final String? title;
+  final String? performer;
+  final String? genre;
+  final String? date;
+  final List<CueTrack> tracks;
+
+  const CueSheet({
+    this.title,
+    this.performer,
+    this.genre,
+    this.date,
+    required this.tracks,
+  });
+
+  static Future<CueSheet> default_() =>
+      RustLib.instance.api.crateApiCueSheetCueSheetDefault();
+
+  @override
+  int get hashCode =>
+      title.hashCode ^
+      performer.hashCode ^
+      genre.hashCode ^
+      date.hashCode ^
+      tracks.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CueSheet &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          performer == other.performer &&
+          genre == other.genre &&
+          date == other.date &&
+          tracks == other.tracks;
+}
+
+class CueTrack {
+  final int number;
+  final String? title;
+  final String? performer;
+  final String file;
+  final double startSecs;
+
+  const CueTrack({
+    required this.number,
+    this.title,
+    this.performer,
+    required this.file,
+    required this.startSecs,
+  });
+
+  @override
+  int get hashCode =>
+      number.hashCode ^
+      title.hashCode ^
+      performer.hashCode ^
+      file.hashCode ^
+      startSecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CueTrack &&
+          runtimeType == other.runtimeType &&
+          number == other.number &&
+          title == other.title &&
+          performer == other.performer &&
+          file == other.file &&
+          startSecs == other.startSecs;
+}
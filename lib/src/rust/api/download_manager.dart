@@ -0,0 +1,166 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `apply_progress`, `build_command`, `emit`, `enqueue`, `finish_job`, `forward_lines`, `is_finished`, `parse_flags`, `run_ytdlp`, `start_queued`, `update_job`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DownloadManager`, `FlatResult`, `FlatThumbnail`, `Job`, `JobSpec`, `YtdlpOutput`, `YtdlpProgress`, `YtdlpProgressLine`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `from`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `forget_download`, `ytdlp_command`
+
+Future<BigInt> queueDownload({required String query, String? flags}) =>
+    RustLib.instance.api
+        .crateApiDownloadManagerQueueDownload(query: query, flags: flags);
+
+Future<BigInt> queueUrlDownload({required String url, String? flags}) =>
+    RustLib.instance.api
+        .crateApiDownloadManagerQueueUrlDownload(url: url, flags: flags);
+
+Future<List<RemoteCandidate>> searchRemote(
+        {required String query, required int count}) =>
+    RustLib.instance.api
+        .crateApiDownloadManagerSearchRemote(query: query, count: count);
+
+Future<BigInt> downloadById({required String id, String? flags}) =>
+    RustLib.instance.api
+        .crateApiDownloadManagerDownloadById(id: id, flags: flags);
+
+Future<List<String>> waitForDownload({required BigInt id}) =>
+    RustLib.instance.api.crateApiDownloadManagerWaitForDownload(id: id);
+
+Stream<DownloadJob> watchDownloads() =>
+    RustLib.instance.api.crateApiDownloadManagerWatchDownloads();
+
+Future<List<DownloadJob>> listDownloads() =>
+    RustLib.instance.api.crateApiDownloadManagerListDownloads();
+
+Future<DownloadJob?> getDownload({required BigInt id}) =>
+    RustLib.instance.api.crateApiDownloadManagerGetDownload(id: id);
+
+Future<bool> cancelDownloadJob({required BigInt id}) =>
+    RustLib.instance.api.crateApiDownloadManagerCancelDownloadJob(id: id);
+
+Future<void> cancelAllDownloads() =>
+    RustLib.instance.api.crateApiDownloadManagerCancelAllDownloads();
+
+Future<int> clearFinishedDownloads() =>
+    RustLib.instance.api.crateApiDownloadManagerClearFinishedDownloads();
+
+Future<void> setMaxConcurrentDownloads({required int max}) =>
+    RustLib.instance.api
+        .crateApiDownloadManagerSetMaxConcurrentDownloads(max: max);
+
+class DownloadJob {
+  final BigInt id;
+  final String query;
+  final DownloadState state;
+  final String? title;
+  final double? progress;
+  final BigInt downloadedBytes;
+  final BigInt? totalBytes;
+  final double? speed;
+  final BigInt? etaSecs;
+  final String tempDir;
+  final List<String> files;
+  final String? error;
+
+  const DownloadJob({
+    required this.id,
+    required this.query,
+    required this.state,
+    this.title,
+    this.progress,
+    required this.downloadedBytes,
+    this.totalBytes,
+    this.speed,
+    this.etaSecs,
+    required this.tempDir,
+    required this.files,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      query.hashCode ^
+      state.hashCode ^
+      title.hashCode ^
+      progress.hashCode ^
+      downloadedBytes.hashCode ^
+      totalBytes.hashCode ^
+      speed.hashCode ^
+      etaSecs.hashCode ^
+      tempDir.hashCode ^
+      files.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DownloadJob &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          query == other.query &&
+          state == other.state &&
+          title == other.title &&
+          progress == other.progress &&
+          downloadedBytes == other.downloadedBytes &&
+          totalBytes == other.totalBytes &&
+          speed == other.speed &&
+          etaSecs == other.etaSecs &&
+          tempDir == other.tempDir &&
+          files == other.files &&
+          error == other.error;
+}
+
+enum DownloadState {
+  queued,
+  downloading,
+  processing,
+  completed,
+  failed,
+  cancelled,
+  ;
+}
+
+class RemoteCandidate {
+  final String id;
+  final String title;
+  final String? uploader;
+  final double? durationSecs;
+  final String? thumbnailUrl;
+  final String url;
+
+  const RemoteCandidate({
+    required this.id,
+    required this.title,
+    this.uploader,
+    this.durationSecs,
+    this.thumbnailUrl,
+    required this.url,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      title.hashCode ^
+      uploader.hashCode ^
+      durationSecs.hashCode ^
+      thumbnailUrl.hashCode ^
+      url.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RemoteCandidate &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          title == other.title &&
+          uploader == other.uploader &&
+          durationSecs == other.durationSecs &&
+          thumbnailUrl == other.thumbnailUrl &&
+          url == other.url;
+}
@@ -0,0 +1,143 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'music_handler.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'replay_gain.dart';
+
+// These functions are ignored because they are not marked as `pub`: `announce_library_addition`, `clean_tags`, `compile_rules`, `config_path`, `fold`, `identify`, `run`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `run_in_place`
+
+Future<PipelineConfig> getPipelineConfig() =>
+    RustLib.instance.api.crateApiDownloadPipelineGetPipelineConfig();
+
+Future<void> setPipelineConfig({required PipelineConfig config}) =>
+    RustLib.instance.api
+        .crateApiDownloadPipelineSetPipelineConfig(config: config);
+
+Future<PipelineResult> processDownload(
+        {required String path, PipelineConfig? config}) =>
+    RustLib.instance.api
+        .crateApiDownloadPipelineProcessDownload(path: path, config: config);
+
+Future<List<PipelineResult>> downloadAndImport(
+        {required String query, String? flags, PipelineConfig? config}) =>
+    RustLib.instance.api.crateApiDownloadPipelineDownloadAndImport(
+        query: query, flags: flags, config: config);
+
+Stream<SongMetadata> watchLibraryAdditions() =>
+    RustLib.instance.api.crateApiDownloadPipelineWatchLibraryAdditions();
+
+class PipelineConfig {
+  final bool cleanTitles;
+  final List<TitleRule> titleRules;
+  final bool splitArtistTitle;
+  final bool identify;
+  final double minIdentifyScore;
+  final bool replayGain;
+  final bool import;
+  final String template;
+
+  const PipelineConfig({
+    required this.cleanTitles,
+    required this.titleRules,
+    required this.splitArtistTitle,
+    required this.identify,
+    required this.minIdentifyScore,
+    required this.replayGain,
+    required this.import,
+    required this.template,
+  });
+
+  static Future<PipelineConfig> default_() =>
+      RustLib.instance.api.crateApiDownloadPipelinePipelineConfigDefault();
+
+  @override
+  int get hashCode =>
+      cleanTitles.hashCode ^
+      titleRules.hashCode ^
+      splitArtistTitle.hashCode ^
+      identify.hashCode ^
+      minIdentifyScore.hashCode ^
+      replayGain.hashCode ^
+      import.hashCode ^
+      template.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PipelineConfig &&
+          runtimeType == other.runtimeType &&
+          cleanTitles == other.cleanTitles &&
+          titleRules == other.titleRules &&
+          splitArtistTitle == other.splitArtistTitle &&
+          identify == other.identify &&
+          minIdentifyScore == other.minIdentifyScore &&
+          replayGain == other.replayGain &&
+          import == other.import &&
+          template == other.template;
+}
+
+class PipelineResult {
+  final String originalPath;
+  final String path;
+  final SongMetadata? metadata;
+  final bool identified;
+  final ReplayGain? replayGain;
+  final List<String> warnings;
+
+  const PipelineResult({
+    required this.originalPath,
+    required this.path,
+    this.metadata,
+    required this.identified,
+    this.replayGain,
+    required this.warnings,
+  });
+
+  @override
+  int get hashCode =>
+      originalPath.hashCode ^
+      path.hashCode ^
+      metadata.hashCode ^
+      identified.hashCode ^
+      replayGain.hashCode ^
+      warnings.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PipelineResult &&
+          runtimeType == other.runtimeType &&
+          originalPath == other.originalPath &&
+          path == other.path &&
+          metadata == other.metadata &&
+          identified == other.identified &&
+          replayGain == other.replayGain &&
+          warnings == other.warnings;
+}
+
+class TitleRule {
+  final String pattern;
+  final String replacement;
+
+  const TitleRule({
+    required this.pattern,
+    required this.replacement,
+  });
+
+  @override
+  int get hashCode => pattern.hashCode ^ replacement.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TitleRule &&
+          runtimeType == other.runtimeType &&
+          pattern == other.pattern &&
+          replacement == other.replacement;
+}
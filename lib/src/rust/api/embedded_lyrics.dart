@@ -0,0 +1,21 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `container`, `read_flac`, `read_id3`, `read_mp4`, `sylt_to_lrc`, `write_flac`, `write_id3`, `write_mp4`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Container`
+
+Future<String?> readEmbeddedLyrics({required String path}) =>
+    RustLib.instance.api.crateApiEmbeddedLyricsReadEmbeddedLyrics(path: path);
+
+Future<void> writeEmbeddedLyrics(
+        {required String path, required String lyrics}) =>
+    RustLib.instance.api
+        .crateApiEmbeddedLyricsWriteEmbeddedLyrics(path: path, lyrics: lyrics);
+
+Future<void> removeEmbeddedLyrics({required String path}) =>
+    RustLib.instance.api.crateApiEmbeddedLyricsRemoveEmbeddedLyrics(path: path);
@@ -0,0 +1,87 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `content_hash`, `find_root`, `get_fingerprint_cache_dir`, `probe_quality`, `quality_rank`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CachedFingerprint`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `cached_fingerprint`
+
+Future<bool> clearFingerprintCache() =>
+    RustLib.instance.api.crateApiFingerprintClearFingerprintCache();
+
+Future<List<DuplicateGroup>> findDuplicates({required String musicFolder}) =>
+    RustLib.instance.api
+        .crateApiFingerprintFindDuplicates(musicFolder: musicFolder);
+
+class DuplicateFile {
+  final String path;
+  final String format;
+  final BigInt sizeBytes;
+  final double duration;
+  final int bitrateKbps;
+  final int? sampleRate;
+  final int? bitsPerSample;
+  final bool lossless;
+
+  const DuplicateFile({
+    required this.path,
+    required this.format,
+    required this.sizeBytes,
+    required this.duration,
+    required this.bitrateKbps,
+    this.sampleRate,
+    this.bitsPerSample,
+    required this.lossless,
+  });
+
+  @override
+  int get hashCode =>
+      path.hashCode ^
+      format.hashCode ^
+      sizeBytes.hashCode ^
+      duration.hashCode ^
+      bitrateKbps.hashCode ^
+      sampleRate.hashCode ^
+      bitsPerSample.hashCode ^
+      lossless.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DuplicateFile &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          format == other.format &&
+          sizeBytes == other.sizeBytes &&
+          duration == other.duration &&
+          bitrateKbps == other.bitrateKbps &&
+          sampleRate == other.sampleRate &&
+          bitsPerSample == other.bitsPerSample &&
+          lossless == other.lossless;
+}
+
+class DuplicateGroup {
+  final List<DuplicateFile> files;
+  final double similarity;
+
+  const DuplicateGroup({
+    required this.files,
+    required this.similarity,
+  });
+
+  @override
+  int get hashCode => files.hashCode ^ similarity.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DuplicateGroup &&
+          runtimeType == other.runtimeType &&
+          files == other.files &&
+          similarity == other.similarity;
+}
@@ -0,0 +1,68 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `announce_title`, `attribute`, `connect`, `consumed`, `decode_connection`, `demux_ts`, `fetch_segment`, `fetch_text`, `header`, `hint_for`, `parse_hls`, `parse_stream_title`, `playlist_target`, `publish`, `push`, `read_body`, `read_metadata`, `refill`, `refresh`, `stream_worker`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `HlsReader`, `HttpStreamSource`, `IcyReader`, `LockedReader`, `Playlist`, `StreamBuffer`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `channels`, `clone`, `current_span_len`, `drop`, `fmt`, `next`, `read`, `read`, `read`, `sample_rate`, `total_duration`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `clear_stream_metadata`, `is_stream_url`, `open_stream`, `stream_song`
+
+Future<StreamMetadata?> getStreamMetadata() =>
+    RustLib.instance.api.crateApiHttpStreamGetStreamMetadata();
+
+Stream<StreamMetadata> watchStreamMetadata() =>
+    RustLib.instance.api.crateApiHttpStreamWatchStreamMetadata();
+
+class StreamMetadata {
+  final String url;
+  final String? stationName;
+  final String? genre;
+  final int? bitrateKbps;
+  final String? nowPlaying;
+  final String? artist;
+  final String? title;
+  final bool reconnecting;
+
+  const StreamMetadata({
+    required this.url,
+    this.stationName,
+    this.genre,
+    this.bitrateKbps,
+    this.nowPlaying,
+    this.artist,
+    this.title,
+    required this.reconnecting,
+  });
+
+  static Future<StreamMetadata> default_() =>
+      RustLib.instance.api.crateApiHttpStreamStreamMetadataDefault();
+
+  @override
+  int get hashCode =>
+      url.hashCode ^
+      stationName.hashCode ^
+      genre.hashCode ^
+      bitrateKbps.hashCode ^
+      nowPlaying.hashCode ^
+      artist.hashCode ^
+      title.hashCode ^
+      reconnecting.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is StreamMetadata &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          stationName == other.stationName &&
+          genre == other.genre &&
+          bitrateKbps == other.bitrateKbps &&
+          nowPlaying == other.nowPlaying &&
+          artist == other.artist &&
+          title == other.title &&
+          reconnecting == other.reconnecting;
+}
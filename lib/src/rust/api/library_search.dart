@@ -0,0 +1,70 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'music_handler.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `all`, `allowed_typos`, `build`, `edit_distance`, `evaluate`, `fold_char`, `from_name`, `has_words`, `index`, `parse`, `parse_and`, `parse_duration`, `parse_or`, `parse_range`, `parse_unary`, `phrase`, `ranked`, `term`, `tokenize`, `word_score`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Doc`, `Field`, `Node`, `Parser`, `Range`, `SearchIndex`, `Token`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `hash`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `fold`, `index_library`, `indexed_library`, `indexed_song`, `is_library_indexed`, `words`
+
+Future<int> rebuildSearchIndex() =>
+    RustLib.instance.api.crateApiLibrarySearchRebuildSearchIndex();
+
+Future<SearchPage> searchLibrary(
+        {required String query, required int page, int? pageSize}) =>
+    RustLib.instance.api.crateApiLibrarySearchSearchLibrary(
+        query: query, page: page, pageSize: pageSize);
+
+class SearchHit {
+  final SongMetadata song;
+  final double score;
+
+  const SearchHit({
+    required this.song,
+    required this.score,
+  });
+
+  @override
+  int get hashCode => song.hashCode ^ score.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SearchHit &&
+          runtimeType == other.runtimeType &&
+          song == other.song &&
+          score == other.score;
+}
+
+class SearchPage {
+  final int total;
+  final int page;
+  final int pageSize;
+  final List<SearchHit> hits;
+
+  const SearchPage({
+    required this.total,
+    required this.page,
+    required this.pageSize,
+    required this.hits,
+  });
+
+  @override
+  int get hashCode =>
+      total.hashCode ^ page.hashCode ^ pageSize.hashCode ^ hits.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SearchPage &&
+          runtimeType == other.runtimeType &&
+          total == other.total &&
+          page == other.page &&
+          pageSize == other.pageSize &&
+          hits == other.hits;
+}
@@ -0,0 +1,64 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `base_url`, `get_json`, `search_rs`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `LrclibRecord`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `fmt`, `from`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `best_lyrics`, `fetch_lrclib`
+
+Future<List<LrclibLyrics>> searchLrclib(
+        {required String title, required String artist}) =>
+    RustLib.instance.api
+        .crateApiLrclibSearchLrclib(title: title, artist: artist);
+
+class LrclibLyrics {
+  final PlatformInt64 id;
+  final String title;
+  final String artist;
+  final String album;
+  final double duration;
+  final bool instrumental;
+  final String? plainLyrics;
+  final String? syncedLyrics;
+
+  const LrclibLyrics({
+    required this.id,
+    required this.title,
+    required this.artist,
+    required this.album,
+    required this.duration,
+    required this.instrumental,
+    this.plainLyrics,
+    this.syncedLyrics,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      title.hashCode ^
+      artist.hashCode ^
+      album.hashCode ^
+      duration.hashCode ^
+      instrumental.hashCode ^
+      plainLyrics.hashCode ^
+      syncedLyrics.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LrclibLyrics &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          title == other.title &&
+          artist == other.artist &&
+          album == other.album &&
+          duration == other.duration &&
+          instrumental == other.instrumental &&
+          plainLyrics == other.plainLyrics &&
+          syncedLyrics == other.syncedLyrics;
+}
@@ -0,0 +1,39 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'synced_lyrics.dart';
+
+// These functions are ignored because they are not marked as `pub`: `default`, `line_mut`, `move_line`, `offset_file`, `playback_ms`, `save_offset`, `with_active`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `TrackOffset`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `saved_offset`
+
+Future<LrcDocument> shiftLyrics({required PlatformInt64 deltaMs}) =>
+    RustLib.instance.api.crateApiLyricsEditorShiftLyrics(deltaMs: deltaMs);
+
+Future<LrcDocument> setLyricLineTime(
+        {required int lineIndex, required BigInt timeMs}) =>
+    RustLib.instance.api.crateApiLyricsEditorSetLyricLineTime(
+        lineIndex: lineIndex, timeMs: timeMs);
+
+Future<LrcDocument> syncLyricLine({required int lineIndex}) =>
+    RustLib.instance.api
+        .crateApiLyricsEditorSyncLyricLine(lineIndex: lineIndex);
+
+Future<void> startTapSync({required int fromLine}) =>
+    RustLib.instance.api.crateApiLyricsEditorStartTapSync(fromLine: fromLine);
+
+Future<int?> tapSync() => RustLib.instance.api.crateApiLyricsEditorTapSync();
+
+Future<LrcDocument> setLyricsOffset({required PlatformInt64 offsetMs}) =>
+    RustLib.instance.api
+        .crateApiLyricsEditorSetLyricsOffset(offsetMs: offsetMs);
+
+Future<PlatformInt64> getLyricsOffset({required String lrcPath}) =>
+    RustLib.instance.api.crateApiLyricsEditorGetLyricsOffset(lrcPath: lrcPath);
+
+Future<String> saveSyncedLyrics({String? path}) =>
+    RustLib.instance.api.crateApiLyricsEditorSaveSyncedLyrics(path: path);
@@ -0,0 +1,70 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'music_handler.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `insert`, `new`, `parse_lrc`, `parse_query`, `remove`, `search`, `song`, `sync`, `term_lines`, `with_index`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `LyricLine`, `LyricsDoc`, `LyricsIndex`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `find_indexed_lrc`, `search_indexed_lyrics`
+
+Future<int> refreshLyricsIndex({required String lyricsDir}) =>
+    RustLib.instance.api
+        .crateApiLyricsIndexRefreshLyricsIndex(lyricsDir: lyricsDir);
+
+Future<List<LyricSearchResult>> searchLyricsLines(
+        {required String lyricsDir, required String query, int? limit}) =>
+    RustLib.instance.api.crateApiLyricsIndexSearchLyricsLines(
+        lyricsDir: lyricsDir, query: query, limit: limit);
+
+class LyricMatchLine {
+  final int line;
+  final BigInt? timestampMs;
+  final String text;
+
+  const LyricMatchLine({
+    required this.line,
+    this.timestampMs,
+    required this.text,
+  });
+
+  @override
+  int get hashCode => line.hashCode ^ timestampMs.hashCode ^ text.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LyricMatchLine &&
+          runtimeType == other.runtimeType &&
+          line == other.line &&
+          timestampMs == other.timestampMs &&
+          text == other.text;
+}
+
+class LyricSearchResult {
+  final SongMetadata song;
+  final double score;
+  final List<LyricMatchLine> lines;
+
+  const LyricSearchResult({
+    required this.song,
+    required this.score,
+    required this.lines,
+  });
+
+  @override
+  int get hashCode => song.hashCode ^ score.hashCode ^ lines.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LyricSearchResult &&
+          runtimeType == other.runtimeType &&
+          song == other.song &&
+          score == other.score &&
+          lines == other.lines;
+}
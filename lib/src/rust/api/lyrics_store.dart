@@ -0,0 +1,77 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'music_handler.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'synced_lyrics.dart';
+
+// These functions are ignored because they are not marked as `pub`: `cache_file`, `find_cached`, `same_text`, `with_headers`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CacheProvider`, `EmbeddedProvider`, `LrclibProvider`, `SidecarProvider`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `fetch`, `fetch`, `fetch`, `fetch`, `source`, `source`, `source`, `source`
+
+Future<String> saveLyrics(
+        {required String lyricsDir,
+        required SongMetadata song,
+        required String content}) =>
+    RustLib.instance.api.crateApiLyricsStoreSaveLyrics(
+        lyricsDir: lyricsDir, song: song, content: content);
+
+Future<String?> findCachedLyrics(
+        {required String lyricsDir, required SongMetadata song}) =>
+    RustLib.instance.api
+        .crateApiLyricsStoreFindCachedLyrics(lyricsDir: lyricsDir, song: song);
+
+Future<bool> deleteCachedLyrics(
+        {required String lyricsDir, required SongMetadata song}) =>
+    RustLib.instance.api.crateApiLyricsStoreDeleteCachedLyrics(
+        lyricsDir: lyricsDir, song: song);
+
+Future<FoundLyrics?> getLyrics(
+        {required String lyricsDir,
+        required SongMetadata song,
+        required bool allowOnline}) =>
+    RustLib.instance.api.crateApiLyricsStoreGetLyrics(
+        lyricsDir: lyricsDir, song: song, allowOnline: allowOnline);
+
+class FoundLyrics {
+  final LyricsSource source;
+  final String content;
+  final LrcDocument document;
+  final String? cachePath;
+
+  const FoundLyrics({
+    required this.source,
+    required this.content,
+    required this.document,
+    this.cachePath,
+  });
+
+  @override
+  int get hashCode =>
+      source.hashCode ^
+      content.hashCode ^
+      document.hashCode ^
+      cachePath.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FoundLyrics &&
+          runtimeType == other.runtimeType &&
+          source == other.source &&
+          content == other.content &&
+          document == other.document &&
+          cachePath == other.cachePath;
+}
+
+enum LyricsSource {
+  cache,
+  sidecar,
+  embedded,
+  lrclib,
+  ;
+}
@@ -6,9 +6,10 @@
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `background_worker`, `crossfade`, `extract_metadata`, `fill_buffer`, `get_cached_mp3_path`, `get_mp3_cache_dir`, `get_position`, `new`, `new`, `parse_cd_path`, `parse_lrc_metadata`, `pause`, `play`, `position_monitor`, `resume`, `seek`, `seek`, `set_volume`, `stop`, `switch_to_preloaded`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AudioChunk`, `AudioPlayer`, `CDStreamSource`, `PlayerMessage`, `SafeSCDStream`, `StreamWrapper`, `StreamingBuffer`, `StreamingSource`, `Track`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `channels`, `channels`, `clone`, `clone`, `clone`, `current_span_len`, `current_span_len`, `drop`, `fmt`, `fmt`, `fmt`, `fmt`, `from_bytes_owned`, `next`, `next`, `sample_rate`, `sample_rate`, `to_bytes`, `total_duration`, `total_duration`
+// These functions are ignored because they are not marked as `pub`: `background_worker`, `continue_with`, `crossfade`, `decode_into`, `decode_span`, `default`, `fill_buffer`, `get_cached_mp3_path`, `get_mp3_cache_dir`, `get_position`, `new`, `new`, `parse_cd_path`, `pause`, `play`, `position_monitor`, `prepare_cover`, `resume`, `runs_into`, `seek`, `seek`, `set_volume`, `span_samples`, `stop`, `switch_to_preloaded`, `track_span`, `unlink`, `write_cover`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AudioChunk`, `AudioPlayer`, `CDStreamSource`, `Continuation`, `PlayerMessage`, `SafeSCDStream`, `StreamWrapper`, `StreamingBuffer`, `StreamingSource`, `Track`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `channels`, `channels`, `clone`, `clone`, `clone`, `clone`, `current_span_len`, `current_span_len`, `drop`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from_bytes_owned`, `next`, `next`, `sample_rate`, `sample_rate`, `to_bytes`, `total_duration`, `total_duration`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `extract_metadata`, `seek_when_buffered`
 
 Future<int> trackNum({required String device}) =>
     RustLib.instance.api.crateApiMusicHandlerTrackNum(device: device);
@@ -40,6 +41,14 @@ Future<List<SongMetadata>> scanMusicDirectory(
 Future<void> writeMeta({required SongMetadata meta}) =>
     RustLib.instance.api.crateApiMusicHandlerWriteMeta(meta: meta);
 
+Future<void> writeSongArt({required String path, Uint8List? art}) =>
+    RustLib.instance.api.crateApiMusicHandlerWriteSongArt(path: path, art: art);
+
+Future<List<ArtWriteResult>> writeAlbumArt(
+        {required List<String> paths, Uint8List? art}) =>
+    RustLib.instance.api
+        .crateApiMusicHandlerWriteAlbumArt(paths: paths, art: art);
+
 Future<bool> playSong({required String path}) =>
     RustLib.instance.api.crateApiMusicHandlerPlaySong(path: path);
 
@@ -73,6 +82,11 @@ Future<bool> skipToPrevious(
 Future<Uint8List?> getCachedAlbumArt({required String path}) =>
     RustLib.instance.api.crateApiMusicHandlerGetCachedAlbumArt(path: path);
 
+Future<Uint8List?> getCachedAlbumArtSized(
+        {required String path, required int size}) =>
+    RustLib.instance.api
+        .crateApiMusicHandlerGetCachedAlbumArtSized(path: path, size: size);
+
 Future<String?> getCurrentSongPath() =>
     RustLib.instance.api.crateApiMusicHandlerGetCurrentSongPath();
 
@@ -141,6 +155,27 @@ Future<bool> switchToPreloadedNow() =>
 Future<bool> restartPlayer() =>
     RustLib.instance.api.crateApiMusicHandlerRestartPlayer();
 
+class ArtWriteResult {
+  final String path;
+  final String? error;
+
+  const ArtWriteResult({
+    required this.path,
+    this.error,
+  });
+
+  @override
+  int get hashCode => path.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ArtWriteResult &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          error == other.error;
+}
+
 class PlayerState {
   final bool initialized;
 
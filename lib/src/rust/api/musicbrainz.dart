@@ -0,0 +1,80 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'acoustid.dart';
+import 'music_handler.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `collect_genres`, `endpoints`, `enrich_rs`, `fetch_cover_art`, `get_json`, `join_artist_credit`, `pick_release`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `MbArtistCredit`, `MbMedium`, `MbRecording`, `MbRelease`, `MbReleaseGroup`, `MbReleaseSummary`, `MbTag`, `MbTrack`, `MbTrackRecording`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+Future<EnrichedMetadata> enrichCandidate(
+        {required AcoustIdCandidate candidate}) =>
+    RustLib.instance.api
+        .crateApiMusicbrainzEnrichCandidate(candidate: candidate);
+
+Future<EnrichedMetadata?> lookupEnriched({required String path}) =>
+    RustLib.instance.api.crateApiMusicbrainzLookupEnriched(path: path);
+
+class EnrichedMetadata {
+  final SongMetadata metadata;
+  final String? recordingId;
+  final String? releaseId;
+  final String? albumArtist;
+  final int? trackNumber;
+  final int? totalTracks;
+  final int? discNumber;
+  final String? date;
+  final int? year;
+  final List<String> genres;
+  final String? isrc;
+
+  const EnrichedMetadata({
+    required this.metadata,
+    this.recordingId,
+    this.releaseId,
+    this.albumArtist,
+    this.trackNumber,
+    this.totalTracks,
+    this.discNumber,
+    this.date,
+    this.year,
+    required this.genres,
+    this.isrc,
+  });
+
+  @override
+  int get hashCode =>
+      metadata.hashCode ^
+      recordingId.hashCode ^
+      releaseId.hashCode ^
+      albumArtist.hashCode ^
+      trackNumber.hashCode ^
+      totalTracks.hashCode ^
+      discNumber.hashCode ^
+      date.hashCode ^
+      year.hashCode ^
+      genres.hashCode ^
+      isrc.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EnrichedMetadata &&
+          runtimeType == other.runtimeType &&
+          metadata == other.metadata &&
+          recordingId == other.recordingId &&
+          releaseId == other.releaseId &&
+          albumArtist == other.albumArtist &&
+          trackNumber == other.trackNumber &&
+          totalTracks == other.totalTracks &&
+          discNumber == other.discNumber &&
+          date == other.date &&
+          year == other.year &&
+          genres == other.genres &&
+          isrc == other.isrc;
+}
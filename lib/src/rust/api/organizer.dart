@@ -0,0 +1,97 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `build_plan`, `journal_path`, `load_journal`, `music_folder`, `parse_template`, `prune_empty_dirs`, `render`, `resolve_link`, `revert_entry`, `save_journal`, `track_fields`, `truncate_bytes`, `update_playlist_links`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `FieldValue`, `LinkChange`, `OrganizeEntry`, `Segment`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `move_file`, `sanitize_component`, `with_collision_suffix`
+
+Future<OrganizeResult> organizeFiles(
+        {required List<String> paths,
+        required String template,
+        String? destRoot,
+        required bool dryRun}) =>
+    RustLib.instance.api.crateApiOrganizerOrganizeFiles(
+        paths: paths, template: template, destRoot: destRoot, dryRun: dryRun);
+
+Future<int> rollbackLastOrganize() =>
+    RustLib.instance.api.crateApiOrganizerRollbackLastOrganize();
+
+class OrganizePlan {
+  final List<PlannedMove> moves;
+  final int unchanged;
+  final List<String> skipped;
+
+  const OrganizePlan({
+    required this.moves,
+    required this.unchanged,
+    required this.skipped,
+  });
+
+  @override
+  int get hashCode => moves.hashCode ^ unchanged.hashCode ^ skipped.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OrganizePlan &&
+          runtimeType == other.runtimeType &&
+          moves == other.moves &&
+          unchanged == other.unchanged &&
+          skipped == other.skipped;
+}
+
+class OrganizeResult {
+  final OrganizePlan plan;
+  final int linksUpdated;
+  final PlatformInt64? journalId;
+
+  const OrganizeResult({
+    required this.plan,
+    required this.linksUpdated,
+    this.journalId,
+  });
+
+  @override
+  int get hashCode =>
+      plan.hashCode ^ linksUpdated.hashCode ^ journalId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OrganizeResult &&
+          runtimeType == other.runtimeType &&
+          plan == other.plan &&
+          linksUpdated == other.linksUpdated &&
+          journalId == other.journalId;
+}
+
+class PlannedMove {
+  final String from;
+  final String to;
+  final bool renamedForCollision;
+
+  const PlannedMove({
+    required this.from,
+    required this.to,
+    required this.renamedForCollision,
+  });
+
+  @override
+  int get hashCode =>
+      from.hashCode ^ to.hashCode ^ renamedForCollision.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PlannedMove &&
+          runtimeType == other.runtimeType &&
+          from == other.from &&
+          to == other.to &&
+          renamedForCollision == other.renamedForCollision;
+}
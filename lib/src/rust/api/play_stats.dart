@@ -0,0 +1,44 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `save`, `schedule_save`, `stats_path`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `play_stats_snapshot`, `record_play`, `stat_key`
+
+Future<void> flushPlayStats() =>
+    RustLib.instance.api.crateApiPlayStatsFlushPlayStats();
+
+Future<PlayStat> getPlayStat({required String path}) =>
+    RustLib.instance.api.crateApiPlayStatsGetPlayStat(path: path);
+
+Future<bool> resetPlayStats() =>
+    RustLib.instance.api.crateApiPlayStatsResetPlayStats();
+
+class PlayStat {
+  final int playCount;
+  final PlatformInt64 lastPlayed;
+
+  const PlayStat({
+    required this.playCount,
+    required this.lastPlayed,
+  });
+
+  static Future<PlayStat> default_() =>
+      RustLib.instance.api.crateApiPlayStatsPlayStatDefault();
+
+  @override
+  int get hashCode => playCount.hashCode ^ lastPlayed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PlayStat &&
+          runtimeType == other.runtimeType &&
+          playCount == other.playCount &&
+          lastPlayed == other.lastPlayed;
+}
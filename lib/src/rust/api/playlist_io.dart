@@ -0,0 +1,140 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `best`, `build`, `decode`, `default`, `display_title`, `duration_fits`, `entry_path`, `find`, `from_path`, `link_target`, `normalize`, `parse_m3u`, `parse_pls`, `parse_seconds`, `parse_xspf`, `percent_decode`, `percent_encode_path`, `relative_path`, `render`, `split_display_title`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Entry`, `LibraryIndex`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+Future<PlaylistImportResult> importPlaylist(
+        {required String file, String? name}) =>
+    RustLib.instance.api
+        .crateApiPlaylistIoImportPlaylist(file: file, name: name);
+
+Future<PlaylistExportResult> exportPlaylist(
+        {required String name,
+        required String destination,
+        PlaylistFormat? format,
+        required bool relativePaths}) =>
+    RustLib.instance.api.crateApiPlaylistIoExportPlaylist(
+        name: name,
+        destination: destination,
+        format: format,
+        relativePaths: relativePaths);
+
+class MatchedEntry {
+  final String location;
+  final String path;
+
+  const MatchedEntry({
+    required this.location,
+    required this.path,
+  });
+
+  @override
+  int get hashCode => location.hashCode ^ path.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MatchedEntry &&
+          runtimeType == other.runtimeType &&
+          location == other.location &&
+          path == other.path;
+}
+
+class MissingEntry {
+  final String location;
+  final String? title;
+  final String? artist;
+
+  const MissingEntry({
+    required this.location,
+    this.title,
+    this.artist,
+  });
+
+  @override
+  int get hashCode => location.hashCode ^ title.hashCode ^ artist.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MissingEntry &&
+          runtimeType == other.runtimeType &&
+          location == other.location &&
+          title == other.title &&
+          artist == other.artist;
+}
+
+class PlaylistExportResult {
+  final int written;
+  final List<String> missing;
+
+  const PlaylistExportResult({
+    required this.written,
+    required this.missing,
+  });
+
+  @override
+  int get hashCode => written.hashCode ^ missing.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PlaylistExportResult &&
+          runtimeType == other.runtimeType &&
+          written == other.written &&
+          missing == other.missing;
+}
+
+enum PlaylistFormat {
+  m3u,
+  m3u8,
+  pls,
+  xspf,
+  ;
+}
+
+class PlaylistImportResult {
+  final String playlist;
+  final int totalEntries;
+  final int linked;
+  final List<MatchedEntry> matched;
+  final int duplicates;
+  final List<MissingEntry> missing;
+
+  const PlaylistImportResult({
+    required this.playlist,
+    required this.totalEntries,
+    required this.linked,
+    required this.matched,
+    required this.duplicates,
+    required this.missing,
+  });
+
+  @override
+  int get hashCode =>
+      playlist.hashCode ^
+      totalEntries.hashCode ^
+      linked.hashCode ^
+      matched.hashCode ^
+      duplicates.hashCode ^
+      missing.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PlaylistImportResult &&
+          runtimeType == other.runtimeType &&
+          playlist == other.playlist &&
+          totalEntries == other.totalEntries &&
+          linked == other.linked &&
+          matched == other.matched &&
+          duplicates == other.duplicates &&
+          missing == other.missing;
+}
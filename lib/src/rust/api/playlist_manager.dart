@@ -0,0 +1,138 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `default`, `editable_playlist`, `existing_playlist`, `insert_links`, `link_target`, `links_on_disk`, `load_order`, `save_order`, `targets`, `unique_link_name`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PlaylistOrder`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `add_links`, `linked_songs`, `order_playlist_songs`, `rename_link_entry`
+
+Future<List<PlaylistInfo>> listPlaylists() =>
+    RustLib.instance.api.crateApiPlaylistManagerListPlaylists();
+
+Future<void> createPlaylist({required String name}) =>
+    RustLib.instance.api.crateApiPlaylistManagerCreatePlaylist(name: name);
+
+Future<void> renamePlaylist({required String name, required String newName}) =>
+    RustLib.instance.api
+        .crateApiPlaylistManagerRenamePlaylist(name: name, newName: newName);
+
+Future<void> deletePlaylist({required String name}) =>
+    RustLib.instance.api.crateApiPlaylistManagerDeletePlaylist(name: name);
+
+Future<void> duplicatePlaylist(
+        {required String name, required String newName}) =>
+    RustLib.instance.api
+        .crateApiPlaylistManagerDuplicatePlaylist(name: name, newName: newName);
+
+Future<List<PlaylistEntry>> getPlaylistEntries({required String name}) =>
+    RustLib.instance.api.crateApiPlaylistManagerGetPlaylistEntries(name: name);
+
+Future<int> addToPlaylist(
+        {required String name,
+        required List<String> songPaths,
+        int? position,
+        required bool allowDuplicates}) =>
+    RustLib.instance.api.crateApiPlaylistManagerAddToPlaylist(
+        name: name,
+        songPaths: songPaths,
+        position: position,
+        allowDuplicates: allowDuplicates);
+
+Future<int> removeFromPlaylist(
+        {required String name, required List<int> indices}) =>
+    RustLib.instance.api.crateApiPlaylistManagerRemoveFromPlaylist(
+        name: name, indices: indices);
+
+Future<void> movePlaylistEntry(
+        {required String name, required int from, required int to}) =>
+    RustLib.instance.api.crateApiPlaylistManagerMovePlaylistEntry(
+        name: name, from: from, to: to);
+
+Future<void> reorderPlaylist(
+        {required String name, required List<int> newOrder}) =>
+    RustLib.instance.api
+        .crateApiPlaylistManagerReorderPlaylist(name: name, newOrder: newOrder);
+
+Future<int> mergePlaylists(
+        {required List<String> sources,
+        required String target,
+        required bool allowDuplicates}) =>
+    RustLib.instance.api.crateApiPlaylistManagerMergePlaylists(
+        sources: sources, target: target, allowDuplicates: allowDuplicates);
+
+Future<void> sortPlaylist(
+        {required String name,
+        required PlaylistSortKey key,
+        required bool descending}) =>
+    RustLib.instance.api.crateApiPlaylistManagerSortPlaylist(
+        name: name, key: key, descending: descending);
+
+Future<int> migratePlaylists() =>
+    RustLib.instance.api.crateApiPlaylistManagerMigratePlaylists();
+
+class PlaylistEntry {
+  final int index;
+  final String link;
+  final String path;
+  final bool missing;
+
+  const PlaylistEntry({
+    required this.index,
+    required this.link,
+    required this.path,
+    required this.missing,
+  });
+
+  @override
+  int get hashCode =>
+      index.hashCode ^ link.hashCode ^ path.hashCode ^ missing.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PlaylistEntry &&
+          runtimeType == other.runtimeType &&
+          index == other.index &&
+          link == other.link &&
+          path == other.path &&
+          missing == other.missing;
+}
+
+class PlaylistInfo {
+  final String name;
+  final int trackCount;
+  final bool isSmart;
+
+  const PlaylistInfo({
+    required this.name,
+    required this.trackCount,
+    required this.isSmart,
+  });
+
+  @override
+  int get hashCode => name.hashCode ^ trackCount.hashCode ^ isSmart.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PlaylistInfo &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          trackCount == other.trackCount &&
+          isSmart == other.isSmart;
+}
+
+enum PlaylistSortKey {
+  title,
+  artist,
+  album,
+  duration,
+  fileName,
+  path,
+  ;
+}
@@ -0,0 +1,95 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `atom_episode`, `atom_link`, `parse_atom`, `parse_date`, `parse_rss`, `psc_chapters`, `rss_episode`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Feed`, `JsonChapter`, `JsonChapters`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `parse_clock`, `parse_feed`, `parse_json_chapters`
+
+class FeedEpisode {
+  final String guid;
+  final String title;
+  final String? description;
+  final PlatformInt64? published;
+  final BigInt? durationSecs;
+  final String? enclosureUrl;
+  final String? enclosureType;
+  final BigInt? enclosureLength;
+  final String? imageUrl;
+  final List<PodcastChapter> chapters;
+  final String? chaptersUrl;
+
+  const FeedEpisode({
+    required this.guid,
+    required this.title,
+    this.description,
+    this.published,
+    this.durationSecs,
+    this.enclosureUrl,
+    this.enclosureType,
+    this.enclosureLength,
+    this.imageUrl,
+    required this.chapters,
+    this.chaptersUrl,
+  });
+
+  @override
+  int get hashCode =>
+      guid.hashCode ^
+      title.hashCode ^
+      description.hashCode ^
+      published.hashCode ^
+      durationSecs.hashCode ^
+      enclosureUrl.hashCode ^
+      enclosureType.hashCode ^
+      enclosureLength.hashCode ^
+      imageUrl.hashCode ^
+      chapters.hashCode ^
+      chaptersUrl.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FeedEpisode &&
+          runtimeType == other.runtimeType &&
+          guid == other.guid &&
+          title == other.title &&
+          description == other.description &&
+          published == other.published &&
+          durationSecs == other.durationSecs &&
+          enclosureUrl == other.enclosureUrl &&
+          enclosureType == other.enclosureType &&
+          enclosureLength == other.enclosureLength &&
+          imageUrl == other.imageUrl &&
+          chapters == other.chapters &&
+          chaptersUrl == other.chaptersUrl;
+}
+
+class PodcastChapter {
+  final double startSecs;
+  final String title;
+  final String? url;
+
+  const PodcastChapter({
+    required this.startSecs,
+    required this.title,
+    this.url,
+  });
+
+  @override
+  int get hashCode => startSecs.hashCode ^ title.hashCode ^ url.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PodcastChapter &&
+          runtimeType == other.runtimeType &&
+          startSecs == other.startSecs &&
+          title == other.title &&
+          url == other.url;
+}
@@ -0,0 +1,248 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'podcast_feed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `copy_enclosure`, `default`, `emit_download`, `episode_extension`, `episode_mut`, `fetch_feed`, `fetch_text`, `find_episode`, `is_http`, `load_subscriptions`, `local_path`, `merge_episodes`, `now`, `podcast_folder`, `podcast_mut`, `prune_downloads`, `resolve`, `run_schedule`, `save_subscriptions`, `subscriptions_path`, `track_position`, `with_subscriptions`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Subscriptions`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`
+
+Future<Podcast> subscribePodcast({required String feedUrl}) =>
+    RustLib.instance.api.crateApiPodcastsSubscribePodcast(feedUrl: feedUrl);
+
+Future<void> unsubscribePodcast(
+        {required BigInt id, required bool deleteDownloads}) =>
+    RustLib.instance.api.crateApiPodcastsUnsubscribePodcast(
+        id: id, deleteDownloads: deleteDownloads);
+
+Future<List<Podcast>> listPodcasts() =>
+    RustLib.instance.api.crateApiPodcastsListPodcasts();
+
+Future<Podcast> getPodcast({required BigInt id}) =>
+    RustLib.instance.api.crateApiPodcastsGetPodcast(id: id);
+
+Future<void> setPodcastAutoDownload(
+        {required BigInt id, required bool enabled, int? keepDownloaded}) =>
+    RustLib.instance.api.crateApiPodcastsSetPodcastAutoDownload(
+        id: id, enabled: enabled, keepDownloaded: keepDownloaded);
+
+Future<List<String>> refreshPodcast({required BigInt id}) =>
+    RustLib.instance.api.crateApiPodcastsRefreshPodcast(id: id);
+
+Future<List<PodcastRefresh>> refreshAllPodcasts() =>
+    RustLib.instance.api.crateApiPodcastsRefreshAllPodcasts();
+
+Future<String> downloadEpisode(
+        {required BigInt podcastId, required String guid}) =>
+    RustLib.instance.api
+        .crateApiPodcastsDownloadEpisode(podcastId: podcastId, guid: guid);
+
+Future<bool> cancelEpisodeDownload(
+        {required BigInt podcastId, required String guid}) =>
+    RustLib.instance.api.crateApiPodcastsCancelEpisodeDownload(
+        podcastId: podcastId, guid: guid);
+
+Future<void> deleteEpisodeDownload(
+        {required BigInt podcastId, required String guid}) =>
+    RustLib.instance.api.crateApiPodcastsDeleteEpisodeDownload(
+        podcastId: podcastId, guid: guid);
+
+Stream<EpisodeDownload> watchEpisodeDownloads() =>
+    RustLib.instance.api.crateApiPodcastsWatchEpisodeDownloads();
+
+Future<List<PodcastChapter>> getEpisodeChapters(
+        {required BigInt podcastId, required String guid}) =>
+    RustLib.instance.api
+        .crateApiPodcastsGetEpisodeChapters(podcastId: podcastId, guid: guid);
+
+Future<void> setEpisodePosition(
+        {required BigInt podcastId,
+        required String guid,
+        required double positionSecs}) =>
+    RustLib.instance.api.crateApiPodcastsSetEpisodePosition(
+        podcastId: podcastId, guid: guid, positionSecs: positionSecs);
+
+Future<double> getEpisodePosition(
+        {required BigInt podcastId, required String guid}) =>
+    RustLib.instance.api
+        .crateApiPodcastsGetEpisodePosition(podcastId: podcastId, guid: guid);
+
+Future<void> markEpisodePlayed(
+        {required BigInt podcastId,
+        required String guid,
+        required bool played}) =>
+    RustLib.instance.api.crateApiPodcastsMarkEpisodePlayed(
+        podcastId: podcastId, guid: guid, played: played);
+
+Future<bool> playEpisode({required BigInt podcastId, required String guid}) =>
+    RustLib.instance.api
+        .crateApiPodcastsPlayEpisode(podcastId: podcastId, guid: guid);
+
+Future<void> startPodcastSchedule({required int intervalMinutes}) =>
+    RustLib.instance.api
+        .crateApiPodcastsStartPodcastSchedule(intervalMinutes: intervalMinutes);
+
+Future<void> stopPodcastSchedule() =>
+    RustLib.instance.api.crateApiPodcastsStopPodcastSchedule();
+
+class EpisodeDownload {
+  final BigInt podcastId;
+  final String guid;
+  final BigInt downloadedBytes;
+  final BigInt? totalBytes;
+  final bool finished;
+  final String? path;
+  final String? error;
+
+  const EpisodeDownload({
+    required this.podcastId,
+    required this.guid,
+    required this.downloadedBytes,
+    this.totalBytes,
+    required this.finished,
+    this.path,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      podcastId.hashCode ^
+      guid.hashCode ^
+      downloadedBytes.hashCode ^
+      totalBytes.hashCode ^
+      finished.hashCode ^
+      path.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EpisodeDownload &&
+          runtimeType == other.runtimeType &&
+          podcastId == other.podcastId &&
+          guid == other.guid &&
+          downloadedBytes == other.downloadedBytes &&
+          totalBytes == other.totalBytes &&
+          finished == other.finished &&
+          path == other.path &&
+          error == other.error;
+}
+
+class Podcast {
+  final BigInt id;
+  final String feedUrl;
+  final String title;
+  final String? author;
+  final String? description;
+  final String? link;
+  final String? imageUrl;
+  final bool autoDownload;
+  final int? keepDownloaded;
+  final PlatformInt64? lastRefreshed;
+  final List<PodcastEpisode> episodes;
+
+  const Podcast({
+    required this.id,
+    required this.feedUrl,
+    required this.title,
+    this.author,
+    this.description,
+    this.link,
+    this.imageUrl,
+    required this.autoDownload,
+    this.keepDownloaded,
+    this.lastRefreshed,
+    required this.episodes,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      feedUrl.hashCode ^
+      title.hashCode ^
+      author.hashCode ^
+      description.hashCode ^
+      link.hashCode ^
+      imageUrl.hashCode ^
+      autoDownload.hashCode ^
+      keepDownloaded.hashCode ^
+      lastRefreshed.hashCode ^
+      episodes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Podcast &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          feedUrl == other.feedUrl &&
+          title == other.title &&
+          author == other.author &&
+          description == other.description &&
+          link == other.link &&
+          imageUrl == other.imageUrl &&
+          autoDownload == other.autoDownload &&
+          keepDownloaded == other.keepDownloaded &&
+          lastRefreshed == other.lastRefreshed &&
+          episodes == other.episodes;
+}
+
+class PodcastEpisode {
+  final FeedEpisode feed;
+  final String? downloadedPath;
+  final double positionSecs;
+  final bool played;
+
+  const PodcastEpisode({
+    required this.feed,
+    this.downloadedPath,
+    required this.positionSecs,
+    required this.played,
+  });
+
+  @override
+  int get hashCode =>
+      feed.hashCode ^
+      downloadedPath.hashCode ^
+      positionSecs.hashCode ^
+      played.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PodcastEpisode &&
+          runtimeType == other.runtimeType &&
+          feed == other.feed &&
+          downloadedPath == other.downloadedPath &&
+          positionSecs == other.positionSecs &&
+          played == other.played;
+}
+
+class PodcastRefresh {
+  final BigInt podcastId;
+  final List<String> newEpisodes;
+  final String? error;
+
+  const PodcastRefresh({
+    required this.podcastId,
+    required this.newEpisodes,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      podcastId.hashCode ^ newEpisodes.hashCode ^ error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PodcastRefresh &&
+          runtimeType == other.runtimeType &&
+          podcastId == other.podcastId &&
+          newEpisodes == other.newEpisodes &&
+          error == other.error;
+}
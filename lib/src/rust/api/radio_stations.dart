@@ -0,0 +1,59 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `read_station`, `station_file`, `stations_dir`, `write_station`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `station_name_for_url`
+
+Future<List<RadioStation>> listStations() =>
+    RustLib.instance.api.crateApiRadioStationsListStations();
+
+Future<RadioStation> getStation({required String name}) =>
+    RustLib.instance.api.crateApiRadioStationsGetStation(name: name);
+
+Future<void> addStation({required RadioStation station}) =>
+    RustLib.instance.api.crateApiRadioStationsAddStation(station: station);
+
+Future<void> updateStation(
+        {required String name, required RadioStation station}) =>
+    RustLib.instance.api
+        .crateApiRadioStationsUpdateStation(name: name, station: station);
+
+Future<void> deleteStation({required String name}) =>
+    RustLib.instance.api.crateApiRadioStationsDeleteStation(name: name);
+
+Future<bool> playStation({required String name}) =>
+    RustLib.instance.api.crateApiRadioStationsPlayStation(name: name);
+
+class RadioStation {
+  final String name;
+  final String url;
+  final String? genre;
+  final String? homepage;
+
+  const RadioStation({
+    required this.name,
+    required this.url,
+    this.genre,
+    this.homepage,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^ url.hashCode ^ genre.hashCode ^ homepage.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RadioStation &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          url == other.url &&
+          genre == other.genre &&
+          homepage == other.homepage;
+}
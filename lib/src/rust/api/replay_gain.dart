@@ -0,0 +1,43 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `analyse`, `default`, `integrated_loudness`, `k_weighting`, `process`, `to_lufs`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Biquad`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`
+
+Future<ReplayGain> computeReplayGain({required String path}) =>
+    RustLib.instance.api.crateApiReplayGainComputeReplayGain(path: path);
+
+Future<void> writeReplayGain(
+        {required String path, required ReplayGain gain}) =>
+    RustLib.instance.api
+        .crateApiReplayGainWriteReplayGain(path: path, gain: gain);
+
+class ReplayGain {
+  final double loudnessLufs;
+  final double gainDb;
+  final double peak;
+
+  const ReplayGain({
+    required this.loudnessLufs,
+    required this.gainDb,
+    required this.peak,
+  });
+
+  @override
+  int get hashCode => loudnessLufs.hashCode ^ gainDb.hashCode ^ peak.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReplayGain &&
+          runtimeType == other.runtimeType &&
+          loudnessLufs == other.loudnessLufs &&
+          gainDb == other.gainDb &&
+          peak == other.peak;
+}
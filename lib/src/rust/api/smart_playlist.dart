@@ -0,0 +1,32 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'music_handler.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `check_field`, `compare`, `default`, `evaluate`, `expect_keyword`, `extra_tags`, `get`, `library`, `matches`, `next`, `parse_and`, `parse_not`, `parse_or`, `parse_primary`, `parse_query`, `parse_query`, `peek`, `peek_keyword`, `read_rule`, `shuffle`, `tokenize`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CmpOp`, `Expr`, `ExtraTags`, `Facts`, `OrderBy`, `Parser`, `SmartQuery`, `SmartRule`, `Token`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `music_folder`, `playlist_dir`, `songs_for_dir`, `tag_year`
+
+Future<void> validateSmartQuery({required String query}) =>
+    RustLib.instance.api.crateApiSmartPlaylistValidateSmartQuery(query: query);
+
+Future<void> saveSmartPlaylist({required String name, required String query}) =>
+    RustLib.instance.api
+        .crateApiSmartPlaylistSaveSmartPlaylist(name: name, query: query);
+
+Future<String?> getSmartPlaylistQuery({required String name}) =>
+    RustLib.instance.api.crateApiSmartPlaylistGetSmartPlaylistQuery(name: name);
+
+Future<bool> isSmartPlaylist({required String name}) =>
+    RustLib.instance.api.crateApiSmartPlaylistIsSmartPlaylist(name: name);
+
+Future<List<String>> listSmartPlaylists() =>
+    RustLib.instance.api.crateApiSmartPlaylistListSmartPlaylists();
+
+Future<List<SongMetadata>> loadSmartPlaylist({required String name}) =>
+    RustLib.instance.api.crateApiSmartPlaylistLoadSmartPlaylist(name: name);
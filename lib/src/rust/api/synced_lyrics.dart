@@ -0,0 +1,199 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `parse_words`, `set_active`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ActiveLyrics`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `apply_offset`, `format_timestamp`, `parse_lrc_document`, `parse_timestamp`, `position_at`, `remove_offset`, `to_lrc`
+
+Future<LrcDocument> parseLrc({required String content}) =>
+    RustLib.instance.api.crateApiSyncedLyricsParseLrc(content: content);
+
+Future<String> lyricsToLrc({required LrcDocument doc}) =>
+    RustLib.instance.api.crateApiSyncedLyricsLyricsToLrc(doc: doc);
+
+Future<LrcDocument> loadSyncedLyrics({required String content}) =>
+    RustLib.instance.api.crateApiSyncedLyricsLoadSyncedLyrics(content: content);
+
+Future<LrcDocument> loadSyncedLyricsFile({required String path}) =>
+    RustLib.instance.api.crateApiSyncedLyricsLoadSyncedLyricsFile(path: path);
+
+Future<LrcDocument?> getSyncedLyrics() =>
+    RustLib.instance.api.crateApiSyncedLyricsGetSyncedLyrics();
+
+Future<void> clearSyncedLyrics() =>
+    RustLib.instance.api.crateApiSyncedLyricsClearSyncedLyrics();
+
+Future<LyricPosition?> currentLyricLine({required double position}) =>
+    RustLib.instance.api
+        .crateApiSyncedLyricsCurrentLyricLine(position: position);
+
+Stream<LyricEvent> watchLyrics({int? intervalMs}) => RustLib.instance.api
+    .crateApiSyncedLyricsWatchLyrics(intervalMs: intervalMs);
+
+Future<void> stopLyricsWatch() =>
+    RustLib.instance.api.crateApiSyncedLyricsStopLyricsWatch();
+
+class LrcDocument {
+  final String? title;
+  final String? artist;
+  final String? album;
+  final String? genre;
+  final String? songPath;
+  final PlatformInt64 offsetMs;
+  final PlatformInt64 userOffsetMs;
+  final BigInt? lengthMs;
+  final bool synced;
+  final List<LrcLine> lines;
+
+  const LrcDocument({
+    this.title,
+    this.artist,
+    this.album,
+    this.genre,
+    this.songPath,
+    required this.offsetMs,
+    required this.userOffsetMs,
+    this.lengthMs,
+    required this.synced,
+    required this.lines,
+  });
+
+  static Future<LrcDocument> default_() =>
+      RustLib.instance.api.crateApiSyncedLyricsLrcDocumentDefault();
+
+  @override
+  int get hashCode =>
+      title.hashCode ^
+      artist.hashCode ^
+      album.hashCode ^
+      genre.hashCode ^
+      songPath.hashCode ^
+      offsetMs.hashCode ^
+      userOffsetMs.hashCode ^
+      lengthMs.hashCode ^
+      synced.hashCode ^
+      lines.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LrcDocument &&
+          runtimeType == other.runtimeType &&
+          title == other.title &&
+          artist == other.artist &&
+          album == other.album &&
+          genre == other.genre &&
+          songPath == other.songPath &&
+          offsetMs == other.offsetMs &&
+          userOffsetMs == other.userOffsetMs &&
+          lengthMs == other.lengthMs &&
+          synced == other.synced &&
+          lines == other.lines;
+}
+
+class LrcLine {
+  final BigInt startMs;
+  final String text;
+  final List<LrcWord> words;
+
+  const LrcLine({
+    required this.startMs,
+    required this.text,
+    required this.words,
+  });
+
+  @override
+  int get hashCode => startMs.hashCode ^ text.hashCode ^ words.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LrcLine &&
+          runtimeType == other.runtimeType &&
+          startMs == other.startMs &&
+          text == other.text &&
+          words == other.words;
+}
+
+class LrcWord {
+  final BigInt startMs;
+  final String text;
+
+  const LrcWord({
+    required this.startMs,
+    required this.text,
+  });
+
+  @override
+  int get hashCode => startMs.hashCode ^ text.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LrcWord &&
+          runtimeType == other.runtimeType &&
+          startMs == other.startMs &&
+          text == other.text;
+}
+
+class LyricEvent {
+  final BigInt positionMs;
+  final LyricPosition? current;
+
+  const LyricEvent({
+    required this.positionMs,
+    this.current,
+  });
+
+  @override
+  int get hashCode => positionMs.hashCode ^ current.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LyricEvent &&
+          runtimeType == other.runtimeType &&
+          positionMs == other.positionMs &&
+          current == other.current;
+}
+
+class LyricPosition {
+  final int lineIndex;
+  final int? wordIndex;
+  final BigInt lineStartMs;
+  final BigInt? lineEndMs;
+  final String text;
+
+  const LyricPosition({
+    required this.lineIndex,
+    this.wordIndex,
+    required this.lineStartMs,
+    this.lineEndMs,
+    required this.text,
+  });
+
+  @override
+  int get hashCode =>
+      lineIndex.hashCode ^
+      wordIndex.hashCode ^
+      lineStartMs.hashCode ^
+      lineEndMs.hashCode ^
+      text.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LyricPosition &&
+          runtimeType == other.runtimeType &&
+          lineIndex == other.lineIndex &&
+          wordIndex == other.wordIndex &&
+          lineStartMs == other.lineStartMs &&
+          lineEndMs == other.lineEndMs &&
+          text == other.text;
+}
@@ -0,0 +1,102 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `journal_path`, `load_journal`, `normalise_change`, `read_field`, `record`, `restore_file`, `save_journal`, `write_field`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `JournalEntry`, `JournalFile`, `PlannedEdit`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_receiver_is_total_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `hash`
+
+Future<TagEditRecord> batchEditTags({required List<TagEdit> edits}) =>
+    RustLib.instance.api.crateApiTagEditorBatchEditTags(edits: edits);
+
+Future<TagEditRecord> undoLastTagEdit() =>
+    RustLib.instance.api.crateApiTagEditorUndoLastTagEdit();
+
+Future<List<TagEditRecord>> getTagEditHistory() =>
+    RustLib.instance.api.crateApiTagEditorGetTagEditHistory();
+
+class FieldChange {
+  final TagField field;
+  final String? value;
+
+  const FieldChange({
+    required this.field,
+    this.value,
+  });
+
+  @override
+  int get hashCode => field.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FieldChange &&
+          runtimeType == other.runtimeType &&
+          field == other.field &&
+          value == other.value;
+}
+
+class TagEdit {
+  final String path;
+  final List<FieldChange> changes;
+
+  const TagEdit({
+    required this.path,
+    required this.changes,
+  });
+
+  @override
+  int get hashCode => path.hashCode ^ changes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TagEdit &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          changes == other.changes;
+}
+
+class TagEditRecord {
+  final PlatformInt64 id;
+  final PlatformInt64 timestamp;
+  final int files;
+
+  const TagEditRecord({
+    required this.id,
+    required this.timestamp,
+    required this.files,
+  });
+
+  @override
+  int get hashCode => id.hashCode ^ timestamp.hashCode ^ files.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TagEditRecord &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          timestamp == other.timestamp &&
+          files == other.files;
+}
+
+enum TagField {
+  title,
+  artist,
+  album,
+  albumArtist,
+  genre,
+  year,
+  trackNumber,
+  totalTracks,
+  discNumber,
+  totalDiscs,
+  composer,
+  comment,
+  ;
+}
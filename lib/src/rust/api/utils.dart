@@ -7,7 +7,8 @@ import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 // These functions are ignored because they are not marked as `pub`: `download_file`
-// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `check_dir`, `fpre`, `validate_path`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `RateLimiter`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `check_dir`, `fpre`, `get_cache_dir`, `get_data_dir`, `new`, `validate_path`, `wait`
 
 Future<bool> checkUnsafeApi() =>
     RustLib.instance.api.crateApiUtilsCheckUnsafeApi();
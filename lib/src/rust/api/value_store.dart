@@ -9,10 +9,11 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'value_store.freezed.dart';
 
+// These functions are ignored because they are not marked as `pub`: `validate_base_url`
 // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ValueStore`
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`
-// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `acquire_read_lock`, `apply_update`, `check_value_store_state`
-// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `new`, `update_music_folder`, `update_plugin_rw_dir`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `acquire_read_lock`, `apply_update`, `check_value_store_state`, `with_store`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `new`, `update_acoustid_base_url`, `update_coverart_base_url`, `update_lrclib_base_url`, `update_music_folder`, `update_musicbrainz_base_url`, `update_plugin_rw_dir`, `update_podcast_folder`, `update_ytdlp_path`
 
 Future<void> initValueStore() =>
     RustLib.instance.api.crateApiValueStoreInitValueStore();
@@ -27,26 +28,54 @@ Future<ValueStoreUpdater> updateStore() =>
 abstract class ValueStoreUpdater implements RustOpaqueInterface {
   Future<void> apply();
 
+  String? get acoustidApiKey;
+
+  String? get acoustidBaseUrl;
+
+  String? get coverartBaseUrl;
+
   CurrentSongUpdate get currentSong;
 
+  String? get lrclibBaseUrl;
+
   String? get musicFolder;
 
+  String? get musicbrainzBaseUrl;
+
   String? get pluginRwDir;
 
   bool? get pluginsEnabled;
 
+  String? get podcastFolder;
+
   bool? get unsafeApis;
 
+  String? get ytdlpPath;
+
+  set acoustidApiKey(String? acoustidApiKey);
+
+  set acoustidBaseUrl(String? acoustidBaseUrl);
+
+  set coverartBaseUrl(String? coverartBaseUrl);
+
   set currentSong(CurrentSongUpdate currentSong);
 
+  set lrclibBaseUrl(String? lrclibBaseUrl);
+
   set musicFolder(String? musicFolder);
 
+  set musicbrainzBaseUrl(String? musicbrainzBaseUrl);
+
   set pluginRwDir(String? pluginRwDir);
 
   set pluginsEnabled(bool? pluginsEnabled);
 
+  set podcastFolder(String? podcastFolder);
+
   set unsafeApis(bool? unsafeApis);
 
+  set ytdlpPath(String? ytdlpPath);
+
   Future<void> clearCurrentSong();
 
   static Future<ValueStoreUpdater> default_() =>
@@ -56,15 +85,29 @@ abstract class ValueStoreUpdater implements RustOpaqueInterface {
   static Future<ValueStoreUpdater> newInstance() =>
       RustLib.instance.api.crateApiValueStoreValueStoreUpdaterNew();
 
+  Future<void> setAcoustidApiKey({required String key});
+
+  Future<void> setAcoustidBaseUrl({required String url});
+
+  Future<void> setCoverartBaseUrl({required String url});
+
   Future<void> setCurrentSong({required SongMetadata song});
 
+  Future<void> setLrclibBaseUrl({required String url});
+
   Future<void> setMusicFolder({required String folder});
 
+  Future<void> setMusicbrainzBaseUrl({required String url});
+
   Future<void> setPluginRwDir({required String folder});
 
   Future<void> setPluginsEnabled({required bool val});
 
+  Future<void> setPodcastFolder({required String folder});
+
   Future<void> setUnsafeApis({required bool value});
+
+  Future<void> setYtdlpPath({required String path});
 }
 
 @freezed
@@ -84,6 +127,13 @@ class ValueStoreUpdate {
   final bool? pluginsEnabled;
   final String? pluginRwDir;
   final bool? unsafeApis;
+  final String? acoustidApiKey;
+  final String? acoustidBaseUrl;
+  final String? musicbrainzBaseUrl;
+  final String? coverartBaseUrl;
+  final String? lrclibBaseUrl;
+  final String? ytdlpPath;
+  final String? podcastFolder;
 
   const ValueStoreUpdate({
     this.musicFolder,
@@ -91,6 +141,13 @@ class ValueStoreUpdate {
     this.pluginsEnabled,
     this.pluginRwDir,
     this.unsafeApis,
+    this.acoustidApiKey,
+    this.acoustidBaseUrl,
+    this.musicbrainzBaseUrl,
+    this.coverartBaseUrl,
+    this.lrclibBaseUrl,
+    this.ytdlpPath,
+    this.podcastFolder,
   });
 
   @override
@@ -99,7 +156,14 @@ class ValueStoreUpdate {
       currentSong.hashCode ^
       pluginsEnabled.hashCode ^
       pluginRwDir.hashCode ^
-      unsafeApis.hashCode;
+      unsafeApis.hashCode ^
+      acoustidApiKey.hashCode ^
+      acoustidBaseUrl.hashCode ^
+      musicbrainzBaseUrl.hashCode ^
+      coverartBaseUrl.hashCode ^
+      lrclibBaseUrl.hashCode ^
+      ytdlpPath.hashCode ^
+      podcastFolder.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -110,5 +174,12 @@ class ValueStoreUpdate {
           currentSong == other.currentSong &&
           pluginsEnabled == other.pluginsEnabled &&
           pluginRwDir == other.pluginRwDir &&
-          unsafeApis == other.unsafeApis;
+          unsafeApis == other.unsafeApis &&
+          acoustidApiKey == other.acoustidApiKey &&
+          acoustidBaseUrl == other.acoustidBaseUrl &&
+          musicbrainzBaseUrl == other.musicbrainzBaseUrl &&
+          coverartBaseUrl == other.coverartBaseUrl &&
+          lrclibBaseUrl == other.lrclibBaseUrl &&
+          ytdlpPath == other.ytdlpPath &&
+          podcastFolder == other.podcastFolder;
 }
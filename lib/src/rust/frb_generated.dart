@@ -4,9 +4,36 @@
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
 import 'api/acoustid.dart';
+import 'api/art_cache.dart';
+import 'api/audiobooks.dart';
+import 'api/chapters.dart';
+import 'api/collection_download.dart';
 import 'api/color_extractor.dart';
+import 'api/cue_sheet.dart';
+import 'api/download_manager.dart';
+import 'api/download_pipeline.dart';
+import 'api/embedded_lyrics.dart';
+import 'api/fingerprint.dart';
+import 'api/http_stream.dart';
+import 'api/library_search.dart';
+import 'api/lrclib.dart';
+import 'api/lyrics_editor.dart';
+import 'api/lyrics_index.dart';
+import 'api/lyrics_store.dart';
 import 'api/music_handler.dart';
+import 'api/musicbrainz.dart';
+import 'api/organizer.dart';
+import 'api/play_stats.dart';
+import 'api/playlist_io.dart';
+import 'api/playlist_manager.dart';
 import 'api/plugin_man.dart';
+import 'api/podcast_feed.dart';
+import 'api/podcasts.dart';
+import 'api/radio_stations.dart';
+import 'api/replay_gain.dart';
+import 'api/smart_playlist.dart';
+import 'api/synced_lyrics.dart';
+import 'api/tag_editor.dart';
 import 'api/utils.dart';
 import 'api/value_store.dart';
 import 'dart:async';
@@ -74,7 +101,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.12.0';
 
   @override
-  int get rustContentHash => 458431315;
+  int get rustContentHash => 1722530296;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -131,38 +158,80 @@ abstract class RustLibApi extends BaseApi {
   Future<void> crateApiValueStoreValueStoreUpdaterApply(
       {required ValueStoreUpdater that});
 
+  String? crateApiValueStoreValueStoreUpdaterAutoAccessorGetAcoustidApiKey(
+      {required ValueStoreUpdater that});
+
+  String? crateApiValueStoreValueStoreUpdaterAutoAccessorGetAcoustidBaseUrl(
+      {required ValueStoreUpdater that});
+
+  String? crateApiValueStoreValueStoreUpdaterAutoAccessorGetCoverartBaseUrl(
+      {required ValueStoreUpdater that});
+
   CurrentSongUpdate
       crateApiValueStoreValueStoreUpdaterAutoAccessorGetCurrentSong(
           {required ValueStoreUpdater that});
 
+  String? crateApiValueStoreValueStoreUpdaterAutoAccessorGetLrclibBaseUrl(
+      {required ValueStoreUpdater that});
+
   String? crateApiValueStoreValueStoreUpdaterAutoAccessorGetMusicFolder(
       {required ValueStoreUpdater that});
 
+  String? crateApiValueStoreValueStoreUpdaterAutoAccessorGetMusicbrainzBaseUrl(
+      {required ValueStoreUpdater that});
+
   String? crateApiValueStoreValueStoreUpdaterAutoAccessorGetPluginRwDir(
       {required ValueStoreUpdater that});
 
   bool? crateApiValueStoreValueStoreUpdaterAutoAccessorGetPluginsEnabled(
       {required ValueStoreUpdater that});
 
+  String? crateApiValueStoreValueStoreUpdaterAutoAccessorGetPodcastFolder(
+      {required ValueStoreUpdater that});
+
   bool? crateApiValueStoreValueStoreUpdaterAutoAccessorGetUnsafeApis(
       {required ValueStoreUpdater that});
 
+  String? crateApiValueStoreValueStoreUpdaterAutoAccessorGetYtdlpPath(
+      {required ValueStoreUpdater that});
+
+  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetAcoustidApiKey(
+      {required ValueStoreUpdater that, String? acoustidApiKey});
+
+  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetAcoustidBaseUrl(
+      {required ValueStoreUpdater that, String? acoustidBaseUrl});
+
+  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetCoverartBaseUrl(
+      {required ValueStoreUpdater that, String? coverartBaseUrl});
+
   void crateApiValueStoreValueStoreUpdaterAutoAccessorSetCurrentSong(
       {required ValueStoreUpdater that,
       required CurrentSongUpdate currentSong});
 
+  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetLrclibBaseUrl(
+      {required ValueStoreUpdater that, String? lrclibBaseUrl});
+
   void crateApiValueStoreValueStoreUpdaterAutoAccessorSetMusicFolder(
       {required ValueStoreUpdater that, String? musicFolder});
 
+  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetMusicbrainzBaseUrl(
+      {required ValueStoreUpdater that, String? musicbrainzBaseUrl});
+
   void crateApiValueStoreValueStoreUpdaterAutoAccessorSetPluginRwDir(
       {required ValueStoreUpdater that, String? pluginRwDir});
 
   void crateApiValueStoreValueStoreUpdaterAutoAccessorSetPluginsEnabled(
       {required ValueStoreUpdater that, bool? pluginsEnabled});
 
+  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetPodcastFolder(
+      {required ValueStoreUpdater that, String? podcastFolder});
+
   void crateApiValueStoreValueStoreUpdaterAutoAccessorSetUnsafeApis(
       {required ValueStoreUpdater that, bool? unsafeApis});
 
+  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetYtdlpPath(
+      {required ValueStoreUpdater that, String? ytdlpPath});
+
   Future<void> crateApiValueStoreValueStoreUpdaterClearCurrentSong(
       {required ValueStoreUpdater that});
 
@@ -170,41 +239,149 @@ abstract class RustLibApi extends BaseApi {
 
   Future<ValueStoreUpdater> crateApiValueStoreValueStoreUpdaterNew();
 
+  Future<void> crateApiValueStoreValueStoreUpdaterSetAcoustidApiKey(
+      {required ValueStoreUpdater that, required String key});
+
+  Future<void> crateApiValueStoreValueStoreUpdaterSetAcoustidBaseUrl(
+      {required ValueStoreUpdater that, required String url});
+
+  Future<void> crateApiValueStoreValueStoreUpdaterSetCoverartBaseUrl(
+      {required ValueStoreUpdater that, required String url});
+
   Future<void> crateApiValueStoreValueStoreUpdaterSetCurrentSong(
       {required ValueStoreUpdater that, required SongMetadata song});
 
+  Future<void> crateApiValueStoreValueStoreUpdaterSetLrclibBaseUrl(
+      {required ValueStoreUpdater that, required String url});
+
   Future<void> crateApiValueStoreValueStoreUpdaterSetMusicFolder(
       {required ValueStoreUpdater that, required String folder});
 
+  Future<void> crateApiValueStoreValueStoreUpdaterSetMusicbrainzBaseUrl(
+      {required ValueStoreUpdater that, required String url});
+
   Future<void> crateApiValueStoreValueStoreUpdaterSetPluginRwDir(
       {required ValueStoreUpdater that, required String folder});
 
   Future<void> crateApiValueStoreValueStoreUpdaterSetPluginsEnabled(
       {required ValueStoreUpdater that, required bool val});
 
+  Future<void> crateApiValueStoreValueStoreUpdaterSetPodcastFolder(
+      {required ValueStoreUpdater that, required String folder});
+
   Future<void> crateApiValueStoreValueStoreUpdaterSetUnsafeApis(
       {required ValueStoreUpdater that, required bool value});
 
+  Future<void> crateApiValueStoreValueStoreUpdaterSetYtdlpPath(
+      {required ValueStoreUpdater that, required String path});
+
   Future<void> crateApiMusicHandlerAddSeparator({required String separator});
 
+  Future<void> crateApiRadioStationsAddStation({required RadioStation station});
+
+  Future<int> crateApiPlaylistManagerAddToPlaylist(
+      {required String name,
+      required List<String> songPaths,
+      int? position,
+      required bool allowDuplicates});
+
+  Future<TagEditRecord> crateApiTagEditorBatchEditTags(
+      {required List<TagEdit> edits});
+
   Future<void> crateApiPluginManCallFuncPlugins({required String func});
 
   Future<bool> crateApiPluginManCallPluginFunc(
       {required String func, required String plugin});
 
+  Future<void> crateApiDownloadManagerCancelAllDownloads();
+
   Future<void> crateApiMusicHandlerCancelDownload();
 
+  Future<bool> crateApiDownloadManagerCancelDownloadJob({required BigInt id});
+
+  Future<bool> crateApiPodcastsCancelEpisodeDownload(
+      {required BigInt podcastId, required String guid});
+
+  Future<bool> crateApiAcoustidCancelIdentify({required BigInt batchId});
+
+  Future<List<Chapter>> crateApiChaptersChapterList({required String path});
+
   Future<bool> crateApiPluginManCheckPluginMan({AdiPluginMan? pmg});
 
   Future<bool> crateApiUtilsCheckPluginsEnabled();
 
   Future<bool> crateApiUtilsCheckUnsafeApi();
 
+  Future<bool> crateApiArtCacheClearArtCache();
+
+  Future<bool> crateApiFingerprintClearFingerprintCache();
+
+  Future<int> crateApiCollectionDownloadClearFinishedCollections();
+
+  Future<int> crateApiDownloadManagerClearFinishedDownloads();
+
   Future<bool> crateApiMusicHandlerClearMp3Cache();
 
+  Future<void> crateApiSyncedLyricsClearSyncedLyrics();
+
+  Future<CollectionOptions>
+      crateApiCollectionDownloadCollectionOptionsDefault();
+
+  Future<ReplayGain> crateApiReplayGainComputeReplayGain(
+      {required String path});
+
+  Future<double> crateApiColorExtractorContrastRatio(
+      {required int a, required int b});
+
+  Future<void> crateApiPlaylistManagerCreatePlaylist({required String name});
+
+  Future<CueSheet> crateApiCueSheetCueSheetDefault();
+
+  Future<Chapter?> crateApiChaptersCurrentChapter();
+
+  Future<LyricPosition?> crateApiSyncedLyricsCurrentLyricLine(
+      {required double position});
+
+  Future<bool> crateApiLyricsStoreDeleteCachedLyrics(
+      {required String lyricsDir, required SongMetadata song});
+
+  Future<void> crateApiPodcastsDeleteEpisodeDownload(
+      {required BigInt podcastId, required String guid});
+
+  Future<void> crateApiPlaylistManagerDeletePlaylist({required String name});
+
+  Future<void> crateApiRadioStationsDeleteStation({required String name});
+
+  Future<List<PipelineResult>> crateApiDownloadPipelineDownloadAndImport(
+      {required String query, String? flags, PipelineConfig? config});
+
+  Future<BigInt> crateApiDownloadManagerDownloadById(
+      {required String id, String? flags});
+
+  Future<CollectionDownload> crateApiCollectionDownloadDownloadCollection(
+      {required String url, required CollectionOptions options});
+
+  Future<String> crateApiPodcastsDownloadEpisode(
+      {required BigInt podcastId, required String guid});
+
   Future<String> crateApiMusicHandlerDownloadToTemp(
       {required String query, String? flags});
 
+  Future<void> crateApiPlaylistManagerDuplicatePlaylist(
+      {required String name, required String newName});
+
+  Future<EnrichedMetadata> crateApiMusicbrainzEnrichCandidate(
+      {required AcoustIdCandidate candidate});
+
+  Future<PlaylistExportResult> crateApiPlaylistIoExportPlaylist(
+      {required String name,
+      required String destination,
+      PlaylistFormat? format,
+      required bool relativePaths});
+
+  Future<Palette?> crateApiColorExtractorExtractPalette(
+      {required List<int> data, required int n});
+
   Future<Float64List> crateApiMusicHandlerExtractWaveformFromMp3(
       {required String mp3Path, int? sampleCount, int? channels});
 
@@ -212,21 +389,42 @@ abstract class RustLibApi extends BaseApi {
 
   Future<String> crateApiPluginManFindButtonsByName({required String name});
 
+  Future<String?> crateApiLyricsStoreFindCachedLyrics(
+      {required String lyricsDir, required SongMetadata song});
+
+  Future<List<DuplicateGroup>> crateApiFingerprintFindDuplicates(
+      {required String musicFolder});
+
   Future<String> crateApiPluginManFindItemsByCallback(
       {required String callback});
 
+  Future<void> crateApiPlayStatsFlushPlayStats();
+
+  Future<void> crateApiAudiobooksForgetBookProgress({required String path});
+
+  Future<MaterialScheme> crateApiColorExtractorGenerateColorScheme(
+      {required int seed, required bool dark});
+
   Future<String> crateApiPluginManGetAllButtons({String? locationFilter});
 
   Future<String> crateApiPluginManGetAllPopups();
 
   Future<String> crateApiPluginManGetAllScreens();
 
+  Future<BigInt> crateApiArtCacheGetArtCacheMemoryUsage();
+
   Future<List<String>> crateApiMusicHandlerGetArtistViaFfprobe(
       {required String filePath});
 
+  Future<BookProgress?> crateApiAudiobooksGetBookProgress(
+      {required String path});
+
   Future<Uint8List?> crateApiMusicHandlerGetCachedAlbumArt(
       {required String path});
 
+  Future<Uint8List?> crateApiMusicHandlerGetCachedAlbumArtSized(
+      {required String path, required int size});
+
   Future<SongMetadata> crateApiMusicHandlerGetCdTrackMetadata(
       {required String device, required int track});
 
@@ -239,78 +437,292 @@ abstract class RustLibApi extends BaseApi {
   Future<int?> crateApiColorExtractorGetDominantColor(
       {required List<int> data});
 
+  Future<DownloadJob?> crateApiDownloadManagerGetDownload({required BigInt id});
+
+  Future<List<PodcastChapter>> crateApiPodcastsGetEpisodeChapters(
+      {required BigInt podcastId, required String guid});
+
+  Future<double> crateApiPodcastsGetEpisodePosition(
+      {required BigInt podcastId, required String guid});
+
   Future<String?> crateApiUtilsGetLatestVersion();
 
+  Future<FoundLyrics?> crateApiLyricsStoreGetLyrics(
+      {required String lyricsDir,
+      required SongMetadata song,
+      required bool allowOnline});
+
+  Future<PlatformInt64> crateApiLyricsEditorGetLyricsOffset(
+      {required String lrcPath});
+
+  Future<PipelineConfig> crateApiDownloadPipelineGetPipelineConfig();
+
+  Future<PlayStat> crateApiPlayStatsGetPlayStat({required String path});
+
   Future<double> crateApiMusicHandlerGetPlaybackPosition();
 
+  Future<List<PlaylistEntry>> crateApiPlaylistManagerGetPlaylistEntries(
+      {required String name});
+
   Future<String> crateApiPluginManGetPluginConfig({required String path});
 
   Future<String> crateApiPluginManGetPluginFadConfig({required String path});
 
+  Future<Podcast> crateApiPodcastsGetPodcast({required BigInt id});
+
   Future<Float32List> crateApiMusicHandlerGetRealtimePeaks();
 
+  Future<String?> crateApiSmartPlaylistGetSmartPlaylistQuery(
+      {required String name});
+
+  Future<RadioStation> crateApiRadioStationsGetStation({required String name});
+
+  Future<StreamMetadata?> crateApiHttpStreamGetStreamMetadata();
+
+  Future<LrcDocument?> crateApiSyncedLyricsGetSyncedLyrics();
+
+  Future<List<TagEditRecord>> crateApiTagEditorGetTagEditHistory();
+
+  Future<bool> crateApiAcoustidHasAcoustidKey();
+
+  Future<BigInt> crateApiAcoustidIdentifyBatch(
+      {required List<String> paths, required int topN, int? workers});
+
+  Future<PlaylistImportResult> crateApiPlaylistIoImportPlaylist(
+      {required String file, String? name});
+
   Future<void> crateApiPluginManInitPluginMan();
 
   Future<void> crateApiValueStoreInitValueStore();
 
   Future<bool> crateApiMusicHandlerInitializePlayer();
 
+  Future<bool> crateApiAudiobooksIsAudiobook({required String path});
+
   Future<bool> crateApiMusicHandlerIsPlaying();
 
   Future<bool> crateApiPluginManIsPluginLoaded({required String path});
 
+  Future<bool> crateApiSmartPlaylistIsSmartPlaylist({required String name});
+
   Future<List<String>> crateApiMusicHandlerListAudioCds();
 
   Future<List<String>> crateApiMusicHandlerListAudioDevices();
 
+  Future<List<BookProgress>> crateApiAudiobooksListBooksInProgress();
+
+  Future<List<CollectionDownload>>
+      crateApiCollectionDownloadListCollectionDownloads();
+
+  Future<List<DownloadJob>> crateApiDownloadManagerListDownloads();
+
   Future<List<String>> crateApiPluginManListLoadedPlugins();
 
+  Future<List<PlaylistInfo>> crateApiPlaylistManagerListPlaylists();
+
+  Future<List<Podcast>> crateApiPodcastsListPodcasts();
+
+  Future<List<String>> crateApiSmartPlaylistListSmartPlaylists();
+
+  Future<List<RadioStation>> crateApiRadioStationsListStations();
+
   Future<String> crateApiPluginManLoadPlugin({required String path});
 
+  Future<List<SongMetadata>> crateApiSmartPlaylistLoadSmartPlaylist(
+      {required String name});
+
+  Future<LrcDocument> crateApiSyncedLyricsLoadSyncedLyrics(
+      {required String content});
+
+  Future<LrcDocument> crateApiSyncedLyricsLoadSyncedLyricsFile(
+      {required String path});
+
   Future<SongMetadata?> crateApiAcoustidLookup({required String path});
 
+  Future<List<AcoustIdCandidate>> crateApiAcoustidLookupCandidates(
+      {required String path, required int topN});
+
+  Future<EnrichedMetadata?> crateApiMusicbrainzLookupEnriched(
+      {required String path});
+
+  Future<LrcDocument> crateApiSyncedLyricsLrcDocumentDefault();
+
+  Future<String> crateApiSyncedLyricsLyricsToLrc({required LrcDocument doc});
+
+  Future<void> crateApiAudiobooksMarkBookFinished(
+      {required String path, required bool finished});
+
+  Future<void> crateApiPodcastsMarkEpisodePlayed(
+      {required BigInt podcastId, required String guid, required bool played});
+
+  Future<int> crateApiPlaylistManagerMergePlaylists(
+      {required List<String> sources,
+      required String target,
+      required bool allowDuplicates});
+
+  Future<int> crateApiPlaylistManagerMigratePlaylists();
+
+  Future<void> crateApiPlaylistManagerMovePlaylistEntry(
+      {required String name, required int from, required int to});
+
+  Future<bool> crateApiChaptersNextChapter();
+
+  Future<OrganizeResult> crateApiOrganizerOrganizeFiles(
+      {required List<String> paths,
+      required String template,
+      String? destRoot,
+      required bool dryRun});
+
+  Future<LrcDocument> crateApiSyncedLyricsParseLrc({required String content});
+
   Future<bool> crateApiMusicHandlerPauseSong();
 
+  Future<PipelineConfig> crateApiDownloadPipelinePipelineConfigDefault();
+
+  Future<bool> crateApiPodcastsPlayEpisode(
+      {required BigInt podcastId, required String guid});
+
   Future<bool> crateApiMusicHandlerPlaySong({required String path});
 
+  Future<PlayStat> crateApiPlayStatsPlayStatDefault();
+
+  Future<bool> crateApiRadioStationsPlayStation({required String name});
+
   Future<PlayerState> crateApiMusicHandlerPlayerStateDefault();
 
   Future<bool> crateApiMusicHandlerPreloadNextSong({required String path});
 
+  Future<bool> crateApiChaptersPreviousChapter();
+
+  Future<PipelineResult> crateApiDownloadPipelineProcessDownload(
+      {required String path, PipelineConfig? config});
+
+  Future<BigInt> crateApiDownloadManagerQueueDownload(
+      {required String query, String? flags});
+
+  Future<BigInt> crateApiDownloadManagerQueueUrlDownload(
+      {required String url, String? flags});
+
+  Future<String?> crateApiEmbeddedLyricsReadEmbeddedLyrics(
+      {required String path});
+
+  Future<int> crateApiLibrarySearchRebuildSearchIndex();
+
+  Future<List<PodcastRefresh>> crateApiPodcastsRefreshAllPodcasts();
+
+  Future<int> crateApiLyricsIndexRefreshLyricsIndex(
+      {required String lyricsDir});
+
+  Future<List<String>> crateApiPodcastsRefreshPodcast({required BigInt id});
+
   Future<String> crateApiPluginManReloadPlugin({required String path});
 
+  Future<void> crateApiEmbeddedLyricsRemoveEmbeddedLyrics(
+      {required String path});
+
+  Future<int> crateApiPlaylistManagerRemoveFromPlaylist(
+      {required String name, required List<int> indices});
+
   Future<String> crateApiPluginManRemovePlugin({required String path});
 
   Future<void> crateApiMusicHandlerRemoveSeparator({required String separator});
 
+  Future<void> crateApiPlaylistManagerRenamePlaylist(
+      {required String name, required String newName});
+
+  Future<void> crateApiPlaylistManagerReorderPlaylist(
+      {required String name, required List<int> newOrder});
+
+  Future<bool> crateApiPlayStatsResetPlayStats();
+
   Future<void> crateApiMusicHandlerResetSeparators();
 
   Future<bool> crateApiMusicHandlerRestartPlayer();
 
   Future<bool> crateApiMusicHandlerResumeSong();
 
+  Future<int> crateApiOrganizerRollbackLastOrganize();
+
+  Future<String> crateApiLyricsStoreSaveLyrics(
+      {required String lyricsDir,
+      required SongMetadata song,
+      required String content});
+
+  Future<void> crateApiSmartPlaylistSaveSmartPlaylist(
+      {required String name, required String query});
+
+  Future<String> crateApiLyricsEditorSaveSyncedLyrics({String? path});
+
   Future<List<String>?> crateApiPluginManScanDir({required String path});
 
   Future<List<SongMetadata>> crateApiMusicHandlerScanMusicDirectory(
       {required String dirPath, required bool autoConvert});
 
+  Future<MaterialScheme> crateApiColorExtractorSchemeFromArt(
+      {required List<int> data, required bool dark});
+
+  Future<SearchPage> crateApiLibrarySearchSearchLibrary(
+      {required String query, required int page, int? pageSize});
+
+  Future<List<LrclibLyrics>> crateApiLrclibSearchLrclib(
+      {required String title, required String artist});
+
   Future<List<SongMetadata>> crateApiMusicHandlerSearchLyrics(
       {required String lyricsDir,
       required String query,
       required String songDir});
 
+  Future<List<LyricSearchResult>> crateApiLyricsIndexSearchLyricsLines(
+      {required String lyricsDir, required String query, int? limit});
+
+  Future<List<RemoteCandidate>> crateApiDownloadManagerSearchRemote(
+      {required String query, required int count});
+
+  Future<bool> crateApiChaptersSeekToChapter({required int index});
+
   Future<bool> crateApiMusicHandlerSeekToPosition({required double position});
 
+  Future<void> crateApiArtCacheSetArtCacheMemoryLimit({required BigInt bytes});
+
+  Future<void> crateApiAudiobooksSetAudiobook(
+      {required String path, required bool enabled});
+
+  Future<void> crateApiAudiobooksSetBookPosition(
+      {required String path, required double positionSecs});
+
+  Future<void> crateApiPodcastsSetEpisodePosition(
+      {required BigInt podcastId,
+      required String guid,
+      required double positionSecs});
+
   Future<void> crateApiMusicHandlerSetFadein({required bool value});
 
+  Future<LrcDocument> crateApiLyricsEditorSetLyricLineTime(
+      {required int lineIndex, required BigInt timeMs});
+
+  Future<LrcDocument> crateApiLyricsEditorSetLyricsOffset(
+      {required PlatformInt64 offsetMs});
+
+  Future<void> crateApiDownloadManagerSetMaxConcurrentDownloads(
+      {required int max});
+
+  Future<void> crateApiDownloadPipelineSetPipelineConfig(
+      {required PipelineConfig config});
+
   Future<String> crateApiPluginManSetPluginConfig(
       {required String path, required String key, required ConfigTypes value});
 
+  Future<void> crateApiPodcastsSetPodcastAutoDownload(
+      {required BigInt id, required bool enabled, int? keepDownloaded});
+
   Future<void> crateApiMusicHandlerSetSeparators(
       {required List<String> separators});
 
   Future<bool> crateApiMusicHandlerSetVolume({required double volume});
 
+  Future<LrcDocument> crateApiLyricsEditorShiftLyrics(
+      {required PlatformInt64 deltaMs});
+
   Future<bool> crateApiMusicHandlerSkipToNext(
       {required List<String> songs, required BigInt currentIndex});
 
@@ -319,22 +731,85 @@ abstract class RustLibApi extends BaseApi {
 
   Future<SongMetadata> crateApiMusicHandlerSongMetadataDefault();
 
+  Future<void> crateApiPlaylistManagerSortPlaylist(
+      {required String name,
+      required PlaylistSortKey key,
+      required bool descending});
+
+  Future<void> crateApiPodcastsStartPodcastSchedule(
+      {required int intervalMinutes});
+
+  Future<void> crateApiLyricsEditorStartTapSync({required int fromLine});
+
+  Future<void> crateApiSyncedLyricsStopLyricsWatch();
+
+  Future<void> crateApiPodcastsStopPodcastSchedule();
+
   Future<bool> crateApiMusicHandlerStopSong();
 
+  Future<StreamMetadata> crateApiHttpStreamStreamMetadataDefault();
+
+  Future<Podcast> crateApiPodcastsSubscribePodcast({required String feedUrl});
+
   Future<bool> crateApiMusicHandlerSwitchToPreloadedNow();
 
+  Future<LrcDocument> crateApiLyricsEditorSyncLyricLine(
+      {required int lineIndex});
+
+  Future<int?> crateApiLyricsEditorTapSync();
+
   Future<int> crateApiMusicHandlerTrackNum({required String device});
 
+  Future<TagEditRecord> crateApiTagEditorUndoLastTagEdit();
+
+  Future<void> crateApiPodcastsUnsubscribePodcast(
+      {required BigInt id, required bool deleteDownloads});
+
   Future<bool> crateApiUtilsUpdateExecutable(
       {required String arch, required String expath});
 
+  Future<void> crateApiRadioStationsUpdateStation(
+      {required String name, required RadioStation station});
+
   Future<ValueStoreUpdater> crateApiValueStoreUpdateStore();
 
   Future<void> crateApiValueStoreUpdateValueStore(
       {required ValueStoreUpdate update});
 
+  Future<void> crateApiSmartPlaylistValidateSmartQuery({required String query});
+
+  Future<List<String>> crateApiDownloadManagerWaitForDownload(
+      {required BigInt id});
+
+  Stream<CollectionDownload>
+      crateApiCollectionDownloadWatchCollectionDownloads();
+
+  Stream<DownloadJob> crateApiDownloadManagerWatchDownloads();
+
+  Stream<EpisodeDownload> crateApiPodcastsWatchEpisodeDownloads();
+
+  Stream<IdentifyProgress> crateApiAcoustidWatchIdentify();
+
+  Stream<SongMetadata> crateApiDownloadPipelineWatchLibraryAdditions();
+
+  Stream<LyricEvent> crateApiSyncedLyricsWatchLyrics({int? intervalMs});
+
+  Stream<StreamMetadata> crateApiHttpStreamWatchStreamMetadata();
+
+  Future<List<ArtWriteResult>> crateApiMusicHandlerWriteAlbumArt(
+      {required List<String> paths, Uint8List? art});
+
+  Future<void> crateApiEmbeddedLyricsWriteEmbeddedLyrics(
+      {required String path, required String lyrics});
+
   Future<void> crateApiMusicHandlerWriteMeta({required SongMetadata meta});
 
+  Future<void> crateApiReplayGainWriteReplayGain(
+      {required String path, required ReplayGain gain});
+
+  Future<void> crateApiMusicHandlerWriteSongArt(
+      {required String path, Uint8List? art});
+
   RustArcIncrementStrongCountFnType
       get rust_arc_increment_strong_count_ArcMutexPlugin;
 
@@ -795,9 +1270,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  CurrentSongUpdate
-      crateApiValueStoreValueStoreUpdaterAutoAccessorGetCurrentSong(
-          {required ValueStoreUpdater that}) {
+  String? crateApiValueStoreValueStoreUpdaterAutoAccessorGetAcoustidApiKey(
+      {required ValueStoreUpdater that}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
@@ -806,25 +1280,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_current_song_update,
+        decodeSuccessData: sse_decode_opt_String,
         decodeErrorData: null,
       ),
       constMeta:
-          kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetCurrentSongConstMeta,
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetAcoustidApiKeyConstMeta,
       argValues: [that],
       apiImpl: this,
     ));
   }
 
   TaskConstMeta
-      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetCurrentSongConstMeta =>
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetAcoustidApiKeyConstMeta =>
           const TaskConstMeta(
-            debugName: "ValueStoreUpdater_auto_accessor_get_current_song",
+            debugName: "ValueStoreUpdater_auto_accessor_get_acoustid_api_key",
             argNames: ["that"],
           );
 
   @override
-  String? crateApiValueStoreValueStoreUpdaterAutoAccessorGetMusicFolder(
+  String? crateApiValueStoreValueStoreUpdaterAutoAccessorGetAcoustidBaseUrl(
       {required ValueStoreUpdater that}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
@@ -838,21 +1312,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         decodeErrorData: null,
       ),
       constMeta:
-          kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetMusicFolderConstMeta,
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetAcoustidBaseUrlConstMeta,
       argValues: [that],
       apiImpl: this,
     ));
   }
 
   TaskConstMeta
-      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetMusicFolderConstMeta =>
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetAcoustidBaseUrlConstMeta =>
           const TaskConstMeta(
-            debugName: "ValueStoreUpdater_auto_accessor_get_music_folder",
+            debugName: "ValueStoreUpdater_auto_accessor_get_acoustid_base_url",
             argNames: ["that"],
           );
 
   @override
-  String? crateApiValueStoreValueStoreUpdaterAutoAccessorGetPluginRwDir(
+  String? crateApiValueStoreValueStoreUpdaterAutoAccessorGetCoverartBaseUrl(
       {required ValueStoreUpdater that}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
@@ -866,22 +1340,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         decodeErrorData: null,
       ),
       constMeta:
-          kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetPluginRwDirConstMeta,
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetCoverartBaseUrlConstMeta,
       argValues: [that],
       apiImpl: this,
     ));
   }
 
   TaskConstMeta
-      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetPluginRwDirConstMeta =>
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetCoverartBaseUrlConstMeta =>
           const TaskConstMeta(
-            debugName: "ValueStoreUpdater_auto_accessor_get_plugin_rw_dir",
+            debugName: "ValueStoreUpdater_auto_accessor_get_coverart_base_url",
             argNames: ["that"],
           );
 
   @override
-  bool? crateApiValueStoreValueStoreUpdaterAutoAccessorGetPluginsEnabled(
-      {required ValueStoreUpdater that}) {
+  CurrentSongUpdate
+      crateApiValueStoreValueStoreUpdaterAutoAccessorGetCurrentSong(
+          {required ValueStoreUpdater that}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
@@ -890,25 +1365,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_bool,
+        decodeSuccessData: sse_decode_current_song_update,
         decodeErrorData: null,
       ),
       constMeta:
-          kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetPluginsEnabledConstMeta,
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetCurrentSongConstMeta,
       argValues: [that],
       apiImpl: this,
     ));
   }
 
   TaskConstMeta
-      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetPluginsEnabledConstMeta =>
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetCurrentSongConstMeta =>
           const TaskConstMeta(
-            debugName: "ValueStoreUpdater_auto_accessor_get_plugins_enabled",
+            debugName: "ValueStoreUpdater_auto_accessor_get_current_song",
             argNames: ["that"],
           );
 
   @override
-  bool? crateApiValueStoreValueStoreUpdaterAutoAccessorGetUnsafeApis(
+  String? crateApiValueStoreValueStoreUpdaterAutoAccessorGetLrclibBaseUrl(
       {required ValueStoreUpdater that}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
@@ -918,568 +1393,598 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_bool,
+        decodeSuccessData: sse_decode_opt_String,
         decodeErrorData: null,
       ),
       constMeta:
-          kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetUnsafeApisConstMeta,
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetLrclibBaseUrlConstMeta,
       argValues: [that],
       apiImpl: this,
     ));
   }
 
   TaskConstMeta
-      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetUnsafeApisConstMeta =>
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetLrclibBaseUrlConstMeta =>
           const TaskConstMeta(
-            debugName: "ValueStoreUpdater_auto_accessor_get_unsafe_apis",
+            debugName: "ValueStoreUpdater_auto_accessor_get_lrclib_base_url",
             argNames: ["that"],
           );
 
   @override
-  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetCurrentSong(
-      {required ValueStoreUpdater that,
-      required CurrentSongUpdate currentSong}) {
+  String? crateApiValueStoreValueStoreUpdaterAutoAccessorGetMusicFolder(
+      {required ValueStoreUpdater that}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
             that, serializer);
-        sse_encode_current_song_update(currentSong, serializer);
         return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_opt_String,
         decodeErrorData: null,
       ),
       constMeta:
-          kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetCurrentSongConstMeta,
-      argValues: [that, currentSong],
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetMusicFolderConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
   TaskConstMeta
-      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetCurrentSongConstMeta =>
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetMusicFolderConstMeta =>
           const TaskConstMeta(
-            debugName: "ValueStoreUpdater_auto_accessor_set_current_song",
-            argNames: ["that", "currentSong"],
+            debugName: "ValueStoreUpdater_auto_accessor_get_music_folder",
+            argNames: ["that"],
           );
 
   @override
-  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetMusicFolder(
-      {required ValueStoreUpdater that, String? musicFolder}) {
+  String? crateApiValueStoreValueStoreUpdaterAutoAccessorGetMusicbrainzBaseUrl(
+      {required ValueStoreUpdater that}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
             that, serializer);
-        sse_encode_opt_String(musicFolder, serializer);
         return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_opt_String,
         decodeErrorData: null,
       ),
       constMeta:
-          kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetMusicFolderConstMeta,
-      argValues: [that, musicFolder],
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetMusicbrainzBaseUrlConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
   TaskConstMeta
-      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetMusicFolderConstMeta =>
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetMusicbrainzBaseUrlConstMeta =>
           const TaskConstMeta(
-            debugName: "ValueStoreUpdater_auto_accessor_set_music_folder",
-            argNames: ["that", "musicFolder"],
+            debugName: "ValueStoreUpdater_auto_accessor_get_musicbrainz_base_url",
+            argNames: ["that"],
           );
 
   @override
-  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetPluginRwDir(
-      {required ValueStoreUpdater that, String? pluginRwDir}) {
-    return handler.executeSync(SyncTask(
+  String? crateApiValueStoreValueStoreUpdaterAutoAccessorGetPluginRwDir(
+      {required ValueStoreUpdater that}) {
+    return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
             that, serializer);
-        sse_encode_opt_String(pluginRwDir, serializer);
         return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_opt_String,
         decodeErrorData: null,
       ),
       constMeta:
-          kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetPluginRwDirConstMeta,
-      argValues: [that, pluginRwDir],
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetPluginRwDirConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
   TaskConstMeta
-      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetPluginRwDirConstMeta =>
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetPluginRwDirConstMeta =>
           const TaskConstMeta(
-            debugName: "ValueStoreUpdater_auto_accessor_set_plugin_rw_dir",
-            argNames: ["that", "pluginRwDir"],
+            debugName: "ValueStoreUpdater_auto_accessor_get_plugin_rw_dir",
+            argNames: ["that"],
           );
 
   @override
-  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetPluginsEnabled(
-      {required ValueStoreUpdater that, bool? pluginsEnabled}) {
+  bool? crateApiValueStoreValueStoreUpdaterAutoAccessorGetPluginsEnabled(
+      {required ValueStoreUpdater that}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
             that, serializer);
-        sse_encode_opt_box_autoadd_bool(pluginsEnabled, serializer);
         return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_opt_box_autoadd_bool,
         decodeErrorData: null,
       ),
       constMeta:
-          kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetPluginsEnabledConstMeta,
-      argValues: [that, pluginsEnabled],
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetPluginsEnabledConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
   TaskConstMeta
-      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetPluginsEnabledConstMeta =>
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetPluginsEnabledConstMeta =>
           const TaskConstMeta(
-            debugName: "ValueStoreUpdater_auto_accessor_set_plugins_enabled",
-            argNames: ["that", "pluginsEnabled"],
+            debugName: "ValueStoreUpdater_auto_accessor_get_plugins_enabled",
+            argNames: ["that"],
           );
 
   @override
-  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetUnsafeApis(
-      {required ValueStoreUpdater that, bool? unsafeApis}) {
+  String? crateApiValueStoreValueStoreUpdaterAutoAccessorGetPodcastFolder(
+      {required ValueStoreUpdater that}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
             that, serializer);
-        sse_encode_opt_box_autoadd_bool(unsafeApis, serializer);
         return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_opt_String,
         decodeErrorData: null,
       ),
       constMeta:
-          kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetUnsafeApisConstMeta,
-      argValues: [that, unsafeApis],
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetPodcastFolderConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
   TaskConstMeta
-      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetUnsafeApisConstMeta =>
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetPodcastFolderConstMeta =>
           const TaskConstMeta(
-            debugName: "ValueStoreUpdater_auto_accessor_set_unsafe_apis",
-            argNames: ["that", "unsafeApis"],
+            debugName: "ValueStoreUpdater_auto_accessor_get_podcast_folder",
+            argNames: ["that"],
           );
 
   @override
-  Future<void> crateApiValueStoreValueStoreUpdaterClearCurrentSong(
+  bool? crateApiValueStoreValueStoreUpdaterAutoAccessorGetUnsafeApis(
       {required ValueStoreUpdater that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
             that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 26, port: port_);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
+        decodeSuccessData: sse_decode_opt_box_autoadd_bool,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiValueStoreValueStoreUpdaterClearCurrentSongConstMeta,
+      constMeta:
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetUnsafeApisConstMeta,
       argValues: [that],
       apiImpl: this,
     ));
   }
 
   TaskConstMeta
-      get kCrateApiValueStoreValueStoreUpdaterClearCurrentSongConstMeta =>
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetUnsafeApisConstMeta =>
           const TaskConstMeta(
-            debugName: "ValueStoreUpdater_clear_current_song",
+            debugName: "ValueStoreUpdater_auto_accessor_get_unsafe_apis",
             argNames: ["that"],
           );
 
   @override
-  Future<ValueStoreUpdater> crateApiValueStoreValueStoreUpdaterDefault() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  String? crateApiValueStoreValueStoreUpdaterAutoAccessorGetYtdlpPath(
+      {required ValueStoreUpdater that}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 27, port: port_);
+        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+            that, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
       },
       codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater,
+        decodeSuccessData: sse_decode_opt_String,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiValueStoreValueStoreUpdaterDefaultConstMeta,
-      argValues: [],
+      constMeta:
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetYtdlpPathConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiValueStoreValueStoreUpdaterDefaultConstMeta =>
-      const TaskConstMeta(
-        debugName: "ValueStoreUpdater_default",
-        argNames: [],
-      );
+  TaskConstMeta
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorGetYtdlpPathConstMeta =>
+          const TaskConstMeta(
+            debugName: "ValueStoreUpdater_auto_accessor_get_ytdlp_path",
+            argNames: ["that"],
+          );
 
   @override
-  Future<ValueStoreUpdater> crateApiValueStoreValueStoreUpdaterNew() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetAcoustidApiKey(
+      {required ValueStoreUpdater that, String? acoustidApiKey}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 28, port: port_);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+            that, serializer);
+        sse_encode_opt_String(acoustidApiKey, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
       },
       codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiValueStoreValueStoreUpdaterNewConstMeta,
-      argValues: [],
+      constMeta:
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetAcoustidApiKeyConstMeta,
+      argValues: [that, acoustidApiKey],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiValueStoreValueStoreUpdaterNewConstMeta =>
-      const TaskConstMeta(
-        debugName: "ValueStoreUpdater_new",
-        argNames: [],
-      );
+  TaskConstMeta
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetAcoustidApiKeyConstMeta =>
+          const TaskConstMeta(
+            debugName: "ValueStoreUpdater_auto_accessor_set_acoustid_api_key",
+            argNames: ["that", "acoustidApiKey"],
+          );
 
   @override
-  Future<void> crateApiValueStoreValueStoreUpdaterSetCurrentSong(
-      {required ValueStoreUpdater that, required SongMetadata song}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetAcoustidBaseUrl(
+      {required ValueStoreUpdater that, String? acoustidBaseUrl}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
             that, serializer);
-        sse_encode_box_autoadd_song_metadata(song, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 29, port: port_);
+        sse_encode_opt_String(acoustidBaseUrl, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiValueStoreValueStoreUpdaterSetCurrentSongConstMeta,
-      argValues: [that, song],
+      constMeta:
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetAcoustidBaseUrlConstMeta,
+      argValues: [that, acoustidBaseUrl],
       apiImpl: this,
     ));
   }
 
   TaskConstMeta
-      get kCrateApiValueStoreValueStoreUpdaterSetCurrentSongConstMeta =>
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetAcoustidBaseUrlConstMeta =>
           const TaskConstMeta(
-            debugName: "ValueStoreUpdater_set_current_song",
-            argNames: ["that", "song"],
+            debugName: "ValueStoreUpdater_auto_accessor_set_acoustid_base_url",
+            argNames: ["that", "acoustidBaseUrl"],
           );
 
   @override
-  Future<void> crateApiValueStoreValueStoreUpdaterSetMusicFolder(
-      {required ValueStoreUpdater that, required String folder}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetCoverartBaseUrl(
+      {required ValueStoreUpdater that, String? coverartBaseUrl}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
             that, serializer);
-        sse_encode_String(folder, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 30, port: port_);
+        sse_encode_opt_String(coverartBaseUrl, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiValueStoreValueStoreUpdaterSetMusicFolderConstMeta,
-      argValues: [that, folder],
+      constMeta:
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetCoverartBaseUrlConstMeta,
+      argValues: [that, coverartBaseUrl],
       apiImpl: this,
     ));
   }
 
   TaskConstMeta
-      get kCrateApiValueStoreValueStoreUpdaterSetMusicFolderConstMeta =>
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetCoverartBaseUrlConstMeta =>
           const TaskConstMeta(
-            debugName: "ValueStoreUpdater_set_music_folder",
-            argNames: ["that", "folder"],
+            debugName: "ValueStoreUpdater_auto_accessor_set_coverart_base_url",
+            argNames: ["that", "coverartBaseUrl"],
           );
 
   @override
-  Future<void> crateApiValueStoreValueStoreUpdaterSetPluginRwDir(
-      {required ValueStoreUpdater that, required String folder}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetCurrentSong(
+      {required ValueStoreUpdater that,
+      required CurrentSongUpdate currentSong}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
             that, serializer);
-        sse_encode_String(folder, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 31, port: port_);
+        sse_encode_current_song_update(currentSong, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiValueStoreValueStoreUpdaterSetPluginRwDirConstMeta,
-      argValues: [that, folder],
+      constMeta:
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetCurrentSongConstMeta,
+      argValues: [that, currentSong],
       apiImpl: this,
     ));
   }
 
   TaskConstMeta
-      get kCrateApiValueStoreValueStoreUpdaterSetPluginRwDirConstMeta =>
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetCurrentSongConstMeta =>
           const TaskConstMeta(
-            debugName: "ValueStoreUpdater_set_plugin_rw_dir",
-            argNames: ["that", "folder"],
+            debugName: "ValueStoreUpdater_auto_accessor_set_current_song",
+            argNames: ["that", "currentSong"],
           );
 
   @override
-  Future<void> crateApiValueStoreValueStoreUpdaterSetPluginsEnabled(
-      {required ValueStoreUpdater that, required bool val}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetLrclibBaseUrl(
+      {required ValueStoreUpdater that, String? lrclibBaseUrl}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
             that, serializer);
-        sse_encode_bool(val, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 32, port: port_);
+        sse_encode_opt_String(lrclibBaseUrl, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiValueStoreValueStoreUpdaterSetPluginsEnabledConstMeta,
-      argValues: [that, val],
+      constMeta:
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetLrclibBaseUrlConstMeta,
+      argValues: [that, lrclibBaseUrl],
       apiImpl: this,
     ));
   }
 
   TaskConstMeta
-      get kCrateApiValueStoreValueStoreUpdaterSetPluginsEnabledConstMeta =>
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetLrclibBaseUrlConstMeta =>
           const TaskConstMeta(
-            debugName: "ValueStoreUpdater_set_plugins_enabled",
-            argNames: ["that", "val"],
+            debugName: "ValueStoreUpdater_auto_accessor_set_lrclib_base_url",
+            argNames: ["that", "lrclibBaseUrl"],
           );
 
   @override
-  Future<void> crateApiValueStoreValueStoreUpdaterSetUnsafeApis(
-      {required ValueStoreUpdater that, required bool value}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetMusicFolder(
+      {required ValueStoreUpdater that, String? musicFolder}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
             that, serializer);
-        sse_encode_bool(value, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 33, port: port_);
+        sse_encode_opt_String(musicFolder, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiValueStoreValueStoreUpdaterSetUnsafeApisConstMeta,
-      argValues: [that, value],
+      constMeta:
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetMusicFolderConstMeta,
+      argValues: [that, musicFolder],
       apiImpl: this,
     ));
   }
 
   TaskConstMeta
-      get kCrateApiValueStoreValueStoreUpdaterSetUnsafeApisConstMeta =>
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetMusicFolderConstMeta =>
           const TaskConstMeta(
-            debugName: "ValueStoreUpdater_set_unsafe_apis",
-            argNames: ["that", "value"],
+            debugName: "ValueStoreUpdater_auto_accessor_set_music_folder",
+            argNames: ["that", "musicFolder"],
           );
 
   @override
-  Future<void> crateApiMusicHandlerAddSeparator({required String separator}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetMusicbrainzBaseUrl(
+      {required ValueStoreUpdater that, String? musicbrainzBaseUrl}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(separator, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 34, port: port_);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+            that, serializer);
+        sse_encode_opt_String(musicbrainzBaseUrl, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_String,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerAddSeparatorConstMeta,
-      argValues: [separator],
+      constMeta:
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetMusicbrainzBaseUrlConstMeta,
+      argValues: [that, musicbrainzBaseUrl],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerAddSeparatorConstMeta =>
-      const TaskConstMeta(
-        debugName: "add_separator",
-        argNames: ["separator"],
-      );
+  TaskConstMeta
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetMusicbrainzBaseUrlConstMeta =>
+          const TaskConstMeta(
+            debugName: "ValueStoreUpdater_auto_accessor_set_musicbrainz_base_url",
+            argNames: ["that", "musicbrainzBaseUrl"],
+          );
 
   @override
-  Future<void> crateApiPluginManCallFuncPlugins({required String func}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetPluginRwDir(
+      {required ValueStoreUpdater that, String? pluginRwDir}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(func, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 35, port: port_);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+            that, serializer);
+        sse_encode_opt_String(pluginRwDir, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiPluginManCallFuncPluginsConstMeta,
-      argValues: [func],
+      constMeta:
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetPluginRwDirConstMeta,
+      argValues: [that, pluginRwDir],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPluginManCallFuncPluginsConstMeta =>
-      const TaskConstMeta(
-        debugName: "call_func_plugins",
-        argNames: ["func"],
-      );
+  TaskConstMeta
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetPluginRwDirConstMeta =>
+          const TaskConstMeta(
+            debugName: "ValueStoreUpdater_auto_accessor_set_plugin_rw_dir",
+            argNames: ["that", "pluginRwDir"],
+          );
 
   @override
-  Future<bool> crateApiPluginManCallPluginFunc(
-      {required String func, required String plugin}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetPluginsEnabled(
+      {required ValueStoreUpdater that, bool? pluginsEnabled}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(func, serializer);
-        sse_encode_String(plugin, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 36, port: port_);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+            that, serializer);
+        sse_encode_opt_box_autoadd_bool(pluginsEnabled, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiPluginManCallPluginFuncConstMeta,
-      argValues: [func, plugin],
+      constMeta:
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetPluginsEnabledConstMeta,
+      argValues: [that, pluginsEnabled],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPluginManCallPluginFuncConstMeta =>
-      const TaskConstMeta(
-        debugName: "call_plugin_func",
-        argNames: ["func", "plugin"],
-      );
+  TaskConstMeta
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetPluginsEnabledConstMeta =>
+          const TaskConstMeta(
+            debugName: "ValueStoreUpdater_auto_accessor_set_plugins_enabled",
+            argNames: ["that", "pluginsEnabled"],
+          );
 
   @override
-  Future<void> crateApiMusicHandlerCancelDownload() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetPodcastFolder(
+      {required ValueStoreUpdater that, String? podcastFolder}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 37, port: port_);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+            that, serializer);
+        sse_encode_opt_String(podcastFolder, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerCancelDownloadConstMeta,
-      argValues: [],
+      constMeta:
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetPodcastFolderConstMeta,
+      argValues: [that, podcastFolder],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerCancelDownloadConstMeta =>
-      const TaskConstMeta(
-        debugName: "cancel_download",
-        argNames: [],
-      );
+  TaskConstMeta
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetPodcastFolderConstMeta =>
+          const TaskConstMeta(
+            debugName: "ValueStoreUpdater_auto_accessor_set_podcast_folder",
+            argNames: ["that", "podcastFolder"],
+          );
 
   @override
-  Future<bool> crateApiPluginManCheckPluginMan({AdiPluginMan? pmg}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetUnsafeApis(
+      {required ValueStoreUpdater that, bool? unsafeApis}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_opt_box_autoadd_adi_plugin_man(pmg, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 38, port: port_);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+            that, serializer);
+        sse_encode_opt_box_autoadd_bool(unsafeApis, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiPluginManCheckPluginManConstMeta,
-      argValues: [pmg],
+      constMeta:
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetUnsafeApisConstMeta,
+      argValues: [that, unsafeApis],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPluginManCheckPluginManConstMeta =>
-      const TaskConstMeta(
-        debugName: "check_plugin_man",
-        argNames: ["pmg"],
-      );
+  TaskConstMeta
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetUnsafeApisConstMeta =>
+          const TaskConstMeta(
+            debugName: "ValueStoreUpdater_auto_accessor_set_unsafe_apis",
+            argNames: ["that", "unsafeApis"],
+          );
 
   @override
-  Future<bool> crateApiUtilsCheckPluginsEnabled() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
+  void crateApiValueStoreValueStoreUpdaterAutoAccessorSetYtdlpPath(
+      {required ValueStoreUpdater that, String? ytdlpPath}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 39, port: port_);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+            that, serializer);
+        sse_encode_opt_String(ytdlpPath, serializer);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiUtilsCheckPluginsEnabledConstMeta,
-      argValues: [],
+      constMeta:
+          kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetYtdlpPathConstMeta,
+      argValues: [that, ytdlpPath],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiUtilsCheckPluginsEnabledConstMeta =>
-      const TaskConstMeta(
-        debugName: "check_plugins_enabled",
-        argNames: [],
-      );
+  TaskConstMeta
+      get kCrateApiValueStoreValueStoreUpdaterAutoAccessorSetYtdlpPathConstMeta =>
+          const TaskConstMeta(
+            debugName: "ValueStoreUpdater_auto_accessor_set_ytdlp_path",
+            argNames: ["that", "ytdlpPath"],
+          );
 
   @override
-  Future<bool> crateApiUtilsCheckUnsafeApi() {
+  Future<void> crateApiValueStoreValueStoreUpdaterClearCurrentSong(
+      {required ValueStoreUpdater that}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+            that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 40, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiUtilsCheckUnsafeApiConstMeta,
-      argValues: [],
+      constMeta: kCrateApiValueStoreValueStoreUpdaterClearCurrentSongConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiUtilsCheckUnsafeApiConstMeta =>
-      const TaskConstMeta(
-        debugName: "check_unsafe_api",
-        argNames: [],
-      );
+  TaskConstMeta
+      get kCrateApiValueStoreValueStoreUpdaterClearCurrentSongConstMeta =>
+          const TaskConstMeta(
+            debugName: "ValueStoreUpdater_clear_current_song",
+            argNames: ["that"],
+          );
 
   @override
-  Future<bool> crateApiMusicHandlerClearMp3Cache() {
+  Future<ValueStoreUpdater> crateApiValueStoreValueStoreUpdaterDefault() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
@@ -1487,502 +1992,560 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             funcId: 41, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
+        decodeSuccessData:
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerClearMp3CacheConstMeta,
+      constMeta: kCrateApiValueStoreValueStoreUpdaterDefaultConstMeta,
       argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerClearMp3CacheConstMeta =>
+  TaskConstMeta get kCrateApiValueStoreValueStoreUpdaterDefaultConstMeta =>
       const TaskConstMeta(
-        debugName: "clear_mp3_cache",
+        debugName: "ValueStoreUpdater_default",
         argNames: [],
       );
 
   @override
-  Future<String> crateApiMusicHandlerDownloadToTemp(
-      {required String query, String? flags}) {
+  Future<ValueStoreUpdater> crateApiValueStoreValueStoreUpdaterNew() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(query, serializer);
-        sse_encode_opt_String(flags, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 42, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_String,
+        decodeSuccessData:
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerDownloadToTempConstMeta,
-      argValues: [query, flags],
+      constMeta: kCrateApiValueStoreValueStoreUpdaterNewConstMeta,
+      argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerDownloadToTempConstMeta =>
+  TaskConstMeta get kCrateApiValueStoreValueStoreUpdaterNewConstMeta =>
       const TaskConstMeta(
-        debugName: "download_to_temp",
-        argNames: ["query", "flags"],
+        debugName: "ValueStoreUpdater_new",
+        argNames: [],
       );
 
   @override
-  Future<Float64List> crateApiMusicHandlerExtractWaveformFromMp3(
-      {required String mp3Path, int? sampleCount, int? channels}) {
+  Future<void> crateApiValueStoreValueStoreUpdaterSetAcoustidApiKey(
+      {required ValueStoreUpdater that, required String key}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(mp3Path, serializer);
-        sse_encode_opt_box_autoadd_u_32(sampleCount, serializer);
-        sse_encode_opt_box_autoadd_u_32(channels, serializer);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+            that, serializer);
+        sse_encode_String(key, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 43, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_prim_f_64_strict,
-        decodeErrorData: sse_decode_String,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerExtractWaveformFromMp3ConstMeta,
-      argValues: [mp3Path, sampleCount, channels],
+      constMeta: kCrateApiValueStoreValueStoreUpdaterSetAcoustidApiKeyConstMeta,
+      argValues: [that, key],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerExtractWaveformFromMp3ConstMeta =>
-      const TaskConstMeta(
-        debugName: "extract_waveform_from_mp3",
-        argNames: ["mp3Path", "sampleCount", "channels"],
-      );
+  TaskConstMeta
+      get kCrateApiValueStoreValueStoreUpdaterSetAcoustidApiKeyConstMeta =>
+          const TaskConstMeta(
+            debugName: "ValueStoreUpdater_set_acoustid_api_key",
+            argNames: ["that", "key"],
+          );
 
   @override
-  Future<bool> crateApiPluginManFadButtonIsValid({required FadButton that}) {
+  Future<void> crateApiValueStoreValueStoreUpdaterSetAcoustidBaseUrl(
+      {required ValueStoreUpdater that, required String url}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_fad_button(that, serializer);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+            that, serializer);
+        sse_encode_String(url, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 44, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiPluginManFadButtonIsValidConstMeta,
-      argValues: [that],
+      constMeta:
+          kCrateApiValueStoreValueStoreUpdaterSetAcoustidBaseUrlConstMeta,
+      argValues: [that, url],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPluginManFadButtonIsValidConstMeta =>
-      const TaskConstMeta(
-        debugName: "fad_button_is_valid",
-        argNames: ["that"],
-      );
+  TaskConstMeta
+      get kCrateApiValueStoreValueStoreUpdaterSetAcoustidBaseUrlConstMeta =>
+          const TaskConstMeta(
+            debugName: "ValueStoreUpdater_set_acoustid_base_url",
+            argNames: ["that", "url"],
+          );
 
   @override
-  Future<String> crateApiPluginManFindButtonsByName({required String name}) {
+  Future<void> crateApiValueStoreValueStoreUpdaterSetCoverartBaseUrl(
+      {required ValueStoreUpdater that, required String url}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(name, serializer);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+            that, serializer);
+        sse_encode_String(url, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 45, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiPluginManFindButtonsByNameConstMeta,
-      argValues: [name],
+      constMeta:
+          kCrateApiValueStoreValueStoreUpdaterSetCoverartBaseUrlConstMeta,
+      argValues: [that, url],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPluginManFindButtonsByNameConstMeta =>
-      const TaskConstMeta(
-        debugName: "find_buttons_by_name",
-        argNames: ["name"],
-      );
+  TaskConstMeta
+      get kCrateApiValueStoreValueStoreUpdaterSetCoverartBaseUrlConstMeta =>
+          const TaskConstMeta(
+            debugName: "ValueStoreUpdater_set_coverart_base_url",
+            argNames: ["that", "url"],
+          );
 
   @override
-  Future<String> crateApiPluginManFindItemsByCallback(
-      {required String callback}) {
+  Future<void> crateApiValueStoreValueStoreUpdaterSetCurrentSong(
+      {required ValueStoreUpdater that, required SongMetadata song}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(callback, serializer);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+            that, serializer);
+        sse_encode_box_autoadd_song_metadata(song, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 46, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiPluginManFindItemsByCallbackConstMeta,
-      argValues: [callback],
+      constMeta: kCrateApiValueStoreValueStoreUpdaterSetCurrentSongConstMeta,
+      argValues: [that, song],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPluginManFindItemsByCallbackConstMeta =>
-      const TaskConstMeta(
-        debugName: "find_items_by_callback",
-        argNames: ["callback"],
-      );
+  TaskConstMeta
+      get kCrateApiValueStoreValueStoreUpdaterSetCurrentSongConstMeta =>
+          const TaskConstMeta(
+            debugName: "ValueStoreUpdater_set_current_song",
+            argNames: ["that", "song"],
+          );
 
   @override
-  Future<String> crateApiPluginManGetAllButtons({String? locationFilter}) {
+  Future<void> crateApiValueStoreValueStoreUpdaterSetLrclibBaseUrl(
+      {required ValueStoreUpdater that, required String url}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_opt_String(locationFilter, serializer);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+            that, serializer);
+        sse_encode_String(url, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 47, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiPluginManGetAllButtonsConstMeta,
-      argValues: [locationFilter],
+      constMeta: kCrateApiValueStoreValueStoreUpdaterSetLrclibBaseUrlConstMeta,
+      argValues: [that, url],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPluginManGetAllButtonsConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_all_buttons",
-        argNames: ["locationFilter"],
-      );
+  TaskConstMeta
+      get kCrateApiValueStoreValueStoreUpdaterSetLrclibBaseUrlConstMeta =>
+          const TaskConstMeta(
+            debugName: "ValueStoreUpdater_set_lrclib_base_url",
+            argNames: ["that", "url"],
+          );
 
   @override
-  Future<String> crateApiPluginManGetAllPopups() {
+  Future<void> crateApiValueStoreValueStoreUpdaterSetMusicFolder(
+      {required ValueStoreUpdater that, required String folder}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 48, port: port_);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+            that, serializer);
+        sse_encode_String(folder, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 48, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiPluginManGetAllPopupsConstMeta,
-      argValues: [],
+      constMeta: kCrateApiValueStoreValueStoreUpdaterSetMusicFolderConstMeta,
+      argValues: [that, folder],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPluginManGetAllPopupsConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_all_popups",
-        argNames: [],
-      );
+  TaskConstMeta
+      get kCrateApiValueStoreValueStoreUpdaterSetMusicFolderConstMeta =>
+          const TaskConstMeta(
+            debugName: "ValueStoreUpdater_set_music_folder",
+            argNames: ["that", "folder"],
+          );
 
   @override
-  Future<String> crateApiPluginManGetAllScreens() {
+  Future<void> crateApiValueStoreValueStoreUpdaterSetMusicbrainzBaseUrl(
+      {required ValueStoreUpdater that, required String url}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+            that, serializer);
+        sse_encode_String(url, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 49, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiPluginManGetAllScreensConstMeta,
-      argValues: [],
+      constMeta:
+          kCrateApiValueStoreValueStoreUpdaterSetMusicbrainzBaseUrlConstMeta,
+      argValues: [that, url],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPluginManGetAllScreensConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_all_screens",
-        argNames: [],
-      );
+  TaskConstMeta
+      get kCrateApiValueStoreValueStoreUpdaterSetMusicbrainzBaseUrlConstMeta =>
+          const TaskConstMeta(
+            debugName: "ValueStoreUpdater_set_musicbrainz_base_url",
+            argNames: ["that", "url"],
+          );
 
   @override
-  Future<List<String>> crateApiMusicHandlerGetArtistViaFfprobe(
-      {required String filePath}) {
+  Future<void> crateApiValueStoreValueStoreUpdaterSetPluginRwDir(
+      {required ValueStoreUpdater that, required String folder}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(filePath, serializer);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+            that, serializer);
+        sse_encode_String(folder, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 50, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_String,
-        decodeErrorData: sse_decode_String,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerGetArtistViaFfprobeConstMeta,
-      argValues: [filePath],
+      constMeta: kCrateApiValueStoreValueStoreUpdaterSetPluginRwDirConstMeta,
+      argValues: [that, folder],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerGetArtistViaFfprobeConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_artist_via_ffprobe",
-        argNames: ["filePath"],
-      );
+  TaskConstMeta
+      get kCrateApiValueStoreValueStoreUpdaterSetPluginRwDirConstMeta =>
+          const TaskConstMeta(
+            debugName: "ValueStoreUpdater_set_plugin_rw_dir",
+            argNames: ["that", "folder"],
+          );
 
   @override
-  Future<Uint8List?> crateApiMusicHandlerGetCachedAlbumArt(
-      {required String path}) {
+  Future<void> crateApiValueStoreValueStoreUpdaterSetPluginsEnabled(
+      {required ValueStoreUpdater that, required bool val}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+            that, serializer);
+        sse_encode_bool(val, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 51, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerGetCachedAlbumArtConstMeta,
-      argValues: [path],
+      constMeta: kCrateApiValueStoreValueStoreUpdaterSetPluginsEnabledConstMeta,
+      argValues: [that, val],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerGetCachedAlbumArtConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_cached_album_art",
-        argNames: ["path"],
-      );
+  TaskConstMeta
+      get kCrateApiValueStoreValueStoreUpdaterSetPluginsEnabledConstMeta =>
+          const TaskConstMeta(
+            debugName: "ValueStoreUpdater_set_plugins_enabled",
+            argNames: ["that", "val"],
+          );
 
   @override
-  Future<SongMetadata> crateApiMusicHandlerGetCdTrackMetadata(
-      {required String device, required int track}) {
+  Future<void> crateApiValueStoreValueStoreUpdaterSetPodcastFolder(
+      {required ValueStoreUpdater that, required String folder}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(device, serializer);
-        sse_encode_u_32(track, serializer);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+            that, serializer);
+        sse_encode_String(folder, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 52, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_song_metadata,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerGetCdTrackMetadataConstMeta,
-      argValues: [device, track],
+      constMeta: kCrateApiValueStoreValueStoreUpdaterSetPodcastFolderConstMeta,
+      argValues: [that, folder],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerGetCdTrackMetadataConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_cd_track_metadata",
-        argNames: ["device", "track"],
-      );
+  TaskConstMeta
+      get kCrateApiValueStoreValueStoreUpdaterSetPodcastFolderConstMeta =>
+          const TaskConstMeta(
+            debugName: "ValueStoreUpdater_set_podcast_folder",
+            argNames: ["that", "folder"],
+          );
 
   @override
-  Future<List<String>> crateApiMusicHandlerGetCurrentSeparators() {
+  Future<void> crateApiValueStoreValueStoreUpdaterSetUnsafeApis(
+      {required ValueStoreUpdater that, required bool value}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+            that, serializer);
+        sse_encode_bool(value, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 53, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_String,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerGetCurrentSeparatorsConstMeta,
-      argValues: [],
+      constMeta: kCrateApiValueStoreValueStoreUpdaterSetUnsafeApisConstMeta,
+      argValues: [that, value],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerGetCurrentSeparatorsConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_current_separators",
-        argNames: [],
-      );
+  TaskConstMeta
+      get kCrateApiValueStoreValueStoreUpdaterSetUnsafeApisConstMeta =>
+          const TaskConstMeta(
+            debugName: "ValueStoreUpdater_set_unsafe_apis",
+            argNames: ["that", "value"],
+          );
 
   @override
-  Future<String?> crateApiMusicHandlerGetCurrentSongPath() {
+  Future<void> crateApiValueStoreValueStoreUpdaterSetYtdlpPath(
+      {required ValueStoreUpdater that, required String path}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+            that, serializer);
+        sse_encode_String(path, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 54, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_String,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerGetCurrentSongPathConstMeta,
-      argValues: [],
+      constMeta: kCrateApiValueStoreValueStoreUpdaterSetYtdlpPathConstMeta,
+      argValues: [that, path],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerGetCurrentSongPathConstMeta =>
+  TaskConstMeta get kCrateApiValueStoreValueStoreUpdaterSetYtdlpPathConstMeta =>
       const TaskConstMeta(
-        debugName: "get_current_song_path",
-        argNames: [],
+        debugName: "ValueStoreUpdater_set_ytdlp_path",
+        argNames: ["that", "path"],
       );
 
   @override
-  Future<double> crateApiMusicHandlerGetCvol() {
+  Future<void> crateApiMusicHandlerAddSeparator({required String separator}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(separator, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 55, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_f_32,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiMusicHandlerGetCvolConstMeta,
-      argValues: [],
+      constMeta: kCrateApiMusicHandlerAddSeparatorConstMeta,
+      argValues: [separator],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerGetCvolConstMeta =>
+  TaskConstMeta get kCrateApiMusicHandlerAddSeparatorConstMeta =>
       const TaskConstMeta(
-        debugName: "get_cvol",
-        argNames: [],
+        debugName: "add_separator",
+        argNames: ["separator"],
       );
 
   @override
-  Future<int?> crateApiColorExtractorGetDominantColor(
-      {required List<int> data}) {
+  Future<void> crateApiRadioStationsAddStation(
+      {required RadioStation station}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_prim_u_8_loose(data, serializer);
+        sse_encode_box_autoadd_radio_station(station, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 56, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiColorExtractorGetDominantColorConstMeta,
-      argValues: [data],
+      constMeta: kCrateApiRadioStationsAddStationConstMeta,
+      argValues: [station],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiColorExtractorGetDominantColorConstMeta =>
+  TaskConstMeta get kCrateApiRadioStationsAddStationConstMeta =>
       const TaskConstMeta(
-        debugName: "get_dominant_color",
-        argNames: ["data"],
+        debugName: "add_station",
+        argNames: ["station"],
       );
 
   @override
-  Future<String?> crateApiUtilsGetLatestVersion() {
+  Future<int> crateApiPlaylistManagerAddToPlaylist(
+      {required String name,
+      required List<String> songPaths,
+      int? position,
+      required bool allowDuplicates}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        sse_encode_list_String(songPaths, serializer);
+        sse_encode_opt_box_autoadd_u_32(position, serializer);
+        sse_encode_bool(allowDuplicates, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 57, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_String,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_u_32,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiUtilsGetLatestVersionConstMeta,
-      argValues: [],
+      constMeta: kCrateApiPlaylistManagerAddToPlaylistConstMeta,
+      argValues: [name, songPaths, position, allowDuplicates],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiUtilsGetLatestVersionConstMeta =>
+  TaskConstMeta get kCrateApiPlaylistManagerAddToPlaylistConstMeta =>
       const TaskConstMeta(
-        debugName: "get_latest_version",
-        argNames: [],
+        debugName: "add_to_playlist",
+        argNames: ["name", "songPaths", "position", "allowDuplicates"],
       );
 
   @override
-  Future<double> crateApiMusicHandlerGetPlaybackPosition() {
+  Future<TagEditRecord> crateApiTagEditorBatchEditTags(
+      {required List<TagEdit> edits}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_tag_edit(edits, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 58, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_f_32,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_tag_edit_record,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiMusicHandlerGetPlaybackPositionConstMeta,
-      argValues: [],
+      constMeta: kCrateApiTagEditorBatchEditTagsConstMeta,
+      argValues: [edits],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerGetPlaybackPositionConstMeta =>
+  TaskConstMeta get kCrateApiTagEditorBatchEditTagsConstMeta =>
       const TaskConstMeta(
-        debugName: "get_playback_position",
-        argNames: [],
+        debugName: "batch_edit_tags",
+        argNames: ["edits"],
       );
 
   @override
-  Future<String> crateApiPluginManGetPluginConfig({required String path}) {
+  Future<void> crateApiPluginManCallFuncPlugins({required String func}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
+        sse_encode_String(func, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 59, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiPluginManGetPluginConfigConstMeta,
-      argValues: [path],
+      constMeta: kCrateApiPluginManCallFuncPluginsConstMeta,
+      argValues: [func],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPluginManGetPluginConfigConstMeta =>
+  TaskConstMeta get kCrateApiPluginManCallFuncPluginsConstMeta =>
       const TaskConstMeta(
-        debugName: "get_plugin_config",
-        argNames: ["path"],
+        debugName: "call_func_plugins",
+        argNames: ["func"],
       );
 
   @override
-  Future<String> crateApiPluginManGetPluginFadConfig({required String path}) {
+  Future<bool> crateApiPluginManCallPluginFunc(
+      {required String func, required String plugin}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
+        sse_encode_String(func, serializer);
+        sse_encode_String(plugin, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 60, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData: sse_decode_bool,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiPluginManGetPluginFadConfigConstMeta,
-      argValues: [path],
+      constMeta: kCrateApiPluginManCallPluginFuncConstMeta,
+      argValues: [func, plugin],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPluginManGetPluginFadConfigConstMeta =>
+  TaskConstMeta get kCrateApiPluginManCallPluginFuncConstMeta =>
       const TaskConstMeta(
-        debugName: "get_plugin_fad_config",
-        argNames: ["path"],
+        debugName: "call_plugin_func",
+        argNames: ["func", "plugin"],
       );
 
   @override
-  Future<Float32List> crateApiMusicHandlerGetRealtimePeaks() {
+  Future<void> crateApiDownloadManagerCancelAllDownloads() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
@@ -1990,23 +2553,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             funcId: 61, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_prim_f_32_strict,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerGetRealtimePeaksConstMeta,
+      constMeta: kCrateApiDownloadManagerCancelAllDownloadsConstMeta,
       argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerGetRealtimePeaksConstMeta =>
+  TaskConstMeta get kCrateApiDownloadManagerCancelAllDownloadsConstMeta =>
       const TaskConstMeta(
-        debugName: "get_realtime_peaks",
+        debugName: "cancel_all_downloads",
         argNames: [],
       );
 
   @override
-  Future<void> crateApiPluginManInitPluginMan() {
+  Future<void> crateApiMusicHandlerCancelDownload() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
@@ -2017,47 +2580,51 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiPluginManInitPluginManConstMeta,
+      constMeta: kCrateApiMusicHandlerCancelDownloadConstMeta,
       argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPluginManInitPluginManConstMeta =>
+  TaskConstMeta get kCrateApiMusicHandlerCancelDownloadConstMeta =>
       const TaskConstMeta(
-        debugName: "init_plugin_man",
+        debugName: "cancel_download",
         argNames: [],
       );
 
   @override
-  Future<void> crateApiValueStoreInitValueStore() {
+  Future<bool> crateApiDownloadManagerCancelDownloadJob({required BigInt id}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(id, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 63, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_String,
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiValueStoreInitValueStoreConstMeta,
-      argValues: [],
+      constMeta: kCrateApiDownloadManagerCancelDownloadJobConstMeta,
+      argValues: [id],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiValueStoreInitValueStoreConstMeta =>
+  TaskConstMeta get kCrateApiDownloadManagerCancelDownloadJobConstMeta =>
       const TaskConstMeta(
-        debugName: "init_value_store",
-        argNames: [],
+        debugName: "cancel_download_job",
+        argNames: ["id"],
       );
 
   @override
-  Future<bool> crateApiMusicHandlerInitializePlayer() {
+  Future<bool> crateApiPodcastsCancelEpisodeDownload(
+      {required BigInt podcastId, required String guid}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(podcastId, serializer);
+        sse_encode_String(guid, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 64, port: port_);
       },
@@ -2065,23 +2632,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         decodeSuccessData: sse_decode_bool,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerInitializePlayerConstMeta,
-      argValues: [],
+      constMeta: kCrateApiPodcastsCancelEpisodeDownloadConstMeta,
+      argValues: [podcastId, guid],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerInitializePlayerConstMeta =>
+  TaskConstMeta get kCrateApiPodcastsCancelEpisodeDownloadConstMeta =>
       const TaskConstMeta(
-        debugName: "initialize_player",
-        argNames: [],
+        debugName: "cancel_episode_download",
+        argNames: ["podcastId", "guid"],
       );
 
   @override
-  Future<bool> crateApiMusicHandlerIsPlaying() {
+  Future<bool> crateApiAcoustidCancelIdentify({required BigInt batchId}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(batchId, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 65, port: port_);
       },
@@ -2089,20 +2657,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         decodeSuccessData: sse_decode_bool,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerIsPlayingConstMeta,
-      argValues: [],
+      constMeta: kCrateApiAcoustidCancelIdentifyConstMeta,
+      argValues: [batchId],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerIsPlayingConstMeta =>
+  TaskConstMeta get kCrateApiAcoustidCancelIdentifyConstMeta =>
       const TaskConstMeta(
-        debugName: "is_playing",
-        argNames: [],
+        debugName: "cancel_identify",
+        argNames: ["batchId"],
       );
 
   @override
-  Future<bool> crateApiPluginManIsPluginLoaded({required String path}) {
+  Future<List<Chapter>> crateApiChaptersChapterList({required String path}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
@@ -2111,47 +2679,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             funcId: 66, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
+        decodeSuccessData: sse_decode_list_chapter,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiPluginManIsPluginLoadedConstMeta,
+      constMeta: kCrateApiChaptersChapterListConstMeta,
       argValues: [path],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPluginManIsPluginLoadedConstMeta =>
+  TaskConstMeta get kCrateApiChaptersChapterListConstMeta =>
       const TaskConstMeta(
-        debugName: "is_plugin_loaded",
+        debugName: "chapter_list",
         argNames: ["path"],
       );
 
   @override
-  Future<List<String>> crateApiMusicHandlerListAudioCds() {
+  Future<bool> crateApiPluginManCheckPluginMan({AdiPluginMan? pmg}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_box_autoadd_adi_plugin_man(pmg, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 67, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_String,
+        decodeSuccessData: sse_decode_bool,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerListAudioCdsConstMeta,
-      argValues: [],
+      constMeta: kCrateApiPluginManCheckPluginManConstMeta,
+      argValues: [pmg],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerListAudioCdsConstMeta =>
+  TaskConstMeta get kCrateApiPluginManCheckPluginManConstMeta =>
       const TaskConstMeta(
-        debugName: "list_audio_cds",
-        argNames: [],
+        debugName: "check_plugin_man",
+        argNames: ["pmg"],
       );
 
   @override
-  Future<List<String>> crateApiMusicHandlerListAudioDevices() {
+  Future<bool> crateApiUtilsCheckPluginsEnabled() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
@@ -2159,23 +2728,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             funcId: 68, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_String,
+        decodeSuccessData: sse_decode_bool,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerListAudioDevicesConstMeta,
+      constMeta: kCrateApiUtilsCheckPluginsEnabledConstMeta,
       argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerListAudioDevicesConstMeta =>
+  TaskConstMeta get kCrateApiUtilsCheckPluginsEnabledConstMeta =>
       const TaskConstMeta(
-        debugName: "list_audio_devices",
+        debugName: "check_plugins_enabled",
         argNames: [],
       );
 
   @override
-  Future<List<String>> crateApiPluginManListLoadedPlugins() {
+  Future<bool> crateApiUtilsCheckUnsafeApi() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
@@ -2183,72 +2752,71 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             funcId: 69, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_String,
+        decodeSuccessData: sse_decode_bool,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiPluginManListLoadedPluginsConstMeta,
+      constMeta: kCrateApiUtilsCheckUnsafeApiConstMeta,
       argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPluginManListLoadedPluginsConstMeta =>
+  TaskConstMeta get kCrateApiUtilsCheckUnsafeApiConstMeta =>
       const TaskConstMeta(
-        debugName: "list_loaded_plugins",
+        debugName: "check_unsafe_api",
         argNames: [],
       );
 
   @override
-  Future<String> crateApiPluginManLoadPlugin({required String path}) {
+  Future<bool> crateApiArtCacheClearArtCache() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 70, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_String,
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiPluginManLoadPluginConstMeta,
-      argValues: [path],
+      constMeta: kCrateApiArtCacheClearArtCacheConstMeta,
+      argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPluginManLoadPluginConstMeta =>
+  TaskConstMeta get kCrateApiArtCacheClearArtCacheConstMeta =>
       const TaskConstMeta(
-        debugName: "load_plugin",
-        argNames: ["path"],
+        debugName: "clear_art_cache",
+        argNames: [],
       );
 
   @override
-  Future<SongMetadata?> crateApiAcoustidLookup({required String path}) {
+  Future<bool> crateApiFingerprintClearFingerprintCache() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 71, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_song_metadata,
+        decodeSuccessData: sse_decode_bool,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiAcoustidLookupConstMeta,
-      argValues: [path],
+      constMeta: kCrateApiFingerprintClearFingerprintCacheConstMeta,
+      argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiAcoustidLookupConstMeta => const TaskConstMeta(
-        debugName: "lookup",
-        argNames: ["path"],
+  TaskConstMeta get kCrateApiFingerprintClearFingerprintCacheConstMeta =>
+      const TaskConstMeta(
+        debugName: "clear_fingerprint_cache",
+        argNames: [],
       );
 
   @override
-  Future<bool> crateApiMusicHandlerPauseSong() {
+  Future<int> crateApiCollectionDownloadClearFinishedCollections() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
@@ -2256,48 +2824,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             funcId: 72, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
+        decodeSuccessData: sse_decode_u_32,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerPauseSongConstMeta,
+      constMeta: kCrateApiCollectionDownloadClearFinishedCollectionsConstMeta,
       argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerPauseSongConstMeta =>
-      const TaskConstMeta(
-        debugName: "pause_song",
-        argNames: [],
-      );
+  TaskConstMeta
+      get kCrateApiCollectionDownloadClearFinishedCollectionsConstMeta =>
+          const TaskConstMeta(
+            debugName: "clear_finished_collections",
+            argNames: [],
+          );
 
   @override
-  Future<bool> crateApiMusicHandlerPlaySong({required String path}) {
+  Future<int> crateApiDownloadManagerClearFinishedDownloads() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 73, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
+        decodeSuccessData: sse_decode_u_32,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerPlaySongConstMeta,
-      argValues: [path],
+      constMeta: kCrateApiDownloadManagerClearFinishedDownloadsConstMeta,
+      argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerPlaySongConstMeta =>
+  TaskConstMeta get kCrateApiDownloadManagerClearFinishedDownloadsConstMeta =>
       const TaskConstMeta(
-        debugName: "play_song",
-        argNames: ["path"],
+        debugName: "clear_finished_downloads",
+        argNames: [],
       );
 
   @override
-  Future<PlayerState> crateApiMusicHandlerPlayerStateDefault() {
+  Future<bool> crateApiMusicHandlerClearMp3Cache() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
@@ -2305,73 +2873,74 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             funcId: 74, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_player_state,
+        decodeSuccessData: sse_decode_bool,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerPlayerStateDefaultConstMeta,
+      constMeta: kCrateApiMusicHandlerClearMp3CacheConstMeta,
       argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerPlayerStateDefaultConstMeta =>
+  TaskConstMeta get kCrateApiMusicHandlerClearMp3CacheConstMeta =>
       const TaskConstMeta(
-        debugName: "player_state_default",
+        debugName: "clear_mp3_cache",
         argNames: [],
       );
 
   @override
-  Future<bool> crateApiMusicHandlerPreloadNextSong({required String path}) {
+  Future<void> crateApiSyncedLyricsClearSyncedLyrics() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 75, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
+        decodeSuccessData: sse_decode_unit,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerPreloadNextSongConstMeta,
-      argValues: [path],
+      constMeta: kCrateApiSyncedLyricsClearSyncedLyricsConstMeta,
+      argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerPreloadNextSongConstMeta =>
+  TaskConstMeta get kCrateApiSyncedLyricsClearSyncedLyricsConstMeta =>
       const TaskConstMeta(
-        debugName: "preload_next_song",
-        argNames: ["path"],
+        debugName: "clear_synced_lyrics",
+        argNames: [],
       );
 
   @override
-  Future<String> crateApiPluginManReloadPlugin({required String path}) {
+  Future<CollectionOptions>
+      crateApiCollectionDownloadCollectionOptionsDefault() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 76, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_String,
+        decodeSuccessData: sse_decode_collection_options,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiPluginManReloadPluginConstMeta,
-      argValues: [path],
+      constMeta: kCrateApiCollectionDownloadCollectionOptionsDefaultConstMeta,
+      argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPluginManReloadPluginConstMeta =>
-      const TaskConstMeta(
-        debugName: "reload_plugin",
-        argNames: ["path"],
-      );
+  TaskConstMeta
+      get kCrateApiCollectionDownloadCollectionOptionsDefaultConstMeta =>
+          const TaskConstMeta(
+            debugName: "collection_options_default",
+            argNames: [],
+          );
 
   @override
-  Future<String> crateApiPluginManRemovePlugin({required String path}) {
+  Future<ReplayGain> crateApiReplayGainComputeReplayGain(
+      {required String path}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
@@ -2380,73 +2949,75 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             funcId: 77, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData: sse_decode_replay_gain,
         decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiPluginManRemovePluginConstMeta,
+      constMeta: kCrateApiReplayGainComputeReplayGainConstMeta,
       argValues: [path],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPluginManRemovePluginConstMeta =>
+  TaskConstMeta get kCrateApiReplayGainComputeReplayGainConstMeta =>
       const TaskConstMeta(
-        debugName: "remove_plugin",
+        debugName: "compute_replay_gain",
         argNames: ["path"],
       );
 
   @override
-  Future<void> crateApiMusicHandlerRemoveSeparator(
-      {required String separator}) {
+  Future<double> crateApiColorExtractorContrastRatio(
+      {required int a, required int b}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(separator, serializer);
+        sse_encode_u_32(a, serializer);
+        sse_encode_u_32(b, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 78, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_String,
+        decodeSuccessData: sse_decode_f_64,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerRemoveSeparatorConstMeta,
-      argValues: [separator],
+      constMeta: kCrateApiColorExtractorContrastRatioConstMeta,
+      argValues: [a, b],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerRemoveSeparatorConstMeta =>
+  TaskConstMeta get kCrateApiColorExtractorContrastRatioConstMeta =>
       const TaskConstMeta(
-        debugName: "remove_separator",
-        argNames: ["separator"],
+        debugName: "contrast_ratio",
+        argNames: ["a", "b"],
       );
 
   @override
-  Future<void> crateApiMusicHandlerResetSeparators() {
+  Future<void> crateApiPlaylistManagerCreatePlaylist({required String name}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 79, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiMusicHandlerResetSeparatorsConstMeta,
-      argValues: [],
+      constMeta: kCrateApiPlaylistManagerCreatePlaylistConstMeta,
+      argValues: [name],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerResetSeparatorsConstMeta =>
+  TaskConstMeta get kCrateApiPlaylistManagerCreatePlaylistConstMeta =>
       const TaskConstMeta(
-        debugName: "reset_separators",
-        argNames: [],
+        debugName: "create_playlist",
+        argNames: ["name"],
       );
 
   @override
-  Future<bool> crateApiMusicHandlerRestartPlayer() {
+  Future<CueSheet> crateApiCueSheetCueSheetDefault() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
@@ -2454,23 +3025,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             funcId: 80, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
+        decodeSuccessData: sse_decode_cue_sheet,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerRestartPlayerConstMeta,
+      constMeta: kCrateApiCueSheetCueSheetDefaultConstMeta,
       argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerRestartPlayerConstMeta =>
+  TaskConstMeta get kCrateApiCueSheetCueSheetDefaultConstMeta =>
       const TaskConstMeta(
-        debugName: "restart_player",
+        debugName: "cue_sheet_default",
         argNames: [],
       );
 
   @override
-  Future<bool> crateApiMusicHandlerResumeSong() {
+  Future<Chapter?> crateApiChaptersCurrentChapter() {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
@@ -2478,2453 +3049,10896 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             funcId: 81, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
+        decodeSuccessData: sse_decode_opt_box_autoadd_chapter,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerResumeSongConstMeta,
+      constMeta: kCrateApiChaptersCurrentChapterConstMeta,
       argValues: [],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerResumeSongConstMeta =>
+  TaskConstMeta get kCrateApiChaptersCurrentChapterConstMeta =>
       const TaskConstMeta(
-        debugName: "resume_song",
+        debugName: "current_chapter",
         argNames: [],
       );
 
   @override
-  Future<List<String>?> crateApiPluginManScanDir({required String path}) {
+  Future<LyricPosition?> crateApiSyncedLyricsCurrentLyricLine(
+      {required double position}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
+        sse_encode_f_32(position, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 82, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_list_String,
+        decodeSuccessData: sse_decode_opt_box_autoadd_lyric_position,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiPluginManScanDirConstMeta,
-      argValues: [path],
+      constMeta: kCrateApiSyncedLyricsCurrentLyricLineConstMeta,
+      argValues: [position],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPluginManScanDirConstMeta => const TaskConstMeta(
-        debugName: "scan_dir",
-        argNames: ["path"],
+  TaskConstMeta get kCrateApiSyncedLyricsCurrentLyricLineConstMeta =>
+      const TaskConstMeta(
+        debugName: "current_lyric_line",
+        argNames: ["position"],
       );
 
   @override
-  Future<List<SongMetadata>> crateApiMusicHandlerScanMusicDirectory(
-      {required String dirPath, required bool autoConvert}) {
+  Future<bool> crateApiLyricsStoreDeleteCachedLyrics(
+      {required String lyricsDir, required SongMetadata song}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(dirPath, serializer);
-        sse_encode_bool(autoConvert, serializer);
+        sse_encode_String(lyricsDir, serializer);
+        sse_encode_box_autoadd_song_metadata(song, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 83, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_song_metadata,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiMusicHandlerScanMusicDirectoryConstMeta,
-      argValues: [dirPath, autoConvert],
+      constMeta: kCrateApiLyricsStoreDeleteCachedLyricsConstMeta,
+      argValues: [lyricsDir, song],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerScanMusicDirectoryConstMeta =>
+  TaskConstMeta get kCrateApiLyricsStoreDeleteCachedLyricsConstMeta =>
       const TaskConstMeta(
-        debugName: "scan_music_directory",
-        argNames: ["dirPath", "autoConvert"],
+        debugName: "delete_cached_lyrics",
+        argNames: ["lyricsDir", "song"],
       );
 
   @override
-  Future<List<SongMetadata>> crateApiMusicHandlerSearchLyrics(
-      {required String lyricsDir,
-      required String query,
-      required String songDir}) {
+  Future<void> crateApiPodcastsDeleteEpisodeDownload(
+      {required BigInt podcastId, required String guid}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(lyricsDir, serializer);
-        sse_encode_String(query, serializer);
-        sse_encode_String(songDir, serializer);
+        sse_encode_u_64(podcastId, serializer);
+        sse_encode_String(guid, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 84, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_list_song_metadata,
-        decodeErrorData: sse_decode_AnyhowException,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiMusicHandlerSearchLyricsConstMeta,
-      argValues: [lyricsDir, query, songDir],
+      constMeta: kCrateApiPodcastsDeleteEpisodeDownloadConstMeta,
+      argValues: [podcastId, guid],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerSearchLyricsConstMeta =>
+  TaskConstMeta get kCrateApiPodcastsDeleteEpisodeDownloadConstMeta =>
       const TaskConstMeta(
-        debugName: "search_lyrics",
-        argNames: ["lyricsDir", "query", "songDir"],
+        debugName: "delete_episode_download",
+        argNames: ["podcastId", "guid"],
       );
 
   @override
-  Future<bool> crateApiMusicHandlerSeekToPosition({required double position}) {
+  Future<void> crateApiPlaylistManagerDeletePlaylist({required String name}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_f_32(position, serializer);
+        sse_encode_String(name, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 85, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiMusicHandlerSeekToPositionConstMeta,
-      argValues: [position],
+      constMeta: kCrateApiPlaylistManagerDeletePlaylistConstMeta,
+      argValues: [name],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerSeekToPositionConstMeta =>
+  TaskConstMeta get kCrateApiPlaylistManagerDeletePlaylistConstMeta =>
       const TaskConstMeta(
-        debugName: "seek_to_position",
-        argNames: ["position"],
+        debugName: "delete_playlist",
+        argNames: ["name"],
       );
 
   @override
-  Future<void> crateApiMusicHandlerSetFadein({required bool value}) {
+  Future<void> crateApiRadioStationsDeleteStation({required String name}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_bool(value, serializer);
+        sse_encode_String(name, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 86, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiMusicHandlerSetFadeinConstMeta,
-      argValues: [value],
+      constMeta: kCrateApiRadioStationsDeleteStationConstMeta,
+      argValues: [name],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerSetFadeinConstMeta =>
+  TaskConstMeta get kCrateApiRadioStationsDeleteStationConstMeta =>
       const TaskConstMeta(
-        debugName: "set_fadein",
-        argNames: ["value"],
+        debugName: "delete_station",
+        argNames: ["name"],
       );
 
   @override
-  Future<String> crateApiPluginManSetPluginConfig(
-      {required String path, required String key, required ConfigTypes value}) {
+  Future<List<PipelineResult>> crateApiDownloadPipelineDownloadAndImport(
+      {required String query, String? flags, PipelineConfig? config}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(path, serializer);
-        sse_encode_String(key, serializer);
-        sse_encode_box_autoadd_config_types(value, serializer);
+        sse_encode_String(query, serializer);
+        sse_encode_opt_String(flags, serializer);
+        sse_encode_opt_box_autoadd_pipeline_config(config, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 87, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData: sse_decode_list_pipeline_result,
         decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiPluginManSetPluginConfigConstMeta,
-      argValues: [path, key, value],
+      constMeta: kCrateApiDownloadPipelineDownloadAndImportConstMeta,
+      argValues: [query, flags, config],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiPluginManSetPluginConfigConstMeta =>
+  TaskConstMeta get kCrateApiDownloadPipelineDownloadAndImportConstMeta =>
       const TaskConstMeta(
-        debugName: "set_plugin_config",
-        argNames: ["path", "key", "value"],
+        debugName: "download_and_import",
+        argNames: ["query", "flags", "config"],
       );
 
   @override
-  Future<void> crateApiMusicHandlerSetSeparators(
-      {required List<String> separators}) {
+  Future<BigInt> crateApiDownloadManagerDownloadById(
+      {required String id, String? flags}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_String(separators, serializer);
+        sse_encode_String(id, serializer);
+        sse_encode_opt_String(flags, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 88, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiMusicHandlerSetSeparatorsConstMeta,
-      argValues: [separators],
+      constMeta: kCrateApiDownloadManagerDownloadByIdConstMeta,
+      argValues: [id, flags],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerSetSeparatorsConstMeta =>
+  TaskConstMeta get kCrateApiDownloadManagerDownloadByIdConstMeta =>
       const TaskConstMeta(
-        debugName: "set_separators",
-        argNames: ["separators"],
+        debugName: "download_by_id",
+        argNames: ["id", "flags"],
       );
 
   @override
-  Future<bool> crateApiMusicHandlerSetVolume({required double volume}) {
+  Future<CollectionDownload> crateApiCollectionDownloadDownloadCollection(
+      {required String url, required CollectionOptions options}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_f_32(volume, serializer);
+        sse_encode_String(url, serializer);
+        sse_encode_box_autoadd_collection_options(options, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 89, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_collection_download,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiMusicHandlerSetVolumeConstMeta,
-      argValues: [volume],
+      constMeta: kCrateApiCollectionDownloadDownloadCollectionConstMeta,
+      argValues: [url, options],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerSetVolumeConstMeta =>
+  TaskConstMeta get kCrateApiCollectionDownloadDownloadCollectionConstMeta =>
       const TaskConstMeta(
-        debugName: "set_volume",
-        argNames: ["volume"],
+        debugName: "download_collection",
+        argNames: ["url", "options"],
       );
 
   @override
-  Future<bool> crateApiMusicHandlerSkipToNext(
-      {required List<String> songs, required BigInt currentIndex}) {
+  Future<String> crateApiPodcastsDownloadEpisode(
+      {required BigInt podcastId, required String guid}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_String(songs, serializer);
-        sse_encode_usize(currentIndex, serializer);
+        sse_encode_u_64(podcastId, serializer);
+        sse_encode_String(guid, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 90, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiMusicHandlerSkipToNextConstMeta,
-      argValues: [songs, currentIndex],
+      constMeta: kCrateApiPodcastsDownloadEpisodeConstMeta,
+      argValues: [podcastId, guid],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerSkipToNextConstMeta =>
+  TaskConstMeta get kCrateApiPodcastsDownloadEpisodeConstMeta =>
       const TaskConstMeta(
-        debugName: "skip_to_next",
-        argNames: ["songs", "currentIndex"],
+        debugName: "download_episode",
+        argNames: ["podcastId", "guid"],
       );
 
   @override
-  Future<bool> crateApiMusicHandlerSkipToPrevious(
-      {required List<String> songs, required BigInt currentIndex}) {
+  Future<String> crateApiMusicHandlerDownloadToTemp(
+      {required String query, String? flags}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_list_String(songs, serializer);
-        sse_encode_usize(currentIndex, serializer);
+        sse_encode_String(query, serializer);
+        sse_encode_opt_String(flags, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 91, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiMusicHandlerSkipToPreviousConstMeta,
-      argValues: [songs, currentIndex],
+      constMeta: kCrateApiMusicHandlerDownloadToTempConstMeta,
+      argValues: [query, flags],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerSkipToPreviousConstMeta =>
+  TaskConstMeta get kCrateApiMusicHandlerDownloadToTempConstMeta =>
       const TaskConstMeta(
-        debugName: "skip_to_previous",
-        argNames: ["songs", "currentIndex"],
+        debugName: "download_to_temp",
+        argNames: ["query", "flags"],
       );
 
   @override
-  Future<SongMetadata> crateApiMusicHandlerSongMetadataDefault() {
+  Future<void> crateApiPlaylistManagerDuplicatePlaylist(
+      {required String name, required String newName}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        sse_encode_String(newName, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 92, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_song_metadata,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiMusicHandlerSongMetadataDefaultConstMeta,
-      argValues: [],
+      constMeta: kCrateApiPlaylistManagerDuplicatePlaylistConstMeta,
+      argValues: [name, newName],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerSongMetadataDefaultConstMeta =>
+  TaskConstMeta get kCrateApiPlaylistManagerDuplicatePlaylistConstMeta =>
       const TaskConstMeta(
-        debugName: "song_metadata_default",
-        argNames: [],
+        debugName: "duplicate_playlist",
+        argNames: ["name", "newName"],
       );
 
   @override
-  Future<bool> crateApiMusicHandlerStopSong() {
+  Future<EnrichedMetadata> crateApiMusicbrainzEnrichCandidate(
+      {required AcoustIdCandidate candidate}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_acoust_id_candidate(candidate, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 93, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_enriched_metadata,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiMusicHandlerStopSongConstMeta,
-      argValues: [],
+      constMeta: kCrateApiMusicbrainzEnrichCandidateConstMeta,
+      argValues: [candidate],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerStopSongConstMeta =>
+  TaskConstMeta get kCrateApiMusicbrainzEnrichCandidateConstMeta =>
       const TaskConstMeta(
-        debugName: "stop_song",
-        argNames: [],
+        debugName: "enrich_candidate",
+        argNames: ["candidate"],
       );
 
   @override
-  Future<bool> crateApiMusicHandlerSwitchToPreloadedNow() {
+  Future<PlaylistExportResult> crateApiPlaylistIoExportPlaylist(
+      {required String name,
+      required String destination,
+      PlaylistFormat? format,
+      required bool relativePaths}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        sse_encode_String(destination, serializer);
+        sse_encode_opt_box_autoadd_playlist_format(format, serializer);
+        sse_encode_bool(relativePaths, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 94, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_playlist_export_result,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiMusicHandlerSwitchToPreloadedNowConstMeta,
-      argValues: [],
+      constMeta: kCrateApiPlaylistIoExportPlaylistConstMeta,
+      argValues: [name, destination, format, relativePaths],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerSwitchToPreloadedNowConstMeta =>
+  TaskConstMeta get kCrateApiPlaylistIoExportPlaylistConstMeta =>
       const TaskConstMeta(
-        debugName: "switch_to_preloaded_now",
-        argNames: [],
+        debugName: "export_playlist",
+        argNames: ["name", "destination", "format", "relativePaths"],
       );
 
   @override
-  Future<int> crateApiMusicHandlerTrackNum({required String device}) {
+  Future<Palette?> crateApiColorExtractorExtractPalette(
+      {required List<int> data, required int n}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(device, serializer);
+        sse_encode_list_prim_u_8_loose(data, serializer);
+        sse_encode_u_32(n, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 95, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_i_32,
+        decodeSuccessData: sse_decode_opt_box_autoadd_palette,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerTrackNumConstMeta,
-      argValues: [device],
+      constMeta: kCrateApiColorExtractorExtractPaletteConstMeta,
+      argValues: [data, n],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerTrackNumConstMeta =>
+  TaskConstMeta get kCrateApiColorExtractorExtractPaletteConstMeta =>
       const TaskConstMeta(
-        debugName: "track_num",
-        argNames: ["device"],
+        debugName: "extract_palette",
+        argNames: ["data", "n"],
       );
 
   @override
-  Future<bool> crateApiUtilsUpdateExecutable(
-      {required String arch, required String expath}) {
+  Future<Float64List> crateApiMusicHandlerExtractWaveformFromMp3(
+      {required String mp3Path, int? sampleCount, int? channels}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(arch, serializer);
-        sse_encode_String(expath, serializer);
+        sse_encode_String(mp3Path, serializer);
+        sse_encode_opt_box_autoadd_u_32(sampleCount, serializer);
+        sse_encode_opt_box_autoadd_u_32(channels, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 96, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_list_prim_f_64_strict,
+        decodeErrorData: sse_decode_String,
       ),
-      constMeta: kCrateApiUtilsUpdateExecutableConstMeta,
-      argValues: [arch, expath],
+      constMeta: kCrateApiMusicHandlerExtractWaveformFromMp3ConstMeta,
+      argValues: [mp3Path, sampleCount, channels],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiUtilsUpdateExecutableConstMeta =>
+  TaskConstMeta get kCrateApiMusicHandlerExtractWaveformFromMp3ConstMeta =>
       const TaskConstMeta(
-        debugName: "update_executable",
-        argNames: ["arch", "expath"],
+        debugName: "extract_waveform_from_mp3",
+        argNames: ["mp3Path", "sampleCount", "channels"],
       );
 
   @override
-  Future<ValueStoreUpdater> crateApiValueStoreUpdateStore() {
+  Future<bool> crateApiPluginManFadButtonIsValid({required FadButton that}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_fad_button(that, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 97, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater,
+        decodeSuccessData: sse_decode_bool,
         decodeErrorData: null,
       ),
-      constMeta: kCrateApiValueStoreUpdateStoreConstMeta,
-      argValues: [],
+      constMeta: kCrateApiPluginManFadButtonIsValidConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiValueStoreUpdateStoreConstMeta =>
+  TaskConstMeta get kCrateApiPluginManFadButtonIsValidConstMeta =>
       const TaskConstMeta(
-        debugName: "update_store",
-        argNames: [],
+        debugName: "fad_button_is_valid",
+        argNames: ["that"],
       );
 
   @override
-  Future<void> crateApiValueStoreUpdateValueStore(
-      {required ValueStoreUpdate update}) {
+  Future<String> crateApiPluginManFindButtonsByName({required String name}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_value_store_update(update, serializer);
+        sse_encode_String(name, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 98, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_String,
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiValueStoreUpdateValueStoreConstMeta,
-      argValues: [update],
+      constMeta: kCrateApiPluginManFindButtonsByNameConstMeta,
+      argValues: [name],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiValueStoreUpdateValueStoreConstMeta =>
+  TaskConstMeta get kCrateApiPluginManFindButtonsByNameConstMeta =>
       const TaskConstMeta(
-        debugName: "update_value_store",
-        argNames: ["update"],
+        debugName: "find_buttons_by_name",
+        argNames: ["name"],
       );
 
   @override
-  Future<void> crateApiMusicHandlerWriteMeta({required SongMetadata meta}) {
+  Future<String?> crateApiLyricsStoreFindCachedLyrics(
+      {required String lyricsDir, required SongMetadata song}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_song_metadata(meta, serializer);
+        sse_encode_String(lyricsDir, serializer);
+        sse_encode_box_autoadd_song_metadata(song, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 99, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_String,
+        decodeSuccessData: sse_decode_opt_String,
+        decodeErrorData: null,
       ),
-      constMeta: kCrateApiMusicHandlerWriteMetaConstMeta,
-      argValues: [meta],
+      constMeta: kCrateApiLyricsStoreFindCachedLyricsConstMeta,
+      argValues: [lyricsDir, song],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiMusicHandlerWriteMetaConstMeta =>
+  TaskConstMeta get kCrateApiLyricsStoreFindCachedLyricsConstMeta =>
       const TaskConstMeta(
-        debugName: "write_meta",
-        argNames: ["meta"],
+        debugName: "find_cached_lyrics",
+        argNames: ["lyricsDir", "song"],
       );
 
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_ArcMutexPlugin => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin;
+  @override
+  Future<List<DuplicateGroup>> crateApiFingerprintFindDuplicates(
+      {required String musicFolder}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(musicFolder, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 100, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_duplicate_group,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiFingerprintFindDuplicatesConstMeta,
+      argValues: [musicFolder],
+      apiImpl: this,
+    ));
+  }
 
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_ArcMutexPlugin => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin;
+  TaskConstMeta get kCrateApiFingerprintFindDuplicatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "find_duplicates",
+        argNames: ["musicFolder"],
+      );
 
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_PluginInode => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode;
+  @override
+  Future<String> crateApiPluginManFindItemsByCallback(
+      {required String callback}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(callback, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 101, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPluginManFindItemsByCallbackConstMeta,
+      argValues: [callback],
+      apiImpl: this,
+    ));
+  }
 
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_PluginInode => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode;
+  TaskConstMeta get kCrateApiPluginManFindItemsByCallbackConstMeta =>
+      const TaskConstMeta(
+        debugName: "find_items_by_callback",
+        argNames: ["callback"],
+      );
 
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_RpcConfig => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig;
+  @override
+  Future<void> crateApiPlayStatsFlushPlayStats() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 102, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPlayStatsFlushPlayStatsConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
 
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_RpcConfig => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig;
+  TaskConstMeta get kCrateApiPlayStatsFlushPlayStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "flush_play_stats",
+        argNames: [],
+      );
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Value =>
-      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue;
+  @override
+  Future<void> crateApiAudiobooksForgetBookProgress({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 103, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiAudiobooksForgetBookProgressConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
 
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Value =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue;
+  TaskConstMeta get kCrateApiAudiobooksForgetBookProgressConstMeta =>
+      const TaskConstMeta(
+        debugName: "forget_book_progress",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<MaterialScheme> crateApiColorExtractorGenerateColorScheme(
+      {required int seed, required bool dark}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(seed, serializer);
+        sse_encode_bool(dark, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 104, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_material_scheme,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiColorExtractorGenerateColorSchemeConstMeta,
+      argValues: [seed, dark],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiColorExtractorGenerateColorSchemeConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_color_scheme",
+        argNames: ["seed", "dark"],
+      );
+
+  @override
+  Future<String> crateApiPluginManGetAllButtons({String? locationFilter}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_String(locationFilter, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 105, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPluginManGetAllButtonsConstMeta,
+      argValues: [locationFilter],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPluginManGetAllButtonsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_all_buttons",
+        argNames: ["locationFilter"],
+      );
+
+  @override
+  Future<String> crateApiPluginManGetAllPopups() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 106, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPluginManGetAllPopupsConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPluginManGetAllPopupsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_all_popups",
+        argNames: [],
+      );
+
+  @override
+  Future<String> crateApiPluginManGetAllScreens() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 107, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPluginManGetAllScreensConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPluginManGetAllScreensConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_all_screens",
+        argNames: [],
+      );
+
+  @override
+  Future<BigInt> crateApiArtCacheGetArtCacheMemoryUsage() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 108, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiArtCacheGetArtCacheMemoryUsageConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiArtCacheGetArtCacheMemoryUsageConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_art_cache_memory_usage",
+        argNames: [],
+      );
+
+  @override
+  Future<List<String>> crateApiMusicHandlerGetArtistViaFfprobe(
+      {required String filePath}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(filePath, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 109, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiMusicHandlerGetArtistViaFfprobeConstMeta,
+      argValues: [filePath],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerGetArtistViaFfprobeConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_artist_via_ffprobe",
+        argNames: ["filePath"],
+      );
+
+  @override
+  Future<BookProgress?> crateApiAudiobooksGetBookProgress(
+      {required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 110, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_book_progress,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiAudiobooksGetBookProgressConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiAudiobooksGetBookProgressConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_book_progress",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<Uint8List?> crateApiMusicHandlerGetCachedAlbumArt(
+      {required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 111, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerGetCachedAlbumArtConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerGetCachedAlbumArtConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_cached_album_art",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<Uint8List?> crateApiMusicHandlerGetCachedAlbumArtSized(
+      {required String path, required int size}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(size, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 112, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerGetCachedAlbumArtSizedConstMeta,
+      argValues: [path, size],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerGetCachedAlbumArtSizedConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_cached_album_art_sized",
+        argNames: ["path", "size"],
+      );
+
+  @override
+  Future<SongMetadata> crateApiMusicHandlerGetCdTrackMetadata(
+      {required String device, required int track}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(device, serializer);
+        sse_encode_u_32(track, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 113, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_song_metadata,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerGetCdTrackMetadataConstMeta,
+      argValues: [device, track],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerGetCdTrackMetadataConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_cd_track_metadata",
+        argNames: ["device", "track"],
+      );
+
+  @override
+  Future<List<String>> crateApiMusicHandlerGetCurrentSeparators() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 114, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerGetCurrentSeparatorsConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerGetCurrentSeparatorsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_current_separators",
+        argNames: [],
+      );
+
+  @override
+  Future<String?> crateApiMusicHandlerGetCurrentSongPath() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 115, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerGetCurrentSongPathConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerGetCurrentSongPathConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_current_song_path",
+        argNames: [],
+      );
+
+  @override
+  Future<double> crateApiMusicHandlerGetCvol() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 116, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_f_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerGetCvolConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerGetCvolConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_cvol",
+        argNames: [],
+      );
+
+  @override
+  Future<int?> crateApiColorExtractorGetDominantColor(
+      {required List<int> data}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_prim_u_8_loose(data, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 117, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiColorExtractorGetDominantColorConstMeta,
+      argValues: [data],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiColorExtractorGetDominantColorConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_dominant_color",
+        argNames: ["data"],
+      );
+
+  @override
+  Future<DownloadJob?> crateApiDownloadManagerGetDownload(
+      {required BigInt id}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(id, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 118, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_download_job,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiDownloadManagerGetDownloadConstMeta,
+      argValues: [id],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiDownloadManagerGetDownloadConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_download",
+        argNames: ["id"],
+      );
+
+  @override
+  Future<List<PodcastChapter>> crateApiPodcastsGetEpisodeChapters(
+      {required BigInt podcastId, required String guid}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(podcastId, serializer);
+        sse_encode_String(guid, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 119, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_podcast_chapter,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiPodcastsGetEpisodeChaptersConstMeta,
+      argValues: [podcastId, guid],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPodcastsGetEpisodeChaptersConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_episode_chapters",
+        argNames: ["podcastId", "guid"],
+      );
+
+  @override
+  Future<double> crateApiPodcastsGetEpisodePosition(
+      {required BigInt podcastId, required String guid}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(podcastId, serializer);
+        sse_encode_String(guid, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 120, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_f_64,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiPodcastsGetEpisodePositionConstMeta,
+      argValues: [podcastId, guid],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPodcastsGetEpisodePositionConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_episode_position",
+        argNames: ["podcastId", "guid"],
+      );
+
+  @override
+  Future<String?> crateApiUtilsGetLatestVersion() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 121, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiUtilsGetLatestVersionConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiUtilsGetLatestVersionConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_latest_version",
+        argNames: [],
+      );
+
+  @override
+  Future<FoundLyrics?> crateApiLyricsStoreGetLyrics(
+      {required String lyricsDir,
+      required SongMetadata song,
+      required bool allowOnline}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(lyricsDir, serializer);
+        sse_encode_box_autoadd_song_metadata(song, serializer);
+        sse_encode_bool(allowOnline, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 122, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_found_lyrics,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiLyricsStoreGetLyricsConstMeta,
+      argValues: [lyricsDir, song, allowOnline],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiLyricsStoreGetLyricsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_lyrics",
+        argNames: ["lyricsDir", "song", "allowOnline"],
+      );
+
+  @override
+  Future<PlatformInt64> crateApiLyricsEditorGetLyricsOffset(
+      {required String lrcPath}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(lrcPath, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 123, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_i_64,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiLyricsEditorGetLyricsOffsetConstMeta,
+      argValues: [lrcPath],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiLyricsEditorGetLyricsOffsetConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_lyrics_offset",
+        argNames: ["lrcPath"],
+      );
+
+  @override
+  Future<PipelineConfig> crateApiDownloadPipelineGetPipelineConfig() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 124, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_pipeline_config,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiDownloadPipelineGetPipelineConfigConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiDownloadPipelineGetPipelineConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_pipeline_config",
+        argNames: [],
+      );
+
+  @override
+  Future<PlayStat> crateApiPlayStatsGetPlayStat({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 125, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_play_stat,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPlayStatsGetPlayStatConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPlayStatsGetPlayStatConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_play_stat",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<double> crateApiMusicHandlerGetPlaybackPosition() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 126, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_f_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerGetPlaybackPositionConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerGetPlaybackPositionConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_playback_position",
+        argNames: [],
+      );
+
+  @override
+  Future<List<PlaylistEntry>> crateApiPlaylistManagerGetPlaylistEntries(
+      {required String name}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 127, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_playlist_entry,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiPlaylistManagerGetPlaylistEntriesConstMeta,
+      argValues: [name],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPlaylistManagerGetPlaylistEntriesConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_playlist_entries",
+        argNames: ["name"],
+      );
+
+  @override
+  Future<String> crateApiPluginManGetPluginConfig({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 128, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPluginManGetPluginConfigConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPluginManGetPluginConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_plugin_config",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<String> crateApiPluginManGetPluginFadConfig({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 129, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPluginManGetPluginFadConfigConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPluginManGetPluginFadConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_plugin_fad_config",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<Podcast> crateApiPodcastsGetPodcast({required BigInt id}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(id, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 130, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_podcast,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiPodcastsGetPodcastConstMeta,
+      argValues: [id],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPodcastsGetPodcastConstMeta => const TaskConstMeta(
+        debugName: "get_podcast",
+        argNames: ["id"],
+      );
+
+  @override
+  Future<Float32List> crateApiMusicHandlerGetRealtimePeaks() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 131, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_prim_f_32_strict,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerGetRealtimePeaksConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerGetRealtimePeaksConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_realtime_peaks",
+        argNames: [],
+      );
+
+  @override
+  Future<String?> crateApiSmartPlaylistGetSmartPlaylistQuery(
+      {required String name}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 132, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSmartPlaylistGetSmartPlaylistQueryConstMeta,
+      argValues: [name],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSmartPlaylistGetSmartPlaylistQueryConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_smart_playlist_query",
+        argNames: ["name"],
+      );
+
+  @override
+  Future<RadioStation> crateApiRadioStationsGetStation({required String name}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 133, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_radio_station,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiRadioStationsGetStationConstMeta,
+      argValues: [name],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiRadioStationsGetStationConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_station",
+        argNames: ["name"],
+      );
+
+  @override
+  Future<StreamMetadata?> crateApiHttpStreamGetStreamMetadata() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 134, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_stream_metadata,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiHttpStreamGetStreamMetadataConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiHttpStreamGetStreamMetadataConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_stream_metadata",
+        argNames: [],
+      );
+
+  @override
+  Future<LrcDocument?> crateApiSyncedLyricsGetSyncedLyrics() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 135, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_lrc_document,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSyncedLyricsGetSyncedLyricsConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSyncedLyricsGetSyncedLyricsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_synced_lyrics",
+        argNames: [],
+      );
+
+  @override
+  Future<List<TagEditRecord>> crateApiTagEditorGetTagEditHistory() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 136, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_tag_edit_record,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiTagEditorGetTagEditHistoryConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTagEditorGetTagEditHistoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_tag_edit_history",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiAcoustidHasAcoustidKey() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 137, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiAcoustidHasAcoustidKeyConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiAcoustidHasAcoustidKeyConstMeta =>
+      const TaskConstMeta(
+        debugName: "has_acoustid_key",
+        argNames: [],
+      );
+
+  @override
+  Future<BigInt> crateApiAcoustidIdentifyBatch(
+      {required List<String> paths, required int topN, int? workers}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_String(paths, serializer);
+        sse_encode_u_32(topN, serializer);
+        sse_encode_opt_box_autoadd_u_32(workers, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 138, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiAcoustidIdentifyBatchConstMeta,
+      argValues: [paths, topN, workers],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiAcoustidIdentifyBatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "identify_batch",
+        argNames: ["paths", "topN", "workers"],
+      );
+
+  @override
+  Future<PlaylistImportResult> crateApiPlaylistIoImportPlaylist(
+      {required String file, String? name}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(file, serializer);
+        sse_encode_opt_String(name, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 139, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_playlist_import_result,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiPlaylistIoImportPlaylistConstMeta,
+      argValues: [file, name],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPlaylistIoImportPlaylistConstMeta =>
+      const TaskConstMeta(
+        debugName: "import_playlist",
+        argNames: ["file", "name"],
+      );
+
+  @override
+  Future<void> crateApiPluginManInitPluginMan() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 140, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPluginManInitPluginManConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPluginManInitPluginManConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_plugin_man",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiValueStoreInitValueStore() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 141, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiValueStoreInitValueStoreConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiValueStoreInitValueStoreConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_value_store",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiMusicHandlerInitializePlayer() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 142, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerInitializePlayerConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerInitializePlayerConstMeta =>
+      const TaskConstMeta(
+        debugName: "initialize_player",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiAudiobooksIsAudiobook({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 143, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiAudiobooksIsAudiobookConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiAudiobooksIsAudiobookConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_audiobook",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<bool> crateApiMusicHandlerIsPlaying() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 144, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerIsPlayingConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerIsPlayingConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_playing",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiPluginManIsPluginLoaded({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 145, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPluginManIsPluginLoadedConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPluginManIsPluginLoadedConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_plugin_loaded",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<bool> crateApiSmartPlaylistIsSmartPlaylist({required String name}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 146, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSmartPlaylistIsSmartPlaylistConstMeta,
+      argValues: [name],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSmartPlaylistIsSmartPlaylistConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_smart_playlist",
+        argNames: ["name"],
+      );
+
+  @override
+  Future<List<String>> crateApiMusicHandlerListAudioCds() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 147, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerListAudioCdsConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerListAudioCdsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_audio_cds",
+        argNames: [],
+      );
+
+  @override
+  Future<List<String>> crateApiMusicHandlerListAudioDevices() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 148, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerListAudioDevicesConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerListAudioDevicesConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_audio_devices",
+        argNames: [],
+      );
+
+  @override
+  Future<List<BookProgress>> crateApiAudiobooksListBooksInProgress() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 149, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_book_progress,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiAudiobooksListBooksInProgressConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiAudiobooksListBooksInProgressConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_books_in_progress",
+        argNames: [],
+      );
+
+  @override
+  Future<List<CollectionDownload>>
+      crateApiCollectionDownloadListCollectionDownloads() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 150, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_collection_download,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiCollectionDownloadListCollectionDownloadsConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiCollectionDownloadListCollectionDownloadsConstMeta =>
+          const TaskConstMeta(
+            debugName: "list_collection_downloads",
+            argNames: [],
+          );
+
+  @override
+  Future<List<DownloadJob>> crateApiDownloadManagerListDownloads() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 151, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_download_job,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiDownloadManagerListDownloadsConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiDownloadManagerListDownloadsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_downloads",
+        argNames: [],
+      );
+
+  @override
+  Future<List<String>> crateApiPluginManListLoadedPlugins() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 152, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPluginManListLoadedPluginsConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPluginManListLoadedPluginsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_loaded_plugins",
+        argNames: [],
+      );
+
+  @override
+  Future<List<PlaylistInfo>> crateApiPlaylistManagerListPlaylists() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 153, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_playlist_info,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPlaylistManagerListPlaylistsConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPlaylistManagerListPlaylistsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_playlists",
+        argNames: [],
+      );
+
+  @override
+  Future<List<Podcast>> crateApiPodcastsListPodcasts() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 154, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_podcast,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPodcastsListPodcastsConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPodcastsListPodcastsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_podcasts",
+        argNames: [],
+      );
+
+  @override
+  Future<List<String>> crateApiSmartPlaylistListSmartPlaylists() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 155, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSmartPlaylistListSmartPlaylistsConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSmartPlaylistListSmartPlaylistsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_smart_playlists",
+        argNames: [],
+      );
+
+  @override
+  Future<List<RadioStation>> crateApiRadioStationsListStations() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 156, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_radio_station,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiRadioStationsListStationsConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiRadioStationsListStationsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_stations",
+        argNames: [],
+      );
+
+  @override
+  Future<String> crateApiPluginManLoadPlugin({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 157, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiPluginManLoadPluginConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPluginManLoadPluginConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_plugin",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<List<SongMetadata>> crateApiSmartPlaylistLoadSmartPlaylist(
+      {required String name}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 158, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_song_metadata,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiSmartPlaylistLoadSmartPlaylistConstMeta,
+      argValues: [name],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSmartPlaylistLoadSmartPlaylistConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_smart_playlist",
+        argNames: ["name"],
+      );
+
+  @override
+  Future<LrcDocument> crateApiSyncedLyricsLoadSyncedLyrics(
+      {required String content}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(content, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 159, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_lrc_document,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSyncedLyricsLoadSyncedLyricsConstMeta,
+      argValues: [content],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSyncedLyricsLoadSyncedLyricsConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_synced_lyrics",
+        argNames: ["content"],
+      );
+
+  @override
+  Future<LrcDocument> crateApiSyncedLyricsLoadSyncedLyricsFile(
+      {required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 160, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_lrc_document,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiSyncedLyricsLoadSyncedLyricsFileConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSyncedLyricsLoadSyncedLyricsFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_synced_lyrics_file",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<SongMetadata?> crateApiAcoustidLookup({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 161, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_song_metadata,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiAcoustidLookupConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiAcoustidLookupConstMeta => const TaskConstMeta(
+        debugName: "lookup",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<List<AcoustIdCandidate>> crateApiAcoustidLookupCandidates(
+      {required String path, required int topN}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_u_32(topN, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 162, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_acoust_id_candidate,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiAcoustidLookupCandidatesConstMeta,
+      argValues: [path, topN],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiAcoustidLookupCandidatesConstMeta =>
+      const TaskConstMeta(
+        debugName: "lookup_candidates",
+        argNames: ["path", "topN"],
+      );
+
+  @override
+  Future<EnrichedMetadata?> crateApiMusicbrainzLookupEnriched(
+      {required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 163, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_enriched_metadata,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiMusicbrainzLookupEnrichedConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicbrainzLookupEnrichedConstMeta =>
+      const TaskConstMeta(
+        debugName: "lookup_enriched",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<LrcDocument> crateApiSyncedLyricsLrcDocumentDefault() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 164, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_lrc_document,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSyncedLyricsLrcDocumentDefaultConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSyncedLyricsLrcDocumentDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "lrc_document_default",
+        argNames: [],
+      );
+
+  @override
+  Future<String> crateApiSyncedLyricsLyricsToLrc({required LrcDocument doc}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_lrc_document(doc, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 165, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSyncedLyricsLyricsToLrcConstMeta,
+      argValues: [doc],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSyncedLyricsLyricsToLrcConstMeta =>
+      const TaskConstMeta(
+        debugName: "lyrics_to_lrc",
+        argNames: ["doc"],
+      );
+
+  @override
+  Future<void> crateApiAudiobooksMarkBookFinished(
+      {required String path, required bool finished}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_bool(finished, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 166, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiAudiobooksMarkBookFinishedConstMeta,
+      argValues: [path, finished],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiAudiobooksMarkBookFinishedConstMeta =>
+      const TaskConstMeta(
+        debugName: "mark_book_finished",
+        argNames: ["path", "finished"],
+      );
+
+  @override
+  Future<void> crateApiPodcastsMarkEpisodePlayed(
+      {required BigInt podcastId, required String guid, required bool played}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(podcastId, serializer);
+        sse_encode_String(guid, serializer);
+        sse_encode_bool(played, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 167, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiPodcastsMarkEpisodePlayedConstMeta,
+      argValues: [podcastId, guid, played],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPodcastsMarkEpisodePlayedConstMeta =>
+      const TaskConstMeta(
+        debugName: "mark_episode_played",
+        argNames: ["podcastId", "guid", "played"],
+      );
+
+  @override
+  Future<int> crateApiPlaylistManagerMergePlaylists(
+      {required List<String> sources,
+      required String target,
+      required bool allowDuplicates}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_String(sources, serializer);
+        sse_encode_String(target, serializer);
+        sse_encode_bool(allowDuplicates, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 168, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_32,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiPlaylistManagerMergePlaylistsConstMeta,
+      argValues: [sources, target, allowDuplicates],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPlaylistManagerMergePlaylistsConstMeta =>
+      const TaskConstMeta(
+        debugName: "merge_playlists",
+        argNames: ["sources", "target", "allowDuplicates"],
+      );
+
+  @override
+  Future<int> crateApiPlaylistManagerMigratePlaylists() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 169, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPlaylistManagerMigratePlaylistsConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPlaylistManagerMigratePlaylistsConstMeta =>
+      const TaskConstMeta(
+        debugName: "migrate_playlists",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiPlaylistManagerMovePlaylistEntry(
+      {required String name, required int from, required int to}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        sse_encode_u_32(from, serializer);
+        sse_encode_u_32(to, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 170, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiPlaylistManagerMovePlaylistEntryConstMeta,
+      argValues: [name, from, to],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPlaylistManagerMovePlaylistEntryConstMeta =>
+      const TaskConstMeta(
+        debugName: "move_playlist_entry",
+        argNames: ["name", "from", "to"],
+      );
+
+  @override
+  Future<bool> crateApiChaptersNextChapter() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 171, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiChaptersNextChapterConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiChaptersNextChapterConstMeta =>
+      const TaskConstMeta(
+        debugName: "next_chapter",
+        argNames: [],
+      );
+
+  @override
+  Future<OrganizeResult> crateApiOrganizerOrganizeFiles(
+      {required List<String> paths,
+      required String template,
+      String? destRoot,
+      required bool dryRun}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_String(paths, serializer);
+        sse_encode_String(template, serializer);
+        sse_encode_opt_String(destRoot, serializer);
+        sse_encode_bool(dryRun, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 172, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_organize_result,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiOrganizerOrganizeFilesConstMeta,
+      argValues: [paths, template, destRoot, dryRun],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiOrganizerOrganizeFilesConstMeta =>
+      const TaskConstMeta(
+        debugName: "organize_files",
+        argNames: ["paths", "template", "destRoot", "dryRun"],
+      );
+
+  @override
+  Future<LrcDocument> crateApiSyncedLyricsParseLrc({required String content}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(content, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 173, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_lrc_document,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSyncedLyricsParseLrcConstMeta,
+      argValues: [content],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSyncedLyricsParseLrcConstMeta =>
+      const TaskConstMeta(
+        debugName: "parse_lrc",
+        argNames: ["content"],
+      );
+
+  @override
+  Future<bool> crateApiMusicHandlerPauseSong() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 174, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerPauseSongConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerPauseSongConstMeta =>
+      const TaskConstMeta(
+        debugName: "pause_song",
+        argNames: [],
+      );
+
+  @override
+  Future<PipelineConfig> crateApiDownloadPipelinePipelineConfigDefault() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 175, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_pipeline_config,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiDownloadPipelinePipelineConfigDefaultConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiDownloadPipelinePipelineConfigDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "pipeline_config_default",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiPodcastsPlayEpisode(
+      {required BigInt podcastId, required String guid}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(podcastId, serializer);
+        sse_encode_String(guid, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 176, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiPodcastsPlayEpisodeConstMeta,
+      argValues: [podcastId, guid],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPodcastsPlayEpisodeConstMeta =>
+      const TaskConstMeta(
+        debugName: "play_episode",
+        argNames: ["podcastId", "guid"],
+      );
+
+  @override
+  Future<bool> crateApiMusicHandlerPlaySong({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 177, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerPlaySongConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerPlaySongConstMeta =>
+      const TaskConstMeta(
+        debugName: "play_song",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<PlayStat> crateApiPlayStatsPlayStatDefault() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 178, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_play_stat,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPlayStatsPlayStatDefaultConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPlayStatsPlayStatDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "play_stat_default",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiRadioStationsPlayStation({required String name}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 179, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiRadioStationsPlayStationConstMeta,
+      argValues: [name],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiRadioStationsPlayStationConstMeta =>
+      const TaskConstMeta(
+        debugName: "play_station",
+        argNames: ["name"],
+      );
+
+  @override
+  Future<PlayerState> crateApiMusicHandlerPlayerStateDefault() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 180, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_player_state,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerPlayerStateDefaultConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerPlayerStateDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "player_state_default",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiMusicHandlerPreloadNextSong({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 181, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerPreloadNextSongConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerPreloadNextSongConstMeta =>
+      const TaskConstMeta(
+        debugName: "preload_next_song",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<bool> crateApiChaptersPreviousChapter() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 182, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiChaptersPreviousChapterConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiChaptersPreviousChapterConstMeta =>
+      const TaskConstMeta(
+        debugName: "previous_chapter",
+        argNames: [],
+      );
+
+  @override
+  Future<PipelineResult> crateApiDownloadPipelineProcessDownload(
+      {required String path, PipelineConfig? config}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_opt_box_autoadd_pipeline_config(config, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 183, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_pipeline_result,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiDownloadPipelineProcessDownloadConstMeta,
+      argValues: [path, config],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiDownloadPipelineProcessDownloadConstMeta =>
+      const TaskConstMeta(
+        debugName: "process_download",
+        argNames: ["path", "config"],
+      );
+
+  @override
+  Future<BigInt> crateApiDownloadManagerQueueDownload(
+      {required String query, String? flags}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(query, serializer);
+        sse_encode_opt_String(flags, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 184, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiDownloadManagerQueueDownloadConstMeta,
+      argValues: [query, flags],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiDownloadManagerQueueDownloadConstMeta =>
+      const TaskConstMeta(
+        debugName: "queue_download",
+        argNames: ["query", "flags"],
+      );
+
+  @override
+  Future<BigInt> crateApiDownloadManagerQueueUrlDownload(
+      {required String url, String? flags}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(url, serializer);
+        sse_encode_opt_String(flags, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 185, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiDownloadManagerQueueUrlDownloadConstMeta,
+      argValues: [url, flags],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiDownloadManagerQueueUrlDownloadConstMeta =>
+      const TaskConstMeta(
+        debugName: "queue_url_download",
+        argNames: ["url", "flags"],
+      );
+
+  @override
+  Future<String?> crateApiEmbeddedLyricsReadEmbeddedLyrics(
+      {required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 186, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_String,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiEmbeddedLyricsReadEmbeddedLyricsConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiEmbeddedLyricsReadEmbeddedLyricsConstMeta =>
+      const TaskConstMeta(
+        debugName: "read_embedded_lyrics",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<int> crateApiLibrarySearchRebuildSearchIndex() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 187, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_32,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiLibrarySearchRebuildSearchIndexConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiLibrarySearchRebuildSearchIndexConstMeta =>
+      const TaskConstMeta(
+        debugName: "rebuild_search_index",
+        argNames: [],
+      );
+
+  @override
+  Future<List<PodcastRefresh>> crateApiPodcastsRefreshAllPodcasts() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 188, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_podcast_refresh,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPodcastsRefreshAllPodcastsConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPodcastsRefreshAllPodcastsConstMeta =>
+      const TaskConstMeta(
+        debugName: "refresh_all_podcasts",
+        argNames: [],
+      );
+
+  @override
+  Future<int> crateApiLyricsIndexRefreshLyricsIndex(
+      {required String lyricsDir}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(lyricsDir, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 189, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiLyricsIndexRefreshLyricsIndexConstMeta,
+      argValues: [lyricsDir],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiLyricsIndexRefreshLyricsIndexConstMeta =>
+      const TaskConstMeta(
+        debugName: "refresh_lyrics_index",
+        argNames: ["lyricsDir"],
+      );
+
+  @override
+  Future<List<String>> crateApiPodcastsRefreshPodcast({required BigInt id}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(id, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 190, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiPodcastsRefreshPodcastConstMeta,
+      argValues: [id],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPodcastsRefreshPodcastConstMeta =>
+      const TaskConstMeta(
+        debugName: "refresh_podcast",
+        argNames: ["id"],
+      );
+
+  @override
+  Future<String> crateApiPluginManReloadPlugin({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 191, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiPluginManReloadPluginConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPluginManReloadPluginConstMeta =>
+      const TaskConstMeta(
+        debugName: "reload_plugin",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<void> crateApiEmbeddedLyricsRemoveEmbeddedLyrics(
+      {required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 192, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiEmbeddedLyricsRemoveEmbeddedLyricsConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiEmbeddedLyricsRemoveEmbeddedLyricsConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_embedded_lyrics",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<int> crateApiPlaylistManagerRemoveFromPlaylist(
+      {required String name, required List<int> indices}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        sse_encode_list_prim_u_32_loose(indices, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 193, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_32,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiPlaylistManagerRemoveFromPlaylistConstMeta,
+      argValues: [name, indices],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPlaylistManagerRemoveFromPlaylistConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_from_playlist",
+        argNames: ["name", "indices"],
+      );
+
+  @override
+  Future<String> crateApiPluginManRemovePlugin({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 194, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiPluginManRemovePluginConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPluginManRemovePluginConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_plugin",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<void> crateApiMusicHandlerRemoveSeparator(
+      {required String separator}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(separator, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 195, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiMusicHandlerRemoveSeparatorConstMeta,
+      argValues: [separator],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerRemoveSeparatorConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_separator",
+        argNames: ["separator"],
+      );
+
+  @override
+  Future<void> crateApiPlaylistManagerRenamePlaylist(
+      {required String name, required String newName}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        sse_encode_String(newName, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 196, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiPlaylistManagerRenamePlaylistConstMeta,
+      argValues: [name, newName],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPlaylistManagerRenamePlaylistConstMeta =>
+      const TaskConstMeta(
+        debugName: "rename_playlist",
+        argNames: ["name", "newName"],
+      );
+
+  @override
+  Future<void> crateApiPlaylistManagerReorderPlaylist(
+      {required String name, required List<int> newOrder}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        sse_encode_list_prim_u_32_loose(newOrder, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 197, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiPlaylistManagerReorderPlaylistConstMeta,
+      argValues: [name, newOrder],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPlaylistManagerReorderPlaylistConstMeta =>
+      const TaskConstMeta(
+        debugName: "reorder_playlist",
+        argNames: ["name", "newOrder"],
+      );
+
+  @override
+  Future<bool> crateApiPlayStatsResetPlayStats() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 198, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPlayStatsResetPlayStatsConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPlayStatsResetPlayStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "reset_play_stats",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiMusicHandlerResetSeparators() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 199, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerResetSeparatorsConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerResetSeparatorsConstMeta =>
+      const TaskConstMeta(
+        debugName: "reset_separators",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiMusicHandlerRestartPlayer() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 200, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerRestartPlayerConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerRestartPlayerConstMeta =>
+      const TaskConstMeta(
+        debugName: "restart_player",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiMusicHandlerResumeSong() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 201, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerResumeSongConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerResumeSongConstMeta =>
+      const TaskConstMeta(
+        debugName: "resume_song",
+        argNames: [],
+      );
+
+  @override
+  Future<int> crateApiOrganizerRollbackLastOrganize() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 202, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_32,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiOrganizerRollbackLastOrganizeConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiOrganizerRollbackLastOrganizeConstMeta =>
+      const TaskConstMeta(
+        debugName: "rollback_last_organize",
+        argNames: [],
+      );
+
+  @override
+  Future<String> crateApiLyricsStoreSaveLyrics(
+      {required String lyricsDir,
+      required SongMetadata song,
+      required String content}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(lyricsDir, serializer);
+        sse_encode_box_autoadd_song_metadata(song, serializer);
+        sse_encode_String(content, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 203, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiLyricsStoreSaveLyricsConstMeta,
+      argValues: [lyricsDir, song, content],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiLyricsStoreSaveLyricsConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_lyrics",
+        argNames: ["lyricsDir", "song", "content"],
+      );
+
+  @override
+  Future<void> crateApiSmartPlaylistSaveSmartPlaylist(
+      {required String name, required String query}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        sse_encode_String(query, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 204, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiSmartPlaylistSaveSmartPlaylistConstMeta,
+      argValues: [name, query],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSmartPlaylistSaveSmartPlaylistConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_smart_playlist",
+        argNames: ["name", "query"],
+      );
+
+  @override
+  Future<String> crateApiLyricsEditorSaveSyncedLyrics({String? path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_opt_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 205, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiLyricsEditorSaveSyncedLyricsConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiLyricsEditorSaveSyncedLyricsConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_synced_lyrics",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<List<String>?> crateApiPluginManScanDir({required String path}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 206, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_list_String,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPluginManScanDirConstMeta,
+      argValues: [path],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPluginManScanDirConstMeta => const TaskConstMeta(
+        debugName: "scan_dir",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<List<SongMetadata>> crateApiMusicHandlerScanMusicDirectory(
+      {required String dirPath, required bool autoConvert}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(dirPath, serializer);
+        sse_encode_bool(autoConvert, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 207, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_song_metadata,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerScanMusicDirectoryConstMeta,
+      argValues: [dirPath, autoConvert],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerScanMusicDirectoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "scan_music_directory",
+        argNames: ["dirPath", "autoConvert"],
+      );
+
+  @override
+  Future<MaterialScheme> crateApiColorExtractorSchemeFromArt(
+      {required List<int> data, required bool dark}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_prim_u_8_loose(data, serializer);
+        sse_encode_bool(dark, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 208, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_material_scheme,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiColorExtractorSchemeFromArtConstMeta,
+      argValues: [data, dark],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiColorExtractorSchemeFromArtConstMeta =>
+      const TaskConstMeta(
+        debugName: "scheme_from_art",
+        argNames: ["data", "dark"],
+      );
+
+  @override
+  Future<SearchPage> crateApiLibrarySearchSearchLibrary(
+      {required String query, required int page, int? pageSize}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(query, serializer);
+        sse_encode_u_32(page, serializer);
+        sse_encode_opt_box_autoadd_u_32(pageSize, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 209, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_search_page,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiLibrarySearchSearchLibraryConstMeta,
+      argValues: [query, page, pageSize],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiLibrarySearchSearchLibraryConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_library",
+        argNames: ["query", "page", "pageSize"],
+      );
+
+  @override
+  Future<List<LrclibLyrics>> crateApiLrclibSearchLrclib(
+      {required String title, required String artist}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(title, serializer);
+        sse_encode_String(artist, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 210, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_lrclib_lyrics,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiLrclibSearchLrclibConstMeta,
+      argValues: [title, artist],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiLrclibSearchLrclibConstMeta => const TaskConstMeta(
+        debugName: "search_lrclib",
+        argNames: ["title", "artist"],
+      );
+
+  @override
+  Future<List<SongMetadata>> crateApiMusicHandlerSearchLyrics(
+      {required String lyricsDir,
+      required String query,
+      required String songDir}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(lyricsDir, serializer);
+        sse_encode_String(query, serializer);
+        sse_encode_String(songDir, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 211, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_song_metadata,
+        decodeErrorData: sse_decode_AnyhowException,
+      ),
+      constMeta: kCrateApiMusicHandlerSearchLyricsConstMeta,
+      argValues: [lyricsDir, query, songDir],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerSearchLyricsConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_lyrics",
+        argNames: ["lyricsDir", "query", "songDir"],
+      );
+
+  @override
+  Future<List<LyricSearchResult>> crateApiLyricsIndexSearchLyricsLines(
+      {required String lyricsDir, required String query, int? limit}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(lyricsDir, serializer);
+        sse_encode_String(query, serializer);
+        sse_encode_opt_box_autoadd_u_32(limit, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 212, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_lyric_search_result,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiLyricsIndexSearchLyricsLinesConstMeta,
+      argValues: [lyricsDir, query, limit],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiLyricsIndexSearchLyricsLinesConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_lyrics_lines",
+        argNames: ["lyricsDir", "query", "limit"],
+      );
+
+  @override
+  Future<List<RemoteCandidate>> crateApiDownloadManagerSearchRemote(
+      {required String query, required int count}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(query, serializer);
+        sse_encode_u_32(count, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 213, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_remote_candidate,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiDownloadManagerSearchRemoteConstMeta,
+      argValues: [query, count],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiDownloadManagerSearchRemoteConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_remote",
+        argNames: ["query", "count"],
+      );
+
+  @override
+  Future<bool> crateApiChaptersSeekToChapter({required int index}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(index, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 214, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiChaptersSeekToChapterConstMeta,
+      argValues: [index],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiChaptersSeekToChapterConstMeta =>
+      const TaskConstMeta(
+        debugName: "seek_to_chapter",
+        argNames: ["index"],
+      );
+
+  @override
+  Future<bool> crateApiMusicHandlerSeekToPosition({required double position}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_f_32(position, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 215, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerSeekToPositionConstMeta,
+      argValues: [position],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerSeekToPositionConstMeta =>
+      const TaskConstMeta(
+        debugName: "seek_to_position",
+        argNames: ["position"],
+      );
+
+  @override
+  Future<void> crateApiArtCacheSetArtCacheMemoryLimit({required BigInt bytes}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(bytes, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 216, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiArtCacheSetArtCacheMemoryLimitConstMeta,
+      argValues: [bytes],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiArtCacheSetArtCacheMemoryLimitConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_art_cache_memory_limit",
+        argNames: ["bytes"],
+      );
+
+  @override
+  Future<void> crateApiAudiobooksSetAudiobook(
+      {required String path, required bool enabled}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_bool(enabled, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 217, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiAudiobooksSetAudiobookConstMeta,
+      argValues: [path, enabled],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiAudiobooksSetAudiobookConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_audiobook",
+        argNames: ["path", "enabled"],
+      );
+
+  @override
+  Future<void> crateApiAudiobooksSetBookPosition(
+      {required String path, required double positionSecs}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_f_64(positionSecs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 218, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiAudiobooksSetBookPositionConstMeta,
+      argValues: [path, positionSecs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiAudiobooksSetBookPositionConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_book_position",
+        argNames: ["path", "positionSecs"],
+      );
+
+  @override
+  Future<void> crateApiPodcastsSetEpisodePosition(
+      {required BigInt podcastId,
+      required String guid,
+      required double positionSecs}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(podcastId, serializer);
+        sse_encode_String(guid, serializer);
+        sse_encode_f_64(positionSecs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 219, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiPodcastsSetEpisodePositionConstMeta,
+      argValues: [podcastId, guid, positionSecs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPodcastsSetEpisodePositionConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_episode_position",
+        argNames: ["podcastId", "guid", "positionSecs"],
+      );
+
+  @override
+  Future<void> crateApiMusicHandlerSetFadein({required bool value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_bool(value, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 220, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerSetFadeinConstMeta,
+      argValues: [value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerSetFadeinConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_fadein",
+        argNames: ["value"],
+      );
+
+  @override
+  Future<LrcDocument> crateApiLyricsEditorSetLyricLineTime(
+      {required int lineIndex, required BigInt timeMs}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(lineIndex, serializer);
+        sse_encode_u_64(timeMs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 221, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_lrc_document,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiLyricsEditorSetLyricLineTimeConstMeta,
+      argValues: [lineIndex, timeMs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiLyricsEditorSetLyricLineTimeConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_lyric_line_time",
+        argNames: ["lineIndex", "timeMs"],
+      );
+
+  @override
+  Future<LrcDocument> crateApiLyricsEditorSetLyricsOffset(
+      {required PlatformInt64 offsetMs}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_i_64(offsetMs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 222, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_lrc_document,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiLyricsEditorSetLyricsOffsetConstMeta,
+      argValues: [offsetMs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiLyricsEditorSetLyricsOffsetConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_lyrics_offset",
+        argNames: ["offsetMs"],
+      );
+
+  @override
+  Future<void> crateApiDownloadManagerSetMaxConcurrentDownloads(
+      {required int max}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(max, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 223, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiDownloadManagerSetMaxConcurrentDownloadsConstMeta,
+      argValues: [max],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta
+      get kCrateApiDownloadManagerSetMaxConcurrentDownloadsConstMeta =>
+          const TaskConstMeta(
+            debugName: "set_max_concurrent_downloads",
+            argNames: ["max"],
+          );
+
+  @override
+  Future<void> crateApiDownloadPipelineSetPipelineConfig(
+      {required PipelineConfig config}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_pipeline_config(config, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 224, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiDownloadPipelineSetPipelineConfigConstMeta,
+      argValues: [config],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiDownloadPipelineSetPipelineConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_pipeline_config",
+        argNames: ["config"],
+      );
+
+  @override
+  Future<String> crateApiPluginManSetPluginConfig(
+      {required String path, required String key, required ConfigTypes value}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_String(key, serializer);
+        sse_encode_box_autoadd_config_types(value, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 225, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiPluginManSetPluginConfigConstMeta,
+      argValues: [path, key, value],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPluginManSetPluginConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_plugin_config",
+        argNames: ["path", "key", "value"],
+      );
+
+  @override
+  Future<void> crateApiPodcastsSetPodcastAutoDownload(
+      {required BigInt id, required bool enabled, int? keepDownloaded}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(id, serializer);
+        sse_encode_bool(enabled, serializer);
+        sse_encode_opt_box_autoadd_u_32(keepDownloaded, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 226, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiPodcastsSetPodcastAutoDownloadConstMeta,
+      argValues: [id, enabled, keepDownloaded],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPodcastsSetPodcastAutoDownloadConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_podcast_auto_download",
+        argNames: ["id", "enabled", "keepDownloaded"],
+      );
+
+  @override
+  Future<void> crateApiMusicHandlerSetSeparators(
+      {required List<String> separators}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_String(separators, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 227, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerSetSeparatorsConstMeta,
+      argValues: [separators],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerSetSeparatorsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_separators",
+        argNames: ["separators"],
+      );
+
+  @override
+  Future<bool> crateApiMusicHandlerSetVolume({required double volume}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_f_32(volume, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 228, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerSetVolumeConstMeta,
+      argValues: [volume],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerSetVolumeConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_volume",
+        argNames: ["volume"],
+      );
+
+  @override
+  Future<LrcDocument> crateApiLyricsEditorShiftLyrics(
+      {required PlatformInt64 deltaMs}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_i_64(deltaMs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 229, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_lrc_document,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiLyricsEditorShiftLyricsConstMeta,
+      argValues: [deltaMs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiLyricsEditorShiftLyricsConstMeta =>
+      const TaskConstMeta(
+        debugName: "shift_lyrics",
+        argNames: ["deltaMs"],
+      );
+
+  @override
+  Future<bool> crateApiMusicHandlerSkipToNext(
+      {required List<String> songs, required BigInt currentIndex}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_String(songs, serializer);
+        sse_encode_usize(currentIndex, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 230, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerSkipToNextConstMeta,
+      argValues: [songs, currentIndex],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerSkipToNextConstMeta =>
+      const TaskConstMeta(
+        debugName: "skip_to_next",
+        argNames: ["songs", "currentIndex"],
+      );
+
+  @override
+  Future<bool> crateApiMusicHandlerSkipToPrevious(
+      {required List<String> songs, required BigInt currentIndex}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_String(songs, serializer);
+        sse_encode_usize(currentIndex, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 231, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerSkipToPreviousConstMeta,
+      argValues: [songs, currentIndex],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerSkipToPreviousConstMeta =>
+      const TaskConstMeta(
+        debugName: "skip_to_previous",
+        argNames: ["songs", "currentIndex"],
+      );
+
+  @override
+  Future<SongMetadata> crateApiMusicHandlerSongMetadataDefault() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 232, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_song_metadata,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerSongMetadataDefaultConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerSongMetadataDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "song_metadata_default",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiPlaylistManagerSortPlaylist(
+      {required String name,
+      required PlaylistSortKey key,
+      required bool descending}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        sse_encode_box_autoadd_playlist_sort_key(key, serializer);
+        sse_encode_bool(descending, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 233, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiPlaylistManagerSortPlaylistConstMeta,
+      argValues: [name, key, descending],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPlaylistManagerSortPlaylistConstMeta =>
+      const TaskConstMeta(
+        debugName: "sort_playlist",
+        argNames: ["name", "key", "descending"],
+      );
+
+  @override
+  Future<void> crateApiPodcastsStartPodcastSchedule(
+      {required int intervalMinutes}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(intervalMinutes, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 234, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPodcastsStartPodcastScheduleConstMeta,
+      argValues: [intervalMinutes],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPodcastsStartPodcastScheduleConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_podcast_schedule",
+        argNames: ["intervalMinutes"],
+      );
+
+  @override
+  Future<void> crateApiLyricsEditorStartTapSync({required int fromLine}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(fromLine, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 235, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiLyricsEditorStartTapSyncConstMeta,
+      argValues: [fromLine],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiLyricsEditorStartTapSyncConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_tap_sync",
+        argNames: ["fromLine"],
+      );
+
+  @override
+  Future<void> crateApiSyncedLyricsStopLyricsWatch() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 236, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiSyncedLyricsStopLyricsWatchConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSyncedLyricsStopLyricsWatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "stop_lyrics_watch",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiPodcastsStopPodcastSchedule() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 237, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPodcastsStopPodcastScheduleConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPodcastsStopPodcastScheduleConstMeta =>
+      const TaskConstMeta(
+        debugName: "stop_podcast_schedule",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiMusicHandlerStopSong() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 238, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerStopSongConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerStopSongConstMeta =>
+      const TaskConstMeta(
+        debugName: "stop_song",
+        argNames: [],
+      );
+
+  @override
+  Future<StreamMetadata> crateApiHttpStreamStreamMetadataDefault() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 239, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_stream_metadata,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiHttpStreamStreamMetadataDefaultConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiHttpStreamStreamMetadataDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "stream_metadata_default",
+        argNames: [],
+      );
+
+  @override
+  Future<Podcast> crateApiPodcastsSubscribePodcast({required String feedUrl}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(feedUrl, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 240, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_podcast,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiPodcastsSubscribePodcastConstMeta,
+      argValues: [feedUrl],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPodcastsSubscribePodcastConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_podcast",
+        argNames: ["feedUrl"],
+      );
+
+  @override
+  Future<bool> crateApiMusicHandlerSwitchToPreloadedNow() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 241, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerSwitchToPreloadedNowConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerSwitchToPreloadedNowConstMeta =>
+      const TaskConstMeta(
+        debugName: "switch_to_preloaded_now",
+        argNames: [],
+      );
+
+  @override
+  Future<LrcDocument> crateApiLyricsEditorSyncLyricLine(
+      {required int lineIndex}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_32(lineIndex, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 242, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_lrc_document,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiLyricsEditorSyncLyricLineConstMeta,
+      argValues: [lineIndex],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiLyricsEditorSyncLyricLineConstMeta =>
+      const TaskConstMeta(
+        debugName: "sync_lyric_line",
+        argNames: ["lineIndex"],
+      );
+
+  @override
+  Future<int?> crateApiLyricsEditorTapSync() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 243, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiLyricsEditorTapSyncConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiLyricsEditorTapSyncConstMeta =>
+      const TaskConstMeta(
+        debugName: "tap_sync",
+        argNames: [],
+      );
+
+  @override
+  Future<int> crateApiMusicHandlerTrackNum({required String device}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(device, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 244, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_i_32,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiMusicHandlerTrackNumConstMeta,
+      argValues: [device],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerTrackNumConstMeta =>
+      const TaskConstMeta(
+        debugName: "track_num",
+        argNames: ["device"],
+      );
+
+  @override
+  Future<TagEditRecord> crateApiTagEditorUndoLastTagEdit() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 245, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_tag_edit_record,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiTagEditorUndoLastTagEditConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiTagEditorUndoLastTagEditConstMeta =>
+      const TaskConstMeta(
+        debugName: "undo_last_tag_edit",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiPodcastsUnsubscribePodcast(
+      {required BigInt id, required bool deleteDownloads}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(id, serializer);
+        sse_encode_bool(deleteDownloads, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 246, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiPodcastsUnsubscribePodcastConstMeta,
+      argValues: [id, deleteDownloads],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiPodcastsUnsubscribePodcastConstMeta =>
+      const TaskConstMeta(
+        debugName: "unsubscribe_podcast",
+        argNames: ["id", "deleteDownloads"],
+      );
+
+  @override
+  Future<bool> crateApiUtilsUpdateExecutable(
+      {required String arch, required String expath}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(arch, serializer);
+        sse_encode_String(expath, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 247, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_bool,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiUtilsUpdateExecutableConstMeta,
+      argValues: [arch, expath],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiUtilsUpdateExecutableConstMeta =>
+      const TaskConstMeta(
+        debugName: "update_executable",
+        argNames: ["arch", "expath"],
+      );
+
+  @override
+  Future<void> crateApiRadioStationsUpdateStation(
+      {required String name, required RadioStation station}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(name, serializer);
+        sse_encode_box_autoadd_radio_station(station, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 248, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiRadioStationsUpdateStationConstMeta,
+      argValues: [name, station],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiRadioStationsUpdateStationConstMeta =>
+      const TaskConstMeta(
+        debugName: "update_station",
+        argNames: ["name", "station"],
+      );
+
+  @override
+  Future<ValueStoreUpdater> crateApiValueStoreUpdateStore() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 249, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData:
+            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiValueStoreUpdateStoreConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiValueStoreUpdateStoreConstMeta =>
+      const TaskConstMeta(
+        debugName: "update_store",
+        argNames: [],
+      );
+
+  @override
+  Future<void> crateApiValueStoreUpdateValueStore(
+      {required ValueStoreUpdate update}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_value_store_update(update, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 250, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiValueStoreUpdateValueStoreConstMeta,
+      argValues: [update],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiValueStoreUpdateValueStoreConstMeta =>
+      const TaskConstMeta(
+        debugName: "update_value_store",
+        argNames: ["update"],
+      );
+
+  @override
+  Future<void> crateApiSmartPlaylistValidateSmartQuery(
+      {required String query}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(query, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 251, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiSmartPlaylistValidateSmartQueryConstMeta,
+      argValues: [query],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiSmartPlaylistValidateSmartQueryConstMeta =>
+      const TaskConstMeta(
+        debugName: "validate_smart_query",
+        argNames: ["query"],
+      );
+
+  @override
+  Future<List<String>> crateApiDownloadManagerWaitForDownload(
+      {required BigInt id}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(id, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 252, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_String,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiDownloadManagerWaitForDownloadConstMeta,
+      argValues: [id],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiDownloadManagerWaitForDownloadConstMeta =>
+      const TaskConstMeta(
+        debugName: "wait_for_download",
+        argNames: ["id"],
+      );
+
+  @override
+  Stream<CollectionDownload>
+      crateApiCollectionDownloadWatchCollectionDownloads() {
+    final sink = RustStreamSink<CollectionDownload>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_StreamSink_collection_download_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 253, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiCollectionDownloadWatchCollectionDownloadsConstMeta,
+      argValues: [sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta
+      get kCrateApiCollectionDownloadWatchCollectionDownloadsConstMeta =>
+          const TaskConstMeta(
+            debugName: "watch_collection_downloads",
+            argNames: ["sink"],
+          );
+
+  @override
+  Stream<DownloadJob> crateApiDownloadManagerWatchDownloads() {
+    final sink = RustStreamSink<DownloadJob>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_StreamSink_download_job_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 254, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiDownloadManagerWatchDownloadsConstMeta,
+      argValues: [sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiDownloadManagerWatchDownloadsConstMeta =>
+      const TaskConstMeta(
+        debugName: "watch_downloads",
+        argNames: ["sink"],
+      );
+
+  @override
+  Stream<EpisodeDownload> crateApiPodcastsWatchEpisodeDownloads() {
+    final sink = RustStreamSink<EpisodeDownload>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_StreamSink_episode_download_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 255, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiPodcastsWatchEpisodeDownloadsConstMeta,
+      argValues: [sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiPodcastsWatchEpisodeDownloadsConstMeta =>
+      const TaskConstMeta(
+        debugName: "watch_episode_downloads",
+        argNames: ["sink"],
+      );
+
+  @override
+  Stream<IdentifyProgress> crateApiAcoustidWatchIdentify() {
+    final sink = RustStreamSink<IdentifyProgress>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_StreamSink_identify_progress_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 256, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiAcoustidWatchIdentifyConstMeta,
+      argValues: [sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiAcoustidWatchIdentifyConstMeta =>
+      const TaskConstMeta(
+        debugName: "watch_identify",
+        argNames: ["sink"],
+      );
+
+  @override
+  Stream<SongMetadata> crateApiDownloadPipelineWatchLibraryAdditions() {
+    final sink = RustStreamSink<SongMetadata>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_StreamSink_song_metadata_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 257, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiDownloadPipelineWatchLibraryAdditionsConstMeta,
+      argValues: [sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiDownloadPipelineWatchLibraryAdditionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "watch_library_additions",
+        argNames: ["sink"],
+      );
+
+  @override
+  Stream<LyricEvent> crateApiSyncedLyricsWatchLyrics({int? intervalMs}) {
+    final sink = RustStreamSink<LyricEvent>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_StreamSink_lyric_event_Sse(sink, serializer);
+        sse_encode_opt_box_autoadd_u_32(intervalMs, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 258, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiSyncedLyricsWatchLyricsConstMeta,
+      argValues: [sink, intervalMs],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiSyncedLyricsWatchLyricsConstMeta =>
+      const TaskConstMeta(
+        debugName: "watch_lyrics",
+        argNames: ["sink", "intervalMs"],
+      );
+
+  @override
+  Stream<StreamMetadata> crateApiHttpStreamWatchStreamMetadata() {
+    final sink = RustStreamSink<StreamMetadata>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_StreamSink_stream_metadata_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 259, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiHttpStreamWatchStreamMetadataConstMeta,
+      argValues: [sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiHttpStreamWatchStreamMetadataConstMeta =>
+      const TaskConstMeta(
+        debugName: "watch_stream_metadata",
+        argNames: ["sink"],
+      );
+
+  @override
+  Future<List<ArtWriteResult>> crateApiMusicHandlerWriteAlbumArt(
+      {required List<String> paths, Uint8List? art}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_list_String(paths, serializer);
+        sse_encode_opt_list_prim_u_8_strict(art, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 260, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_art_write_result,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiMusicHandlerWriteAlbumArtConstMeta,
+      argValues: [paths, art],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerWriteAlbumArtConstMeta =>
+      const TaskConstMeta(
+        debugName: "write_album_art",
+        argNames: ["paths", "art"],
+      );
+
+  @override
+  Future<void> crateApiEmbeddedLyricsWriteEmbeddedLyrics(
+      {required String path, required String lyrics}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_String(lyrics, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 261, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiEmbeddedLyricsWriteEmbeddedLyricsConstMeta,
+      argValues: [path, lyrics],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiEmbeddedLyricsWriteEmbeddedLyricsConstMeta =>
+      const TaskConstMeta(
+        debugName: "write_embedded_lyrics",
+        argNames: ["path", "lyrics"],
+      );
+
+  @override
+  Future<void> crateApiMusicHandlerWriteMeta({required SongMetadata meta}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_box_autoadd_song_metadata(meta, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 262, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiMusicHandlerWriteMetaConstMeta,
+      argValues: [meta],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerWriteMetaConstMeta =>
+      const TaskConstMeta(
+        debugName: "write_meta",
+        argNames: ["meta"],
+      );
+
+  @override
+  Future<void> crateApiReplayGainWriteReplayGain(
+      {required String path, required ReplayGain gain}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_box_autoadd_replay_gain(gain, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 263, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiReplayGainWriteReplayGainConstMeta,
+      argValues: [path, gain],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiReplayGainWriteReplayGainConstMeta =>
+      const TaskConstMeta(
+        debugName: "write_replay_gain",
+        argNames: ["path", "gain"],
+      );
+
+  @override
+  Future<void> crateApiMusicHandlerWriteSongArt(
+      {required String path, Uint8List? art}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(path, serializer);
+        sse_encode_opt_list_prim_u_8_strict(art, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 264, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_String,
+      ),
+      constMeta: kCrateApiMusicHandlerWriteSongArtConstMeta,
+      argValues: [path, art],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiMusicHandlerWriteSongArtConstMeta =>
+      const TaskConstMeta(
+        debugName: "write_song_art",
+        argNames: ["path", "art"],
+      );
+
+  RustArcIncrementStrongCountFnType
+      get rust_arc_increment_strong_count_ArcMutexPlugin => wire
+          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin;
+
+  RustArcDecrementStrongCountFnType
+      get rust_arc_decrement_strong_count_ArcMutexPlugin => wire
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin;
+
+  RustArcIncrementStrongCountFnType
+      get rust_arc_increment_strong_count_PluginInode => wire
+          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode;
+
+  RustArcDecrementStrongCountFnType
+      get rust_arc_decrement_strong_count_PluginInode => wire
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode;
+
+  RustArcIncrementStrongCountFnType
+      get rust_arc_increment_strong_count_RpcConfig => wire
+          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig;
+
+  RustArcDecrementStrongCountFnType
+      get rust_arc_decrement_strong_count_RpcConfig => wire
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig;
+
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Value =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Value =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue;
 
   RustArcIncrementStrongCountFnType
       get rust_arc_increment_strong_count_ValueStoreUpdater => wire
           .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater;
 
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_ValueStoreUpdater => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater;
+  RustArcDecrementStrongCountFnType
+      get rust_arc_decrement_strong_count_ValueStoreUpdater => wire
+          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  ArcMutexPlugin
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ArcMutexPluginImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  PluginInode
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PluginInodeImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RpcConfig
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RpcConfigImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Value
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ValueImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ValueStoreUpdater
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ValueStoreUpdaterImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  PluginInode
+      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PluginInodeImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RpcConfig
+      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RpcConfigImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ValueStoreUpdater
+      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ValueStoreUpdaterImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  PluginInode
+      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PluginInodeImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RpcConfig
+      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RpcConfigImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ValueStoreUpdater
+      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ValueStoreUpdaterImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  BigInt dco_decode_I128(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BigInt.parse(raw);
+  }
+
+  @protected
+  Map<String, PluginInode>
+      dco_decode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode_None(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+        dco_decode_list_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
+                raw)
+            .map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  Map<String, ConfigTypes> dco_decode_Map_String_config_types_None(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(dco_decode_list_record_string_config_types(raw)
+        .map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  ArcMutexPlugin
+      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ArcMutexPluginImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  PluginInode
+      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PluginInodeImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RpcConfig
+      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RpcConfigImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Value
+      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ValueImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ValueStoreUpdater
+      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ValueStoreUpdaterImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RustStreamSink<CollectionDownload>
+      dco_decode_StreamSink_collection_download_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<DownloadJob> dco_decode_StreamSink_download_job_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<EpisodeDownload> dco_decode_StreamSink_episode_download_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<IdentifyProgress> dco_decode_StreamSink_identify_progress_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<LyricEvent> dco_decode_StreamSink_lyric_event_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<SongMetadata> dco_decode_StreamSink_song_metadata_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<StreamMetadata> dco_decode_StreamSink_stream_metadata_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  BigInt dco_decode_U128(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BigInt.parse(raw);
+  }
+
+  @protected
+  AcoustIdCandidate dco_decode_acoust_id_candidate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AcoustIdCandidate(
+      score: dco_decode_f_64(arr[0]),
+      recordingId: dco_decode_opt_String(arr[1]),
+      metadata: dco_decode_song_metadata(arr[2]),
+    );
+  }
+
+  @protected
+  AdiPluginMan dco_decode_adi_plugin_man(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return AdiPluginMan(
+      pluginMeta:
+          dco_decode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode_None(
+              arr[0]),
+    );
+  }
+
+  @protected
+  ArtWriteResult dco_decode_art_write_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ArtWriteResult(
+      path: dco_decode_String(arr[0]),
+      error: dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  BookProgress dco_decode_book_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return BookProgress(
+      path: dco_decode_String(arr[0]),
+      positionSecs: dco_decode_f_64(arr[1]),
+      durationSecs: dco_decode_f_64(arr[2]),
+      finished: dco_decode_bool(arr[3]),
+      updated: dco_decode_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  AcoustIdCandidate dco_decode_box_autoadd_acoust_id_candidate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_acoust_id_candidate(raw);
+  }
+
+  @protected
+  AdiPluginMan dco_decode_box_autoadd_adi_plugin_man(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_adi_plugin_man(raw);
+  }
+
+  @protected
+  BookProgress dco_decode_box_autoadd_book_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_book_progress(raw);
+  }
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  Chapter dco_decode_box_autoadd_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_chapter(raw);
+  }
+
+  @protected
+  CollectionOptions dco_decode_box_autoadd_collection_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_collection_options(raw);
+  }
+
+  @protected
+  ConfigTypes dco_decode_box_autoadd_config_types(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_config_types(raw);
+  }
+
+  @protected
+  DownloadJob dco_decode_box_autoadd_download_job(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_download_job(raw);
+  }
+
+  @protected
+  EnrichedMetadata dco_decode_box_autoadd_enriched_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_enriched_metadata(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FadButton dco_decode_box_autoadd_fad_button(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_fad_button(raw);
+  }
+
+  @protected
+  FadConfig dco_decode_box_autoadd_fad_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_fad_config(raw);
+  }
+
+  @protected
+  FoundLyrics dco_decode_box_autoadd_found_lyrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_found_lyrics(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  LrcDocument dco_decode_box_autoadd_lrc_document(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_lrc_document(raw);
+  }
+
+  @protected
+  LyricPosition dco_decode_box_autoadd_lyric_position(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_lyric_position(raw);
+  }
+
+  @protected
+  Palette dco_decode_box_autoadd_palette(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_palette(raw);
+  }
+
+  @protected
+  PipelineConfig dco_decode_box_autoadd_pipeline_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_pipeline_config(raw);
+  }
+
+  @protected
+  PlaylistFormat dco_decode_box_autoadd_playlist_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_playlist_format(raw);
+  }
+
+  @protected
+  PlaylistSortKey dco_decode_box_autoadd_playlist_sort_key(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_playlist_sort_key(raw);
+  }
+
+  @protected
+  RadioStation dco_decode_box_autoadd_radio_station(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_radio_station(raw);
+  }
+
+  @protected
+  ReplayGain dco_decode_box_autoadd_replay_gain(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_replay_gain(raw);
+  }
+
+  @protected
+  SongMetadata dco_decode_box_autoadd_song_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_song_metadata(raw);
+  }
+
+  @protected
+  StreamMetadata dco_decode_box_autoadd_stream_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_stream_metadata(raw);
+  }
+
+  @protected
+  Swatch dco_decode_box_autoadd_swatch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_swatch(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  ValueStoreUpdate dco_decode_box_autoadd_value_store_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_value_store_update(raw);
+  }
+
+  @protected
+  Chapter dco_decode_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return Chapter(
+      index: dco_decode_u_32(arr[0]),
+      title: dco_decode_String(arr[1]),
+      startSecs: dco_decode_f_64(arr[2]),
+      endSecs: dco_decode_f_64(arr[3]),
+    );
+  }
+
+  @protected
+  CollectionDownload dco_decode_collection_download(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return CollectionDownload(
+      id: dco_decode_u_64(arr[0]),
+      url: dco_decode_String(arr[1]),
+      title: dco_decode_String(arr[2]),
+      folder: dco_decode_String(arr[3]),
+      tracks: dco_decode_list_collection_track(arr[4]),
+      playlist: dco_decode_opt_String(arr[5]),
+      finished: dco_decode_bool(arr[6]),
+    );
+  }
+
+  @protected
+  CollectionOptions dco_decode_collection_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return CollectionOptions(
+      flags: dco_decode_opt_String(arr[0]),
+      folder: dco_decode_opt_String(arr[1]),
+      createPlaylist: dco_decode_bool(arr[2]),
+      playlistName: dco_decode_opt_String(arr[3]),
+      postProcess: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  CollectionTrack dco_decode_collection_track(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return CollectionTrack(
+      position: dco_decode_u_32(arr[0]),
+      title: dco_decode_opt_String(arr[1]),
+      jobId: dco_decode_u_64(arr[2]),
+      path: dco_decode_opt_String(arr[3]),
+      error: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  ConfigTypes dco_decode_config_types(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ConfigTypes_String(
+          dco_decode_String(raw[1]),
+        );
+      case 1:
+        return ConfigTypes_Bool(
+          dco_decode_bool(raw[1]),
+        );
+      case 2:
+        return ConfigTypes_Int(
+          dco_decode_i_32(raw[1]),
+        );
+      case 3:
+        return ConfigTypes_UInt(
+          dco_decode_u_32(raw[1]),
+        );
+      case 4:
+        return ConfigTypes_BigInt(
+          dco_decode_I128(raw[1]),
+        );
+      case 5:
+        return ConfigTypes_BigUInt(
+          dco_decode_U128(raw[1]),
+        );
+      case 6:
+        return ConfigTypes_Float(
+          dco_decode_f_64(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  CueSheet dco_decode_cue_sheet(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return CueSheet(
+      title: dco_decode_opt_String(arr[0]),
+      performer: dco_decode_opt_String(arr[1]),
+      genre: dco_decode_opt_String(arr[2]),
+      date: dco_decode_opt_String(arr[3]),
+      tracks: dco_decode_list_cue_track(arr[4]),
+    );
+  }
+
+  @protected
+  CueTrack dco_decode_cue_track(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return CueTrack(
+      number: dco_decode_u_32(arr[0]),
+      title: dco_decode_opt_String(arr[1]),
+      performer: dco_decode_opt_String(arr[2]),
+      file: dco_decode_String(arr[3]),
+      startSecs: dco_decode_f_64(arr[4]),
+    );
+  }
+
+  @protected
+  CurrentSongUpdate dco_decode_current_song_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return CurrentSongUpdate_NoChange();
+      case 1:
+        return CurrentSongUpdate_SetToNone();
+      case 2:
+        return CurrentSongUpdate_SetToSome(
+          dco_decode_box_autoadd_song_metadata(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  DownloadJob dco_decode_download_job(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return DownloadJob(
+      id: dco_decode_u_64(arr[0]),
+      query: dco_decode_String(arr[1]),
+      state: dco_decode_download_state(arr[2]),
+      title: dco_decode_opt_String(arr[3]),
+      progress: dco_decode_opt_box_autoadd_f_32(arr[4]),
+      downloadedBytes: dco_decode_u_64(arr[5]),
+      totalBytes: dco_decode_opt_box_autoadd_u_64(arr[6]),
+      speed: dco_decode_opt_box_autoadd_f_64(arr[7]),
+      etaSecs: dco_decode_opt_box_autoadd_u_64(arr[8]),
+      tempDir: dco_decode_String(arr[9]),
+      files: dco_decode_list_String(arr[10]),
+      error: dco_decode_opt_String(arr[11]),
+    );
+  }
+
+  @protected
+  DownloadState dco_decode_download_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DownloadState.values[raw as int];
+  }
+
+  @protected
+  DuplicateFile dco_decode_duplicate_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return DuplicateFile(
+      path: dco_decode_String(arr[0]),
+      format: dco_decode_String(arr[1]),
+      sizeBytes: dco_decode_u_64(arr[2]),
+      duration: dco_decode_f_64(arr[3]),
+      bitrateKbps: dco_decode_u_32(arr[4]),
+      sampleRate: dco_decode_opt_box_autoadd_u_32(arr[5]),
+      bitsPerSample: dco_decode_opt_box_autoadd_u_32(arr[6]),
+      lossless: dco_decode_bool(arr[7]),
+    );
+  }
+
+  @protected
+  DuplicateGroup dco_decode_duplicate_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return DuplicateGroup(
+      files: dco_decode_list_duplicate_file(arr[0]),
+      similarity: dco_decode_f_64(arr[1]),
+    );
+  }
+
+  @protected
+  EnrichedMetadata dco_decode_enriched_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return EnrichedMetadata(
+      metadata: dco_decode_song_metadata(arr[0]),
+      recordingId: dco_decode_opt_String(arr[1]),
+      releaseId: dco_decode_opt_String(arr[2]),
+      albumArtist: dco_decode_opt_String(arr[3]),
+      trackNumber: dco_decode_opt_box_autoadd_u_32(arr[4]),
+      totalTracks: dco_decode_opt_box_autoadd_u_32(arr[5]),
+      discNumber: dco_decode_opt_box_autoadd_u_32(arr[6]),
+      date: dco_decode_opt_String(arr[7]),
+      year: dco_decode_opt_box_autoadd_i_32(arr[8]),
+      genres: dco_decode_list_String(arr[9]),
+      isrc: dco_decode_opt_String(arr[10]),
+    );
+  }
+
+  @protected
+  EpisodeDownload dco_decode_episode_download(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return EpisodeDownload(
+      podcastId: dco_decode_u_64(arr[0]),
+      guid: dco_decode_String(arr[1]),
+      downloadedBytes: dco_decode_u_64(arr[2]),
+      totalBytes: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      finished: dco_decode_bool(arr[4]),
+      path: dco_decode_opt_String(arr[5]),
+      error: dco_decode_opt_String(arr[6]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FadButton dco_decode_fad_button(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return FadButton(
+      name: dco_decode_String(arr[0]),
+      icon: dco_decode_opt_String(arr[1]),
+      location: dco_decode_opt_String(arr[2]),
+      callback: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  FadConfig dco_decode_fad_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FadConfig(
+      screens: dco_decode_opt_list_fad_screen(arr[0]),
+      popups: dco_decode_opt_list_fad_popup(arr[1]),
+      buttons: dco_decode_opt_list_fad_button(arr[2]),
+    );
+  }
+
+  @protected
+  FadLabel dco_decode_fad_label(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FadLabel(
+      size: dco_decode_f_64(arr[0]),
+      text: dco_decode_String(arr[1]),
+      color: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  FadPopup dco_decode_fad_popup(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FadPopup(
+      title: dco_decode_opt_String(arr[0]),
+      buttons: dco_decode_opt_list_fad_button(arr[1]),
+      labels: dco_decode_opt_list_fad_label(arr[2]),
+    );
+  }
+
+  @protected
+  FadScreen dco_decode_fad_screen(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FadScreen(
+      title: dco_decode_opt_String(arr[0]),
+      buttons: dco_decode_opt_list_fad_button(arr[1]),
+      labels: dco_decode_opt_list_fad_label(arr[2]),
+    );
+  }
+
+  @protected
+  FeedEpisode dco_decode_feed_episode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return FeedEpisode(
+      guid: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      description: dco_decode_opt_String(arr[2]),
+      published: dco_decode_opt_box_autoadd_i_64(arr[3]),
+      durationSecs: dco_decode_opt_box_autoadd_u_64(arr[4]),
+      enclosureUrl: dco_decode_opt_String(arr[5]),
+      enclosureType: dco_decode_opt_String(arr[6]),
+      enclosureLength: dco_decode_opt_box_autoadd_u_64(arr[7]),
+      imageUrl: dco_decode_opt_String(arr[8]),
+      chapters: dco_decode_list_podcast_chapter(arr[9]),
+      chaptersUrl: dco_decode_opt_String(arr[10]),
+    );
+  }
+
+  @protected
+  FieldChange dco_decode_field_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FieldChange(
+      field: dco_decode_tag_field(arr[0]),
+      value: dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  FoundLyrics dco_decode_found_lyrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return FoundLyrics(
+      source: dco_decode_lyrics_source(arr[0]),
+      content: dco_decode_String(arr[1]),
+      document: dco_decode_lrc_document(arr[2]),
+      cachePath: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  IdentifyProgress dco_decode_identify_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return IdentifyProgress(
+      batchId: dco_decode_u_64(arr[0]),
+      completed: dco_decode_u_32(arr[1]),
+      total: dco_decode_u_32(arr[2]),
+      result: dco_decode_identify_result(arr[3]),
+    );
+  }
+
+  @protected
+  IdentifyResult dco_decode_identify_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return IdentifyResult(
+      path: dco_decode_String(arr[0]),
+      candidates: dco_decode_list_acoust_id_candidate(arr[1]),
+      error: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<AcoustIdCandidate> dco_decode_list_acoust_id_candidate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_acoust_id_candidate).toList();
+  }
+
+  @protected
+  List<ArtWriteResult> dco_decode_list_art_write_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_art_write_result).toList();
+  }
+
+  @protected
+  List<BookProgress> dco_decode_list_book_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_book_progress).toList();
+  }
+
+  @protected
+  List<Chapter> dco_decode_list_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_chapter).toList();
+  }
+
+  @protected
+  List<CollectionDownload> dco_decode_list_collection_download(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_collection_download).toList();
+  }
+
+  @protected
+  List<CollectionTrack> dco_decode_list_collection_track(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_collection_track).toList();
+  }
+
+  @protected
+  List<CueTrack> dco_decode_list_cue_track(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_cue_track).toList();
+  }
+
+  @protected
+  List<DownloadJob> dco_decode_list_download_job(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_download_job).toList();
+  }
+
+  @protected
+  List<DuplicateFile> dco_decode_list_duplicate_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_duplicate_file).toList();
+  }
+
+  @protected
+  List<DuplicateGroup> dco_decode_list_duplicate_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_duplicate_group).toList();
+  }
+
+  @protected
+  List<FadButton> dco_decode_list_fad_button(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_fad_button).toList();
+  }
+
+  @protected
+  List<FadLabel> dco_decode_list_fad_label(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_fad_label).toList();
+  }
+
+  @protected
+  List<FadPopup> dco_decode_list_fad_popup(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_fad_popup).toList();
+  }
+
+  @protected
+  List<FadScreen> dco_decode_list_fad_screen(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_fad_screen).toList();
+  }
+
+  @protected
+  List<FieldChange> dco_decode_list_field_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_field_change).toList();
+  }
+
+  @protected
+  List<LrcLine> dco_decode_list_lrc_line(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_lrc_line).toList();
+  }
+
+  @protected
+  List<LrcWord> dco_decode_list_lrc_word(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_lrc_word).toList();
+  }
+
+  @protected
+  List<LrclibLyrics> dco_decode_list_lrclib_lyrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_lrclib_lyrics).toList();
+  }
+
+  @protected
+  List<LyricMatchLine> dco_decode_list_lyric_match_line(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_lyric_match_line).toList();
+  }
+
+  @protected
+  List<LyricSearchResult> dco_decode_list_lyric_search_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_lyric_search_result).toList();
+  }
+
+  @protected
+  List<MatchedEntry> dco_decode_list_matched_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_matched_entry).toList();
+  }
+
+  @protected
+  List<MissingEntry> dco_decode_list_missing_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_missing_entry).toList();
+  }
+
+  @protected
+  List<PipelineResult> dco_decode_list_pipeline_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pipeline_result).toList();
+  }
+
+  @protected
+  List<PlannedMove> dco_decode_list_planned_move(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_planned_move).toList();
+  }
+
+  @protected
+  List<PlaylistEntry> dco_decode_list_playlist_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_playlist_entry).toList();
+  }
+
+  @protected
+  List<PlaylistInfo> dco_decode_list_playlist_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_playlist_info).toList();
+  }
+
+  @protected
+  List<Podcast> dco_decode_list_podcast(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_podcast).toList();
+  }
+
+  @protected
+  List<PodcastChapter> dco_decode_list_podcast_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_podcast_chapter).toList();
+  }
+
+  @protected
+  List<PodcastEpisode> dco_decode_list_podcast_episode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_podcast_episode).toList();
+  }
+
+  @protected
+  List<PodcastRefresh> dco_decode_list_podcast_refresh(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_podcast_refresh).toList();
+  }
+
+  @protected
+  Float32List dco_decode_list_prim_f_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Float32List;
+  }
+
+  @protected
+  Float64List dco_decode_list_prim_f_64_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Float64List;
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_32_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<RadioStation> dco_decode_list_radio_station(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_radio_station).toList();
+  }
+
+  @protected
+  List<(String, PluginInode)>
+      dco_decode_list_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(
+            dco_decode_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode)
+        .toList();
+  }
+
+  @protected
+  List<(String, ConfigTypes)> dco_decode_list_record_string_config_types(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_record_string_config_types)
+        .toList();
+  }
+
+  @protected
+  List<RemoteCandidate> dco_decode_list_remote_candidate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_remote_candidate).toList();
+  }
+
+  @protected
+  List<SearchHit> dco_decode_list_search_hit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_search_hit).toList();
+  }
+
+  @protected
+  List<SongMetadata> dco_decode_list_song_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_song_metadata).toList();
+  }
+
+  @protected
+  List<Swatch> dco_decode_list_swatch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_swatch).toList();
+  }
+
+  @protected
+  List<TagEdit> dco_decode_list_tag_edit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_tag_edit).toList();
+  }
+
+  @protected
+  List<TagEditRecord> dco_decode_list_tag_edit_record(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_tag_edit_record).toList();
+  }
+
+  @protected
+  List<TitleRule> dco_decode_list_title_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_title_rule).toList();
+  }
+
+  @protected
+  LrcDocument dco_decode_lrc_document(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return LrcDocument(
+      title: dco_decode_opt_String(arr[0]),
+      artist: dco_decode_opt_String(arr[1]),
+      album: dco_decode_opt_String(arr[2]),
+      genre: dco_decode_opt_String(arr[3]),
+      songPath: dco_decode_opt_String(arr[4]),
+      offsetMs: dco_decode_i_64(arr[5]),
+      userOffsetMs: dco_decode_i_64(arr[6]),
+      lengthMs: dco_decode_opt_box_autoadd_u_64(arr[7]),
+      synced: dco_decode_bool(arr[8]),
+      lines: dco_decode_list_lrc_line(arr[9]),
+    );
+  }
+
+  @protected
+  LrcLine dco_decode_lrc_line(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return LrcLine(
+      startMs: dco_decode_u_64(arr[0]),
+      text: dco_decode_String(arr[1]),
+      words: dco_decode_list_lrc_word(arr[2]),
+    );
+  }
+
+  @protected
+  LrcWord dco_decode_lrc_word(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LrcWord(
+      startMs: dco_decode_u_64(arr[0]),
+      text: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  LrclibLyrics dco_decode_lrclib_lyrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return LrclibLyrics(
+      id: dco_decode_i_64(arr[0]),
+      title: dco_decode_String(arr[1]),
+      artist: dco_decode_String(arr[2]),
+      album: dco_decode_String(arr[3]),
+      duration: dco_decode_f_64(arr[4]),
+      instrumental: dco_decode_bool(arr[5]),
+      plainLyrics: dco_decode_opt_String(arr[6]),
+      syncedLyrics: dco_decode_opt_String(arr[7]),
+    );
+  }
+
+  @protected
+  LyricEvent dco_decode_lyric_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LyricEvent(
+      positionMs: dco_decode_u_64(arr[0]),
+      current: dco_decode_opt_box_autoadd_lyric_position(arr[1]),
+    );
+  }
+
+  @protected
+  LyricMatchLine dco_decode_lyric_match_line(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return LyricMatchLine(
+      line: dco_decode_u_32(arr[0]),
+      timestampMs: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      text: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  LyricPosition dco_decode_lyric_position(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return LyricPosition(
+      lineIndex: dco_decode_u_32(arr[0]),
+      wordIndex: dco_decode_opt_box_autoadd_u_32(arr[1]),
+      lineStartMs: dco_decode_u_64(arr[2]),
+      lineEndMs: dco_decode_opt_box_autoadd_u_64(arr[3]),
+      text: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  LyricSearchResult dco_decode_lyric_search_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return LyricSearchResult(
+      song: dco_decode_song_metadata(arr[0]),
+      score: dco_decode_f_32(arr[1]),
+      lines: dco_decode_list_lyric_match_line(arr[2]),
+    );
+  }
+
+  @protected
+  LyricsSource dco_decode_lyrics_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LyricsSource.values[raw as int];
+  }
+
+  @protected
+  MatchedEntry dco_decode_matched_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MatchedEntry(
+      location: dco_decode_String(arr[0]),
+      path: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  MaterialScheme dco_decode_material_scheme(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 30)
+      throw Exception('unexpected arr length: expect 30 but see ${arr.length}');
+    return MaterialScheme(
+      isDark: dco_decode_bool(arr[0]),
+      seed: dco_decode_u_32(arr[1]),
+      primary: dco_decode_u_32(arr[2]),
+      onPrimary: dco_decode_u_32(arr[3]),
+      primaryContainer: dco_decode_u_32(arr[4]),
+      onPrimaryContainer: dco_decode_u_32(arr[5]),
+      secondary: dco_decode_u_32(arr[6]),
+      onSecondary: dco_decode_u_32(arr[7]),
+      secondaryContainer: dco_decode_u_32(arr[8]),
+      onSecondaryContainer: dco_decode_u_32(arr[9]),
+      tertiary: dco_decode_u_32(arr[10]),
+      onTertiary: dco_decode_u_32(arr[11]),
+      tertiaryContainer: dco_decode_u_32(arr[12]),
+      onTertiaryContainer: dco_decode_u_32(arr[13]),
+      error: dco_decode_u_32(arr[14]),
+      onError: dco_decode_u_32(arr[15]),
+      errorContainer: dco_decode_u_32(arr[16]),
+      onErrorContainer: dco_decode_u_32(arr[17]),
+      background: dco_decode_u_32(arr[18]),
+      onBackground: dco_decode_u_32(arr[19]),
+      surface: dco_decode_u_32(arr[20]),
+      onSurface: dco_decode_u_32(arr[21]),
+      surfaceVariant: dco_decode_u_32(arr[22]),
+      onSurfaceVariant: dco_decode_u_32(arr[23]),
+      surfaceContainer: dco_decode_u_32(arr[24]),
+      outline: dco_decode_u_32(arr[25]),
+      outlineVariant: dco_decode_u_32(arr[26]),
+      inverseSurface: dco_decode_u_32(arr[27]),
+      inverseOnSurface: dco_decode_u_32(arr[28]),
+      inversePrimary: dco_decode_u_32(arr[29]),
+    );
+  }
+
+  @protected
+  MissingEntry dco_decode_missing_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MissingEntry(
+      location: dco_decode_String(arr[0]),
+      title: dco_decode_opt_String(arr[1]),
+      artist: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  Map<String, ConfigTypes>? dco_decode_opt_Map_String_config_types_None(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_Map_String_config_types_None(raw);
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  AdiPluginMan? dco_decode_opt_box_autoadd_adi_plugin_man(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_adi_plugin_man(raw);
+  }
+
+  @protected
+  BookProgress? dco_decode_opt_box_autoadd_book_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_book_progress(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  Chapter? dco_decode_opt_box_autoadd_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_chapter(raw);
+  }
+
+  @protected
+  DownloadJob? dco_decode_opt_box_autoadd_download_job(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_download_job(raw);
+  }
+
+  @protected
+  EnrichedMetadata? dco_decode_opt_box_autoadd_enriched_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_enriched_metadata(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_32(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  FadConfig? dco_decode_opt_box_autoadd_fad_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_fad_config(raw);
+  }
+
+  @protected
+  FoundLyrics? dco_decode_opt_box_autoadd_found_lyrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_found_lyrics(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  LrcDocument? dco_decode_opt_box_autoadd_lrc_document(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_lrc_document(raw);
+  }
+
+  @protected
+  LyricPosition? dco_decode_opt_box_autoadd_lyric_position(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_lyric_position(raw);
+  }
+
+  @protected
+  Palette? dco_decode_opt_box_autoadd_palette(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_palette(raw);
+  }
+
+  @protected
+  PipelineConfig? dco_decode_opt_box_autoadd_pipeline_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_pipeline_config(raw);
+  }
+
+  @protected
+  PlaylistFormat? dco_decode_opt_box_autoadd_playlist_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_playlist_format(raw);
+  }
+
+  @protected
+  ReplayGain? dco_decode_opt_box_autoadd_replay_gain(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_replay_gain(raw);
+  }
+
+  @protected
+  SongMetadata? dco_decode_opt_box_autoadd_song_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_song_metadata(raw);
+  }
+
+  @protected
+  StreamMetadata? dco_decode_opt_box_autoadd_stream_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_stream_metadata(raw);
+  }
+
+  @protected
+  Swatch? dco_decode_opt_box_autoadd_swatch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_swatch(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_String(raw);
+  }
+
+  @protected
+  List<FadButton>? dco_decode_opt_list_fad_button(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_fad_button(raw);
+  }
+
+  @protected
+  List<FadLabel>? dco_decode_opt_list_fad_label(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_fad_label(raw);
+  }
+
+  @protected
+  List<FadPopup>? dco_decode_opt_list_fad_popup(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_fad_popup(raw);
+  }
+
+  @protected
+  List<FadScreen>? dco_decode_opt_list_fad_screen(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_fad_screen(raw);
+  }
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
+  }
+
+  @protected
+  OrganizePlan dco_decode_organize_plan(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return OrganizePlan(
+      moves: dco_decode_list_planned_move(arr[0]),
+      unchanged: dco_decode_u_32(arr[1]),
+      skipped: dco_decode_list_String(arr[2]),
+    );
+  }
+
+  @protected
+  OrganizeResult dco_decode_organize_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return OrganizeResult(
+      plan: dco_decode_organize_plan(arr[0]),
+      linksUpdated: dco_decode_u_32(arr[1]),
+      journalId: dco_decode_opt_box_autoadd_i_64(arr[2]),
+    );
+  }
+
+  @protected
+  Palette dco_decode_palette(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return Palette(
+      swatches: dco_decode_list_swatch(arr[0]),
+      vibrant: dco_decode_opt_box_autoadd_swatch(arr[1]),
+      lightVibrant: dco_decode_opt_box_autoadd_swatch(arr[2]),
+      darkVibrant: dco_decode_opt_box_autoadd_swatch(arr[3]),
+      muted: dco_decode_opt_box_autoadd_swatch(arr[4]),
+      lightMuted: dco_decode_opt_box_autoadd_swatch(arr[5]),
+      darkMuted: dco_decode_opt_box_autoadd_swatch(arr[6]),
+    );
+  }
+
+  @protected
+  PipelineConfig dco_decode_pipeline_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return PipelineConfig(
+      cleanTitles: dco_decode_bool(arr[0]),
+      titleRules: dco_decode_list_title_rule(arr[1]),
+      splitArtistTitle: dco_decode_bool(arr[2]),
+      identify: dco_decode_bool(arr[3]),
+      minIdentifyScore: dco_decode_f_64(arr[4]),
+      replayGain: dco_decode_bool(arr[5]),
+      import: dco_decode_bool(arr[6]),
+      template: dco_decode_String(arr[7]),
+    );
+  }
+
+  @protected
+  PipelineResult dco_decode_pipeline_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return PipelineResult(
+      originalPath: dco_decode_String(arr[0]),
+      path: dco_decode_String(arr[1]),
+      metadata: dco_decode_opt_box_autoadd_song_metadata(arr[2]),
+      identified: dco_decode_bool(arr[3]),
+      replayGain: dco_decode_opt_box_autoadd_replay_gain(arr[4]),
+      warnings: dco_decode_list_String(arr[5]),
+    );
+  }
+
+  @protected
+  PlannedMove dco_decode_planned_move(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PlannedMove(
+      from: dco_decode_String(arr[0]),
+      to: dco_decode_String(arr[1]),
+      renamedForCollision: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  PlayStat dco_decode_play_stat(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PlayStat(
+      playCount: dco_decode_u_32(arr[0]),
+      lastPlayed: dco_decode_i_64(arr[1]),
+    );
+  }
+
+  @protected
+  PlayerState dco_decode_player_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return PlayerState(
+      initialized: dco_decode_bool(arr[0]),
+    );
+  }
+
+  @protected
+  PlaylistEntry dco_decode_playlist_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PlaylistEntry(
+      index: dco_decode_u_32(arr[0]),
+      link: dco_decode_String(arr[1]),
+      path: dco_decode_String(arr[2]),
+      missing: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  PlaylistExportResult dco_decode_playlist_export_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PlaylistExportResult(
+      written: dco_decode_u_32(arr[0]),
+      missing: dco_decode_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  PlaylistFormat dco_decode_playlist_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PlaylistFormat.values[raw as int];
+  }
+
+  @protected
+  PlaylistImportResult dco_decode_playlist_import_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return PlaylistImportResult(
+      playlist: dco_decode_String(arr[0]),
+      totalEntries: dco_decode_u_32(arr[1]),
+      linked: dco_decode_u_32(arr[2]),
+      matched: dco_decode_list_matched_entry(arr[3]),
+      duplicates: dco_decode_u_32(arr[4]),
+      missing: dco_decode_list_missing_entry(arr[5]),
+    );
+  }
+
+  @protected
+  PlaylistInfo dco_decode_playlist_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PlaylistInfo(
+      name: dco_decode_String(arr[0]),
+      trackCount: dco_decode_u_32(arr[1]),
+      isSmart: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  PlaylistSortKey dco_decode_playlist_sort_key(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PlaylistSortKey.values[raw as int];
+  }
+
+  @protected
+  Podcast dco_decode_podcast(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return Podcast(
+      id: dco_decode_u_64(arr[0]),
+      feedUrl: dco_decode_String(arr[1]),
+      title: dco_decode_String(arr[2]),
+      author: dco_decode_opt_String(arr[3]),
+      description: dco_decode_opt_String(arr[4]),
+      link: dco_decode_opt_String(arr[5]),
+      imageUrl: dco_decode_opt_String(arr[6]),
+      autoDownload: dco_decode_bool(arr[7]),
+      keepDownloaded: dco_decode_opt_box_autoadd_u_32(arr[8]),
+      lastRefreshed: dco_decode_opt_box_autoadd_i_64(arr[9]),
+      episodes: dco_decode_list_podcast_episode(arr[10]),
+    );
+  }
+
+  @protected
+  PodcastChapter dco_decode_podcast_chapter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PodcastChapter(
+      startSecs: dco_decode_f_64(arr[0]),
+      title: dco_decode_String(arr[1]),
+      url: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  PodcastEpisode dco_decode_podcast_episode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PodcastEpisode(
+      feed: dco_decode_feed_episode(arr[0]),
+      downloadedPath: dco_decode_opt_String(arr[1]),
+      positionSecs: dco_decode_f_64(arr[2]),
+      played: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  PodcastRefresh dco_decode_podcast_refresh(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PodcastRefresh(
+      podcastId: dco_decode_u_64(arr[0]),
+      newEpisodes: dco_decode_list_String(arr[1]),
+      error: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  RadioStation dco_decode_radio_station(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return RadioStation(
+      name: dco_decode_String(arr[0]),
+      url: dco_decode_String(arr[1]),
+      genre: dco_decode_opt_String(arr[2]),
+      homepage: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  (
+    String,
+    PluginInode
+  ) dco_decode_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_String(arr[0]),
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
+          arr[1]),
+    );
+  }
+
+  @protected
+  (String, ConfigTypes) dco_decode_record_string_config_types(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_String(arr[0]),
+      dco_decode_config_types(arr[1]),
+    );
+  }
+
+  @protected
+  RemoteCandidate dco_decode_remote_candidate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return RemoteCandidate(
+      id: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      uploader: dco_decode_opt_String(arr[2]),
+      durationSecs: dco_decode_opt_box_autoadd_f_64(arr[3]),
+      thumbnailUrl: dco_decode_opt_String(arr[4]),
+      url: dco_decode_String(arr[5]),
+    );
+  }
+
+  @protected
+  ReplayGain dco_decode_replay_gain(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ReplayGain(
+      loudnessLufs: dco_decode_f_64(arr[0]),
+      gainDb: dco_decode_f_64(arr[1]),
+      peak: dco_decode_f_64(arr[2]),
+    );
+  }
+
+  @protected
+  SearchHit dco_decode_search_hit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SearchHit(
+      song: dco_decode_song_metadata(arr[0]),
+      score: dco_decode_f_32(arr[1]),
+    );
+  }
+
+  @protected
+  SearchPage dco_decode_search_page(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SearchPage(
+      total: dco_decode_u_32(arr[0]),
+      page: dco_decode_u_32(arr[1]),
+      pageSize: dco_decode_u_32(arr[2]),
+      hits: dco_decode_list_search_hit(arr[3]),
+    );
+  }
+
+  @protected
+  SongMetadata dco_decode_song_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return SongMetadata(
+      title: dco_decode_String(arr[0]),
+      artist: dco_decode_String(arr[1]),
+      album: dco_decode_String(arr[2]),
+      duration: dco_decode_u_64(arr[3]),
+      path: dco_decode_String(arr[4]),
+      albumArt: dco_decode_opt_list_prim_u_8_strict(arr[5]),
+      genre: dco_decode_String(arr[6]),
+    );
+  }
+
+  @protected
+  StreamMetadata dco_decode_stream_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return StreamMetadata(
+      url: dco_decode_String(arr[0]),
+      stationName: dco_decode_opt_String(arr[1]),
+      genre: dco_decode_opt_String(arr[2]),
+      bitrateKbps: dco_decode_opt_box_autoadd_u_32(arr[3]),
+      nowPlaying: dco_decode_opt_String(arr[4]),
+      artist: dco_decode_opt_String(arr[5]),
+      title: dco_decode_opt_String(arr[6]),
+      reconnecting: dco_decode_bool(arr[7]),
+    );
+  }
+
+  @protected
+  Swatch dco_decode_swatch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return Swatch(
+      color: dco_decode_u_32(arr[0]),
+      population: dco_decode_u_32(arr[1]),
+      textColor: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  TagEdit dco_decode_tag_edit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TagEdit(
+      path: dco_decode_String(arr[0]),
+      changes: dco_decode_list_field_change(arr[1]),
+    );
+  }
+
+  @protected
+  TagEditRecord dco_decode_tag_edit_record(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TagEditRecord(
+      id: dco_decode_i_64(arr[0]),
+      timestamp: dco_decode_i_64(arr[1]),
+      files: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  TagField dco_decode_tag_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TagField.values[raw as int];
+  }
+
+  @protected
+  TitleRule dco_decode_title_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TitleRule(
+      pattern: dco_decode_String(arr[0]),
+      replacement: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  ValueStoreUpdate dco_decode_value_store_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ValueStoreUpdate(
+      musicFolder: dco_decode_opt_String(arr[0]),
+      currentSong: dco_decode_current_song_update(arr[1]),
+      pluginsEnabled: dco_decode_opt_box_autoadd_bool(arr[2]),
+      pluginRwDir: dco_decode_opt_String(arr[3]),
+      unsafeApis: dco_decode_opt_box_autoadd_bool(arr[4]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  ArcMutexPlugin
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ArcMutexPluginImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  PluginInode
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return PluginInodeImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  RpcConfig
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RpcConfigImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  Value
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ValueImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  ValueStoreUpdater
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ValueStoreUpdaterImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  PluginInode
+      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return PluginInodeImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  RpcConfig
+      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RpcConfigImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  ValueStoreUpdater
+      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ValueStoreUpdaterImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  PluginInode
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return PluginInodeImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  RpcConfig
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RpcConfigImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  ValueStoreUpdater
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ValueStoreUpdaterImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_I128(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return BigInt.parse(inner);
+  }
+
+  @protected
+  Map<String, PluginInode>
+      sse_decode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode_None(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner =
+        sse_decode_list_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
+            deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  Map<String, ConfigTypes> sse_decode_Map_String_config_types_None(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_config_types(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  ArcMutexPlugin
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ArcMutexPluginImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  PluginInode
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return PluginInodeImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  RpcConfig
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RpcConfigImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  Value
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ValueImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  ValueStoreUpdater
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ValueStoreUpdaterImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  RustStreamSink<CollectionDownload>
+      sse_decode_StreamSink_collection_download_Sse(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<DownloadJob> sse_decode_StreamSink_download_job_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<EpisodeDownload> sse_decode_StreamSink_episode_download_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<IdentifyProgress> sse_decode_StreamSink_identify_progress_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<LyricEvent> sse_decode_StreamSink_lyric_event_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<SongMetadata> sse_decode_StreamSink_song_metadata_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<StreamMetadata> sse_decode_StreamSink_stream_metadata_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  BigInt sse_decode_U128(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return BigInt.parse(inner);
+  }
+
+  @protected
+  AcoustIdCandidate sse_decode_acoust_id_candidate(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_score = sse_decode_f_64(deserializer);
+    var var_recordingId = sse_decode_opt_String(deserializer);
+    var var_metadata = sse_decode_song_metadata(deserializer);
+    return AcoustIdCandidate(
+        score: var_score, recordingId: var_recordingId, metadata: var_metadata);
+  }
+
+  @protected
+  AdiPluginMan sse_decode_adi_plugin_man(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pluginMeta =
+        sse_decode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode_None(
+            deserializer);
+    return AdiPluginMan(pluginMeta: var_pluginMeta);
+  }
+
+  @protected
+  ArtWriteResult sse_decode_art_write_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return ArtWriteResult(path: var_path, error: var_error);
+  }
+
+  @protected
+  BookProgress sse_decode_book_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_positionSecs = sse_decode_f_64(deserializer);
+    var var_durationSecs = sse_decode_f_64(deserializer);
+    var var_finished = sse_decode_bool(deserializer);
+    var var_updated = sse_decode_i_64(deserializer);
+    return BookProgress(
+        path: var_path,
+        positionSecs: var_positionSecs,
+        durationSecs: var_durationSecs,
+        finished: var_finished,
+        updated: var_updated);
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  AcoustIdCandidate sse_decode_box_autoadd_acoust_id_candidate(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_acoust_id_candidate(deserializer));
+  }
+
+  @protected
+  AdiPluginMan sse_decode_box_autoadd_adi_plugin_man(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_adi_plugin_man(deserializer));
+  }
+
+  @protected
+  BookProgress sse_decode_box_autoadd_book_progress(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_book_progress(deserializer));
+  }
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
+  @protected
+  Chapter sse_decode_box_autoadd_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_chapter(deserializer));
+  }
+
+  @protected
+  CollectionOptions sse_decode_box_autoadd_collection_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_collection_options(deserializer));
+  }
+
+  @protected
+  ConfigTypes sse_decode_box_autoadd_config_types(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_config_types(deserializer));
+  }
+
+  @protected
+  DownloadJob sse_decode_box_autoadd_download_job(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_download_job(deserializer));
+  }
+
+  @protected
+  EnrichedMetadata sse_decode_box_autoadd_enriched_metadata(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_enriched_metadata(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_32(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  FadButton sse_decode_box_autoadd_fad_button(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_fad_button(deserializer));
+  }
+
+  @protected
+  FadConfig sse_decode_box_autoadd_fad_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_fad_config(deserializer));
+  }
+
+  @protected
+  FoundLyrics sse_decode_box_autoadd_found_lyrics(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_found_lyrics(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  LrcDocument sse_decode_box_autoadd_lrc_document(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_lrc_document(deserializer));
+  }
+
+  @protected
+  LyricPosition sse_decode_box_autoadd_lyric_position(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_lyric_position(deserializer));
+  }
+
+  @protected
+  Palette sse_decode_box_autoadd_palette(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_palette(deserializer));
+  }
+
+  @protected
+  PipelineConfig sse_decode_box_autoadd_pipeline_config(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_pipeline_config(deserializer));
+  }
+
+  @protected
+  PlaylistFormat sse_decode_box_autoadd_playlist_format(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_playlist_format(deserializer));
+  }
+
+  @protected
+  PlaylistSortKey sse_decode_box_autoadd_playlist_sort_key(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_playlist_sort_key(deserializer));
+  }
+
+  @protected
+  RadioStation sse_decode_box_autoadd_radio_station(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_radio_station(deserializer));
+  }
+
+  @protected
+  ReplayGain sse_decode_box_autoadd_replay_gain(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_replay_gain(deserializer));
+  }
+
+  @protected
+  SongMetadata sse_decode_box_autoadd_song_metadata(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_song_metadata(deserializer));
+  }
+
+  @protected
+  StreamMetadata sse_decode_box_autoadd_stream_metadata(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_stream_metadata(deserializer));
+  }
+
+  @protected
+  Swatch sse_decode_box_autoadd_swatch(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_swatch(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  ValueStoreUpdate sse_decode_box_autoadd_value_store_update(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_value_store_update(deserializer));
+  }
+
+  @protected
+  Chapter sse_decode_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_index = sse_decode_u_32(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_startSecs = sse_decode_f_64(deserializer);
+    var var_endSecs = sse_decode_f_64(deserializer);
+    return Chapter(
+        index: var_index,
+        title: var_title,
+        startSecs: var_startSecs,
+        endSecs: var_endSecs);
+  }
+
+  @protected
+  CollectionDownload sse_decode_collection_download(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_u_64(deserializer);
+    var var_url = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_folder = sse_decode_String(deserializer);
+    var var_tracks = sse_decode_list_collection_track(deserializer);
+    var var_playlist = sse_decode_opt_String(deserializer);
+    var var_finished = sse_decode_bool(deserializer);
+    return CollectionDownload(
+        id: var_id,
+        url: var_url,
+        title: var_title,
+        folder: var_folder,
+        tracks: var_tracks,
+        playlist: var_playlist,
+        finished: var_finished);
+  }
+
+  @protected
+  CollectionOptions sse_decode_collection_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_flags = sse_decode_opt_String(deserializer);
+    var var_folder = sse_decode_opt_String(deserializer);
+    var var_createPlaylist = sse_decode_bool(deserializer);
+    var var_playlistName = sse_decode_opt_String(deserializer);
+    var var_postProcess = sse_decode_bool(deserializer);
+    return CollectionOptions(
+        flags: var_flags,
+        folder: var_folder,
+        createPlaylist: var_createPlaylist,
+        playlistName: var_playlistName,
+        postProcess: var_postProcess);
+  }
+
+  @protected
+  CollectionTrack sse_decode_collection_track(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_position = sse_decode_u_32(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_jobId = sse_decode_u_64(deserializer);
+    var var_path = sse_decode_opt_String(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return CollectionTrack(
+        position: var_position,
+        title: var_title,
+        jobId: var_jobId,
+        path: var_path,
+        error: var_error);
+  }
+
+  @protected
+  ConfigTypes sse_decode_config_types(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_String(deserializer);
+        return ConfigTypes_String(var_field0);
+      case 1:
+        var var_field0 = sse_decode_bool(deserializer);
+        return ConfigTypes_Bool(var_field0);
+      case 2:
+        var var_field0 = sse_decode_i_32(deserializer);
+        return ConfigTypes_Int(var_field0);
+      case 3:
+        var var_field0 = sse_decode_u_32(deserializer);
+        return ConfigTypes_UInt(var_field0);
+      case 4:
+        var var_field0 = sse_decode_I128(deserializer);
+        return ConfigTypes_BigInt(var_field0);
+      case 5:
+        var var_field0 = sse_decode_U128(deserializer);
+        return ConfigTypes_BigUInt(var_field0);
+      case 6:
+        var var_field0 = sse_decode_f_64(deserializer);
+        return ConfigTypes_Float(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  CueSheet sse_decode_cue_sheet(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_performer = sse_decode_opt_String(deserializer);
+    var var_genre = sse_decode_opt_String(deserializer);
+    var var_date = sse_decode_opt_String(deserializer);
+    var var_tracks = sse_decode_list_cue_track(deserializer);
+    return CueSheet(
+        title: var_title,
+        performer: var_performer,
+        genre: var_genre,
+        date: var_date,
+        tracks: var_tracks);
+  }
+
+  @protected
+  CueTrack sse_decode_cue_track(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_number = sse_decode_u_32(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_performer = sse_decode_opt_String(deserializer);
+    var var_file = sse_decode_String(deserializer);
+    var var_startSecs = sse_decode_f_64(deserializer);
+    return CueTrack(
+        number: var_number,
+        title: var_title,
+        performer: var_performer,
+        file: var_file,
+        startSecs: var_startSecs);
+  }
+
+  @protected
+  CurrentSongUpdate sse_decode_current_song_update(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return CurrentSongUpdate_NoChange();
+      case 1:
+        return CurrentSongUpdate_SetToNone();
+      case 2:
+        var var_field0 = sse_decode_box_autoadd_song_metadata(deserializer);
+        return CurrentSongUpdate_SetToSome(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  DownloadJob sse_decode_download_job(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_u_64(deserializer);
+    var var_query = sse_decode_String(deserializer);
+    var var_state = sse_decode_download_state(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_progress = sse_decode_opt_box_autoadd_f_32(deserializer);
+    var var_downloadedBytes = sse_decode_u_64(deserializer);
+    var var_totalBytes = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_speed = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_etaSecs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_tempDir = sse_decode_String(deserializer);
+    var var_files = sse_decode_list_String(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return DownloadJob(
+        id: var_id,
+        query: var_query,
+        state: var_state,
+        title: var_title,
+        progress: var_progress,
+        downloadedBytes: var_downloadedBytes,
+        totalBytes: var_totalBytes,
+        speed: var_speed,
+        etaSecs: var_etaSecs,
+        tempDir: var_tempDir,
+        files: var_files,
+        error: var_error);
+  }
+
+  @protected
+  DownloadState sse_decode_download_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DownloadState.values[inner];
+  }
+
+  @protected
+  DuplicateFile sse_decode_duplicate_file(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_format = sse_decode_String(deserializer);
+    var var_sizeBytes = sse_decode_u_64(deserializer);
+    var var_duration = sse_decode_f_64(deserializer);
+    var var_bitrateKbps = sse_decode_u_32(deserializer);
+    var var_sampleRate = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_bitsPerSample = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_lossless = sse_decode_bool(deserializer);
+    return DuplicateFile(
+        path: var_path,
+        format: var_format,
+        sizeBytes: var_sizeBytes,
+        duration: var_duration,
+        bitrateKbps: var_bitrateKbps,
+        sampleRate: var_sampleRate,
+        bitsPerSample: var_bitsPerSample,
+        lossless: var_lossless);
+  }
+
+  @protected
+  DuplicateGroup sse_decode_duplicate_group(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_files = sse_decode_list_duplicate_file(deserializer);
+    var var_similarity = sse_decode_f_64(deserializer);
+    return DuplicateGroup(files: var_files, similarity: var_similarity);
+  }
+
+  @protected
+  EnrichedMetadata sse_decode_enriched_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_metadata = sse_decode_song_metadata(deserializer);
+    var var_recordingId = sse_decode_opt_String(deserializer);
+    var var_releaseId = sse_decode_opt_String(deserializer);
+    var var_albumArtist = sse_decode_opt_String(deserializer);
+    var var_trackNumber = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_totalTracks = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_discNumber = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_date = sse_decode_opt_String(deserializer);
+    var var_year = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_genres = sse_decode_list_String(deserializer);
+    var var_isrc = sse_decode_opt_String(deserializer);
+    return EnrichedMetadata(
+        metadata: var_metadata,
+        recordingId: var_recordingId,
+        releaseId: var_releaseId,
+        albumArtist: var_albumArtist,
+        trackNumber: var_trackNumber,
+        totalTracks: var_totalTracks,
+        discNumber: var_discNumber,
+        date: var_date,
+        year: var_year,
+        genres: var_genres,
+        isrc: var_isrc);
+  }
+
+  @protected
+  EpisodeDownload sse_decode_episode_download(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_podcastId = sse_decode_u_64(deserializer);
+    var var_guid = sse_decode_String(deserializer);
+    var var_downloadedBytes = sse_decode_u_64(deserializer);
+    var var_totalBytes = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_finished = sse_decode_bool(deserializer);
+    var var_path = sse_decode_opt_String(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return EpisodeDownload(
+        podcastId: var_podcastId,
+        guid: var_guid,
+        downloadedBytes: var_downloadedBytes,
+        totalBytes: var_totalBytes,
+        finished: var_finished,
+        path: var_path,
+        error: var_error);
+  }
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat32();
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  FadButton sse_decode_fad_button(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_icon = sse_decode_opt_String(deserializer);
+    var var_location = sse_decode_opt_String(deserializer);
+    var var_callback = sse_decode_String(deserializer);
+    return FadButton(
+        name: var_name,
+        icon: var_icon,
+        location: var_location,
+        callback: var_callback);
+  }
+
+  @protected
+  FadConfig sse_decode_fad_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_screens = sse_decode_opt_list_fad_screen(deserializer);
+    var var_popups = sse_decode_opt_list_fad_popup(deserializer);
+    var var_buttons = sse_decode_opt_list_fad_button(deserializer);
+    return FadConfig(
+        screens: var_screens, popups: var_popups, buttons: var_buttons);
+  }
+
+  @protected
+  FadLabel sse_decode_fad_label(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_size = sse_decode_f_64(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    var var_color = sse_decode_opt_String(deserializer);
+    return FadLabel(size: var_size, text: var_text, color: var_color);
+  }
+
+  @protected
+  FadPopup sse_decode_fad_popup(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_buttons = sse_decode_opt_list_fad_button(deserializer);
+    var var_labels = sse_decode_opt_list_fad_label(deserializer);
+    return FadPopup(title: var_title, buttons: var_buttons, labels: var_labels);
+  }
+
+  @protected
+  FadScreen sse_decode_fad_screen(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_buttons = sse_decode_opt_list_fad_button(deserializer);
+    var var_labels = sse_decode_opt_list_fad_label(deserializer);
+    return FadScreen(
+        title: var_title, buttons: var_buttons, labels: var_labels);
+  }
+
+  @protected
+  FeedEpisode sse_decode_feed_episode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_guid = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_published = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_durationSecs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_enclosureUrl = sse_decode_opt_String(deserializer);
+    var var_enclosureType = sse_decode_opt_String(deserializer);
+    var var_enclosureLength = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_imageUrl = sse_decode_opt_String(deserializer);
+    var var_chapters = sse_decode_list_podcast_chapter(deserializer);
+    var var_chaptersUrl = sse_decode_opt_String(deserializer);
+    return FeedEpisode(
+        guid: var_guid,
+        title: var_title,
+        description: var_description,
+        published: var_published,
+        durationSecs: var_durationSecs,
+        enclosureUrl: var_enclosureUrl,
+        enclosureType: var_enclosureType,
+        enclosureLength: var_enclosureLength,
+        imageUrl: var_imageUrl,
+        chapters: var_chapters,
+        chaptersUrl: var_chaptersUrl);
+  }
+
+  @protected
+  FieldChange sse_decode_field_change(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field = sse_decode_tag_field(deserializer);
+    var var_value = sse_decode_opt_String(deserializer);
+    return FieldChange(field: var_field, value: var_value);
+  }
+
+  @protected
+  FoundLyrics sse_decode_found_lyrics(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_source = sse_decode_lyrics_source(deserializer);
+    var var_content = sse_decode_String(deserializer);
+    var var_document = sse_decode_lrc_document(deserializer);
+    var var_cachePath = sse_decode_opt_String(deserializer);
+    return FoundLyrics(
+        source: var_source,
+        content: var_content,
+        document: var_document,
+        cachePath: var_cachePath);
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  IdentifyProgress sse_decode_identify_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_batchId = sse_decode_u_64(deserializer);
+    var var_completed = sse_decode_u_32(deserializer);
+    var var_total = sse_decode_u_32(deserializer);
+    var var_result = sse_decode_identify_result(deserializer);
+    return IdentifyProgress(
+        batchId: var_batchId,
+        completed: var_completed,
+        total: var_total,
+        result: var_result);
+  }
+
+  @protected
+  IdentifyResult sse_decode_identify_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_candidates = sse_decode_list_acoust_id_candidate(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return IdentifyResult(
+        path: var_path, candidates: var_candidates, error: var_error);
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AcoustIdCandidate> sse_decode_list_acoust_id_candidate(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AcoustIdCandidate>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_acoust_id_candidate(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ArtWriteResult> sse_decode_list_art_write_result(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ArtWriteResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_art_write_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BookProgress> sse_decode_list_book_progress(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BookProgress>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_book_progress(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Chapter> sse_decode_list_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Chapter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_chapter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CollectionDownload> sse_decode_list_collection_download(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CollectionDownload>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_collection_download(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CollectionTrack> sse_decode_list_collection_track(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CollectionTrack>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_collection_track(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CueTrack> sse_decode_list_cue_track(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CueTrack>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_cue_track(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DownloadJob> sse_decode_list_download_job(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DownloadJob>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_download_job(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DuplicateFile> sse_decode_list_duplicate_file(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DuplicateFile>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_duplicate_file(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DuplicateGroup> sse_decode_list_duplicate_group(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DuplicateGroup>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_duplicate_group(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FadButton> sse_decode_list_fad_button(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FadButton>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_fad_button(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FadLabel> sse_decode_list_fad_label(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FadLabel>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_fad_label(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FadPopup> sse_decode_list_fad_popup(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FadPopup>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_fad_popup(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FadScreen> sse_decode_list_fad_screen(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FadScreen>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_fad_screen(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FieldChange> sse_decode_list_field_change(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FieldChange>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_field_change(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LrcLine> sse_decode_list_lrc_line(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LrcLine>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_lrc_line(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LrcWord> sse_decode_list_lrc_word(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LrcWord>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_lrc_word(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LrclibLyrics> sse_decode_list_lrclib_lyrics(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LrclibLyrics>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_lrclib_lyrics(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LyricMatchLine> sse_decode_list_lyric_match_line(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LyricMatchLine>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_lyric_match_line(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LyricSearchResult> sse_decode_list_lyric_search_result(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LyricSearchResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_lyric_search_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MatchedEntry> sse_decode_list_matched_entry(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MatchedEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_matched_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MissingEntry> sse_decode_list_missing_entry(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MissingEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_missing_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PipelineResult> sse_decode_list_pipeline_result(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PipelineResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pipeline_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PlannedMove> sse_decode_list_planned_move(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PlannedMove>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_planned_move(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PlaylistEntry> sse_decode_list_playlist_entry(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PlaylistEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_playlist_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PlaylistInfo> sse_decode_list_playlist_info(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PlaylistInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_playlist_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Podcast> sse_decode_list_podcast(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Podcast>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_podcast(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PodcastChapter> sse_decode_list_podcast_chapter(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PodcastChapter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_podcast_chapter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PodcastEpisode> sse_decode_list_podcast_episode(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PodcastEpisode>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_podcast_episode(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PodcastRefresh> sse_decode_list_podcast_refresh(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PodcastRefresh>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_podcast_refresh(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getFloat32List(len_);
+  }
+
+  @protected
+  Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getFloat64List(len_);
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_32_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint32List(len_);
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<RadioStation> sse_decode_list_radio_station(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RadioStation>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_radio_station(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<(String, PluginInode)>
+      sse_decode_list_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, PluginInode)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(
+          sse_decode_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
+              deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<(String, ConfigTypes)> sse_decode_list_record_string_config_types(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, ConfigTypes)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_config_types(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RemoteCandidate> sse_decode_list_remote_candidate(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RemoteCandidate>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_remote_candidate(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SearchHit> sse_decode_list_search_hit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SearchHit>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_search_hit(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SongMetadata> sse_decode_list_song_metadata(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SongMetadata>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_song_metadata(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Swatch> sse_decode_list_swatch(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Swatch>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_swatch(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TagEdit> sse_decode_list_tag_edit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TagEdit>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_tag_edit(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TagEditRecord> sse_decode_list_tag_edit_record(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TagEditRecord>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_tag_edit_record(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TitleRule> sse_decode_list_title_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TitleRule>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_title_rule(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  LrcDocument sse_decode_lrc_document(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_artist = sse_decode_opt_String(deserializer);
+    var var_album = sse_decode_opt_String(deserializer);
+    var var_genre = sse_decode_opt_String(deserializer);
+    var var_songPath = sse_decode_opt_String(deserializer);
+    var var_offsetMs = sse_decode_i_64(deserializer);
+    var var_userOffsetMs = sse_decode_i_64(deserializer);
+    var var_lengthMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_synced = sse_decode_bool(deserializer);
+    var var_lines = sse_decode_list_lrc_line(deserializer);
+    return LrcDocument(
+        title: var_title,
+        artist: var_artist,
+        album: var_album,
+        genre: var_genre,
+        songPath: var_songPath,
+        offsetMs: var_offsetMs,
+        userOffsetMs: var_userOffsetMs,
+        lengthMs: var_lengthMs,
+        synced: var_synced,
+        lines: var_lines);
+  }
+
+  @protected
+  LrcLine sse_decode_lrc_line(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_startMs = sse_decode_u_64(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    var var_words = sse_decode_list_lrc_word(deserializer);
+    return LrcLine(startMs: var_startMs, text: var_text, words: var_words);
+  }
+
+  @protected
+  LrcWord sse_decode_lrc_word(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_startMs = sse_decode_u_64(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    return LrcWord(startMs: var_startMs, text: var_text);
+  }
+
+  @protected
+  LrclibLyrics sse_decode_lrclib_lyrics(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_artist = sse_decode_String(deserializer);
+    var var_album = sse_decode_String(deserializer);
+    var var_duration = sse_decode_f_64(deserializer);
+    var var_instrumental = sse_decode_bool(deserializer);
+    var var_plainLyrics = sse_decode_opt_String(deserializer);
+    var var_syncedLyrics = sse_decode_opt_String(deserializer);
+    return LrclibLyrics(
+        id: var_id,
+        title: var_title,
+        artist: var_artist,
+        album: var_album,
+        duration: var_duration,
+        instrumental: var_instrumental,
+        plainLyrics: var_plainLyrics,
+        syncedLyrics: var_syncedLyrics);
+  }
+
+  @protected
+  LyricEvent sse_decode_lyric_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_positionMs = sse_decode_u_64(deserializer);
+    var var_current = sse_decode_opt_box_autoadd_lyric_position(deserializer);
+    return LyricEvent(positionMs: var_positionMs, current: var_current);
+  }
+
+  @protected
+  LyricMatchLine sse_decode_lyric_match_line(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_line = sse_decode_u_32(deserializer);
+    var var_timestampMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    return LyricMatchLine(
+        line: var_line, timestampMs: var_timestampMs, text: var_text);
+  }
+
+  @protected
+  LyricPosition sse_decode_lyric_position(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_lineIndex = sse_decode_u_32(deserializer);
+    var var_wordIndex = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_lineStartMs = sse_decode_u_64(deserializer);
+    var var_lineEndMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    return LyricPosition(
+        lineIndex: var_lineIndex,
+        wordIndex: var_wordIndex,
+        lineStartMs: var_lineStartMs,
+        lineEndMs: var_lineEndMs,
+        text: var_text);
+  }
+
+  @protected
+  LyricSearchResult sse_decode_lyric_search_result(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_song = sse_decode_song_metadata(deserializer);
+    var var_score = sse_decode_f_32(deserializer);
+    var var_lines = sse_decode_list_lyric_match_line(deserializer);
+    return LyricSearchResult(
+        song: var_song, score: var_score, lines: var_lines);
+  }
+
+  @protected
+  LyricsSource sse_decode_lyrics_source(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return LyricsSource.values[inner];
+  }
+
+  @protected
+  MatchedEntry sse_decode_matched_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_location = sse_decode_String(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    return MatchedEntry(location: var_location, path: var_path);
+  }
+
+  @protected
+  MaterialScheme sse_decode_material_scheme(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_isDark = sse_decode_bool(deserializer);
+    var var_seed = sse_decode_u_32(deserializer);
+    var var_primary = sse_decode_u_32(deserializer);
+    var var_onPrimary = sse_decode_u_32(deserializer);
+    var var_primaryContainer = sse_decode_u_32(deserializer);
+    var var_onPrimaryContainer = sse_decode_u_32(deserializer);
+    var var_secondary = sse_decode_u_32(deserializer);
+    var var_onSecondary = sse_decode_u_32(deserializer);
+    var var_secondaryContainer = sse_decode_u_32(deserializer);
+    var var_onSecondaryContainer = sse_decode_u_32(deserializer);
+    var var_tertiary = sse_decode_u_32(deserializer);
+    var var_onTertiary = sse_decode_u_32(deserializer);
+    var var_tertiaryContainer = sse_decode_u_32(deserializer);
+    var var_onTertiaryContainer = sse_decode_u_32(deserializer);
+    var var_error = sse_decode_u_32(deserializer);
+    var var_onError = sse_decode_u_32(deserializer);
+    var var_errorContainer = sse_decode_u_32(deserializer);
+    var var_onErrorContainer = sse_decode_u_32(deserializer);
+    var var_background = sse_decode_u_32(deserializer);
+    var var_onBackground = sse_decode_u_32(deserializer);
+    var var_surface = sse_decode_u_32(deserializer);
+    var var_onSurface = sse_decode_u_32(deserializer);
+    var var_surfaceVariant = sse_decode_u_32(deserializer);
+    var var_onSurfaceVariant = sse_decode_u_32(deserializer);
+    var var_surfaceContainer = sse_decode_u_32(deserializer);
+    var var_outline = sse_decode_u_32(deserializer);
+    var var_outlineVariant = sse_decode_u_32(deserializer);
+    var var_inverseSurface = sse_decode_u_32(deserializer);
+    var var_inverseOnSurface = sse_decode_u_32(deserializer);
+    var var_inversePrimary = sse_decode_u_32(deserializer);
+    return MaterialScheme(
+        isDark: var_isDark,
+        seed: var_seed,
+        primary: var_primary,
+        onPrimary: var_onPrimary,
+        primaryContainer: var_primaryContainer,
+        onPrimaryContainer: var_onPrimaryContainer,
+        secondary: var_secondary,
+        onSecondary: var_onSecondary,
+        secondaryContainer: var_secondaryContainer,
+        onSecondaryContainer: var_onSecondaryContainer,
+        tertiary: var_tertiary,
+        onTertiary: var_onTertiary,
+        tertiaryContainer: var_tertiaryContainer,
+        onTertiaryContainer: var_onTertiaryContainer,
+        error: var_error,
+        onError: var_onError,
+        errorContainer: var_errorContainer,
+        onErrorContainer: var_onErrorContainer,
+        background: var_background,
+        onBackground: var_onBackground,
+        surface: var_surface,
+        onSurface: var_onSurface,
+        surfaceVariant: var_surfaceVariant,
+        onSurfaceVariant: var_onSurfaceVariant,
+        surfaceContainer: var_surfaceContainer,
+        outline: var_outline,
+        outlineVariant: var_outlineVariant,
+        inverseSurface: var_inverseSurface,
+        inverseOnSurface: var_inverseOnSurface,
+        inversePrimary: var_inversePrimary);
+  }
+
+  @protected
+  MissingEntry sse_decode_missing_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_location = sse_decode_String(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_artist = sse_decode_opt_String(deserializer);
+    return MissingEntry(
+        location: var_location, title: var_title, artist: var_artist);
+  }
+
+  @protected
+  Map<String, ConfigTypes>? sse_decode_opt_Map_String_config_types_None(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_Map_String_config_types_None(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AdiPluginMan? sse_decode_opt_box_autoadd_adi_plugin_man(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_adi_plugin_man(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BookProgress? sse_decode_opt_box_autoadd_book_progress(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_book_progress(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Chapter? sse_decode_opt_box_autoadd_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_chapter(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DownloadJob? sse_decode_opt_box_autoadd_download_job(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_download_job(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  EnrichedMetadata? sse_decode_opt_box_autoadd_enriched_metadata(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_enriched_metadata(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  FadConfig? sse_decode_opt_box_autoadd_fad_config(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_fad_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  FoundLyrics? sse_decode_opt_box_autoadd_found_lyrics(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_found_lyrics(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_32(deserializer));
+    } else {
+      return null;
+    }
+  }
 
   @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  ArcMutexPlugin
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ArcMutexPluginImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  LrcDocument? sse_decode_opt_box_autoadd_lrc_document(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_lrc_document(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  PluginInode
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return PluginInodeImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  LyricPosition? sse_decode_opt_box_autoadd_lyric_position(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_lyric_position(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  RpcConfig
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return RpcConfigImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  Palette? sse_decode_opt_box_autoadd_palette(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_palette(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  Value
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ValueImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  PipelineConfig? sse_decode_opt_box_autoadd_pipeline_config(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_pipeline_config(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  ValueStoreUpdater
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ValueStoreUpdaterImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  PlaylistFormat? sse_decode_opt_box_autoadd_playlist_format(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_playlist_format(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  PluginInode
-      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return PluginInodeImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  ReplayGain? sse_decode_opt_box_autoadd_replay_gain(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_replay_gain(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  RpcConfig
-      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return RpcConfigImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  SongMetadata? sse_decode_opt_box_autoadd_song_metadata(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_song_metadata(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  ValueStoreUpdater
-      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ValueStoreUpdaterImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  StreamMetadata? sse_decode_opt_box_autoadd_stream_metadata(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_stream_metadata(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  PluginInode
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return PluginInodeImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  Swatch? sse_decode_opt_box_autoadd_swatch(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_swatch(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  RpcConfig
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return RpcConfigImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  ValueStoreUpdater
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ValueStoreUpdaterImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  BigInt dco_decode_I128(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return BigInt.parse(raw);
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_String(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  Map<String, PluginInode>
-      dco_decode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode_None(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-        dco_decode_list_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
-                raw)
-            .map((e) => MapEntry(e.$1, e.$2)));
+  List<FadButton>? sse_decode_opt_list_fad_button(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_fad_button(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  Map<String, ConfigTypes> dco_decode_Map_String_config_types_None(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(dco_decode_list_record_string_config_types(raw)
-        .map((e) => MapEntry(e.$1, e.$2)));
+  List<FadLabel>? sse_decode_opt_list_fad_label(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_fad_label(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  ArcMutexPlugin
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ArcMutexPluginImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  List<FadPopup>? sse_decode_opt_list_fad_popup(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_fad_popup(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  PluginInode
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return PluginInodeImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  List<FadScreen>? sse_decode_opt_list_fad_screen(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_fad_screen(deserializer));
+    } else {
+      return null;
+    }
   }
 
   @protected
-  RpcConfig
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return RpcConfigImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_prim_u_8_strict(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  OrganizePlan sse_decode_organize_plan(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_moves = sse_decode_list_planned_move(deserializer);
+    var var_unchanged = sse_decode_u_32(deserializer);
+    var var_skipped = sse_decode_list_String(deserializer);
+    return OrganizePlan(
+        moves: var_moves, unchanged: var_unchanged, skipped: var_skipped);
+  }
+
+  @protected
+  OrganizeResult sse_decode_organize_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_plan = sse_decode_organize_plan(deserializer);
+    var var_linksUpdated = sse_decode_u_32(deserializer);
+    var var_journalId = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return OrganizeResult(
+        plan: var_plan,
+        linksUpdated: var_linksUpdated,
+        journalId: var_journalId);
+  }
+
+  @protected
+  Palette sse_decode_palette(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_swatches = sse_decode_list_swatch(deserializer);
+    var var_vibrant = sse_decode_opt_box_autoadd_swatch(deserializer);
+    var var_lightVibrant = sse_decode_opt_box_autoadd_swatch(deserializer);
+    var var_darkVibrant = sse_decode_opt_box_autoadd_swatch(deserializer);
+    var var_muted = sse_decode_opt_box_autoadd_swatch(deserializer);
+    var var_lightMuted = sse_decode_opt_box_autoadd_swatch(deserializer);
+    var var_darkMuted = sse_decode_opt_box_autoadd_swatch(deserializer);
+    return Palette(
+        swatches: var_swatches,
+        vibrant: var_vibrant,
+        lightVibrant: var_lightVibrant,
+        darkVibrant: var_darkVibrant,
+        muted: var_muted,
+        lightMuted: var_lightMuted,
+        darkMuted: var_darkMuted);
+  }
+
+  @protected
+  PipelineConfig sse_decode_pipeline_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_cleanTitles = sse_decode_bool(deserializer);
+    var var_titleRules = sse_decode_list_title_rule(deserializer);
+    var var_splitArtistTitle = sse_decode_bool(deserializer);
+    var var_identify = sse_decode_bool(deserializer);
+    var var_minIdentifyScore = sse_decode_f_64(deserializer);
+    var var_replayGain = sse_decode_bool(deserializer);
+    var var_import = sse_decode_bool(deserializer);
+    var var_template = sse_decode_String(deserializer);
+    return PipelineConfig(
+        cleanTitles: var_cleanTitles,
+        titleRules: var_titleRules,
+        splitArtistTitle: var_splitArtistTitle,
+        identify: var_identify,
+        minIdentifyScore: var_minIdentifyScore,
+        replayGain: var_replayGain,
+        import: var_import,
+        template: var_template);
+  }
+
+  @protected
+  PipelineResult sse_decode_pipeline_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_originalPath = sse_decode_String(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_metadata = sse_decode_opt_box_autoadd_song_metadata(deserializer);
+    var var_identified = sse_decode_bool(deserializer);
+    var var_replayGain = sse_decode_opt_box_autoadd_replay_gain(deserializer);
+    var var_warnings = sse_decode_list_String(deserializer);
+    return PipelineResult(
+        originalPath: var_originalPath,
+        path: var_path,
+        metadata: var_metadata,
+        identified: var_identified,
+        replayGain: var_replayGain,
+        warnings: var_warnings);
   }
 
   @protected
-  Value
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ValueImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  PlannedMove sse_decode_planned_move(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_from = sse_decode_String(deserializer);
+    var var_to = sse_decode_String(deserializer);
+    var var_renamedForCollision = sse_decode_bool(deserializer);
+    return PlannedMove(
+        from: var_from,
+        to: var_to,
+        renamedForCollision: var_renamedForCollision);
   }
 
   @protected
-  ValueStoreUpdater
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ValueStoreUpdaterImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  PlayStat sse_decode_play_stat(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_playCount = sse_decode_u_32(deserializer);
+    var var_lastPlayed = sse_decode_i_64(deserializer);
+    return PlayStat(playCount: var_playCount, lastPlayed: var_lastPlayed);
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  PlayerState sse_decode_player_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_initialized = sse_decode_bool(deserializer);
+    return PlayerState(initialized: var_initialized);
   }
 
   @protected
-  BigInt dco_decode_U128(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return BigInt.parse(raw);
+  PlaylistEntry sse_decode_playlist_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_index = sse_decode_u_32(deserializer);
+    var var_link = sse_decode_String(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_missing = sse_decode_bool(deserializer);
+    return PlaylistEntry(
+        index: var_index, link: var_link, path: var_path, missing: var_missing);
   }
 
   @protected
-  AdiPluginMan dco_decode_adi_plugin_man(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return AdiPluginMan(
-      pluginMeta:
-          dco_decode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode_None(
-              arr[0]),
-    );
+  PlaylistExportResult sse_decode_playlist_export_result(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_written = sse_decode_u_32(deserializer);
+    var var_missing = sse_decode_list_String(deserializer);
+    return PlaylistExportResult(written: var_written, missing: var_missing);
   }
 
   @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  PlaylistFormat sse_decode_playlist_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PlaylistFormat.values[inner];
   }
 
   @protected
-  AdiPluginMan dco_decode_box_autoadd_adi_plugin_man(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_adi_plugin_man(raw);
+  PlaylistImportResult sse_decode_playlist_import_result(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_playlist = sse_decode_String(deserializer);
+    var var_totalEntries = sse_decode_u_32(deserializer);
+    var var_linked = sse_decode_u_32(deserializer);
+    var var_matched = sse_decode_list_matched_entry(deserializer);
+    var var_duplicates = sse_decode_u_32(deserializer);
+    var var_missing = sse_decode_list_missing_entry(deserializer);
+    return PlaylistImportResult(
+        playlist: var_playlist,
+        totalEntries: var_totalEntries,
+        linked: var_linked,
+        matched: var_matched,
+        duplicates: var_duplicates,
+        missing: var_missing);
   }
 
   @protected
-  bool dco_decode_box_autoadd_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  PlaylistInfo sse_decode_playlist_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_trackCount = sse_decode_u_32(deserializer);
+    var var_isSmart = sse_decode_bool(deserializer);
+    return PlaylistInfo(
+        name: var_name, trackCount: var_trackCount, isSmart: var_isSmart);
   }
 
   @protected
-  ConfigTypes dco_decode_box_autoadd_config_types(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_config_types(raw);
+  PlaylistSortKey sse_decode_playlist_sort_key(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PlaylistSortKey.values[inner];
   }
 
   @protected
-  FadButton dco_decode_box_autoadd_fad_button(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_fad_button(raw);
+  Podcast sse_decode_podcast(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_u_64(deserializer);
+    var var_feedUrl = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_author = sse_decode_opt_String(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_link = sse_decode_opt_String(deserializer);
+    var var_imageUrl = sse_decode_opt_String(deserializer);
+    var var_autoDownload = sse_decode_bool(deserializer);
+    var var_keepDownloaded = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_lastRefreshed = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_episodes = sse_decode_list_podcast_episode(deserializer);
+    return Podcast(
+        id: var_id,
+        feedUrl: var_feedUrl,
+        title: var_title,
+        author: var_author,
+        description: var_description,
+        link: var_link,
+        imageUrl: var_imageUrl,
+        autoDownload: var_autoDownload,
+        keepDownloaded: var_keepDownloaded,
+        lastRefreshed: var_lastRefreshed,
+        episodes: var_episodes);
   }
 
   @protected
-  FadConfig dco_decode_box_autoadd_fad_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_fad_config(raw);
+  PodcastChapter sse_decode_podcast_chapter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_startSecs = sse_decode_f_64(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_url = sse_decode_opt_String(deserializer);
+    return PodcastChapter(
+        startSecs: var_startSecs, title: var_title, url: var_url);
   }
 
   @protected
-  SongMetadata dco_decode_box_autoadd_song_metadata(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_song_metadata(raw);
+  PodcastEpisode sse_decode_podcast_episode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_feed = sse_decode_feed_episode(deserializer);
+    var var_downloadedPath = sse_decode_opt_String(deserializer);
+    var var_positionSecs = sse_decode_f_64(deserializer);
+    var var_played = sse_decode_bool(deserializer);
+    return PodcastEpisode(
+        feed: var_feed,
+        downloadedPath: var_downloadedPath,
+        positionSecs: var_positionSecs,
+        played: var_played);
   }
 
   @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  PodcastRefresh sse_decode_podcast_refresh(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_podcastId = sse_decode_u_64(deserializer);
+    var var_newEpisodes = sse_decode_list_String(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return PodcastRefresh(
+        podcastId: var_podcastId,
+        newEpisodes: var_newEpisodes,
+        error: var_error);
   }
 
   @protected
-  ValueStoreUpdate dco_decode_box_autoadd_value_store_update(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_value_store_update(raw);
+  RadioStation sse_decode_radio_station(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_url = sse_decode_String(deserializer);
+    var var_genre = sse_decode_opt_String(deserializer);
+    var var_homepage = sse_decode_opt_String(deserializer);
+    return RadioStation(
+        name: var_name, url: var_url, genre: var_genre, homepage: var_homepage);
   }
 
   @protected
-  ConfigTypes dco_decode_config_types(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return ConfigTypes_String(
-          dco_decode_String(raw[1]),
-        );
-      case 1:
-        return ConfigTypes_Bool(
-          dco_decode_bool(raw[1]),
-        );
-      case 2:
-        return ConfigTypes_Int(
-          dco_decode_i_32(raw[1]),
-        );
-      case 3:
-        return ConfigTypes_UInt(
-          dco_decode_u_32(raw[1]),
-        );
-      case 4:
-        return ConfigTypes_BigInt(
-          dco_decode_I128(raw[1]),
-        );
-      case 5:
-        return ConfigTypes_BigUInt(
-          dco_decode_U128(raw[1]),
-        );
-      case 6:
-        return ConfigTypes_Float(
-          dco_decode_f_64(raw[1]),
-        );
-      default:
-        throw Exception("unreachable");
-    }
+  (
+    String,
+    PluginInode
+  ) sse_decode_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
+            deserializer);
+    return (var_field0, var_field1);
   }
 
   @protected
-  CurrentSongUpdate dco_decode_current_song_update(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return CurrentSongUpdate_NoChange();
-      case 1:
-        return CurrentSongUpdate_SetToNone();
-      case 2:
-        return CurrentSongUpdate_SetToSome(
-          dco_decode_box_autoadd_song_metadata(raw[1]),
-        );
-      default:
-        throw Exception("unreachable");
-    }
+  (String, ConfigTypes) sse_decode_record_string_config_types(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_config_types(deserializer);
+    return (var_field0, var_field1);
   }
 
   @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  RemoteCandidate sse_decode_remote_candidate(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_uploader = sse_decode_opt_String(deserializer);
+    var var_durationSecs = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_thumbnailUrl = sse_decode_opt_String(deserializer);
+    var var_url = sse_decode_String(deserializer);
+    return RemoteCandidate(
+        id: var_id,
+        title: var_title,
+        uploader: var_uploader,
+        durationSecs: var_durationSecs,
+        thumbnailUrl: var_thumbnailUrl,
+        url: var_url);
   }
 
   @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  ReplayGain sse_decode_replay_gain(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_loudnessLufs = sse_decode_f_64(deserializer);
+    var var_gainDb = sse_decode_f_64(deserializer);
+    var var_peak = sse_decode_f_64(deserializer);
+    return ReplayGain(
+        loudnessLufs: var_loudnessLufs, gainDb: var_gainDb, peak: var_peak);
   }
 
   @protected
-  FadButton dco_decode_fad_button(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return FadButton(
-      name: dco_decode_String(arr[0]),
-      icon: dco_decode_opt_String(arr[1]),
-      location: dco_decode_opt_String(arr[2]),
-      callback: dco_decode_String(arr[3]),
-    );
+  SearchHit sse_decode_search_hit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_song = sse_decode_song_metadata(deserializer);
+    var var_score = sse_decode_f_32(deserializer);
+    return SearchHit(song: var_song, score: var_score);
   }
 
   @protected
-  FadConfig dco_decode_fad_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return FadConfig(
-      screens: dco_decode_opt_list_fad_screen(arr[0]),
-      popups: dco_decode_opt_list_fad_popup(arr[1]),
-      buttons: dco_decode_opt_list_fad_button(arr[2]),
-    );
+  SearchPage sse_decode_search_page(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_total = sse_decode_u_32(deserializer);
+    var var_page = sse_decode_u_32(deserializer);
+    var var_pageSize = sse_decode_u_32(deserializer);
+    var var_hits = sse_decode_list_search_hit(deserializer);
+    return SearchPage(
+        total: var_total,
+        page: var_page,
+        pageSize: var_pageSize,
+        hits: var_hits);
   }
 
   @protected
-  FadLabel dco_decode_fad_label(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return FadLabel(
-      size: dco_decode_f_64(arr[0]),
-      text: dco_decode_String(arr[1]),
-      color: dco_decode_opt_String(arr[2]),
-    );
+  SongMetadata sse_decode_song_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_title = sse_decode_String(deserializer);
+    var var_artist = sse_decode_String(deserializer);
+    var var_album = sse_decode_String(deserializer);
+    var var_duration = sse_decode_u_64(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_albumArt = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    var var_genre = sse_decode_String(deserializer);
+    return SongMetadata(
+        title: var_title,
+        artist: var_artist,
+        album: var_album,
+        duration: var_duration,
+        path: var_path,
+        albumArt: var_albumArt,
+        genre: var_genre);
   }
 
   @protected
-  FadPopup dco_decode_fad_popup(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return FadPopup(
-      title: dco_decode_opt_String(arr[0]),
-      buttons: dco_decode_opt_list_fad_button(arr[1]),
-      labels: dco_decode_opt_list_fad_label(arr[2]),
-    );
+  StreamMetadata sse_decode_stream_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_stationName = sse_decode_opt_String(deserializer);
+    var var_genre = sse_decode_opt_String(deserializer);
+    var var_bitrateKbps = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_nowPlaying = sse_decode_opt_String(deserializer);
+    var var_artist = sse_decode_opt_String(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_reconnecting = sse_decode_bool(deserializer);
+    return StreamMetadata(
+        url: var_url,
+        stationName: var_stationName,
+        genre: var_genre,
+        bitrateKbps: var_bitrateKbps,
+        nowPlaying: var_nowPlaying,
+        artist: var_artist,
+        title: var_title,
+        reconnecting: var_reconnecting);
   }
 
   @protected
-  FadScreen dco_decode_fad_screen(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return FadScreen(
-      title: dco_decode_opt_String(arr[0]),
-      buttons: dco_decode_opt_list_fad_button(arr[1]),
-      labels: dco_decode_opt_list_fad_label(arr[2]),
-    );
+  Swatch sse_decode_swatch(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_color = sse_decode_u_32(deserializer);
+    var var_population = sse_decode_u_32(deserializer);
+    var var_textColor = sse_decode_u_32(deserializer);
+    return Swatch(
+        color: var_color, population: var_population, textColor: var_textColor);
   }
 
   @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  TagEdit sse_decode_tag_edit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_changes = sse_decode_list_field_change(deserializer);
+    return TagEdit(path: var_path, changes: var_changes);
   }
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  TagEditRecord sse_decode_tag_edit_record(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_i_64(deserializer);
+    var var_timestamp = sse_decode_i_64(deserializer);
+    var var_files = sse_decode_u_32(deserializer);
+    return TagEditRecord(
+        id: var_id, timestamp: var_timestamp, files: var_files);
   }
 
   @protected
-  List<FadButton> dco_decode_list_fad_button(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_fad_button).toList();
+  TagField sse_decode_tag_field(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TagField.values[inner];
   }
 
   @protected
-  List<FadLabel> dco_decode_list_fad_label(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_fad_label).toList();
+  TitleRule sse_decode_title_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pattern = sse_decode_String(deserializer);
+    var var_replacement = sse_decode_String(deserializer);
+    return TitleRule(pattern: var_pattern, replacement: var_replacement);
   }
 
   @protected
-  List<FadPopup> dco_decode_list_fad_popup(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_fad_popup).toList();
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
   }
 
   @protected
-  List<FadScreen> dco_decode_list_fad_screen(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_fad_screen).toList();
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  Float32List dco_decode_list_prim_f_32_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Float32List;
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
   }
 
   @protected
-  Float64List dco_decode_list_prim_f_64_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Float64List;
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  ValueStoreUpdate sse_decode_value_store_update(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_musicFolder = sse_decode_opt_String(deserializer);
+    var var_currentSong = sse_decode_current_song_update(deserializer);
+    var var_pluginsEnabled = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_pluginRwDir = sse_decode_opt_String(deserializer);
+    var var_unsafeApis = sse_decode_opt_box_autoadd_bool(deserializer);
+    return ValueStoreUpdate(
+        musicFolder: var_musicFolder,
+        currentSong: var_currentSong,
+        pluginsEnabled: var_pluginsEnabled,
+        pluginRwDir: var_pluginRwDir,
+        unsafeApis: var_unsafeApis);
   }
 
   @protected
-  List<(String, PluginInode)>
-      dco_decode_list_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(
-            dco_decode_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode)
-        .toList();
+  void sse_encode_AnyhowException(
+      AnyhowException self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  List<(String, ConfigTypes)> dco_decode_list_record_string_config_types(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_record_string_config_types)
-        .toList();
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin(
+          ArcMutexPlugin self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as ArcMutexPluginImpl).frbInternalSseEncode(move: true),
+        serializer);
   }
 
   @protected
-  List<SongMetadata> dco_decode_list_song_metadata(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_song_metadata).toList();
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
+          PluginInode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as PluginInodeImpl).frbInternalSseEncode(move: true), serializer);
   }
 
   @protected
-  Map<String, ConfigTypes>? dco_decode_opt_Map_String_config_types_None(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_Map_String_config_types_None(raw);
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
+          RpcConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as RpcConfigImpl).frbInternalSseEncode(move: true), serializer);
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
+          Value self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as ValueImpl).frbInternalSseEncode(move: true), serializer);
   }
 
   @protected
-  AdiPluginMan? dco_decode_opt_box_autoadd_adi_plugin_man(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_adi_plugin_man(raw);
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+          ValueStoreUpdater self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as ValueStoreUpdaterImpl).frbInternalSseEncode(move: true),
+        serializer);
   }
 
   @protected
-  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  void
+      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
+          PluginInode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as PluginInodeImpl).frbInternalSseEncode(move: false),
+        serializer);
   }
 
   @protected
-  FadConfig? dco_decode_opt_box_autoadd_fad_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_fad_config(raw);
+  void
+      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
+          RpcConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as RpcConfigImpl).frbInternalSseEncode(move: false), serializer);
   }
 
   @protected
-  SongMetadata? dco_decode_opt_box_autoadd_song_metadata(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_song_metadata(raw);
+  void
+      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+          ValueStoreUpdater self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as ValueStoreUpdaterImpl).frbInternalSseEncode(move: false),
+        serializer);
   }
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  void
+      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
+          PluginInode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as PluginInodeImpl).frbInternalSseEncode(move: false),
+        serializer);
   }
 
   @protected
-  List<String>? dco_decode_opt_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_String(raw);
+  void
+      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
+          RpcConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as RpcConfigImpl).frbInternalSseEncode(move: false), serializer);
   }
 
   @protected
-  List<FadButton>? dco_decode_opt_list_fad_button(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_fad_button(raw);
+  void
+      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+          ValueStoreUpdater self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as ValueStoreUpdaterImpl).frbInternalSseEncode(move: false),
+        serializer);
   }
 
   @protected
-  List<FadLabel>? dco_decode_opt_list_fad_label(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_fad_label(raw);
+  void sse_encode_I128(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.toString(), serializer);
   }
 
   @protected
-  List<FadPopup>? dco_decode_opt_list_fad_popup(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_fad_popup(raw);
+  void
+      sse_encode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode_None(
+          Map<String, PluginInode> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
+        self.entries.map((e) => (e.key, e.value)).toList(), serializer);
   }
 
   @protected
-  List<FadScreen>? dco_decode_opt_list_fad_screen(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_fad_screen(raw);
+  void sse_encode_Map_String_config_types_None(
+      Map<String, ConfigTypes> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_config_types(
+        self.entries.map((e) => (e.key, e.value)).toList(), serializer);
   }
 
   @protected
-  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin(
+          ArcMutexPlugin self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as ArcMutexPluginImpl).frbInternalSseEncode(move: null),
+        serializer);
   }
 
   @protected
-  PlayerState dco_decode_player_state(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1)
-      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return PlayerState(
-      initialized: dco_decode_bool(arr[0]),
-    );
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
+          PluginInode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as PluginInodeImpl).frbInternalSseEncode(move: null), serializer);
   }
 
   @protected
-  (
-    String,
-    PluginInode
-  ) dco_decode_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_String(arr[0]),
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
-          arr[1]),
-    );
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
+          RpcConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as RpcConfigImpl).frbInternalSseEncode(move: null), serializer);
   }
 
   @protected
-  (String, ConfigTypes) dco_decode_record_string_config_types(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_String(arr[0]),
-      dco_decode_config_types(arr[1]),
-    );
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
+          Value self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as ValueImpl).frbInternalSseEncode(move: null), serializer);
   }
 
   @protected
-  SongMetadata dco_decode_song_metadata(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return SongMetadata(
-      title: dco_decode_String(arr[0]),
-      artist: dco_decode_String(arr[1]),
-      album: dco_decode_String(arr[2]),
-      duration: dco_decode_u_64(arr[3]),
-      path: dco_decode_String(arr[4]),
-      albumArt: dco_decode_opt_list_prim_u_8_strict(arr[5]),
-      genre: dco_decode_String(arr[6]),
-    );
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+          ValueStoreUpdater self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+        (self as ValueStoreUpdaterImpl).frbInternalSseEncode(move: null),
+        serializer);
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_StreamSink_collection_download_Sse(
+      RustStreamSink<CollectionDownload> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_collection_download,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
   }
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_StreamSink_download_job_Sse(
+      RustStreamSink<DownloadJob> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_download_job,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_StreamSink_episode_download_Sse(
+      RustStreamSink<EpisodeDownload> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_episode_download,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_StreamSink_identify_progress_Sse(
+      RustStreamSink<IdentifyProgress> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_identify_progress,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_StreamSink_lyric_event_Sse(
+      RustStreamSink<LyricEvent> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_lyric_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
   }
 
   @protected
-  ValueStoreUpdate dco_decode_value_store_update(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return ValueStoreUpdate(
-      musicFolder: dco_decode_opt_String(arr[0]),
-      currentSong: dco_decode_current_song_update(arr[1]),
-      pluginsEnabled: dco_decode_opt_box_autoadd_bool(arr[2]),
-      pluginRwDir: dco_decode_opt_String(arr[3]),
-      unsafeApis: dco_decode_opt_box_autoadd_bool(arr[4]),
-    );
+  void sse_encode_StreamSink_song_metadata_Sse(
+      RustStreamSink<SongMetadata> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_song_metadata,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_StreamSink_stream_metadata_Sse(
+      RustStreamSink<StreamMetadata> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_stream_metadata,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
   }
 
   @protected
-  ArcMutexPlugin
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin(
-          SseDeserializer deserializer) {
+  void sse_encode_String(String self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ArcMutexPluginImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
   @protected
-  PluginInode
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
-          SseDeserializer deserializer) {
+  void sse_encode_U128(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return PluginInodeImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_String(self.toString(), serializer);
   }
 
   @protected
-  RpcConfig
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
-          SseDeserializer deserializer) {
+  void sse_encode_acoust_id_candidate(
+      AcoustIdCandidate self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return RpcConfigImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_f_64(self.score, serializer);
+    sse_encode_opt_String(self.recordingId, serializer);
+    sse_encode_song_metadata(self.metadata, serializer);
   }
 
   @protected
-  Value
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          SseDeserializer deserializer) {
+  void sse_encode_adi_plugin_man(AdiPluginMan self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ValueImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode_None(
+        self.pluginMeta, serializer);
   }
 
   @protected
-  ValueStoreUpdater
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
-          SseDeserializer deserializer) {
+  void sse_encode_art_write_result(
+      ArtWriteResult self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ValueStoreUpdaterImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_String(self.path, serializer);
+    sse_encode_opt_String(self.error, serializer);
   }
 
   @protected
-  PluginInode
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
-          SseDeserializer deserializer) {
+  void sse_encode_book_progress(BookProgress self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return PluginInodeImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_String(self.path, serializer);
+    sse_encode_f_64(self.positionSecs, serializer);
+    sse_encode_f_64(self.durationSecs, serializer);
+    sse_encode_bool(self.finished, serializer);
+    sse_encode_i_64(self.updated, serializer);
   }
 
   @protected
-  RpcConfig
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
-          SseDeserializer deserializer) {
+  void sse_encode_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return RpcConfigImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    serializer.buffer.putUint8(self ? 1 : 0);
   }
 
   @protected
-  ValueStoreUpdater
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
-          SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_acoust_id_candidate(
+      AcoustIdCandidate self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ValueStoreUpdaterImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_acoust_id_candidate(self, serializer);
   }
 
   @protected
-  PluginInode
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
-          SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_adi_plugin_man(
+      AdiPluginMan self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return PluginInodeImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_adi_plugin_man(self, serializer);
   }
 
   @protected
-  RpcConfig
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
-          SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_book_progress(
+      BookProgress self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return RpcConfigImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_book_progress(self, serializer);
   }
 
   @protected
-  ValueStoreUpdater
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
-          SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ValueStoreUpdaterImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_bool(self, serializer);
   }
 
   @protected
-  BigInt sse_decode_I128(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_chapter(Chapter self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return BigInt.parse(inner);
+    sse_encode_chapter(self, serializer);
   }
 
   @protected
-  Map<String, PluginInode>
-      sse_decode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode_None(
-          SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_collection_options(
+      CollectionOptions self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner =
-        sse_decode_list_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
-            deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+    sse_encode_collection_options(self, serializer);
   }
 
   @protected
-  Map<String, ConfigTypes> sse_decode_Map_String_config_types_None(
-      SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_config_types(
+      ConfigTypes self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_string_config_types(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+    sse_encode_config_types(self, serializer);
   }
 
   @protected
-  ArcMutexPlugin
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin(
-          SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_download_job(
+      DownloadJob self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ArcMutexPluginImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_download_job(self, serializer);
   }
 
   @protected
-  PluginInode
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
-          SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_enriched_metadata(
+      EnrichedMetadata self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return PluginInodeImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_enriched_metadata(self, serializer);
   }
 
   @protected
-  RpcConfig
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
-          SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return RpcConfigImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_f_32(self, serializer);
   }
 
   @protected
-  Value
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ValueImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_f_64(self, serializer);
   }
 
   @protected
-  ValueStoreUpdater
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
-          SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_fad_button(
+      FadButton self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return ValueStoreUpdaterImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_fad_button(self, serializer);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_fad_config(
+      FadConfig self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_fad_config(self, serializer);
   }
 
   @protected
-  BigInt sse_decode_U128(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_found_lyrics(
+      FoundLyrics self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return BigInt.parse(inner);
+    sse_encode_found_lyrics(self, serializer);
   }
 
   @protected
-  AdiPluginMan sse_decode_adi_plugin_man(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_pluginMeta =
-        sse_decode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode_None(
-            deserializer);
-    return AdiPluginMan(pluginMeta: var_pluginMeta);
+    sse_encode_i_32(self, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_i_64(
+      PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_i_64(self, serializer);
   }
 
   @protected
-  AdiPluginMan sse_decode_box_autoadd_adi_plugin_man(
-      SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_lrc_document(
+      LrcDocument self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_adi_plugin_man(deserializer));
+    sse_encode_lrc_document(self, serializer);
   }
 
   @protected
-  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_lyric_position(
+      LyricPosition self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_bool(deserializer));
+    sse_encode_lyric_position(self, serializer);
   }
 
   @protected
-  ConfigTypes sse_decode_box_autoadd_config_types(
-      SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_palette(Palette self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_config_types(deserializer));
+    sse_encode_palette(self, serializer);
   }
 
   @protected
-  FadButton sse_decode_box_autoadd_fad_button(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_pipeline_config(
+      PipelineConfig self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_fad_button(deserializer));
+    sse_encode_pipeline_config(self, serializer);
   }
 
   @protected
-  FadConfig sse_decode_box_autoadd_fad_config(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_playlist_format(
+      PlaylistFormat self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_fad_config(deserializer));
+    sse_encode_playlist_format(self, serializer);
   }
 
   @protected
-  SongMetadata sse_decode_box_autoadd_song_metadata(
-      SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_playlist_sort_key(
+      PlaylistSortKey self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_song_metadata(deserializer));
+    sse_encode_playlist_sort_key(self, serializer);
   }
 
   @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_radio_station(
+      RadioStation self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_32(deserializer));
+    sse_encode_radio_station(self, serializer);
   }
 
   @protected
-  ValueStoreUpdate sse_decode_box_autoadd_value_store_update(
-      SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_replay_gain(
+      ReplayGain self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_value_store_update(deserializer));
+    sse_encode_replay_gain(self, serializer);
   }
 
   @protected
-  ConfigTypes sse_decode_config_types(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_song_metadata(
+      SongMetadata self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_song_metadata(self, serializer);
+  }
 
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_field0 = sse_decode_String(deserializer);
-        return ConfigTypes_String(var_field0);
-      case 1:
-        var var_field0 = sse_decode_bool(deserializer);
-        return ConfigTypes_Bool(var_field0);
-      case 2:
-        var var_field0 = sse_decode_i_32(deserializer);
-        return ConfigTypes_Int(var_field0);
-      case 3:
-        var var_field0 = sse_decode_u_32(deserializer);
-        return ConfigTypes_UInt(var_field0);
-      case 4:
-        var var_field0 = sse_decode_I128(deserializer);
-        return ConfigTypes_BigInt(var_field0);
-      case 5:
-        var var_field0 = sse_decode_U128(deserializer);
-        return ConfigTypes_BigUInt(var_field0);
-      case 6:
-        var var_field0 = sse_decode_f_64(deserializer);
-        return ConfigTypes_Float(var_field0);
-      default:
-        throw UnimplementedError('');
-    }
+  @protected
+  void sse_encode_box_autoadd_stream_metadata(
+      StreamMetadata self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_stream_metadata(self, serializer);
   }
 
   @protected
-  CurrentSongUpdate sse_decode_current_song_update(
-      SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_swatch(Swatch self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_swatch(self, serializer);
+  }
 
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        return CurrentSongUpdate_NoChange();
-      case 1:
-        return CurrentSongUpdate_SetToNone();
-      case 2:
-        var var_field0 = sse_decode_box_autoadd_song_metadata(deserializer);
-        return CurrentSongUpdate_SetToSome(var_field0);
-      default:
-        throw UnimplementedError('');
-    }
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
   }
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_value_store_update(
+      ValueStoreUpdate self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
+    sse_encode_value_store_update(self, serializer);
   }
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
+  void sse_encode_chapter(Chapter self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
+    sse_encode_u_32(self.index, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_f_64(self.startSecs, serializer);
+    sse_encode_f_64(self.endSecs, serializer);
   }
 
   @protected
-  FadButton sse_decode_fad_button(SseDeserializer deserializer) {
+  void sse_encode_collection_download(
+      CollectionDownload self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_name = sse_decode_String(deserializer);
-    var var_icon = sse_decode_opt_String(deserializer);
-    var var_location = sse_decode_opt_String(deserializer);
-    var var_callback = sse_decode_String(deserializer);
-    return FadButton(
-        name: var_name,
-        icon: var_icon,
-        location: var_location,
-        callback: var_callback);
+    sse_encode_u_64(self.id, serializer);
+    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.folder, serializer);
+    sse_encode_list_collection_track(self.tracks, serializer);
+    sse_encode_opt_String(self.playlist, serializer);
+    sse_encode_bool(self.finished, serializer);
   }
 
   @protected
-  FadConfig sse_decode_fad_config(SseDeserializer deserializer) {
+  void sse_encode_collection_options(
+      CollectionOptions self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_screens = sse_decode_opt_list_fad_screen(deserializer);
-    var var_popups = sse_decode_opt_list_fad_popup(deserializer);
-    var var_buttons = sse_decode_opt_list_fad_button(deserializer);
-    return FadConfig(
-        screens: var_screens, popups: var_popups, buttons: var_buttons);
+    sse_encode_opt_String(self.flags, serializer);
+    sse_encode_opt_String(self.folder, serializer);
+    sse_encode_bool(self.createPlaylist, serializer);
+    sse_encode_opt_String(self.playlistName, serializer);
+    sse_encode_bool(self.postProcess, serializer);
   }
 
   @protected
-  FadLabel sse_decode_fad_label(SseDeserializer deserializer) {
+  void sse_encode_collection_track(
+      CollectionTrack self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_size = sse_decode_f_64(deserializer);
-    var var_text = sse_decode_String(deserializer);
-    var var_color = sse_decode_opt_String(deserializer);
-    return FadLabel(size: var_size, text: var_text, color: var_color);
+    sse_encode_u_32(self.position, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_u_64(self.jobId, serializer);
+    sse_encode_opt_String(self.path, serializer);
+    sse_encode_opt_String(self.error, serializer);
   }
 
   @protected
-  FadPopup sse_decode_fad_popup(SseDeserializer deserializer) {
+  void sse_encode_config_types(ConfigTypes self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_title = sse_decode_opt_String(deserializer);
-    var var_buttons = sse_decode_opt_list_fad_button(deserializer);
-    var var_labels = sse_decode_opt_list_fad_label(deserializer);
-    return FadPopup(title: var_title, buttons: var_buttons, labels: var_labels);
+    switch (self) {
+      case ConfigTypes_String(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(field0, serializer);
+      case ConfigTypes_Bool(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_bool(field0, serializer);
+      case ConfigTypes_Int(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_i_32(field0, serializer);
+      case ConfigTypes_UInt(field0: final field0):
+        sse_encode_i_32(3, serializer);
+        sse_encode_u_32(field0, serializer);
+      case ConfigTypes_BigInt(field0: final field0):
+        sse_encode_i_32(4, serializer);
+        sse_encode_I128(field0, serializer);
+      case ConfigTypes_BigUInt(field0: final field0):
+        sse_encode_i_32(5, serializer);
+        sse_encode_U128(field0, serializer);
+      case ConfigTypes_Float(field0: final field0):
+        sse_encode_i_32(6, serializer);
+        sse_encode_f_64(field0, serializer);
+    }
   }
 
   @protected
-  FadScreen sse_decode_fad_screen(SseDeserializer deserializer) {
+  void sse_encode_cue_sheet(CueSheet self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_title = sse_decode_opt_String(deserializer);
-    var var_buttons = sse_decode_opt_list_fad_button(deserializer);
-    var var_labels = sse_decode_opt_list_fad_label(deserializer);
-    return FadScreen(
-        title: var_title, buttons: var_buttons, labels: var_labels);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.performer, serializer);
+    sse_encode_opt_String(self.genre, serializer);
+    sse_encode_opt_String(self.date, serializer);
+    sse_encode_list_cue_track(self.tracks, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_cue_track(CueTrack self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_u_32(self.number, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.performer, serializer);
+    sse_encode_String(self.file, serializer);
+    sse_encode_f_64(self.startSecs, serializer);
   }
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+  void sse_encode_current_song_update(
+      CurrentSongUpdate self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
+    switch (self) {
+      case CurrentSongUpdate_NoChange():
+        sse_encode_i_32(0, serializer);
+      case CurrentSongUpdate_SetToNone():
+        sse_encode_i_32(1, serializer);
+      case CurrentSongUpdate_SetToSome(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_box_autoadd_song_metadata(field0, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<FadButton> sse_decode_list_fad_button(SseDeserializer deserializer) {
+  void sse_encode_download_job(DownloadJob self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <FadButton>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_fad_button(deserializer));
-    }
-    return ans_;
+    sse_encode_u_64(self.id, serializer);
+    sse_encode_String(self.query, serializer);
+    sse_encode_download_state(self.state, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.progress, serializer);
+    sse_encode_u_64(self.downloadedBytes, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.totalBytes, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.speed, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.etaSecs, serializer);
+    sse_encode_String(self.tempDir, serializer);
+    sse_encode_list_String(self.files, serializer);
+    sse_encode_opt_String(self.error, serializer);
   }
 
   @protected
-  List<FadLabel> sse_decode_list_fad_label(SseDeserializer deserializer) {
+  void sse_encode_download_state(DownloadState self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <FadLabel>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_fad_label(deserializer));
-    }
-    return ans_;
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  List<FadPopup> sse_decode_list_fad_popup(SseDeserializer deserializer) {
+  void sse_encode_duplicate_file(DuplicateFile self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <FadPopup>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_fad_popup(deserializer));
-    }
-    return ans_;
+    sse_encode_String(self.path, serializer);
+    sse_encode_String(self.format, serializer);
+    sse_encode_u_64(self.sizeBytes, serializer);
+    sse_encode_f_64(self.duration, serializer);
+    sse_encode_u_32(self.bitrateKbps, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.sampleRate, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.bitsPerSample, serializer);
+    sse_encode_bool(self.lossless, serializer);
   }
 
   @protected
-  List<FadScreen> sse_decode_list_fad_screen(SseDeserializer deserializer) {
+  void sse_encode_duplicate_group(
+      DuplicateGroup self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <FadScreen>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_fad_screen(deserializer));
-    }
-    return ans_;
+    sse_encode_list_duplicate_file(self.files, serializer);
+    sse_encode_f_64(self.similarity, serializer);
   }
 
   @protected
-  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer) {
+  void sse_encode_enriched_metadata(
+      EnrichedMetadata self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getFloat32List(len_);
+    sse_encode_song_metadata(self.metadata, serializer);
+    sse_encode_opt_String(self.recordingId, serializer);
+    sse_encode_opt_String(self.releaseId, serializer);
+    sse_encode_opt_String(self.albumArtist, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.trackNumber, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.totalTracks, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.discNumber, serializer);
+    sse_encode_opt_String(self.date, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.year, serializer);
+    sse_encode_list_String(self.genres, serializer);
+    sse_encode_opt_String(self.isrc, serializer);
   }
 
   @protected
-  Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer) {
+  void sse_encode_episode_download(
+      EpisodeDownload self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getFloat64List(len_);
+    sse_encode_u_64(self.podcastId, serializer);
+    sse_encode_String(self.guid, serializer);
+    sse_encode_u_64(self.downloadedBytes, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.totalBytes, serializer);
+    sse_encode_bool(self.finished, serializer);
+    sse_encode_opt_String(self.path, serializer);
+    sse_encode_opt_String(self.error, serializer);
   }
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+  void sse_encode_f_32(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    serializer.buffer.putFloat32(self);
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    serializer.buffer.putFloat64(self);
   }
 
   @protected
-  List<(String, PluginInode)>
-      sse_decode_list_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
-          SseDeserializer deserializer) {
+  void sse_encode_fad_button(FadButton self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, PluginInode)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
-              deserializer));
-    }
-    return ans_;
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.icon, serializer);
+    sse_encode_opt_String(self.location, serializer);
+    sse_encode_String(self.callback, serializer);
   }
 
   @protected
-  List<(String, ConfigTypes)> sse_decode_list_record_string_config_types(
-      SseDeserializer deserializer) {
+  void sse_encode_fad_config(FadConfig self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_list_fad_screen(self.screens, serializer);
+    sse_encode_opt_list_fad_popup(self.popups, serializer);
+    sse_encode_opt_list_fad_button(self.buttons, serializer);
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, ConfigTypes)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_config_types(deserializer));
-    }
-    return ans_;
+  @protected
+  void sse_encode_fad_label(FadLabel self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.size, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_opt_String(self.color, serializer);
   }
 
   @protected
-  List<SongMetadata> sse_decode_list_song_metadata(
-      SseDeserializer deserializer) {
+  void sse_encode_fad_popup(FadPopup self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_list_fad_button(self.buttons, serializer);
+    sse_encode_opt_list_fad_label(self.labels, serializer);
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SongMetadata>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_song_metadata(deserializer));
-    }
-    return ans_;
+  @protected
+  void sse_encode_fad_screen(FadScreen self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_list_fad_button(self.buttons, serializer);
+    sse_encode_opt_list_fad_label(self.labels, serializer);
   }
 
   @protected
-  Map<String, ConfigTypes>? sse_decode_opt_Map_String_config_types_None(
-      SseDeserializer deserializer) {
+  void sse_encode_feed_episode(FeedEpisode self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.guid, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.published, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.durationSecs, serializer);
+    sse_encode_opt_String(self.enclosureUrl, serializer);
+    sse_encode_opt_String(self.enclosureType, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.enclosureLength, serializer);
+    sse_encode_opt_String(self.imageUrl, serializer);
+    sse_encode_list_podcast_chapter(self.chapters, serializer);
+    sse_encode_opt_String(self.chaptersUrl, serializer);
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_Map_String_config_types_None(deserializer));
-    } else {
-      return null;
-    }
+  @protected
+  void sse_encode_field_change(FieldChange self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_tag_field(self.field, serializer);
+    sse_encode_opt_String(self.value, serializer);
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_found_lyrics(FoundLyrics self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_lyrics_source(self.source, serializer);
+    sse_encode_String(self.content, serializer);
+    sse_encode_lrc_document(self.document, serializer);
+    sse_encode_opt_String(self.cachePath, serializer);
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
   }
 
   @protected
-  AdiPluginMan? sse_decode_opt_box_autoadd_adi_plugin_man(
-      SseDeserializer deserializer) {
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_adi_plugin_man(deserializer));
-    } else {
-      return null;
-    }
+  @protected
+  void sse_encode_identify_progress(
+      IdentifyProgress self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.batchId, serializer);
+    sse_encode_u_32(self.completed, serializer);
+    sse_encode_u_32(self.total, serializer);
+    sse_encode_identify_result(self.result, serializer);
   }
 
   @protected
-  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+  void sse_encode_identify_result(
+      IdentifyResult self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_list_acoust_id_candidate(self.candidates, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_bool(deserializer));
-    } else {
-      return null;
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
     }
   }
 
   @protected
-  FadConfig? sse_decode_opt_box_autoadd_fad_config(
-      SseDeserializer deserializer) {
+  void sse_encode_list_acoust_id_candidate(
+      List<AcoustIdCandidate> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_fad_config(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_acoust_id_candidate(item, serializer);
     }
   }
 
   @protected
-  SongMetadata? sse_decode_opt_box_autoadd_song_metadata(
-      SseDeserializer deserializer) {
+  void sse_encode_list_art_write_result(
+      List<ArtWriteResult> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_song_metadata(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_art_write_result(item, serializer);
     }
   }
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+  void sse_encode_list_book_progress(
+      List<BookProgress> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_32(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_book_progress(item, serializer);
     }
   }
 
   @protected
-  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer) {
+  void sse_encode_list_chapter(List<Chapter> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_String(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_chapter(item, serializer);
     }
   }
 
   @protected
-  List<FadButton>? sse_decode_opt_list_fad_button(
-      SseDeserializer deserializer) {
+  void sse_encode_list_collection_download(
+      List<CollectionDownload> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_fad_button(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_collection_download(item, serializer);
     }
   }
 
   @protected
-  List<FadLabel>? sse_decode_opt_list_fad_label(SseDeserializer deserializer) {
+  void sse_encode_list_collection_track(
+      List<CollectionTrack> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_fad_label(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_collection_track(item, serializer);
     }
   }
 
   @protected
-  List<FadPopup>? sse_decode_opt_list_fad_popup(SseDeserializer deserializer) {
+  void sse_encode_list_cue_track(
+      List<CueTrack> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_fad_popup(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_cue_track(item, serializer);
     }
   }
 
   @protected
-  List<FadScreen>? sse_decode_opt_list_fad_screen(
-      SseDeserializer deserializer) {
+  void sse_encode_list_download_job(
+      List<DownloadJob> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_fad_screen(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_download_job(item, serializer);
     }
   }
 
   @protected
-  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_list_duplicate_file(
+      List<DuplicateFile> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_prim_u_8_strict(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_duplicate_file(item, serializer);
     }
   }
 
   @protected
-  PlayerState sse_decode_player_state(SseDeserializer deserializer) {
+  void sse_encode_list_duplicate_group(
+      List<DuplicateGroup> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_initialized = sse_decode_bool(deserializer);
-    return PlayerState(initialized: var_initialized);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_duplicate_group(item, serializer);
+    }
   }
 
   @protected
-  (
-    String,
-    PluginInode
-  ) sse_decode_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
-      SseDeserializer deserializer) {
+  void sse_encode_list_fad_button(
+      List<FadButton> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
-            deserializer);
-    return (var_field0, var_field1);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_fad_button(item, serializer);
+    }
   }
 
   @protected
-  (String, ConfigTypes) sse_decode_record_string_config_types(
-      SseDeserializer deserializer) {
+  void sse_encode_list_fad_label(
+      List<FadLabel> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_config_types(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_fad_label(item, serializer);
+    }
   }
 
   @protected
-  SongMetadata sse_decode_song_metadata(SseDeserializer deserializer) {
+  void sse_encode_list_fad_popup(
+      List<FadPopup> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_title = sse_decode_String(deserializer);
-    var var_artist = sse_decode_String(deserializer);
-    var var_album = sse_decode_String(deserializer);
-    var var_duration = sse_decode_u_64(deserializer);
-    var var_path = sse_decode_String(deserializer);
-    var var_albumArt = sse_decode_opt_list_prim_u_8_strict(deserializer);
-    var var_genre = sse_decode_String(deserializer);
-    return SongMetadata(
-        title: var_title,
-        artist: var_artist,
-        album: var_album,
-        duration: var_duration,
-        path: var_path,
-        albumArt: var_albumArt,
-        genre: var_genre);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_fad_popup(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_list_fad_screen(
+      List<FadScreen> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_fad_screen(item, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+  void sse_encode_list_field_change(
+      List<FieldChange> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_field_change(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_list_lrc_line(List<LrcLine> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_lrc_line(item, serializer);
+    }
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_list_lrc_word(List<LrcWord> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_lrc_word(item, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  void sse_encode_list_lrclib_lyrics(
+      List<LrclibLyrics> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_lrclib_lyrics(item, serializer);
+    }
   }
 
   @protected
-  ValueStoreUpdate sse_decode_value_store_update(SseDeserializer deserializer) {
+  void sse_encode_list_lyric_match_line(
+      List<LyricMatchLine> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_musicFolder = sse_decode_opt_String(deserializer);
-    var var_currentSong = sse_decode_current_song_update(deserializer);
-    var var_pluginsEnabled = sse_decode_opt_box_autoadd_bool(deserializer);
-    var var_pluginRwDir = sse_decode_opt_String(deserializer);
-    var var_unsafeApis = sse_decode_opt_box_autoadd_bool(deserializer);
-    return ValueStoreUpdate(
-        musicFolder: var_musicFolder,
-        currentSong: var_currentSong,
-        pluginsEnabled: var_pluginsEnabled,
-        pluginRwDir: var_pluginRwDir,
-        unsafeApis: var_unsafeApis);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_lyric_match_line(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer) {
+  void sse_encode_list_lyric_search_result(
+      List<LyricSearchResult> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_lyric_search_result(item, serializer);
+    }
   }
 
   @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin(
-          ArcMutexPlugin self, SseSerializer serializer) {
+  void sse_encode_list_matched_entry(
+      List<MatchedEntry> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as ArcMutexPluginImpl).frbInternalSseEncode(move: true),
-        serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_matched_entry(item, serializer);
+    }
   }
 
   @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
-          PluginInode self, SseSerializer serializer) {
+  void sse_encode_list_missing_entry(
+      List<MissingEntry> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as PluginInodeImpl).frbInternalSseEncode(move: true), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_missing_entry(item, serializer);
+    }
   }
 
   @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
-          RpcConfig self, SseSerializer serializer) {
+  void sse_encode_list_pipeline_result(
+      List<PipelineResult> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as RpcConfigImpl).frbInternalSseEncode(move: true), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_pipeline_result(item, serializer);
+    }
   }
 
   @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          Value self, SseSerializer serializer) {
+  void sse_encode_list_planned_move(
+      List<PlannedMove> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as ValueImpl).frbInternalSseEncode(move: true), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_planned_move(item, serializer);
+    }
   }
 
   @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
-          ValueStoreUpdater self, SseSerializer serializer) {
+  void sse_encode_list_playlist_entry(
+      List<PlaylistEntry> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as ValueStoreUpdaterImpl).frbInternalSseEncode(move: true),
-        serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_playlist_entry(item, serializer);
+    }
   }
 
   @protected
-  void
-      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
-          PluginInode self, SseSerializer serializer) {
+  void sse_encode_list_playlist_info(
+      List<PlaylistInfo> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as PluginInodeImpl).frbInternalSseEncode(move: false),
-        serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_playlist_info(item, serializer);
+    }
   }
 
   @protected
-  void
-      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
-          RpcConfig self, SseSerializer serializer) {
+  void sse_encode_list_podcast(List<Podcast> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as RpcConfigImpl).frbInternalSseEncode(move: false), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_podcast(item, serializer);
+    }
   }
 
   @protected
-  void
-      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
-          ValueStoreUpdater self, SseSerializer serializer) {
+  void sse_encode_list_podcast_chapter(
+      List<PodcastChapter> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as ValueStoreUpdaterImpl).frbInternalSseEncode(move: false),
-        serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_podcast_chapter(item, serializer);
+    }
   }
 
   @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
-          PluginInode self, SseSerializer serializer) {
+  void sse_encode_list_podcast_episode(
+      List<PodcastEpisode> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as PluginInodeImpl).frbInternalSseEncode(move: false),
-        serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_podcast_episode(item, serializer);
+    }
   }
 
   @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
-          RpcConfig self, SseSerializer serializer) {
+  void sse_encode_list_podcast_refresh(
+      List<PodcastRefresh> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as RpcConfigImpl).frbInternalSseEncode(move: false), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_podcast_refresh(item, serializer);
+    }
   }
 
   @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
-          ValueStoreUpdater self, SseSerializer serializer) {
+  void sse_encode_list_prim_f_32_strict(
+      Float32List self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as ValueStoreUpdaterImpl).frbInternalSseEncode(move: false),
-        serializer);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putFloat32List(self);
   }
 
   @protected
-  void sse_encode_I128(BigInt self, SseSerializer serializer) {
+  void sse_encode_list_prim_f_64_strict(
+      Float64List self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.toString(), serializer);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putFloat64List(self);
   }
 
   @protected
-  void
-      sse_encode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode_None(
-          Map<String, PluginInode> self, SseSerializer serializer) {
+  void sse_encode_list_prim_u_32_loose(
+      List<int> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
-        self.entries.map((e) => (e.key, e.value)).toList(), serializer);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer
+        .putUint32List(self is Uint32List ? self : Uint32List.fromList(self));
   }
 
   @protected
-  void sse_encode_Map_String_config_types_None(
-      Map<String, ConfigTypes> self, SseSerializer serializer) {
+  void sse_encode_list_prim_u_8_loose(
+      List<int> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_string_config_types(
-        self.entries.map((e) => (e.key, e.value)).toList(), serializer);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer
+        .putUint8List(self is Uint8List ? self : Uint8List.fromList(self));
   }
 
   @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin(
-          ArcMutexPlugin self, SseSerializer serializer) {
+  void sse_encode_list_prim_u_8_strict(
+      Uint8List self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as ArcMutexPluginImpl).frbInternalSseEncode(move: null),
-        serializer);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
-          PluginInode self, SseSerializer serializer) {
+  void sse_encode_list_radio_station(
+      List<RadioStation> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as PluginInodeImpl).frbInternalSseEncode(move: null), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_radio_station(item, serializer);
+    }
   }
 
   @protected
   void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
-          RpcConfig self, SseSerializer serializer) {
+      sse_encode_list_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
+          List<(String, PluginInode)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as RpcConfigImpl).frbInternalSseEncode(move: null), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
+          item, serializer);
+    }
   }
 
   @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          Value self, SseSerializer serializer) {
+  void sse_encode_list_record_string_config_types(
+      List<(String, ConfigTypes)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as ValueImpl).frbInternalSseEncode(move: null), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_config_types(item, serializer);
+    }
   }
 
   @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
-          ValueStoreUpdater self, SseSerializer serializer) {
+  void sse_encode_list_remote_candidate(
+      List<RemoteCandidate> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as ValueStoreUpdaterImpl).frbInternalSseEncode(move: null),
-        serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_remote_candidate(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_list_search_hit(
+      List<SearchHit> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_search_hit(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_U128(BigInt self, SseSerializer serializer) {
+  void sse_encode_list_song_metadata(
+      List<SongMetadata> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.toString(), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_song_metadata(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_adi_plugin_man(AdiPluginMan self, SseSerializer serializer) {
+  void sse_encode_list_swatch(List<Swatch> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode_None(
-        self.pluginMeta, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_swatch(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_list_tag_edit(List<TagEdit> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_tag_edit(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_adi_plugin_man(
-      AdiPluginMan self, SseSerializer serializer) {
+  void sse_encode_list_tag_edit_record(
+      List<TagEditRecord> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_adi_plugin_man(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_tag_edit_record(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+  void sse_encode_list_title_rule(
+      List<TitleRule> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_title_rule(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_config_types(
-      ConfigTypes self, SseSerializer serializer) {
+  void sse_encode_lrc_document(LrcDocument self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_config_types(self, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.artist, serializer);
+    sse_encode_opt_String(self.album, serializer);
+    sse_encode_opt_String(self.genre, serializer);
+    sse_encode_opt_String(self.songPath, serializer);
+    sse_encode_i_64(self.offsetMs, serializer);
+    sse_encode_i_64(self.userOffsetMs, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.lengthMs, serializer);
+    sse_encode_bool(self.synced, serializer);
+    sse_encode_list_lrc_line(self.lines, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_fad_button(
-      FadButton self, SseSerializer serializer) {
+  void sse_encode_lrc_line(LrcLine self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_fad_button(self, serializer);
+    sse_encode_u_64(self.startMs, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_list_lrc_word(self.words, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_fad_config(
-      FadConfig self, SseSerializer serializer) {
+  void sse_encode_lrc_word(LrcWord self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_fad_config(self, serializer);
+    sse_encode_u_64(self.startMs, serializer);
+    sse_encode_String(self.text, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_song_metadata(
-      SongMetadata self, SseSerializer serializer) {
+  void sse_encode_lrclib_lyrics(LrclibLyrics self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_song_metadata(self, serializer);
+    sse_encode_i_64(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.artist, serializer);
+    sse_encode_String(self.album, serializer);
+    sse_encode_f_64(self.duration, serializer);
+    sse_encode_bool(self.instrumental, serializer);
+    sse_encode_opt_String(self.plainLyrics, serializer);
+    sse_encode_opt_String(self.syncedLyrics, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+  void sse_encode_lyric_event(LyricEvent self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self, serializer);
+    sse_encode_u_64(self.positionMs, serializer);
+    sse_encode_opt_box_autoadd_lyric_position(self.current, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_value_store_update(
-      ValueStoreUpdate self, SseSerializer serializer) {
+  void sse_encode_lyric_match_line(
+      LyricMatchLine self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_value_store_update(self, serializer);
+    sse_encode_u_32(self.line, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.timestampMs, serializer);
+    sse_encode_String(self.text, serializer);
   }
 
   @protected
-  void sse_encode_config_types(ConfigTypes self, SseSerializer serializer) {
+  void sse_encode_lyric_position(LyricPosition self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case ConfigTypes_String(field0: final field0):
-        sse_encode_i_32(0, serializer);
-        sse_encode_String(field0, serializer);
-      case ConfigTypes_Bool(field0: final field0):
-        sse_encode_i_32(1, serializer);
-        sse_encode_bool(field0, serializer);
-      case ConfigTypes_Int(field0: final field0):
-        sse_encode_i_32(2, serializer);
-        sse_encode_i_32(field0, serializer);
-      case ConfigTypes_UInt(field0: final field0):
-        sse_encode_i_32(3, serializer);
-        sse_encode_u_32(field0, serializer);
-      case ConfigTypes_BigInt(field0: final field0):
-        sse_encode_i_32(4, serializer);
-        sse_encode_I128(field0, serializer);
-      case ConfigTypes_BigUInt(field0: final field0):
-        sse_encode_i_32(5, serializer);
-        sse_encode_U128(field0, serializer);
-      case ConfigTypes_Float(field0: final field0):
-        sse_encode_i_32(6, serializer);
-        sse_encode_f_64(field0, serializer);
-    }
+    sse_encode_u_32(self.lineIndex, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.wordIndex, serializer);
+    sse_encode_u_64(self.lineStartMs, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.lineEndMs, serializer);
+    sse_encode_String(self.text, serializer);
   }
 
   @protected
-  void sse_encode_current_song_update(
-      CurrentSongUpdate self, SseSerializer serializer) {
+  void sse_encode_lyric_search_result(
+      LyricSearchResult self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case CurrentSongUpdate_NoChange():
-        sse_encode_i_32(0, serializer);
-      case CurrentSongUpdate_SetToNone():
-        sse_encode_i_32(1, serializer);
-      case CurrentSongUpdate_SetToSome(field0: final field0):
-        sse_encode_i_32(2, serializer);
-        sse_encode_box_autoadd_song_metadata(field0, serializer);
-    }
+    sse_encode_song_metadata(self.song, serializer);
+    sse_encode_f_32(self.score, serializer);
+    sse_encode_list_lyric_match_line(self.lines, serializer);
   }
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
+  void sse_encode_lyrics_source(LyricsSource self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
+  void sse_encode_matched_entry(MatchedEntry self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
+    sse_encode_String(self.location, serializer);
+    sse_encode_String(self.path, serializer);
   }
 
   @protected
-  void sse_encode_fad_button(FadButton self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.name, serializer);
-    sse_encode_opt_String(self.icon, serializer);
-    sse_encode_opt_String(self.location, serializer);
-    sse_encode_String(self.callback, serializer);
+  void sse_encode_material_scheme(
+      MaterialScheme self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.isDark, serializer);
+    sse_encode_u_32(self.seed, serializer);
+    sse_encode_u_32(self.primary, serializer);
+    sse_encode_u_32(self.onPrimary, serializer);
+    sse_encode_u_32(self.primaryContainer, serializer);
+    sse_encode_u_32(self.onPrimaryContainer, serializer);
+    sse_encode_u_32(self.secondary, serializer);
+    sse_encode_u_32(self.onSecondary, serializer);
+    sse_encode_u_32(self.secondaryContainer, serializer);
+    sse_encode_u_32(self.onSecondaryContainer, serializer);
+    sse_encode_u_32(self.tertiary, serializer);
+    sse_encode_u_32(self.onTertiary, serializer);
+    sse_encode_u_32(self.tertiaryContainer, serializer);
+    sse_encode_u_32(self.onTertiaryContainer, serializer);
+    sse_encode_u_32(self.error, serializer);
+    sse_encode_u_32(self.onError, serializer);
+    sse_encode_u_32(self.errorContainer, serializer);
+    sse_encode_u_32(self.onErrorContainer, serializer);
+    sse_encode_u_32(self.background, serializer);
+    sse_encode_u_32(self.onBackground, serializer);
+    sse_encode_u_32(self.surface, serializer);
+    sse_encode_u_32(self.onSurface, serializer);
+    sse_encode_u_32(self.surfaceVariant, serializer);
+    sse_encode_u_32(self.onSurfaceVariant, serializer);
+    sse_encode_u_32(self.surfaceContainer, serializer);
+    sse_encode_u_32(self.outline, serializer);
+    sse_encode_u_32(self.outlineVariant, serializer);
+    sse_encode_u_32(self.inverseSurface, serializer);
+    sse_encode_u_32(self.inverseOnSurface, serializer);
+    sse_encode_u_32(self.inversePrimary, serializer);
+  }
+
+  @protected
+  void sse_encode_missing_entry(MissingEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.location, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.artist, serializer);
   }
 
   @protected
-  void sse_encode_fad_config(FadConfig self, SseSerializer serializer) {
+  void sse_encode_opt_Map_String_config_types_None(
+      Map<String, ConfigTypes>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_list_fad_screen(self.screens, serializer);
-    sse_encode_opt_list_fad_popup(self.popups, serializer);
-    sse_encode_opt_list_fad_button(self.buttons, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_Map_String_config_types_None(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_fad_label(FadLabel self, SseSerializer serializer) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_64(self.size, serializer);
-    sse_encode_String(self.text, serializer);
-    sse_encode_opt_String(self.color, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_fad_popup(FadPopup self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_adi_plugin_man(
+      AdiPluginMan? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_String(self.title, serializer);
-    sse_encode_opt_list_fad_button(self.buttons, serializer);
-    sse_encode_opt_list_fad_label(self.labels, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_adi_plugin_man(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_fad_screen(FadScreen self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_book_progress(
+      BookProgress? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_String(self.title, serializer);
-    sse_encode_opt_list_fad_button(self.buttons, serializer);
-    sse_encode_opt_list_fad_label(self.labels, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_book_progress(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_chapter(
+      Chapter? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_chapter(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_fad_button(
-      List<FadButton> self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_download_job(
+      DownloadJob? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_fad_button(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_download_job(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_fad_label(
-      List<FadLabel> self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_enriched_metadata(
+      EnrichedMetadata? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_fad_label(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_enriched_metadata(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_fad_popup(
-      List<FadPopup> self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_fad_popup(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_32(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_fad_screen(
-      List<FadScreen> self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_fad_screen(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_prim_f_32_strict(
-      Float32List self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_fad_config(
+      FadConfig? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putFloat32List(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_fad_config(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_prim_f_64_strict(
-      Float64List self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_found_lyrics(
+      FoundLyrics? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putFloat64List(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_found_lyrics(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_loose(
-      List<int> self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer
-        .putUint8List(self is Uint8List ? self : Uint8List.fromList(self));
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_32(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_i_64(
+      PlatformInt64? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
+    }
   }
 
   @protected
-  void
-      sse_encode_list_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
-          List<(String, PluginInode)> self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_lrc_document(
+      LrcDocument? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
-          item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_lrc_document(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_record_string_config_types(
-      List<(String, ConfigTypes)> self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_lyric_position(
+      LyricPosition? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_config_types(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_lyric_position(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_song_metadata(
-      List<SongMetadata> self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_palette(
+      Palette? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_song_metadata(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_palette(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_Map_String_config_types_None(
-      Map<String, ConfigTypes>? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_pipeline_config(
+      PipelineConfig? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_Map_String_config_types_None(self, serializer);
+      sse_encode_box_autoadd_pipeline_config(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_playlist_format(
+      PlaylistFormat? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_String(self, serializer);
+      sse_encode_box_autoadd_playlist_format(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_adi_plugin_man(
-      AdiPluginMan? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_replay_gain(
+      ReplayGain? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_adi_plugin_man(self, serializer);
+      sse_encode_box_autoadd_replay_gain(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_song_metadata(
+      SongMetadata? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_bool(self, serializer);
+      sse_encode_box_autoadd_song_metadata(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_fad_config(
-      FadConfig? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_stream_metadata(
+      StreamMetadata? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_fad_config(self, serializer);
+      sse_encode_box_autoadd_stream_metadata(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_song_metadata(
-      SongMetadata? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_swatch(
+      Swatch? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_song_metadata(self, serializer);
+      sse_encode_box_autoadd_swatch(self, serializer);
     }
   }
 
@@ -4938,6 +13952,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_list_String(
       List<String>? self, SseSerializer serializer) {
@@ -5004,12 +14028,186 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_organize_plan(OrganizePlan self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_planned_move(self.moves, serializer);
+    sse_encode_u_32(self.unchanged, serializer);
+    sse_encode_list_String(self.skipped, serializer);
+  }
+
+  @protected
+  void sse_encode_organize_result(
+      OrganizeResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_organize_plan(self.plan, serializer);
+    sse_encode_u_32(self.linksUpdated, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.journalId, serializer);
+  }
+
+  @protected
+  void sse_encode_palette(Palette self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_swatch(self.swatches, serializer);
+    sse_encode_opt_box_autoadd_swatch(self.vibrant, serializer);
+    sse_encode_opt_box_autoadd_swatch(self.lightVibrant, serializer);
+    sse_encode_opt_box_autoadd_swatch(self.darkVibrant, serializer);
+    sse_encode_opt_box_autoadd_swatch(self.muted, serializer);
+    sse_encode_opt_box_autoadd_swatch(self.lightMuted, serializer);
+    sse_encode_opt_box_autoadd_swatch(self.darkMuted, serializer);
+  }
+
+  @protected
+  void sse_encode_pipeline_config(
+      PipelineConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.cleanTitles, serializer);
+    sse_encode_list_title_rule(self.titleRules, serializer);
+    sse_encode_bool(self.splitArtistTitle, serializer);
+    sse_encode_bool(self.identify, serializer);
+    sse_encode_f_64(self.minIdentifyScore, serializer);
+    sse_encode_bool(self.replayGain, serializer);
+    sse_encode_bool(self.import, serializer);
+    sse_encode_String(self.template, serializer);
+  }
+
+  @protected
+  void sse_encode_pipeline_result(
+      PipelineResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.originalPath, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_opt_box_autoadd_song_metadata(self.metadata, serializer);
+    sse_encode_bool(self.identified, serializer);
+    sse_encode_opt_box_autoadd_replay_gain(self.replayGain, serializer);
+    sse_encode_list_String(self.warnings, serializer);
+  }
+
+  @protected
+  void sse_encode_planned_move(PlannedMove self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.from, serializer);
+    sse_encode_String(self.to, serializer);
+    sse_encode_bool(self.renamedForCollision, serializer);
+  }
+
+  @protected
+  void sse_encode_play_stat(PlayStat self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.playCount, serializer);
+    sse_encode_i_64(self.lastPlayed, serializer);
+  }
+
   @protected
   void sse_encode_player_state(PlayerState self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_bool(self.initialized, serializer);
   }
 
+  @protected
+  void sse_encode_playlist_entry(PlaylistEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.index, serializer);
+    sse_encode_String(self.link, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_bool(self.missing, serializer);
+  }
+
+  @protected
+  void sse_encode_playlist_export_result(
+      PlaylistExportResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.written, serializer);
+    sse_encode_list_String(self.missing, serializer);
+  }
+
+  @protected
+  void sse_encode_playlist_format(
+      PlaylistFormat self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_playlist_import_result(
+      PlaylistImportResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.playlist, serializer);
+    sse_encode_u_32(self.totalEntries, serializer);
+    sse_encode_u_32(self.linked, serializer);
+    sse_encode_list_matched_entry(self.matched, serializer);
+    sse_encode_u_32(self.duplicates, serializer);
+    sse_encode_list_missing_entry(self.missing, serializer);
+  }
+
+  @protected
+  void sse_encode_playlist_info(PlaylistInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_u_32(self.trackCount, serializer);
+    sse_encode_bool(self.isSmart, serializer);
+  }
+
+  @protected
+  void sse_encode_playlist_sort_key(
+      PlaylistSortKey self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_podcast(Podcast self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.id, serializer);
+    sse_encode_String(self.feedUrl, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_opt_String(self.author, serializer);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_opt_String(self.link, serializer);
+    sse_encode_opt_String(self.imageUrl, serializer);
+    sse_encode_bool(self.autoDownload, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.keepDownloaded, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.lastRefreshed, serializer);
+    sse_encode_list_podcast_episode(self.episodes, serializer);
+  }
+
+  @protected
+  void sse_encode_podcast_chapter(
+      PodcastChapter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.startSecs, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_opt_String(self.url, serializer);
+  }
+
+  @protected
+  void sse_encode_podcast_episode(
+      PodcastEpisode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_feed_episode(self.feed, serializer);
+    sse_encode_opt_String(self.downloadedPath, serializer);
+    sse_encode_f_64(self.positionSecs, serializer);
+    sse_encode_bool(self.played, serializer);
+  }
+
+  @protected
+  void sse_encode_podcast_refresh(
+      PodcastRefresh self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.podcastId, serializer);
+    sse_encode_list_String(self.newEpisodes, serializer);
+    sse_encode_opt_String(self.error, serializer);
+  }
+
+  @protected
+  void sse_encode_radio_station(RadioStation self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.url, serializer);
+    sse_encode_opt_String(self.genre, serializer);
+    sse_encode_opt_String(self.homepage, serializer);
+  }
+
   @protected
   void
       sse_encode_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
@@ -5028,6 +14226,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_config_types(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_remote_candidate(
+      RemoteCandidate self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_opt_String(self.uploader, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.durationSecs, serializer);
+    sse_encode_opt_String(self.thumbnailUrl, serializer);
+    sse_encode_String(self.url, serializer);
+  }
+
+  @protected
+  void sse_encode_replay_gain(ReplayGain self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.loudnessLufs, serializer);
+    sse_encode_f_64(self.gainDb, serializer);
+    sse_encode_f_64(self.peak, serializer);
+  }
+
+  @protected
+  void sse_encode_search_hit(SearchHit self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_song_metadata(self.song, serializer);
+    sse_encode_f_32(self.score, serializer);
+  }
+
+  @protected
+  void sse_encode_search_page(SearchPage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.total, serializer);
+    sse_encode_u_32(self.page, serializer);
+    sse_encode_u_32(self.pageSize, serializer);
+    sse_encode_list_search_hit(self.hits, serializer);
+  }
+
   @protected
   void sse_encode_song_metadata(SongMetadata self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5040,6 +14274,57 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.genre, serializer);
   }
 
+  @protected
+  void sse_encode_stream_metadata(
+      StreamMetadata self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_opt_String(self.stationName, serializer);
+    sse_encode_opt_String(self.genre, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.bitrateKbps, serializer);
+    sse_encode_opt_String(self.nowPlaying, serializer);
+    sse_encode_opt_String(self.artist, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_bool(self.reconnecting, serializer);
+  }
+
+  @protected
+  void sse_encode_swatch(Swatch self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.color, serializer);
+    sse_encode_u_32(self.population, serializer);
+    sse_encode_u_32(self.textColor, serializer);
+  }
+
+  @protected
+  void sse_encode_tag_edit(TagEdit self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_list_field_change(self.changes, serializer);
+  }
+
+  @protected
+  void sse_encode_tag_edit_record(
+      TagEditRecord self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.id, serializer);
+    sse_encode_i_64(self.timestamp, serializer);
+    sse_encode_u_32(self.files, serializer);
+  }
+
+  @protected
+  void sse_encode_tag_field(TagField self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_title_rule(TitleRule self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.pattern, serializer);
+    sse_encode_String(self.replacement, serializer);
+  }
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5246,16 +14531,41 @@ class ValueStoreUpdaterImpl extends RustOpaque implements ValueStoreUpdater {
         that: this,
       );
 
+  String? get acoustidApiKey => RustLib.instance.api
+          .crateApiValueStoreValueStoreUpdaterAutoAccessorGetAcoustidApiKey(
+        that: this,
+      );
+
+  String? get acoustidBaseUrl => RustLib.instance.api
+          .crateApiValueStoreValueStoreUpdaterAutoAccessorGetAcoustidBaseUrl(
+        that: this,
+      );
+
+  String? get coverartBaseUrl => RustLib.instance.api
+          .crateApiValueStoreValueStoreUpdaterAutoAccessorGetCoverartBaseUrl(
+        that: this,
+      );
+
   CurrentSongUpdate get currentSong => RustLib.instance.api
           .crateApiValueStoreValueStoreUpdaterAutoAccessorGetCurrentSong(
         that: this,
       );
 
+  String? get lrclibBaseUrl => RustLib.instance.api
+          .crateApiValueStoreValueStoreUpdaterAutoAccessorGetLrclibBaseUrl(
+        that: this,
+      );
+
   String? get musicFolder => RustLib.instance.api
           .crateApiValueStoreValueStoreUpdaterAutoAccessorGetMusicFolder(
         that: this,
       );
 
+  String? get musicbrainzBaseUrl => RustLib.instance.api
+          .crateApiValueStoreValueStoreUpdaterAutoAccessorGetMusicbrainzBaseUrl(
+        that: this,
+      );
+
   String? get pluginRwDir => RustLib.instance.api
           .crateApiValueStoreValueStoreUpdaterAutoAccessorGetPluginRwDir(
         that: this,
@@ -5266,19 +14576,49 @@ class ValueStoreUpdaterImpl extends RustOpaque implements ValueStoreUpdater {
         that: this,
       );
 
+  String? get podcastFolder => RustLib.instance.api
+          .crateApiValueStoreValueStoreUpdaterAutoAccessorGetPodcastFolder(
+        that: this,
+      );
+
   bool? get unsafeApis => RustLib.instance.api
           .crateApiValueStoreValueStoreUpdaterAutoAccessorGetUnsafeApis(
         that: this,
       );
 
+  String? get ytdlpPath => RustLib.instance.api
+          .crateApiValueStoreValueStoreUpdaterAutoAccessorGetYtdlpPath(
+        that: this,
+      );
+
+  set acoustidApiKey(String? acoustidApiKey) => RustLib.instance.api
+      .crateApiValueStoreValueStoreUpdaterAutoAccessorSetAcoustidApiKey(
+          that: this, acoustidApiKey: acoustidApiKey);
+
+  set acoustidBaseUrl(String? acoustidBaseUrl) => RustLib.instance.api
+      .crateApiValueStoreValueStoreUpdaterAutoAccessorSetAcoustidBaseUrl(
+          that: this, acoustidBaseUrl: acoustidBaseUrl);
+
+  set coverartBaseUrl(String? coverartBaseUrl) => RustLib.instance.api
+      .crateApiValueStoreValueStoreUpdaterAutoAccessorSetCoverartBaseUrl(
+          that: this, coverartBaseUrl: coverartBaseUrl);
+
   set currentSong(CurrentSongUpdate currentSong) => RustLib.instance.api
       .crateApiValueStoreValueStoreUpdaterAutoAccessorSetCurrentSong(
           that: this, currentSong: currentSong);
 
+  set lrclibBaseUrl(String? lrclibBaseUrl) => RustLib.instance.api
+      .crateApiValueStoreValueStoreUpdaterAutoAccessorSetLrclibBaseUrl(
+          that: this, lrclibBaseUrl: lrclibBaseUrl);
+
   set musicFolder(String? musicFolder) => RustLib.instance.api
       .crateApiValueStoreValueStoreUpdaterAutoAccessorSetMusicFolder(
           that: this, musicFolder: musicFolder);
 
+  set musicbrainzBaseUrl(String? musicbrainzBaseUrl) => RustLib.instance.api
+      .crateApiValueStoreValueStoreUpdaterAutoAccessorSetMusicbrainzBaseUrl(
+          that: this, musicbrainzBaseUrl: musicbrainzBaseUrl);
+
   set pluginRwDir(String? pluginRwDir) => RustLib.instance.api
       .crateApiValueStoreValueStoreUpdaterAutoAccessorSetPluginRwDir(
           that: this, pluginRwDir: pluginRwDir);
@@ -5287,23 +14627,52 @@ class ValueStoreUpdaterImpl extends RustOpaque implements ValueStoreUpdater {
       .crateApiValueStoreValueStoreUpdaterAutoAccessorSetPluginsEnabled(
           that: this, pluginsEnabled: pluginsEnabled);
 
+  set podcastFolder(String? podcastFolder) => RustLib.instance.api
+      .crateApiValueStoreValueStoreUpdaterAutoAccessorSetPodcastFolder(
+          that: this, podcastFolder: podcastFolder);
+
   set unsafeApis(bool? unsafeApis) => RustLib.instance.api
       .crateApiValueStoreValueStoreUpdaterAutoAccessorSetUnsafeApis(
           that: this, unsafeApis: unsafeApis);
 
+  set ytdlpPath(String? ytdlpPath) => RustLib.instance.api
+      .crateApiValueStoreValueStoreUpdaterAutoAccessorSetYtdlpPath(
+          that: this, ytdlpPath: ytdlpPath);
+
   Future<void> clearCurrentSong() =>
       RustLib.instance.api.crateApiValueStoreValueStoreUpdaterClearCurrentSong(
         that: this,
       );
 
+  Future<void> setAcoustidApiKey({required String key}) =>
+      RustLib.instance.api.crateApiValueStoreValueStoreUpdaterSetAcoustidApiKey(
+          that: this, key: key);
+
+  Future<void> setAcoustidBaseUrl({required String url}) => RustLib.instance.api
+      .crateApiValueStoreValueStoreUpdaterSetAcoustidBaseUrl(
+          that: this, url: url);
+
+  Future<void> setCoverartBaseUrl({required String url}) => RustLib.instance.api
+      .crateApiValueStoreValueStoreUpdaterSetCoverartBaseUrl(
+          that: this, url: url);
+
   Future<void> setCurrentSong({required SongMetadata song}) =>
       RustLib.instance.api.crateApiValueStoreValueStoreUpdaterSetCurrentSong(
           that: this, song: song);
 
+  Future<void> setLrclibBaseUrl({required String url}) =>
+      RustLib.instance.api.crateApiValueStoreValueStoreUpdaterSetLrclibBaseUrl(
+          that: this, url: url);
+
   Future<void> setMusicFolder({required String folder}) =>
       RustLib.instance.api.crateApiValueStoreValueStoreUpdaterSetMusicFolder(
           that: this, folder: folder);
 
+  Future<void> setMusicbrainzBaseUrl({required String url}) =>
+      RustLib.instance.api
+          .crateApiValueStoreValueStoreUpdaterSetMusicbrainzBaseUrl(
+              that: this, url: url);
+
   Future<void> setPluginRwDir({required String folder}) =>
       RustLib.instance.api.crateApiValueStoreValueStoreUpdaterSetPluginRwDir(
           that: this, folder: folder);
@@ -5312,7 +14681,14 @@ class ValueStoreUpdaterImpl extends RustOpaque implements ValueStoreUpdater {
       RustLib.instance.api.crateApiValueStoreValueStoreUpdaterSetPluginsEnabled(
           that: this, val: val);
 
+  Future<void> setPodcastFolder({required String folder}) =>
+      RustLib.instance.api.crateApiValueStoreValueStoreUpdaterSetPodcastFolder(
+          that: this, folder: folder);
+
   Future<void> setUnsafeApis({required bool value}) =>
       RustLib.instance.api.crateApiValueStoreValueStoreUpdaterSetUnsafeApis(
           that: this, value: value);
+
+  Future<void> setYtdlpPath({required String path}) => RustLib.instance.api
+      .crateApiValueStoreValueStoreUpdaterSetYtdlpPath(that: this, path: path);
 }
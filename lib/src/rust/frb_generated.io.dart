@@ -4,9 +4,36 @@
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
 import 'api/acoustid.dart';
+import 'api/art_cache.dart';
+import 'api/audiobooks.dart';
+import 'api/chapters.dart';
+import 'api/collection_download.dart';
 import 'api/color_extractor.dart';
+import 'api/cue_sheet.dart';
+import 'api/download_manager.dart';
+import 'api/download_pipeline.dart';
+import 'api/embedded_lyrics.dart';
+import 'api/fingerprint.dart';
+import 'api/http_stream.dart';
+import 'api/library_search.dart';
+import 'api/lrclib.dart';
+import 'api/lyrics_editor.dart';
+import 'api/lyrics_index.dart';
+import 'api/lyrics_store.dart';
 import 'api/music_handler.dart';
+import 'api/musicbrainz.dart';
+import 'api/organizer.dart';
+import 'api/play_stats.dart';
+import 'api/playlist_io.dart';
+import 'api/playlist_manager.dart';
 import 'api/plugin_man.dart';
+import 'api/podcast_feed.dart';
+import 'api/podcasts.dart';
+import 'api/radio_stations.dart';
+import 'api/replay_gain.dart';
+import 'api/smart_playlist.dart';
+import 'api/synced_lyrics.dart';
+import 'api/tag_editor.dart';
 import 'api/utils.dart';
 import 'api/value_store.dart';
 import 'dart:async';
@@ -137,493 +164,1405 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
           dynamic raw);
 
   @protected
-  String dco_decode_String(dynamic raw);
+  RustStreamSink<CollectionDownload>
+      dco_decode_StreamSink_collection_download_Sse(dynamic raw);
 
   @protected
-  BigInt dco_decode_U128(dynamic raw);
+  RustStreamSink<DownloadJob> dco_decode_StreamSink_download_job_Sse(
+      dynamic raw);
 
   @protected
-  AdiPluginMan dco_decode_adi_plugin_man(dynamic raw);
+  RustStreamSink<EpisodeDownload> dco_decode_StreamSink_episode_download_Sse(
+      dynamic raw);
 
   @protected
-  bool dco_decode_bool(dynamic raw);
+  RustStreamSink<IdentifyProgress> dco_decode_StreamSink_identify_progress_Sse(
+      dynamic raw);
 
   @protected
-  AdiPluginMan dco_decode_box_autoadd_adi_plugin_man(dynamic raw);
+  RustStreamSink<LyricEvent> dco_decode_StreamSink_lyric_event_Sse(dynamic raw);
 
   @protected
-  bool dco_decode_box_autoadd_bool(dynamic raw);
+  RustStreamSink<SongMetadata> dco_decode_StreamSink_song_metadata_Sse(
+      dynamic raw);
 
   @protected
-  ConfigTypes dco_decode_box_autoadd_config_types(dynamic raw);
+  RustStreamSink<StreamMetadata> dco_decode_StreamSink_stream_metadata_Sse(
+      dynamic raw);
 
   @protected
-  FadButton dco_decode_box_autoadd_fad_button(dynamic raw);
+  String dco_decode_String(dynamic raw);
 
   @protected
-  FadConfig dco_decode_box_autoadd_fad_config(dynamic raw);
+  BigInt dco_decode_U128(dynamic raw);
 
   @protected
-  SongMetadata dco_decode_box_autoadd_song_metadata(dynamic raw);
+  AcoustIdCandidate dco_decode_acoust_id_candidate(dynamic raw);
 
   @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw);
+  AdiPluginMan dco_decode_adi_plugin_man(dynamic raw);
 
   @protected
-  ValueStoreUpdate dco_decode_box_autoadd_value_store_update(dynamic raw);
+  ArtWriteResult dco_decode_art_write_result(dynamic raw);
 
   @protected
-  ConfigTypes dco_decode_config_types(dynamic raw);
+  BookProgress dco_decode_book_progress(dynamic raw);
 
   @protected
-  CurrentSongUpdate dco_decode_current_song_update(dynamic raw);
+  bool dco_decode_bool(dynamic raw);
 
   @protected
-  double dco_decode_f_32(dynamic raw);
+  AcoustIdCandidate dco_decode_box_autoadd_acoust_id_candidate(dynamic raw);
 
   @protected
-  double dco_decode_f_64(dynamic raw);
+  AdiPluginMan dco_decode_box_autoadd_adi_plugin_man(dynamic raw);
 
   @protected
-  FadButton dco_decode_fad_button(dynamic raw);
+  BookProgress dco_decode_box_autoadd_book_progress(dynamic raw);
 
   @protected
-  FadConfig dco_decode_fad_config(dynamic raw);
+  bool dco_decode_box_autoadd_bool(dynamic raw);
 
   @protected
-  FadLabel dco_decode_fad_label(dynamic raw);
+  Chapter dco_decode_box_autoadd_chapter(dynamic raw);
 
   @protected
-  FadPopup dco_decode_fad_popup(dynamic raw);
+  CollectionOptions dco_decode_box_autoadd_collection_options(dynamic raw);
 
   @protected
-  FadScreen dco_decode_fad_screen(dynamic raw);
+  ConfigTypes dco_decode_box_autoadd_config_types(dynamic raw);
 
   @protected
-  int dco_decode_i_32(dynamic raw);
+  DownloadJob dco_decode_box_autoadd_download_job(dynamic raw);
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw);
+  EnrichedMetadata dco_decode_box_autoadd_enriched_metadata(dynamic raw);
 
   @protected
-  List<FadButton> dco_decode_list_fad_button(dynamic raw);
+  double dco_decode_box_autoadd_f_32(dynamic raw);
 
   @protected
-  List<FadLabel> dco_decode_list_fad_label(dynamic raw);
+  double dco_decode_box_autoadd_f_64(dynamic raw);
 
   @protected
-  List<FadPopup> dco_decode_list_fad_popup(dynamic raw);
+  FadButton dco_decode_box_autoadd_fad_button(dynamic raw);
 
   @protected
-  List<FadScreen> dco_decode_list_fad_screen(dynamic raw);
+  FadConfig dco_decode_box_autoadd_fad_config(dynamic raw);
 
   @protected
-  Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
+  FoundLyrics dco_decode_box_autoadd_found_lyrics(dynamic raw);
 
   @protected
-  Float64List dco_decode_list_prim_f_64_strict(dynamic raw);
+  int dco_decode_box_autoadd_i_32(dynamic raw);
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  LrcDocument dco_decode_box_autoadd_lrc_document(dynamic raw);
 
   @protected
-  List<(String, PluginInode)>
-      dco_decode_list_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
-          dynamic raw);
+  LyricPosition dco_decode_box_autoadd_lyric_position(dynamic raw);
 
   @protected
-  List<(String, ConfigTypes)> dco_decode_list_record_string_config_types(
-      dynamic raw);
+  Palette dco_decode_box_autoadd_palette(dynamic raw);
 
   @protected
-  List<SongMetadata> dco_decode_list_song_metadata(dynamic raw);
+  PipelineConfig dco_decode_box_autoadd_pipeline_config(dynamic raw);
 
   @protected
-  Map<String, ConfigTypes>? dco_decode_opt_Map_String_config_types_None(
-      dynamic raw);
+  PlaylistFormat dco_decode_box_autoadd_playlist_format(dynamic raw);
 
   @protected
-  String? dco_decode_opt_String(dynamic raw);
+  PlaylistSortKey dco_decode_box_autoadd_playlist_sort_key(dynamic raw);
 
   @protected
-  AdiPluginMan? dco_decode_opt_box_autoadd_adi_plugin_man(dynamic raw);
+  RadioStation dco_decode_box_autoadd_radio_station(dynamic raw);
 
   @protected
-  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+  ReplayGain dco_decode_box_autoadd_replay_gain(dynamic raw);
 
   @protected
-  FadConfig? dco_decode_opt_box_autoadd_fad_config(dynamic raw);
+  SongMetadata dco_decode_box_autoadd_song_metadata(dynamic raw);
 
   @protected
-  SongMetadata? dco_decode_opt_box_autoadd_song_metadata(dynamic raw);
+  StreamMetadata dco_decode_box_autoadd_stream_metadata(dynamic raw);
 
   @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+  Swatch dco_decode_box_autoadd_swatch(dynamic raw);
 
   @protected
-  List<String>? dco_decode_opt_list_String(dynamic raw);
+  int dco_decode_box_autoadd_u_32(dynamic raw);
 
   @protected
-  List<FadButton>? dco_decode_opt_list_fad_button(dynamic raw);
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
   @protected
-  List<FadLabel>? dco_decode_opt_list_fad_label(dynamic raw);
+  ValueStoreUpdate dco_decode_box_autoadd_value_store_update(dynamic raw);
 
   @protected
-  List<FadPopup>? dco_decode_opt_list_fad_popup(dynamic raw);
+  Chapter dco_decode_chapter(dynamic raw);
 
   @protected
-  List<FadScreen>? dco_decode_opt_list_fad_screen(dynamic raw);
+  CollectionDownload dco_decode_collection_download(dynamic raw);
 
   @protected
-  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+  CollectionOptions dco_decode_collection_options(dynamic raw);
 
   @protected
-  PlayerState dco_decode_player_state(dynamic raw);
+  CollectionTrack dco_decode_collection_track(dynamic raw);
 
   @protected
-  (
-    String,
-    PluginInode
-  ) dco_decode_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
-      dynamic raw);
+  ConfigTypes dco_decode_config_types(dynamic raw);
 
   @protected
-  (String, ConfigTypes) dco_decode_record_string_config_types(dynamic raw);
+  CueSheet dco_decode_cue_sheet(dynamic raw);
 
   @protected
-  SongMetadata dco_decode_song_metadata(dynamic raw);
+  CueTrack dco_decode_cue_track(dynamic raw);
 
   @protected
-  int dco_decode_u_32(dynamic raw);
+  CurrentSongUpdate dco_decode_current_song_update(dynamic raw);
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw);
+  DownloadJob dco_decode_download_job(dynamic raw);
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  DownloadState dco_decode_download_state(dynamic raw);
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  DuplicateFile dco_decode_duplicate_file(dynamic raw);
 
   @protected
-  BigInt dco_decode_usize(dynamic raw);
+  DuplicateGroup dco_decode_duplicate_group(dynamic raw);
 
   @protected
-  ValueStoreUpdate dco_decode_value_store_update(dynamic raw);
+  EnrichedMetadata dco_decode_enriched_metadata(dynamic raw);
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+  EpisodeDownload dco_decode_episode_download(dynamic raw);
 
   @protected
-  ArcMutexPlugin
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin(
-          SseDeserializer deserializer);
+  double dco_decode_f_32(dynamic raw);
 
   @protected
-  PluginInode
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
-          SseDeserializer deserializer);
+  double dco_decode_f_64(dynamic raw);
 
   @protected
-  RpcConfig
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
-          SseDeserializer deserializer);
+  FadButton dco_decode_fad_button(dynamic raw);
 
   @protected
-  Value
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          SseDeserializer deserializer);
+  FadConfig dco_decode_fad_config(dynamic raw);
 
   @protected
-  ValueStoreUpdater
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
-          SseDeserializer deserializer);
+  FadLabel dco_decode_fad_label(dynamic raw);
 
   @protected
-  PluginInode
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
-          SseDeserializer deserializer);
+  FadPopup dco_decode_fad_popup(dynamic raw);
 
   @protected
-  RpcConfig
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
-          SseDeserializer deserializer);
+  FadScreen dco_decode_fad_screen(dynamic raw);
 
   @protected
-  ValueStoreUpdater
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
-          SseDeserializer deserializer);
+  FeedEpisode dco_decode_feed_episode(dynamic raw);
 
   @protected
-  PluginInode
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
-          SseDeserializer deserializer);
+  FieldChange dco_decode_field_change(dynamic raw);
 
   @protected
-  RpcConfig
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
-          SseDeserializer deserializer);
+  FoundLyrics dco_decode_found_lyrics(dynamic raw);
 
   @protected
-  ValueStoreUpdater
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
-          SseDeserializer deserializer);
+  int dco_decode_i_32(dynamic raw);
 
   @protected
-  BigInt sse_decode_I128(SseDeserializer deserializer);
+  PlatformInt64 dco_decode_i_64(dynamic raw);
 
   @protected
-  Map<String, PluginInode>
-      sse_decode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode_None(
-          SseDeserializer deserializer);
+  IdentifyProgress dco_decode_identify_progress(dynamic raw);
 
   @protected
-  Map<String, ConfigTypes> sse_decode_Map_String_config_types_None(
-      SseDeserializer deserializer);
+  IdentifyResult dco_decode_identify_result(dynamic raw);
 
   @protected
-  ArcMutexPlugin
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin(
-          SseDeserializer deserializer);
+  List<String> dco_decode_list_String(dynamic raw);
 
   @protected
-  PluginInode
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
-          SseDeserializer deserializer);
+  List<AcoustIdCandidate> dco_decode_list_acoust_id_candidate(dynamic raw);
 
   @protected
-  RpcConfig
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
-          SseDeserializer deserializer);
+  List<ArtWriteResult> dco_decode_list_art_write_result(dynamic raw);
 
   @protected
-  Value
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          SseDeserializer deserializer);
+  List<BookProgress> dco_decode_list_book_progress(dynamic raw);
 
   @protected
-  ValueStoreUpdater
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
-          SseDeserializer deserializer);
+  List<Chapter> dco_decode_list_chapter(dynamic raw);
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  List<CollectionDownload> dco_decode_list_collection_download(dynamic raw);
 
   @protected
-  BigInt sse_decode_U128(SseDeserializer deserializer);
+  List<CollectionTrack> dco_decode_list_collection_track(dynamic raw);
 
   @protected
-  AdiPluginMan sse_decode_adi_plugin_man(SseDeserializer deserializer);
+  List<CueTrack> dco_decode_list_cue_track(dynamic raw);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  List<DownloadJob> dco_decode_list_download_job(dynamic raw);
 
   @protected
-  AdiPluginMan sse_decode_box_autoadd_adi_plugin_man(
-      SseDeserializer deserializer);
+  List<DuplicateFile> dco_decode_list_duplicate_file(dynamic raw);
 
   @protected
-  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+  List<DuplicateGroup> dco_decode_list_duplicate_group(dynamic raw);
 
   @protected
-  ConfigTypes sse_decode_box_autoadd_config_types(SseDeserializer deserializer);
+  List<FadButton> dco_decode_list_fad_button(dynamic raw);
 
   @protected
-  FadButton sse_decode_box_autoadd_fad_button(SseDeserializer deserializer);
+  List<FadLabel> dco_decode_list_fad_label(dynamic raw);
 
   @protected
-  FadConfig sse_decode_box_autoadd_fad_config(SseDeserializer deserializer);
+  List<FadPopup> dco_decode_list_fad_popup(dynamic raw);
 
   @protected
-  SongMetadata sse_decode_box_autoadd_song_metadata(
-      SseDeserializer deserializer);
+  List<FadScreen> dco_decode_list_fad_screen(dynamic raw);
 
   @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+  List<FieldChange> dco_decode_list_field_change(dynamic raw);
 
   @protected
-  ValueStoreUpdate sse_decode_box_autoadd_value_store_update(
-      SseDeserializer deserializer);
+  List<LrcLine> dco_decode_list_lrc_line(dynamic raw);
 
   @protected
-  ConfigTypes sse_decode_config_types(SseDeserializer deserializer);
+  List<LrcWord> dco_decode_list_lrc_word(dynamic raw);
 
   @protected
-  CurrentSongUpdate sse_decode_current_song_update(
-      SseDeserializer deserializer);
+  List<LrclibLyrics> dco_decode_list_lrclib_lyrics(dynamic raw);
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer);
+  List<LyricMatchLine> dco_decode_list_lyric_match_line(dynamic raw);
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer);
+  List<LyricSearchResult> dco_decode_list_lyric_search_result(dynamic raw);
 
   @protected
-  FadButton sse_decode_fad_button(SseDeserializer deserializer);
+  List<MatchedEntry> dco_decode_list_matched_entry(dynamic raw);
 
   @protected
-  FadConfig sse_decode_fad_config(SseDeserializer deserializer);
+  List<MissingEntry> dco_decode_list_missing_entry(dynamic raw);
 
   @protected
-  FadLabel sse_decode_fad_label(SseDeserializer deserializer);
+  List<PipelineResult> dco_decode_list_pipeline_result(dynamic raw);
 
   @protected
-  FadPopup sse_decode_fad_popup(SseDeserializer deserializer);
+  List<PlannedMove> dco_decode_list_planned_move(dynamic raw);
 
   @protected
-  FadScreen sse_decode_fad_screen(SseDeserializer deserializer);
+  List<PlaylistEntry> dco_decode_list_playlist_entry(dynamic raw);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  List<PlaylistInfo> dco_decode_list_playlist_info(dynamic raw);
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+  List<Podcast> dco_decode_list_podcast(dynamic raw);
 
   @protected
-  List<FadButton> sse_decode_list_fad_button(SseDeserializer deserializer);
+  List<PodcastChapter> dco_decode_list_podcast_chapter(dynamic raw);
 
   @protected
-  List<FadLabel> sse_decode_list_fad_label(SseDeserializer deserializer);
+  List<PodcastEpisode> dco_decode_list_podcast_episode(dynamic raw);
 
   @protected
-  List<FadPopup> sse_decode_list_fad_popup(SseDeserializer deserializer);
+  List<PodcastRefresh> dco_decode_list_podcast_refresh(dynamic raw);
 
   @protected
-  List<FadScreen> sse_decode_list_fad_screen(SseDeserializer deserializer);
+  Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
 
   @protected
-  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
+  Float64List dco_decode_list_prim_f_64_strict(dynamic raw);
 
   @protected
-  Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer);
+  List<int> dco_decode_list_prim_u_32_loose(dynamic raw);
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  List<RadioStation> dco_decode_list_radio_station(dynamic raw);
 
   @protected
   List<(String, PluginInode)>
-      sse_decode_list_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
-          SseDeserializer deserializer);
+      dco_decode_list_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
+          dynamic raw);
 
   @protected
-  List<(String, ConfigTypes)> sse_decode_list_record_string_config_types(
-      SseDeserializer deserializer);
+  List<(String, ConfigTypes)> dco_decode_list_record_string_config_types(
+      dynamic raw);
 
   @protected
-  List<SongMetadata> sse_decode_list_song_metadata(
-      SseDeserializer deserializer);
+  List<RemoteCandidate> dco_decode_list_remote_candidate(dynamic raw);
 
   @protected
-  Map<String, ConfigTypes>? sse_decode_opt_Map_String_config_types_None(
-      SseDeserializer deserializer);
+  List<SearchHit> dco_decode_list_search_hit(dynamic raw);
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  List<SongMetadata> dco_decode_list_song_metadata(dynamic raw);
 
   @protected
-  AdiPluginMan? sse_decode_opt_box_autoadd_adi_plugin_man(
-      SseDeserializer deserializer);
+  List<Swatch> dco_decode_list_swatch(dynamic raw);
 
   @protected
-  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+  List<TagEdit> dco_decode_list_tag_edit(dynamic raw);
 
   @protected
-  FadConfig? sse_decode_opt_box_autoadd_fad_config(
-      SseDeserializer deserializer);
+  List<TagEditRecord> dco_decode_list_tag_edit_record(dynamic raw);
 
   @protected
-  SongMetadata? sse_decode_opt_box_autoadd_song_metadata(
-      SseDeserializer deserializer);
+  List<TitleRule> dco_decode_list_title_rule(dynamic raw);
 
   @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+  LrcDocument dco_decode_lrc_document(dynamic raw);
 
   @protected
-  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+  LrcLine dco_decode_lrc_line(dynamic raw);
 
   @protected
-  List<FadButton>? sse_decode_opt_list_fad_button(SseDeserializer deserializer);
+  LrcWord dco_decode_lrc_word(dynamic raw);
 
   @protected
-  List<FadLabel>? sse_decode_opt_list_fad_label(SseDeserializer deserializer);
+  LrclibLyrics dco_decode_lrclib_lyrics(dynamic raw);
 
   @protected
-  List<FadPopup>? sse_decode_opt_list_fad_popup(SseDeserializer deserializer);
+  LyricEvent dco_decode_lyric_event(dynamic raw);
 
   @protected
-  List<FadScreen>? sse_decode_opt_list_fad_screen(SseDeserializer deserializer);
+  LyricMatchLine dco_decode_lyric_match_line(dynamic raw);
 
   @protected
-  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+  LyricPosition dco_decode_lyric_position(dynamic raw);
 
   @protected
-  PlayerState sse_decode_player_state(SseDeserializer deserializer);
+  LyricSearchResult dco_decode_lyric_search_result(dynamic raw);
 
   @protected
-  (
-    String,
-    PluginInode
-  ) sse_decode_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
-      SseDeserializer deserializer);
+  LyricsSource dco_decode_lyrics_source(dynamic raw);
 
   @protected
-  (String, ConfigTypes) sse_decode_record_string_config_types(
-      SseDeserializer deserializer);
+  MatchedEntry dco_decode_matched_entry(dynamic raw);
 
   @protected
-  SongMetadata sse_decode_song_metadata(SseDeserializer deserializer);
+  MaterialScheme dco_decode_material_scheme(dynamic raw);
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  MissingEntry dco_decode_missing_entry(dynamic raw);
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+  Map<String, ConfigTypes>? dco_decode_opt_Map_String_config_types_None(
+      dynamic raw);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  String? dco_decode_opt_String(dynamic raw);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  AdiPluginMan? dco_decode_opt_box_autoadd_adi_plugin_man(dynamic raw);
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+  BookProgress? dco_decode_opt_box_autoadd_book_progress(dynamic raw);
 
   @protected
-  ValueStoreUpdate sse_decode_value_store_update(SseDeserializer deserializer);
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
 
   @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer);
+  Chapter? dco_decode_opt_box_autoadd_chapter(dynamic raw);
 
   @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin(
-          ArcMutexPlugin self, SseSerializer serializer);
+  DownloadJob? dco_decode_opt_box_autoadd_download_job(dynamic raw);
 
   @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
-          PluginInode self, SseSerializer serializer);
+  EnrichedMetadata? dco_decode_opt_box_autoadd_enriched_metadata(dynamic raw);
 
   @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
-          RpcConfig self, SseSerializer serializer);
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw);
 
   @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          Value self, SseSerializer serializer);
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
 
   @protected
-  void
+  FadConfig? dco_decode_opt_box_autoadd_fad_config(dynamic raw);
+
+  @protected
+  FoundLyrics? dco_decode_opt_box_autoadd_found_lyrics(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  LrcDocument? dco_decode_opt_box_autoadd_lrc_document(dynamic raw);
+
+  @protected
+  LyricPosition? dco_decode_opt_box_autoadd_lyric_position(dynamic raw);
+
+  @protected
+  Palette? dco_decode_opt_box_autoadd_palette(dynamic raw);
+
+  @protected
+  PipelineConfig? dco_decode_opt_box_autoadd_pipeline_config(dynamic raw);
+
+  @protected
+  PlaylistFormat? dco_decode_opt_box_autoadd_playlist_format(dynamic raw);
+
+  @protected
+  ReplayGain? dco_decode_opt_box_autoadd_replay_gain(dynamic raw);
+
+  @protected
+  SongMetadata? dco_decode_opt_box_autoadd_song_metadata(dynamic raw);
+
+  @protected
+  StreamMetadata? dco_decode_opt_box_autoadd_stream_metadata(dynamic raw);
+
+  @protected
+  Swatch? dco_decode_opt_box_autoadd_swatch(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw);
+
+  @protected
+  List<FadButton>? dco_decode_opt_list_fad_button(dynamic raw);
+
+  @protected
+  List<FadLabel>? dco_decode_opt_list_fad_label(dynamic raw);
+
+  @protected
+  List<FadPopup>? dco_decode_opt_list_fad_popup(dynamic raw);
+
+  @protected
+  List<FadScreen>? dco_decode_opt_list_fad_screen(dynamic raw);
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  OrganizePlan dco_decode_organize_plan(dynamic raw);
+
+  @protected
+  OrganizeResult dco_decode_organize_result(dynamic raw);
+
+  @protected
+  Palette dco_decode_palette(dynamic raw);
+
+  @protected
+  PipelineConfig dco_decode_pipeline_config(dynamic raw);
+
+  @protected
+  PipelineResult dco_decode_pipeline_result(dynamic raw);
+
+  @protected
+  PlannedMove dco_decode_planned_move(dynamic raw);
+
+  @protected
+  PlayStat dco_decode_play_stat(dynamic raw);
+
+  @protected
+  PlayerState dco_decode_player_state(dynamic raw);
+
+  @protected
+  PlaylistEntry dco_decode_playlist_entry(dynamic raw);
+
+  @protected
+  PlaylistExportResult dco_decode_playlist_export_result(dynamic raw);
+
+  @protected
+  PlaylistFormat dco_decode_playlist_format(dynamic raw);
+
+  @protected
+  PlaylistImportResult dco_decode_playlist_import_result(dynamic raw);
+
+  @protected
+  PlaylistInfo dco_decode_playlist_info(dynamic raw);
+
+  @protected
+  PlaylistSortKey dco_decode_playlist_sort_key(dynamic raw);
+
+  @protected
+  Podcast dco_decode_podcast(dynamic raw);
+
+  @protected
+  PodcastChapter dco_decode_podcast_chapter(dynamic raw);
+
+  @protected
+  PodcastEpisode dco_decode_podcast_episode(dynamic raw);
+
+  @protected
+  PodcastRefresh dco_decode_podcast_refresh(dynamic raw);
+
+  @protected
+  RadioStation dco_decode_radio_station(dynamic raw);
+
+  @protected
+  (
+    String,
+    PluginInode
+  ) dco_decode_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
+      dynamic raw);
+
+  @protected
+  (String, ConfigTypes) dco_decode_record_string_config_types(dynamic raw);
+
+  @protected
+  RemoteCandidate dco_decode_remote_candidate(dynamic raw);
+
+  @protected
+  ReplayGain dco_decode_replay_gain(dynamic raw);
+
+  @protected
+  SearchHit dco_decode_search_hit(dynamic raw);
+
+  @protected
+  SearchPage dco_decode_search_page(dynamic raw);
+
+  @protected
+  SongMetadata dco_decode_song_metadata(dynamic raw);
+
+  @protected
+  StreamMetadata dco_decode_stream_metadata(dynamic raw);
+
+  @protected
+  Swatch dco_decode_swatch(dynamic raw);
+
+  @protected
+  TagEdit dco_decode_tag_edit(dynamic raw);
+
+  @protected
+  TagEditRecord dco_decode_tag_edit_record(dynamic raw);
+
+  @protected
+  TagField dco_decode_tag_field(dynamic raw);
+
+  @protected
+  TitleRule dco_decode_title_rule(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
+  @protected
+  ValueStoreUpdate dco_decode_value_store_update(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  ArcMutexPlugin
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin(
+          SseDeserializer deserializer);
+
+  @protected
+  PluginInode
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
+          SseDeserializer deserializer);
+
+  @protected
+  RpcConfig
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
+          SseDeserializer deserializer);
+
+  @protected
+  Value
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
+          SseDeserializer deserializer);
+
+  @protected
+  ValueStoreUpdater
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+          SseDeserializer deserializer);
+
+  @protected
+  PluginInode
+      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
+          SseDeserializer deserializer);
+
+  @protected
+  RpcConfig
+      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
+          SseDeserializer deserializer);
+
+  @protected
+  ValueStoreUpdater
+      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+          SseDeserializer deserializer);
+
+  @protected
+  PluginInode
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
+          SseDeserializer deserializer);
+
+  @protected
+  RpcConfig
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
+          SseDeserializer deserializer);
+
+  @protected
+  ValueStoreUpdater
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+          SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_I128(SseDeserializer deserializer);
+
+  @protected
+  Map<String, PluginInode>
+      sse_decode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode_None(
+          SseDeserializer deserializer);
+
+  @protected
+  Map<String, ConfigTypes> sse_decode_Map_String_config_types_None(
+      SseDeserializer deserializer);
+
+  @protected
+  ArcMutexPlugin
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin(
+          SseDeserializer deserializer);
+
+  @protected
+  PluginInode
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
+          SseDeserializer deserializer);
+
+  @protected
+  RpcConfig
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
+          SseDeserializer deserializer);
+
+  @protected
+  Value
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
+          SseDeserializer deserializer);
+
+  @protected
+  ValueStoreUpdater
+      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+          SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<CollectionDownload>
+      sse_decode_StreamSink_collection_download_Sse(
+          SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<DownloadJob> sse_decode_StreamSink_download_job_Sse(
+      SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<EpisodeDownload> sse_decode_StreamSink_episode_download_Sse(
+      SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<IdentifyProgress> sse_decode_StreamSink_identify_progress_Sse(
+      SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<LyricEvent> sse_decode_StreamSink_lyric_event_Sse(
+      SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<SongMetadata> sse_decode_StreamSink_song_metadata_Sse(
+      SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<StreamMetadata> sse_decode_StreamSink_stream_metadata_Sse(
+      SseDeserializer deserializer);
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_U128(SseDeserializer deserializer);
+
+  @protected
+  AcoustIdCandidate sse_decode_acoust_id_candidate(
+      SseDeserializer deserializer);
+
+  @protected
+  AdiPluginMan sse_decode_adi_plugin_man(SseDeserializer deserializer);
+
+  @protected
+  ArtWriteResult sse_decode_art_write_result(SseDeserializer deserializer);
+
+  @protected
+  BookProgress sse_decode_book_progress(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  AcoustIdCandidate sse_decode_box_autoadd_acoust_id_candidate(
+      SseDeserializer deserializer);
+
+  @protected
+  AdiPluginMan sse_decode_box_autoadd_adi_plugin_man(
+      SseDeserializer deserializer);
+
+  @protected
+  BookProgress sse_decode_box_autoadd_book_progress(
+      SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  Chapter sse_decode_box_autoadd_chapter(SseDeserializer deserializer);
+
+  @protected
+  CollectionOptions sse_decode_box_autoadd_collection_options(
+      SseDeserializer deserializer);
+
+  @protected
+  ConfigTypes sse_decode_box_autoadd_config_types(SseDeserializer deserializer);
+
+  @protected
+  DownloadJob sse_decode_box_autoadd_download_job(SseDeserializer deserializer);
+
+  @protected
+  EnrichedMetadata sse_decode_box_autoadd_enriched_metadata(
+      SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  FadButton sse_decode_box_autoadd_fad_button(SseDeserializer deserializer);
+
+  @protected
+  FadConfig sse_decode_box_autoadd_fad_config(SseDeserializer deserializer);
+
+  @protected
+  FoundLyrics sse_decode_box_autoadd_found_lyrics(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  LrcDocument sse_decode_box_autoadd_lrc_document(SseDeserializer deserializer);
+
+  @protected
+  LyricPosition sse_decode_box_autoadd_lyric_position(
+      SseDeserializer deserializer);
+
+  @protected
+  Palette sse_decode_box_autoadd_palette(SseDeserializer deserializer);
+
+  @protected
+  PipelineConfig sse_decode_box_autoadd_pipeline_config(
+      SseDeserializer deserializer);
+
+  @protected
+  PlaylistFormat sse_decode_box_autoadd_playlist_format(
+      SseDeserializer deserializer);
+
+  @protected
+  PlaylistSortKey sse_decode_box_autoadd_playlist_sort_key(
+      SseDeserializer deserializer);
+
+  @protected
+  RadioStation sse_decode_box_autoadd_radio_station(
+      SseDeserializer deserializer);
+
+  @protected
+  ReplayGain sse_decode_box_autoadd_replay_gain(SseDeserializer deserializer);
+
+  @protected
+  SongMetadata sse_decode_box_autoadd_song_metadata(
+      SseDeserializer deserializer);
+
+  @protected
+  StreamMetadata sse_decode_box_autoadd_stream_metadata(
+      SseDeserializer deserializer);
+
+  @protected
+  Swatch sse_decode_box_autoadd_swatch(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  ValueStoreUpdate sse_decode_box_autoadd_value_store_update(
+      SseDeserializer deserializer);
+
+  @protected
+  Chapter sse_decode_chapter(SseDeserializer deserializer);
+
+  @protected
+  CollectionDownload sse_decode_collection_download(
+      SseDeserializer deserializer);
+
+  @protected
+  CollectionOptions sse_decode_collection_options(SseDeserializer deserializer);
+
+  @protected
+  CollectionTrack sse_decode_collection_track(SseDeserializer deserializer);
+
+  @protected
+  ConfigTypes sse_decode_config_types(SseDeserializer deserializer);
+
+  @protected
+  CueSheet sse_decode_cue_sheet(SseDeserializer deserializer);
+
+  @protected
+  CueTrack sse_decode_cue_track(SseDeserializer deserializer);
+
+  @protected
+  CurrentSongUpdate sse_decode_current_song_update(
+      SseDeserializer deserializer);
+
+  @protected
+  DownloadJob sse_decode_download_job(SseDeserializer deserializer);
+
+  @protected
+  DownloadState sse_decode_download_state(SseDeserializer deserializer);
+
+  @protected
+  DuplicateFile sse_decode_duplicate_file(SseDeserializer deserializer);
+
+  @protected
+  DuplicateGroup sse_decode_duplicate_group(SseDeserializer deserializer);
+
+  @protected
+  EnrichedMetadata sse_decode_enriched_metadata(SseDeserializer deserializer);
+
+  @protected
+  EpisodeDownload sse_decode_episode_download(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  FadButton sse_decode_fad_button(SseDeserializer deserializer);
+
+  @protected
+  FadConfig sse_decode_fad_config(SseDeserializer deserializer);
+
+  @protected
+  FadLabel sse_decode_fad_label(SseDeserializer deserializer);
+
+  @protected
+  FadPopup sse_decode_fad_popup(SseDeserializer deserializer);
+
+  @protected
+  FadScreen sse_decode_fad_screen(SseDeserializer deserializer);
+
+  @protected
+  FeedEpisode sse_decode_feed_episode(SseDeserializer deserializer);
+
+  @protected
+  FieldChange sse_decode_field_change(SseDeserializer deserializer);
+
+  @protected
+  FoundLyrics sse_decode_found_lyrics(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  IdentifyProgress sse_decode_identify_progress(SseDeserializer deserializer);
+
+  @protected
+  IdentifyResult sse_decode_identify_result(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<AcoustIdCandidate> sse_decode_list_acoust_id_candidate(
+      SseDeserializer deserializer);
+
+  @protected
+  List<ArtWriteResult> sse_decode_list_art_write_result(
+      SseDeserializer deserializer);
+
+  @protected
+  List<BookProgress> sse_decode_list_book_progress(
+      SseDeserializer deserializer);
+
+  @protected
+  List<Chapter> sse_decode_list_chapter(SseDeserializer deserializer);
+
+  @protected
+  List<CollectionDownload> sse_decode_list_collection_download(
+      SseDeserializer deserializer);
+
+  @protected
+  List<CollectionTrack> sse_decode_list_collection_track(
+      SseDeserializer deserializer);
+
+  @protected
+  List<CueTrack> sse_decode_list_cue_track(SseDeserializer deserializer);
+
+  @protected
+  List<DownloadJob> sse_decode_list_download_job(SseDeserializer deserializer);
+
+  @protected
+  List<DuplicateFile> sse_decode_list_duplicate_file(
+      SseDeserializer deserializer);
+
+  @protected
+  List<DuplicateGroup> sse_decode_list_duplicate_group(
+      SseDeserializer deserializer);
+
+  @protected
+  List<FadButton> sse_decode_list_fad_button(SseDeserializer deserializer);
+
+  @protected
+  List<FadLabel> sse_decode_list_fad_label(SseDeserializer deserializer);
+
+  @protected
+  List<FadPopup> sse_decode_list_fad_popup(SseDeserializer deserializer);
+
+  @protected
+  List<FadScreen> sse_decode_list_fad_screen(SseDeserializer deserializer);
+
+  @protected
+  List<FieldChange> sse_decode_list_field_change(SseDeserializer deserializer);
+
+  @protected
+  List<LrcLine> sse_decode_list_lrc_line(SseDeserializer deserializer);
+
+  @protected
+  List<LrcWord> sse_decode_list_lrc_word(SseDeserializer deserializer);
+
+  @protected
+  List<LrclibLyrics> sse_decode_list_lrclib_lyrics(
+      SseDeserializer deserializer);
+
+  @protected
+  List<LyricMatchLine> sse_decode_list_lyric_match_line(
+      SseDeserializer deserializer);
+
+  @protected
+  List<LyricSearchResult> sse_decode_list_lyric_search_result(
+      SseDeserializer deserializer);
+
+  @protected
+  List<MatchedEntry> sse_decode_list_matched_entry(
+      SseDeserializer deserializer);
+
+  @protected
+  List<MissingEntry> sse_decode_list_missing_entry(
+      SseDeserializer deserializer);
+
+  @protected
+  List<PipelineResult> sse_decode_list_pipeline_result(
+      SseDeserializer deserializer);
+
+  @protected
+  List<PlannedMove> sse_decode_list_planned_move(SseDeserializer deserializer);
+
+  @protected
+  List<PlaylistEntry> sse_decode_list_playlist_entry(
+      SseDeserializer deserializer);
+
+  @protected
+  List<PlaylistInfo> sse_decode_list_playlist_info(
+      SseDeserializer deserializer);
+
+  @protected
+  List<Podcast> sse_decode_list_podcast(SseDeserializer deserializer);
+
+  @protected
+  List<PodcastChapter> sse_decode_list_podcast_chapter(
+      SseDeserializer deserializer);
+
+  @protected
+  List<PodcastEpisode> sse_decode_list_podcast_episode(
+      SseDeserializer deserializer);
+
+  @protected
+  List<PodcastRefresh> sse_decode_list_podcast_refresh(
+      SseDeserializer deserializer);
+
+  @protected
+  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
+
+  @protected
+  Float64List sse_decode_list_prim_f_64_strict(SseDeserializer deserializer);
+
+  @protected
+  List<int> sse_decode_list_prim_u_32_loose(SseDeserializer deserializer);
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<RadioStation> sse_decode_list_radio_station(
+      SseDeserializer deserializer);
+
+  @protected
+  List<(String, PluginInode)>
+      sse_decode_list_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
+          SseDeserializer deserializer);
+
+  @protected
+  List<(String, ConfigTypes)> sse_decode_list_record_string_config_types(
+      SseDeserializer deserializer);
+
+  @protected
+  List<RemoteCandidate> sse_decode_list_remote_candidate(
+      SseDeserializer deserializer);
+
+  @protected
+  List<SearchHit> sse_decode_list_search_hit(SseDeserializer deserializer);
+
+  @protected
+  List<SongMetadata> sse_decode_list_song_metadata(
+      SseDeserializer deserializer);
+
+  @protected
+  List<Swatch> sse_decode_list_swatch(SseDeserializer deserializer);
+
+  @protected
+  List<TagEdit> sse_decode_list_tag_edit(SseDeserializer deserializer);
+
+  @protected
+  List<TagEditRecord> sse_decode_list_tag_edit_record(
+      SseDeserializer deserializer);
+
+  @protected
+  List<TitleRule> sse_decode_list_title_rule(SseDeserializer deserializer);
+
+  @protected
+  LrcDocument sse_decode_lrc_document(SseDeserializer deserializer);
+
+  @protected
+  LrcLine sse_decode_lrc_line(SseDeserializer deserializer);
+
+  @protected
+  LrcWord sse_decode_lrc_word(SseDeserializer deserializer);
+
+  @protected
+  LrclibLyrics sse_decode_lrclib_lyrics(SseDeserializer deserializer);
+
+  @protected
+  LyricEvent sse_decode_lyric_event(SseDeserializer deserializer);
+
+  @protected
+  LyricMatchLine sse_decode_lyric_match_line(SseDeserializer deserializer);
+
+  @protected
+  LyricPosition sse_decode_lyric_position(SseDeserializer deserializer);
+
+  @protected
+  LyricSearchResult sse_decode_lyric_search_result(
+      SseDeserializer deserializer);
+
+  @protected
+  LyricsSource sse_decode_lyrics_source(SseDeserializer deserializer);
+
+  @protected
+  MatchedEntry sse_decode_matched_entry(SseDeserializer deserializer);
+
+  @protected
+  MaterialScheme sse_decode_material_scheme(SseDeserializer deserializer);
+
+  @protected
+  MissingEntry sse_decode_missing_entry(SseDeserializer deserializer);
+
+  @protected
+  Map<String, ConfigTypes>? sse_decode_opt_Map_String_config_types_None(
+      SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  AdiPluginMan? sse_decode_opt_box_autoadd_adi_plugin_man(
+      SseDeserializer deserializer);
+
+  @protected
+  BookProgress? sse_decode_opt_box_autoadd_book_progress(
+      SseDeserializer deserializer);
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  Chapter? sse_decode_opt_box_autoadd_chapter(SseDeserializer deserializer);
+
+  @protected
+  DownloadJob? sse_decode_opt_box_autoadd_download_job(
+      SseDeserializer deserializer);
+
+  @protected
+  EnrichedMetadata? sse_decode_opt_box_autoadd_enriched_metadata(
+      SseDeserializer deserializer);
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer);
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  FadConfig? sse_decode_opt_box_autoadd_fad_config(
+      SseDeserializer deserializer);
+
+  @protected
+  FoundLyrics? sse_decode_opt_box_autoadd_found_lyrics(
+      SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  LrcDocument? sse_decode_opt_box_autoadd_lrc_document(
+      SseDeserializer deserializer);
+
+  @protected
+  LyricPosition? sse_decode_opt_box_autoadd_lyric_position(
+      SseDeserializer deserializer);
+
+  @protected
+  Palette? sse_decode_opt_box_autoadd_palette(SseDeserializer deserializer);
+
+  @protected
+  PipelineConfig? sse_decode_opt_box_autoadd_pipeline_config(
+      SseDeserializer deserializer);
+
+  @protected
+  PlaylistFormat? sse_decode_opt_box_autoadd_playlist_format(
+      SseDeserializer deserializer);
+
+  @protected
+  ReplayGain? sse_decode_opt_box_autoadd_replay_gain(
+      SseDeserializer deserializer);
+
+  @protected
+  SongMetadata? sse_decode_opt_box_autoadd_song_metadata(
+      SseDeserializer deserializer);
+
+  @protected
+  StreamMetadata? sse_decode_opt_box_autoadd_stream_metadata(
+      SseDeserializer deserializer);
+
+  @protected
+  Swatch? sse_decode_opt_box_autoadd_swatch(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<FadButton>? sse_decode_opt_list_fad_button(SseDeserializer deserializer);
+
+  @protected
+  List<FadLabel>? sse_decode_opt_list_fad_label(SseDeserializer deserializer);
+
+  @protected
+  List<FadPopup>? sse_decode_opt_list_fad_popup(SseDeserializer deserializer);
+
+  @protected
+  List<FadScreen>? sse_decode_opt_list_fad_screen(SseDeserializer deserializer);
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  OrganizePlan sse_decode_organize_plan(SseDeserializer deserializer);
+
+  @protected
+  OrganizeResult sse_decode_organize_result(SseDeserializer deserializer);
+
+  @protected
+  Palette sse_decode_palette(SseDeserializer deserializer);
+
+  @protected
+  PipelineConfig sse_decode_pipeline_config(SseDeserializer deserializer);
+
+  @protected
+  PipelineResult sse_decode_pipeline_result(SseDeserializer deserializer);
+
+  @protected
+  PlannedMove sse_decode_planned_move(SseDeserializer deserializer);
+
+  @protected
+  PlayStat sse_decode_play_stat(SseDeserializer deserializer);
+
+  @protected
+  PlayerState sse_decode_player_state(SseDeserializer deserializer);
+
+  @protected
+  PlaylistEntry sse_decode_playlist_entry(SseDeserializer deserializer);
+
+  @protected
+  PlaylistExportResult sse_decode_playlist_export_result(
+      SseDeserializer deserializer);
+
+  @protected
+  PlaylistFormat sse_decode_playlist_format(SseDeserializer deserializer);
+
+  @protected
+  PlaylistImportResult sse_decode_playlist_import_result(
+      SseDeserializer deserializer);
+
+  @protected
+  PlaylistInfo sse_decode_playlist_info(SseDeserializer deserializer);
+
+  @protected
+  PlaylistSortKey sse_decode_playlist_sort_key(SseDeserializer deserializer);
+
+  @protected
+  Podcast sse_decode_podcast(SseDeserializer deserializer);
+
+  @protected
+  PodcastChapter sse_decode_podcast_chapter(SseDeserializer deserializer);
+
+  @protected
+  PodcastEpisode sse_decode_podcast_episode(SseDeserializer deserializer);
+
+  @protected
+  PodcastRefresh sse_decode_podcast_refresh(SseDeserializer deserializer);
+
+  @protected
+  RadioStation sse_decode_radio_station(SseDeserializer deserializer);
+
+  @protected
+  (
+    String,
+    PluginInode
+  ) sse_decode_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
+      SseDeserializer deserializer);
+
+  @protected
+  (String, ConfigTypes) sse_decode_record_string_config_types(
+      SseDeserializer deserializer);
+
+  @protected
+  RemoteCandidate sse_decode_remote_candidate(SseDeserializer deserializer);
+
+  @protected
+  ReplayGain sse_decode_replay_gain(SseDeserializer deserializer);
+
+  @protected
+  SearchHit sse_decode_search_hit(SseDeserializer deserializer);
+
+  @protected
+  SearchPage sse_decode_search_page(SseDeserializer deserializer);
+
+  @protected
+  SongMetadata sse_decode_song_metadata(SseDeserializer deserializer);
+
+  @protected
+  StreamMetadata sse_decode_stream_metadata(SseDeserializer deserializer);
+
+  @protected
+  Swatch sse_decode_swatch(SseDeserializer deserializer);
+
+  @protected
+  TagEdit sse_decode_tag_edit(SseDeserializer deserializer);
+
+  @protected
+  TagEditRecord sse_decode_tag_edit_record(SseDeserializer deserializer);
+
+  @protected
+  TagField sse_decode_tag_field(SseDeserializer deserializer);
+
+  @protected
+  TitleRule sse_decode_title_rule(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  ValueStoreUpdate sse_decode_value_store_update(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+      AnyhowException self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin(
+          ArcMutexPlugin self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
+          PluginInode self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
+          RpcConfig self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
+          Value self, SseSerializer serializer);
+
+  @protected
+  void
       sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
           ValueStoreUpdater self, SseSerializer serializer);
 
@@ -643,105 +1582,267 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
           ValueStoreUpdater self, SseSerializer serializer);
 
   @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
-          PluginInode self, SseSerializer serializer);
+  void
+      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
+          PluginInode self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
+          RpcConfig self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+          ValueStoreUpdater self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_I128(BigInt self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode_None(
+          Map<String, PluginInode> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Map_String_config_types_None(
+      Map<String, ConfigTypes> self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin(
+          ArcMutexPlugin self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
+          PluginInode self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
+          RpcConfig self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
+          Value self, SseSerializer serializer);
+
+  @protected
+  void
+      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
+          ValueStoreUpdater self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_collection_download_Sse(
+      RustStreamSink<CollectionDownload> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_download_job_Sse(
+      RustStreamSink<DownloadJob> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_episode_download_Sse(
+      RustStreamSink<EpisodeDownload> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_identify_progress_Sse(
+      RustStreamSink<IdentifyProgress> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_lyric_event_Sse(
+      RustStreamSink<LyricEvent> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_song_metadata_Sse(
+      RustStreamSink<SongMetadata> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_stream_metadata_Sse(
+      RustStreamSink<StreamMetadata> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_U128(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_acoust_id_candidate(
+      AcoustIdCandidate self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_adi_plugin_man(AdiPluginMan self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_art_write_result(
+      ArtWriteResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_book_progress(BookProgress self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_acoust_id_candidate(
+      AcoustIdCandidate self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_adi_plugin_man(
+      AdiPluginMan self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_book_progress(
+      BookProgress self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_chapter(Chapter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_collection_options(
+      CollectionOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_config_types(
+      ConfigTypes self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_download_job(
+      DownloadJob self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_enriched_metadata(
+      EnrichedMetadata self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_fad_button(
+      FadButton self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_fad_config(
+      FadConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_found_lyrics(
+      FoundLyrics self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+      PlatformInt64 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_lrc_document(
+      LrcDocument self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_lyric_position(
+      LyricPosition self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_palette(Palette self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_pipeline_config(
+      PipelineConfig self, SseSerializer serializer);
 
   @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
-          RpcConfig self, SseSerializer serializer);
+  void sse_encode_box_autoadd_playlist_format(
+      PlaylistFormat self, SseSerializer serializer);
 
   @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
-          ValueStoreUpdater self, SseSerializer serializer);
+  void sse_encode_box_autoadd_playlist_sort_key(
+      PlaylistSortKey self, SseSerializer serializer);
 
   @protected
-  void sse_encode_I128(BigInt self, SseSerializer serializer);
+  void sse_encode_box_autoadd_radio_station(
+      RadioStation self, SseSerializer serializer);
 
   @protected
-  void
-      sse_encode_Map_String_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode_None(
-          Map<String, PluginInode> self, SseSerializer serializer);
+  void sse_encode_box_autoadd_replay_gain(
+      ReplayGain self, SseSerializer serializer);
 
   @protected
-  void sse_encode_Map_String_config_types_None(
-      Map<String, ConfigTypes> self, SseSerializer serializer);
+  void sse_encode_box_autoadd_song_metadata(
+      SongMetadata self, SseSerializer serializer);
 
   @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerArcMutexPlugin(
-          ArcMutexPlugin self, SseSerializer serializer);
+  void sse_encode_box_autoadd_stream_metadata(
+      StreamMetadata self, SseSerializer serializer);
 
   @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPluginInode(
-          PluginInode self, SseSerializer serializer);
+  void sse_encode_box_autoadd_swatch(Swatch self, SseSerializer serializer);
 
   @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRpcConfig(
-          RpcConfig self, SseSerializer serializer);
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
   @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          Value self, SseSerializer serializer);
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
 
   @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValueStoreUpdater(
-          ValueStoreUpdater self, SseSerializer serializer);
+  void sse_encode_box_autoadd_value_store_update(
+      ValueStoreUpdate self, SseSerializer serializer);
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_chapter(Chapter self, SseSerializer serializer);
 
   @protected
-  void sse_encode_U128(BigInt self, SseSerializer serializer);
+  void sse_encode_collection_download(
+      CollectionDownload self, SseSerializer serializer);
 
   @protected
-  void sse_encode_adi_plugin_man(AdiPluginMan self, SseSerializer serializer);
+  void sse_encode_collection_options(
+      CollectionOptions self, SseSerializer serializer);
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_collection_track(
+      CollectionTrack self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_adi_plugin_man(
-      AdiPluginMan self, SseSerializer serializer);
+  void sse_encode_config_types(ConfigTypes self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+  void sse_encode_cue_sheet(CueSheet self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_config_types(
-      ConfigTypes self, SseSerializer serializer);
+  void sse_encode_cue_track(CueTrack self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_fad_button(
-      FadButton self, SseSerializer serializer);
+  void sse_encode_current_song_update(
+      CurrentSongUpdate self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_fad_config(
-      FadConfig self, SseSerializer serializer);
+  void sse_encode_download_job(DownloadJob self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_song_metadata(
-      SongMetadata self, SseSerializer serializer);
+  void sse_encode_download_state(DownloadState self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+  void sse_encode_duplicate_file(DuplicateFile self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_value_store_update(
-      ValueStoreUpdate self, SseSerializer serializer);
+  void sse_encode_duplicate_group(
+      DuplicateGroup self, SseSerializer serializer);
 
   @protected
-  void sse_encode_config_types(ConfigTypes self, SseSerializer serializer);
+  void sse_encode_enriched_metadata(
+      EnrichedMetadata self, SseSerializer serializer);
 
   @protected
-  void sse_encode_current_song_update(
-      CurrentSongUpdate self, SseSerializer serializer);
+  void sse_encode_episode_download(
+      EpisodeDownload self, SseSerializer serializer);
 
   @protected
   void sse_encode_f_32(double self, SseSerializer serializer);
@@ -764,12 +1865,70 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_fad_screen(FadScreen self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_feed_episode(FeedEpisode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_field_change(FieldChange self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_found_lyrics(FoundLyrics self, SseSerializer serializer);
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_identify_progress(
+      IdentifyProgress self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_identify_result(
+      IdentifyResult self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_acoust_id_candidate(
+      List<AcoustIdCandidate> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_art_write_result(
+      List<ArtWriteResult> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_book_progress(
+      List<BookProgress> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_chapter(List<Chapter> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_collection_download(
+      List<CollectionDownload> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_collection_track(
+      List<CollectionTrack> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_cue_track(List<CueTrack> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_download_job(
+      List<DownloadJob> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_duplicate_file(
+      List<DuplicateFile> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_duplicate_group(
+      List<DuplicateGroup> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_fad_button(
       List<FadButton> self, SseSerializer serializer);
@@ -784,6 +1943,67 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_list_fad_screen(
       List<FadScreen> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_field_change(
+      List<FieldChange> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_lrc_line(List<LrcLine> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_lrc_word(List<LrcWord> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_lrclib_lyrics(
+      List<LrclibLyrics> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_lyric_match_line(
+      List<LyricMatchLine> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_lyric_search_result(
+      List<LyricSearchResult> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_matched_entry(
+      List<MatchedEntry> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_missing_entry(
+      List<MissingEntry> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_pipeline_result(
+      List<PipelineResult> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_planned_move(
+      List<PlannedMove> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_playlist_entry(
+      List<PlaylistEntry> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_playlist_info(
+      List<PlaylistInfo> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_podcast(List<Podcast> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_podcast_chapter(
+      List<PodcastChapter> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_podcast_episode(
+      List<PodcastEpisode> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_podcast_refresh(
+      List<PodcastRefresh> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_f_32_strict(
       Float32List self, SseSerializer serializer);
@@ -792,6 +2012,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_list_prim_f_64_strict(
       Float64List self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_prim_u_32_loose(
+      List<int> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
 
@@ -799,6 +2023,10 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_list_prim_u_8_strict(
       Uint8List self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_radio_station(
+      List<RadioStation> self, SseSerializer serializer);
+
   @protected
   void
       sse_encode_list_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
@@ -808,10 +2036,71 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_list_record_string_config_types(
       List<(String, ConfigTypes)> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_remote_candidate(
+      List<RemoteCandidate> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_search_hit(
+      List<SearchHit> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_song_metadata(
       List<SongMetadata> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_swatch(List<Swatch> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_tag_edit(List<TagEdit> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_tag_edit_record(
+      List<TagEditRecord> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_title_rule(
+      List<TitleRule> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_lrc_document(LrcDocument self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_lrc_line(LrcLine self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_lrc_word(LrcWord self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_lrclib_lyrics(LrclibLyrics self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_lyric_event(LyricEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_lyric_match_line(
+      LyricMatchLine self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_lyric_position(LyricPosition self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_lyric_search_result(
+      LyricSearchResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_lyrics_source(LyricsSource self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_matched_entry(MatchedEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_material_scheme(
+      MaterialScheme self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_missing_entry(MissingEntry self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_Map_String_config_types_None(
       Map<String, ConfigTypes>? self, SseSerializer serializer);
@@ -823,20 +2112,88 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_opt_box_autoadd_adi_plugin_man(
       AdiPluginMan? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_book_progress(
+      BookProgress? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_chapter(
+      Chapter? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_download_job(
+      DownloadJob? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_enriched_metadata(
+      EnrichedMetadata? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_fad_config(
       FadConfig? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_found_lyrics(
+      FoundLyrics? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+      PlatformInt64? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_lrc_document(
+      LrcDocument? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_lyric_position(
+      LyricPosition? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_palette(
+      Palette? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_pipeline_config(
+      PipelineConfig? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_playlist_format(
+      PlaylistFormat? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_replay_gain(
+      ReplayGain? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_song_metadata(
       SongMetadata? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_stream_metadata(
+      StreamMetadata? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_swatch(
+      Swatch? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
 
@@ -860,9 +2217,73 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_opt_list_prim_u_8_strict(
       Uint8List? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_organize_plan(OrganizePlan self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_organize_result(
+      OrganizeResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_palette(Palette self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pipeline_config(
+      PipelineConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pipeline_result(
+      PipelineResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_planned_move(PlannedMove self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_play_stat(PlayStat self, SseSerializer serializer);
+
   @protected
   void sse_encode_player_state(PlayerState self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_playlist_entry(PlaylistEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_playlist_export_result(
+      PlaylistExportResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_playlist_format(
+      PlaylistFormat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_playlist_import_result(
+      PlaylistImportResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_playlist_info(PlaylistInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_playlist_sort_key(
+      PlaylistSortKey self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_podcast(Podcast self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_podcast_chapter(
+      PodcastChapter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_podcast_episode(
+      PodcastEpisode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_podcast_refresh(
+      PodcastRefresh self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_radio_station(RadioStation self, SseSerializer serializer);
+
   @protected
   void
       sse_encode_record_string_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_plugin_inode(
@@ -872,9 +2293,41 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_record_string_config_types(
       (String, ConfigTypes) self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_remote_candidate(
+      RemoteCandidate self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_replay_gain(ReplayGain self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_search_hit(SearchHit self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_search_page(SearchPage self, SseSerializer serializer);
+
   @protected
   void sse_encode_song_metadata(SongMetadata self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_stream_metadata(
+      StreamMetadata self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_swatch(Swatch self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tag_edit(TagEdit self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tag_edit_record(TagEditRecord self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tag_field(TagField self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_title_rule(TitleRule self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
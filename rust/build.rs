@@ -2,9 +2,12 @@ use dotenvy::dotenv_iter;
 
 fn main() {
     println!("cargo:rerun-if-changed=.env");
-    
-    for item in dotenv_iter().unwrap() {
-        let (key, value) = item.unwrap();
-        println!("cargo:rustc-env={key}={value}");
+
+    // The .env is optional, without it the AcoustID key has to be set at runtime
+    if let Ok(iter) = dotenv_iter() {
+        for item in iter.flatten() {
+            let (key, value) = item;
+            println!("cargo:rustc-env={key}={value}");
+        }
     }
 }
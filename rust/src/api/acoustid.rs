@@ -1,36 +1,226 @@
-use crate::api::music_handler::SongMetadata;
+use crate::{
+    api::{
+        fingerprint::cached_fingerprint,
+        music_handler::SongMetadata,
+        musicbrainz::enrich_candidate,
+        utils::RateLimiter,
+        value_store::{DEFAULT_ACOUSTID_BASE_URL, with_store},
+    },
+    frb_generated::StreamSink,
+};
 use anyhow::{Context, bail};
 use base64::{Engine as _, engine::general_purpose};
+use once_cell::sync::Lazy;
+use rayon::{ThreadPoolBuilder, prelude::*};
 use rodio::source::Source;
 use rusty_chromaprint::{Configuration, FingerprintCompressor, Fingerprinter};
 use serde::Deserialize;
-use std::path::Path;
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, AtomicU32, Ordering},
+    },
+    thread,
+    time::Duration,
+};
+
+// AcoustID allows at most 3 requests per second per client key
+const ACOUSTID_REQUEST_INTERVAL: Duration = Duration::from_millis(334);
+const DEFAULT_WORKERS: u32 = 4;
+
+static ACOUSTID_CLIENT: Lazy<reqwest::blocking::Client> = Lazy::new(|| {
+    reqwest::blocking::Client::builder()
+        .timeout(Duration::from_secs(30))
+        .build()
+        .unwrap_or_default()
+});
+static ACOUSTID_LIMITER: Lazy<RateLimiter> =
+    Lazy::new(|| RateLimiter::new(ACOUSTID_REQUEST_INTERVAL));
+static IDENTIFY_BATCHES: Lazy<Mutex<IdentifyBatches>> = Lazy::new(|| {
+    Mutex::new(IdentifyBatches {
+        next_id: 1,
+        cancels: HashMap::new(),
+        sinks: Vec::new(),
+    })
+});
+
+struct IdentifyBatches {
+    next_id: u64,
+    // The cancel flag of every batch that is still running
+    cancels: HashMap<u64, Arc<AtomicBool>>,
+    sinks: Vec<StreamSink<IdentifyProgress>>,
+}
+
+// A possible match for a file, the metadata path is set to the identified file so it can be passed
+// straight to write_meta once the user picks one
+#[derive(Debug, Clone)]
+pub struct AcoustIdCandidate {
+    pub score: f64,
+    pub recording_id: Option<String>,
+    pub metadata: SongMetadata,
+}
+
+#[derive(Debug, Clone)]
+pub struct IdentifyResult {
+    pub path: String,
+    pub candidates: Vec<AcoustIdCandidate>,
+    pub error: Option<String>,
+}
+
+// Sent once per file as the batch progresses
+#[derive(Debug, Clone)]
+pub struct IdentifyProgress {
+    pub batch_id: u64,
+    pub completed: u32,
+    pub total: u32,
+    pub result: IdentifyResult,
+}
+
+// Resolves the key and base url to use, preferring whatever is in the value store and falling
+// back to the compiled in key (if the build had one) and the public AcoustID server
+fn acoustid_endpoint() -> anyhow::Result<(String, String)> {
+    let (key, base_url) = with_store(|store| {
+        (
+            store.acoustid_api_key.clone(),
+            store.acoustid_base_url.clone(),
+        )
+    })
+    .unwrap_or((None, DEFAULT_ACOUSTID_BASE_URL.to_string()));
+    let key = key
+        .or_else(|| envcrypt::option_envc!("ACOUSTID_API").map(|k| k.to_string()))
+        .context("No AcoustID API key set, add one in the settings")?;
+    Ok((key, base_url))
+}
+
+// Whether a lookup can be attempted at all, lets the UI hide AcoustID features without a key
+pub fn has_acoustid_key() -> bool {
+    acoustid_endpoint().is_ok()
+}
 
 // The main lookup function
-fn lookup_rs(path: impl AsRef<Path> + ToString) -> Option<SongMetadata> {
+fn lookup_rs(
+    path: impl AsRef<Path> + ToString,
+    top_n: usize,
+) -> anyhow::Result<Vec<AcoustIdCandidate>> {
     let pref = path.as_ref();
-    let f = match calc_fingerprint(pref) {
-        Ok(f) => f,
-        Err(e) => {
-            eprintln!("{e}");
-            return None;
-        }
-    };
+    let f = cached_fingerprint(pref)?;
     let d = get_duration(pref);
-    match lookup_metadata(f.as_slice(), d, path) {
-        Ok(sm) => sm,
+    ACOUSTID_LIMITER.wait();
+    lookup_metadata(f.as_slice(), d, path, top_n)
+}
+
+// The best match filled in by MusicBrainz, which is where the genre and cover art come from. When
+// MusicBrainz or the Cover Art Archive fail the AcoustID match is returned as it is
+pub fn lookup(path: String) -> Result<Option<SongMetadata>, String> {
+    let Some(candidate) = lookup_candidates(path, 1)?.into_iter().next() else {
+        return Ok(None);
+    };
+    // Without a recording id there is nothing to look up
+    if candidate.recording_id.is_none() {
+        return Ok(Some(candidate.metadata));
+    }
+    let metadata = candidate.metadata.clone();
+    match enrich_candidate(candidate) {
+        Ok(enriched) => Ok(Some(enriched.metadata)),
         Err(e) => {
-            eprintln!("{e}");
-            None
+            println!(
+                "Failed to enrich the AcoustID match for {}: {e}",
+                metadata.path
+            );
+            Ok(Some(metadata))
         }
     }
 }
 
-pub fn lookup(path: String) -> Option<SongMetadata> {
-    lookup_rs(path)
+// Returns up to top_n matches for a single file, best first
+pub fn lookup_candidates(path: String, top_n: u32) -> Result<Vec<AcoustIdCandidate>, String> {
+    lookup_rs(path, top_n.max(1) as usize).map_err(|e| format!("{e:#}"))
+}
+
+fn emit_progress(progress: IdentifyProgress) {
+    let mut batches = IDENTIFY_BATCHES.lock().unwrap();
+    batches
+        .sinks
+        .retain(|sink| sink.add(progress.clone()).is_ok());
+}
+
+// Identifies every path on a pool of workers in the background and returns the batch's id. Each
+// file's candidates are sent to watch_identify as soon as it is done. Requests are rate limited
+// across all workers (and batches) so large batches are safe.
+pub fn identify_batch(paths: Vec<String>, top_n: u32, workers: Option<u32>) -> Result<u64, String> {
+    let total = paths.len() as u32;
+    let top_n = top_n.max(1) as usize;
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(workers.unwrap_or(DEFAULT_WORKERS).max(1) as usize)
+        .build()
+        .map_err(|e| format!("Failed to build identify worker pool: {e}"))?;
+
+    let cancel = Arc::new(AtomicBool::new(false));
+    let id = {
+        let mut batches = IDENTIFY_BATCHES.lock().unwrap();
+        let id = batches.next_id;
+        batches.next_id += 1;
+        batches.cancels.insert(id, cancel.clone());
+        id
+    };
+
+    thread::spawn(move || {
+        let completed = AtomicU32::new(0);
+        pool.install(|| {
+            paths.into_par_iter().for_each(|path| {
+                let result = if cancel.load(Ordering::SeqCst) {
+                    IdentifyResult {
+                        path,
+                        candidates: Vec::new(),
+                        error: Some("Identification cancelled".to_string()),
+                    }
+                } else {
+                    match lookup_rs(path.clone(), top_n) {
+                        Ok(candidates) => IdentifyResult {
+                            path,
+                            candidates,
+                            error: None,
+                        },
+                        Err(e) => IdentifyResult {
+                            path,
+                            candidates: Vec::new(),
+                            error: Some(format!("{e:#}")),
+                        },
+                    }
+                };
+                emit_progress(IdentifyProgress {
+                    batch_id: id,
+                    completed: completed.fetch_add(1, Ordering::SeqCst) + 1,
+                    total,
+                    result,
+                });
+            })
+        });
+        IDENTIFY_BATCHES.lock().unwrap().cancels.remove(&id);
+    });
+    Ok(id)
+}
+
+// Receives the progress of every batch, IdentifyProgress.batch_id tells them apart
+pub fn watch_identify(sink: StreamSink<IdentifyProgress>) {
+    IDENTIFY_BATCHES.lock().unwrap().sinks.push(sink);
+}
+
+// Only stops the batch with this id. Returns false when it does not exist or has already finished
+pub fn cancel_identify(batch_id: u64) -> bool {
+    match IDENTIFY_BATCHES.lock().unwrap().cancels.get(&batch_id) {
+        Some(cancel) => {
+            cancel.store(true, Ordering::SeqCst);
+            true
+        }
+        None => false,
+    }
 }
 
-fn calc_fingerprint(path: impl AsRef<Path>) -> anyhow::Result<Vec<u32>> {
+#[flutter_rust_bridge::frb(ignore)]
+pub fn calc_fingerprint(path: impl AsRef<Path>) -> anyhow::Result<Vec<u32>> {
     let path = path.as_ref();
     let src = std::fs::File::open(path).context("Failed to open file")?;
     let mss = symphonia::core::io::MediaSourceStream::new(Box::new(src), Default::default());
@@ -105,7 +295,8 @@ fn calc_fingerprint(path: impl AsRef<Path>) -> anyhow::Result<Vec<u32>> {
     Ok(printer.fingerprint().to_vec())
 }
 
-fn get_duration(path: impl AsRef<Path>) -> u64 {
+#[flutter_rust_bridge::frb(ignore)]
+pub fn get_duration(path: impl AsRef<Path>) -> u64 {
     // Extract duration (fallback to 0 if decoding fails)
     if let Ok(file) = std::fs::File::open(path) {
         rodio::Decoder::try_from(file)
@@ -119,6 +310,7 @@ fn get_duration(path: impl AsRef<Path>) -> u64 {
 
 #[derive(Debug, Deserialize, Clone)]
 struct AcoustIdRecording {
+    id: Option<String>,
     title: Option<String>,
     artists: Option<Vec<AcoustIdArtist>>,
     duration: Option<f64>,
@@ -150,22 +342,20 @@ fn lookup_metadata(
     fingerprint: &[u32],
     duration_secs: u64,
     path: impl AsRef<Path> + ToString,
-) -> anyhow::Result<Option<SongMetadata>> {
+    top_n: usize,
+) -> anyhow::Result<Vec<AcoustIdCandidate>> {
     let compressed_fingerprint =
         FingerprintCompressor::from(&Configuration::default()).compress(fingerprint);
 
     let fingerprint_string = general_purpose::URL_SAFE_NO_PAD.encode(&compressed_fingerprint);
 
+    let (key, base_url) = acoustid_endpoint()?;
     let url = format!(
-        "https://api.acoustid.org/v2/lookup?client={}&duration={}&fingerprint={}&meta=recordings+releasegroups+compress",
-        envcrypt::envc!("ACOUSTID_API"),
-        duration_secs,
-        fingerprint_string
+        "{}/lookup?client={}&duration={}&fingerprint={}&meta=recordings+releasegroups+compress",
+        base_url, key, duration_secs, fingerprint_string
     );
 
-    let client: reqwest::blocking::Client = reqwest::blocking::Client::new();
-
-    let response = client
+    let response = ACOUSTID_CLIENT
         .get(&url)
         .send()
         .context("Failed to send request to AcoustID")?;
@@ -183,31 +373,43 @@ fn lookup_metadata(
         .json()
         .context("Failed to parse AcoustID response")?;
 
-    // Extract the best match (highest score)
-    let best_result = acoustid_response
-        .results
-        .into_iter()
-        .max_by(|a, b| a.score.partial_cmp(&b.score).unwrap());
-
-    let Some(result) = best_result else {
-        return Ok(None);
-    };
+    let mut results = acoustid_response.results;
+    results.sort_by(|a, b| b.score.total_cmp(&a.score));
 
-    let Some(recordings) = result.recordings else {
-        return Ok(None);
-    };
+    let path = path.to_string();
+    let mut seen = HashSet::new();
+    let mut candidates = Vec::new();
 
-    // Take the first recording with available data
-    let recording = recordings
-        .clone()
-        .into_iter()
-        .find(|rec| rec.title.is_some() && rec.artists.is_some())
-        .or_else(|| recordings.into_iter().next());
+    // Walk results best first, skipping recordings another (better) result already gave us
+    for result in results {
+        for recording in result.recordings.unwrap_or_default() {
+            if recording.title.is_none() && recording.artists.is_none() {
+                continue;
+            }
+            if let Some(id) = &recording.id
+                && !seen.insert(id.clone())
+            {
+                continue;
+            }
+            candidates.push(AcoustIdCandidate {
+                score: result.score,
+                recording_id: recording.id.clone(),
+                metadata: recording_to_metadata(recording, duration_secs, path.clone()),
+            });
+            if candidates.len() >= top_n {
+                return Ok(candidates);
+            }
+        }
+    }
 
-    let Some(recording) = recording else {
-        return Ok(None);
-    };
+    Ok(candidates)
+}
 
+fn recording_to_metadata(
+    recording: AcoustIdRecording,
+    duration_secs: u64,
+    path: String,
+) -> SongMetadata {
     let artist = recording
         .artists
         .and_then(|artists| artists.into_iter().next())
@@ -220,7 +422,7 @@ fn lookup_metadata(
         .map(|group| group.title)
         .unwrap_or_else(|| "Unknown Album".to_string());
 
-    Ok(Some(SongMetadata {
+    SongMetadata {
         title: recording
             .title
             .unwrap_or_else(|| "Unknown Title".to_string()),
@@ -230,9 +432,9 @@ fn lookup_metadata(
             .duration
             .map(|d| d as u64)
             .unwrap_or(duration_secs),
-        path: path.to_string(),
+        path,
         // Following 2 fields are never provided
         album_art: None,
         genre: "Unknown Genre".to_string(),
-    }))
+    }
 }
@@ -0,0 +1,312 @@
+use crate::api::utils::get_cache_dir;
+use anyhow::Context;
+use audiotags::Tag;
+use once_cell::sync::Lazy;
+use sha2::{Digest, Sha256};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    io::Cursor,
+    path::{Path, PathBuf},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::SystemTime,
+};
+
+// Requested sizes get snapped up to one of these so each cover only ever has a few thumbnails
+pub const THUMBNAIL_SIZES: [u32; 3] = [64, 256, 640];
+const DEFAULT_MEMORY_LIMIT: u64 = 64 * 1024 * 1024;
+const THUMBNAIL_QUALITY: u8 = 85;
+// Checked in this order, the first one found in the track's folder wins
+const FOLDER_ART_NAMES: [&str; 5] = ["cover", "folder", "front", "album", "albumart"];
+const FOLDER_ART_EXTENSIONS: [&str; 4] = ["jpg", "jpeg", "png", "webp"];
+
+// Content hash and thumbnail size (0 for the original), so an album's cover is only held once
+type ArtKey = (String, u32);
+// Track path to (size, modified time, art hash) so tags are not reread for unchanged files
+type ArtIndex = HashMap<PathBuf, (u64, SystemTime, Option<String>)>;
+
+static MEMORY_LIMIT: AtomicU64 = AtomicU64::new(DEFAULT_MEMORY_LIMIT);
+static ART_MEMORY: Lazy<Mutex<ArtLru>> = Lazy::new(|| Mutex::new(ArtLru::default()));
+static ART_INDEX: Lazy<Mutex<ArtIndex>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Default)]
+struct ArtLru {
+    entries: HashMap<ArtKey, (Arc<Vec<u8>>, u64)>,
+    // Last use tick to key, the first entry is always the least recently used one
+    order: BTreeMap<u64, ArtKey>,
+    tick: u64,
+    bytes: u64,
+}
+
+impl ArtLru {
+    fn get(&mut self, key: &ArtKey) -> Option<Arc<Vec<u8>>> {
+        self.tick += 1;
+        let tick = self.tick;
+        let (data, last_used) = self.entries.get_mut(key)?;
+        self.order.remove(last_used);
+        *last_used = tick;
+        self.order.insert(tick, key.clone());
+        Some(Arc::clone(data))
+    }
+
+    fn insert(&mut self, key: ArtKey, data: Arc<Vec<u8>>) {
+        let limit = MEMORY_LIMIT.load(Ordering::Relaxed);
+        // Something bigger than the whole budget would just evict everything else for nothing
+        if data.len() as u64 > limit {
+            return;
+        }
+        self.remove(&key);
+        self.tick += 1;
+        self.bytes += data.len() as u64;
+        self.order.insert(self.tick, key.clone());
+        self.entries.insert(key, (data, self.tick));
+        self.evict(limit);
+    }
+
+    fn remove(&mut self, key: &ArtKey) {
+        if let Some((data, last_used)) = self.entries.remove(key) {
+            self.order.remove(&last_used);
+            self.bytes -= data.len() as u64;
+        }
+    }
+
+    fn evict(&mut self, limit: u64) {
+        while self.bytes > limit {
+            let Some((_, key)) = self.order.pop_first() else {
+                break;
+            };
+            if let Some((data, _)) = self.entries.remove(&key) {
+                self.bytes -= data.len() as u64;
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+        self.bytes = 0;
+    }
+}
+
+fn get_art_cache_dir() -> PathBuf {
+    get_cache_dir("art")
+}
+
+fn hash_bytes(data: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(data))
+}
+
+fn original_path(hash: &str) -> PathBuf {
+    get_art_cache_dir().join(hash)
+}
+
+fn thumbnail_path(hash: &str, size: u32) -> PathBuf {
+    get_art_cache_dir().join(format!("{hash}_{size}.jpg"))
+}
+
+// Picks the smallest thumbnail that is at least as big as what was asked for, 0 means the original
+fn snap_size(size: u32) -> u32 {
+    if size == 0 {
+        return 0;
+    }
+    THUMBNAIL_SIZES
+        .iter()
+        .copied()
+        .find(|s| *s >= size)
+        .unwrap_or(0)
+}
+
+fn find_folder_art(track: &Path) -> Option<PathBuf> {
+    let dir = track.parent()?;
+    let files: Vec<PathBuf> = fs::read_dir(dir)
+        .ok()?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_file())
+        .collect();
+
+    FOLDER_ART_NAMES.iter().find_map(|name| {
+        files
+            .iter()
+            .find(|p| {
+                let stem = p.file_stem().map(|s| s.to_string_lossy().to_lowercase());
+                let ext = p.extension().map(|e| e.to_string_lossy().to_lowercase());
+                stem.as_deref() == Some(*name)
+                    && ext
+                        .as_deref()
+                        .is_some_and(|e| FOLDER_ART_EXTENSIONS.contains(&e))
+            })
+            .cloned()
+    })
+}
+
+// Stores art under its content hash in memory and on disk, identical covers share one copy
+fn store_original(data: Vec<u8>) -> (String, Arc<Vec<u8>>) {
+    let hash = hash_bytes(&data);
+    let key = (hash.clone(), 0);
+    let mut memory = ART_MEMORY.lock().unwrap();
+    if let Some(existing) = memory.get(&key) {
+        return (hash, existing);
+    }
+    let path = original_path(&hash);
+    if !path.exists() {
+        // Without the disk copy thumbnails just get made from memory or the track again
+        let _ = fs::write(&path, &data);
+    }
+    let data = Arc::new(data);
+    memory.insert(key, Arc::clone(&data));
+    (hash, data)
+}
+
+fn has_original(hash: &str) -> bool {
+    ART_MEMORY
+        .lock()
+        .unwrap()
+        .entries
+        .contains_key(&(hash.to_string(), 0))
+        || original_path(hash).exists()
+}
+
+fn load_original(hash: &str) -> Option<Arc<Vec<u8>>> {
+    let key = (hash.to_string(), 0);
+    if let Some(data) = ART_MEMORY.lock().unwrap().get(&key) {
+        return Some(data);
+    }
+    let data = Arc::new(fs::read(original_path(hash)).ok()?);
+    ART_MEMORY.lock().unwrap().insert(key, Arc::clone(&data));
+    Some(data)
+}
+
+fn make_thumbnail(original: &[u8], size: u32) -> anyhow::Result<Vec<u8>> {
+    let img = image::load_from_memory(original).context("Failed to decode album art")?;
+    let thumb = img.thumbnail(size, size).to_rgb8();
+    let mut out = Cursor::new(Vec::new());
+    image::codecs::jpeg::JpegEncoder::new_with_quality(&mut out, THUMBNAIL_QUALITY)
+        .encode_image(&thumb)
+        .context("Failed to encode thumbnail")?;
+    Ok(out.into_inner())
+}
+
+fn load_thumbnail(hash: &str, size: u32) -> Option<Arc<Vec<u8>>> {
+    let key = (hash.to_string(), size);
+    if let Some(data) = ART_MEMORY.lock().unwrap().get(&key) {
+        return Some(data);
+    }
+
+    let path = thumbnail_path(hash, size);
+    let data = match fs::read(&path) {
+        Ok(data) => data,
+        Err(_) => {
+            let original = load_original(hash)?;
+            // Scaling up only makes the file bigger so small covers are returned as they are
+            if let Ok((w, h)) = image::ImageReader::new(Cursor::new(original.as_slice()))
+                .with_guessed_format()
+                .map_err(anyhow::Error::from)
+                .and_then(|r| r.into_dimensions().map_err(anyhow::Error::from))
+                && w.max(h) <= size
+            {
+                return Some(original);
+            }
+            let thumb = match make_thumbnail(&original, size) {
+                Ok(thumb) => thumb,
+                Err(e) => {
+                    eprintln!("Failed to make a {size}px thumbnail for {hash}: {e}");
+                    return Some(original);
+                }
+            };
+            let _ = fs::write(&path, &thumb);
+            thumb
+        }
+    };
+
+    let data = Arc::new(data);
+    ART_MEMORY.lock().unwrap().insert(key, Arc::clone(&data));
+    Some(data)
+}
+
+fn index_lookup(path: &Path) -> Option<Option<String>> {
+    let meta = fs::metadata(path).ok()?;
+    let modified = meta.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+    match ART_INDEX.lock().unwrap().get(path) {
+        Some((len, mtime, hash)) if *len == meta.len() && *mtime == modified => Some(hash.clone()),
+        _ => None,
+    }
+}
+
+fn index_insert(path: &Path, hash: Option<String>) {
+    if let Ok(meta) = fs::metadata(path) {
+        let modified = meta.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+        ART_INDEX
+            .lock()
+            .unwrap()
+            .insert(path.to_path_buf(), (meta.len(), modified, hash));
+    }
+}
+
+// Registers a track's art (embedded art if it was already read, otherwise a cover image next to
+// it) and returns the full size image. The bytes are shared by every track with the same cover
+#[flutter_rust_bridge::frb(ignore)]
+pub fn register_track_art(path: &Path, embedded: Option<Vec<u8>>) -> Option<Arc<Vec<u8>>> {
+    let art = embedded
+        .filter(|a| !a.is_empty())
+        .or_else(|| find_folder_art(path).and_then(|p| fs::read(p).ok()));
+    let Some(art) = art else {
+        index_insert(path, None);
+        return None;
+    };
+    let (hash, data) = store_original(art);
+    index_insert(path, Some(hash));
+    Some(data)
+}
+
+fn track_art_hash(path: &Path) -> Option<String> {
+    if let Some(hash) = index_lookup(path) {
+        return hash;
+    }
+    let embedded = Tag::default()
+        .read_from_path(path)
+        .ok()
+        .and_then(|t| t.album_cover().map(|c| c.data.to_vec()));
+    register_track_art(path, embedded)?;
+    index_lookup(path).flatten()
+}
+
+// Returns the art for a track at roughly size pixels on its longest side (snapped up to one of
+// THUMBNAIL_SIZES), 0 gives the original image
+#[flutter_rust_bridge::frb(ignore)]
+pub fn album_art_for(path: &Path, size: u32) -> Option<Arc<Vec<u8>>> {
+    let mut hash = track_art_hash(path)?;
+    // The cache folder may have been cleared from outside since the track was indexed
+    if !has_original(&hash) {
+        ART_INDEX.lock().unwrap().remove(path);
+        hash = track_art_hash(path)?;
+    }
+    match snap_size(size) {
+        0 => load_original(&hash),
+        size => load_thumbnail(&hash, size),
+    }
+}
+
+// Caps how much art is kept in memory, the disk cache is not affected
+pub fn set_art_cache_memory_limit(bytes: u64) {
+    MEMORY_LIMIT.store(bytes, Ordering::Relaxed);
+    ART_MEMORY.lock().unwrap().evict(bytes);
+}
+
+pub fn get_art_cache_memory_usage() -> u64 {
+    ART_MEMORY.lock().unwrap().bytes
+}
+
+pub fn clear_art_cache() -> bool {
+    ART_MEMORY.lock().unwrap().clear();
+    ART_INDEX.lock().unwrap().clear();
+    let cache_dir = get_art_cache_dir();
+    if cache_dir.exists() {
+        fs::remove_dir_all(&cache_dir).is_ok()
+    } else {
+        true
+    }
+}
@@ -0,0 +1,227 @@
+use crate::api::{
+    chapters::{media_duration, read_chapters},
+    music_handler::{get_current_song_path, get_playback_position, is_playing, seek_when_buffered},
+    utils::get_data_dir,
+    value_store::with_store,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Reverse,
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::{
+        Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+// Files with chapters this long count as books without being marked as one
+const BOOK_MIN_SECS: f64 = 20.0 * 60.0;
+// Stopping this close to the end counts as having finished the book
+const FINISHED_MARGIN_SECS: f64 = 30.0;
+// How often the position of the book playing is looked at
+const POSITION_POLL_INTERVAL: Duration = Duration::from_secs(1);
+// How often the position is saved while the book keeps playing
+const POSITION_SAVE_INTERVAL: Duration = Duration::from_secs(30);
+// Books are long, so decoding up to where one was left can take a while
+const RESUME_TIMEOUT: Duration = Duration::from_secs(60);
+
+static BOOK_LOCK: Mutex<()> = Mutex::new(());
+// Bumped by every play_song so only the newest tracker keeps saving
+static TRACK_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BookProgress {
+    pub path: String,
+    pub position_secs: f64,
+    // 0 when the length of the book is not known
+    pub duration_secs: f64,
+    pub finished: bool,
+    // Unix seconds
+    pub updated: i64,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct BookStore {
+    progress: HashMap<String, BookProgress>,
+    // Set by set_audiobook, false keeps a file that looks like a book from being treated as one
+    marked: HashMap<String, bool>,
+}
+
+fn store_path() -> PathBuf {
+    get_data_dir("audiobooks").join("progress.json")
+}
+
+fn load_books() -> BookStore {
+    fs::read_to_string(store_path())
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn with_books<T>(f: impl FnOnce(&mut BookStore) -> T) -> Result<T, String> {
+    let _guard = BOOK_LOCK.lock().unwrap();
+    let mut books = load_books();
+    let result = f(&mut books);
+    let path = store_path();
+    let tmp = path.with_extension("json.tmp");
+    let json = serde_json::to_string(&books)
+        .map_err(|e| format!("Failed to serialise the audiobook progress: {e}"))?;
+    fs::write(&tmp, json)
+        .and_then(|_| fs::rename(&tmp, &path))
+        .map_err(|e| format!("Failed to save the audiobook progress: {e}"))?;
+    Ok(result)
+}
+
+// M4B files and long files with chapters, unless set_audiobook says otherwise. Podcast episodes
+// keep their own position so they never count
+pub fn is_audiobook(path: String) -> bool {
+    let in_podcasts = with_store(|store| {
+        !store.podcast_folder.is_empty() && Path::new(&path).starts_with(&store.podcast_folder)
+    })
+    .unwrap_or(false);
+    if in_podcasts {
+        return false;
+    }
+    let marked = {
+        let _guard = BOOK_LOCK.lock().unwrap();
+        load_books().marked.get(&path).copied()
+    };
+    if let Some(marked) = marked {
+        return marked;
+    }
+    let path = Path::new(&path);
+    if path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("m4b"))
+    {
+        return true;
+    }
+    let chapters = read_chapters(path);
+    chapters.len() > 1 && chapters.last().is_some_and(|c| c.end_secs >= BOOK_MIN_SECS)
+}
+
+pub fn set_audiobook(path: String, enabled: bool) -> Result<(), String> {
+    with_books(|books| {
+        books.marked.insert(path, enabled);
+    })
+}
+
+pub fn get_book_progress(path: String) -> Option<BookProgress> {
+    let _guard = BOOK_LOCK.lock().unwrap();
+    load_books().progress.remove(&path)
+}
+
+// Books that were started and not finished, most recently listened to first
+pub fn list_books_in_progress() -> Vec<BookProgress> {
+    let _guard = BOOK_LOCK.lock().unwrap();
+    let mut books: Vec<BookProgress> = load_books()
+        .progress
+        .into_values()
+        .filter(|b| !b.finished && b.position_secs > 0.0)
+        .collect();
+    books.sort_by_key(|b| Reverse(b.updated));
+    books
+}
+
+fn book_duration(path: &Path) -> f64 {
+    read_chapters(path)
+        .last()
+        .map(|c| c.end_secs)
+        .filter(|&end| end > 0.0)
+        .or_else(|| media_duration(path))
+        .unwrap_or(0.0)
+}
+
+fn save_position(path: String, position_secs: f64, duration_secs: f64) -> Result<(), String> {
+    let finished = duration_secs > 0.0 && position_secs >= duration_secs - FINISHED_MARGIN_SECS;
+    with_books(|books| {
+        books.progress.insert(
+            path.clone(),
+            BookProgress {
+                path,
+                position_secs: if finished {
+                    0.0
+                } else {
+                    position_secs.max(0.0)
+                },
+                duration_secs,
+                finished,
+                updated: chrono::Utc::now().timestamp(),
+            },
+        );
+    })
+}
+
+// Finishing a book starts it from the beginning next time
+pub fn set_book_position(path: String, position_secs: f64) -> Result<(), String> {
+    let duration_secs = book_duration(Path::new(&path));
+    save_position(path, position_secs, duration_secs)
+}
+
+pub fn mark_book_finished(path: String, finished: bool) -> Result<(), String> {
+    with_books(|books| {
+        if let Some(book) = books.progress.get_mut(&path) {
+            book.finished = finished;
+            book.position_secs = 0.0;
+            book.updated = chrono::Utc::now().timestamp();
+        }
+    })
+}
+
+pub fn forget_book_progress(path: String) -> Result<(), String> {
+    with_books(|books| {
+        books.progress.remove(&path);
+    })
+}
+
+// Called for every song played. When it is a book this picks up where it was left and saves the
+// position once it is paused, stopped or replaced. Saving rewrites the progress of every book so
+// while it plays on it is only saved every POSITION_SAVE_INTERVAL, in case the app is quit or dies
+pub(crate) fn track_book(path: &str) {
+    let generation = TRACK_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let path = path.to_string();
+    thread::spawn(move || {
+        if !is_audiobook(path.clone()) {
+            return;
+        }
+        if let Some(book) = get_book_progress(path.clone())
+            && !book.finished
+            && book.position_secs > 0.0
+        {
+            seek_when_buffered(&path, book.position_secs as f32, RESUME_TIMEOUT);
+        }
+        let duration = book_duration(Path::new(&path));
+        let mut position = None;
+        let mut saved = None;
+        let mut last_save = Instant::now();
+        loop {
+            thread::sleep(POSITION_POLL_INTERVAL);
+            let current = TRACK_GENERATION.load(Ordering::SeqCst) == generation
+                && get_current_song_path().as_deref() == Some(path.as_str());
+            if current {
+                // Once something else plays the player position is not this book's
+                position = Some(get_playback_position() as f64);
+                if is_playing() && last_save.elapsed() < POSITION_SAVE_INTERVAL {
+                    continue;
+                }
+            }
+            if let Some(position) = position
+                && saved != Some(position)
+            {
+                if let Err(e) = save_position(path.clone(), position, duration) {
+                    println!("Failed to save the audiobook position: {e}");
+                    break;
+                }
+                saved = Some(position);
+                last_save = Instant::now();
+            }
+            if !current {
+                break;
+            }
+        }
+    });
+}
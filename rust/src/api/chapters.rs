@@ -0,0 +1,313 @@
+use crate::api::{
+    cue_sheet::{cue_for_audio, split_virtual_track},
+    music_handler::{get_current_song_path, get_playback_position, seek_when_buffered},
+};
+use id3::frame::{Chapter as Id3Chapter, TableOfContents};
+use once_cell::sync::Lazy;
+use rodio::{Decoder, Source};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    io::{Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::{Duration, SystemTime},
+};
+
+// Nero chpl start times are in 100 nanosecond units
+const CHPL_UNITS_PER_SEC: f64 = 10_000_000.0;
+// Larger than any real chapter list, guards against reading a broken box whole
+const MAX_CHAPTER_BOX: u64 = 1 << 20;
+// previous_chapter goes back to the start of this chapter when further in than this
+const RESTART_THRESHOLD_SECS: f64 = 3.0;
+// Chapter seeks wait this long for the decoder to reach the chapter
+const CHAPTER_SEEK_TIMEOUT: Duration = Duration::from_secs(10);
+
+// Chapter start times and titles, and the length of the file when it records one
+type ChapterMarks = (Vec<(f64, String)>, Option<f64>);
+// File to its modification time and chapters
+type ChapterMemo = HashMap<PathBuf, (Option<SystemTime>, Vec<Chapter>)>;
+
+// Keyed by file and its modification time so retagged files are read again
+static CHAPTER_CACHE: Lazy<Mutex<ChapterMemo>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Debug, Clone)]
+pub struct Chapter {
+    pub index: u32,
+    pub title: String,
+    pub start_secs: f64,
+    // The start of the next chapter, for the last one the length of the file or 0 when that is
+    // not known
+    pub end_secs: f64,
+}
+
+fn read_at(file: &mut fs::File, at: u64, len: u64) -> Option<Vec<u8>> {
+    let mut data = vec![0; len as usize];
+    file.seek(SeekFrom::Start(at)).ok()?;
+    file.read_exact(&mut data).ok()?;
+    Some(data)
+}
+
+// The content range of the first box called name between start and end
+fn find_box(file: &mut fs::File, start: u64, end: u64, name: &[u8; 4]) -> Option<(u64, u64)> {
+    let mut at = start;
+    while at + 8 <= end {
+        let header = read_at(file, at, 8)?;
+        let size = u32::from_be_bytes(header[..4].try_into().ok()?) as u64;
+        let (header_len, size) = match size {
+            0 => (8, end - at),
+            1 => (
+                16,
+                u64::from_be_bytes(read_at(file, at + 8, 8)?.try_into().ok()?),
+            ),
+            size => (8, size),
+        };
+        if size < header_len || at + size > end {
+            return None;
+        }
+        if &header[4..] == name {
+            return Some((at + header_len, at + size));
+        }
+        at += size;
+    }
+    None
+}
+
+fn be_u32(data: &[u8], at: usize) -> Option<u64> {
+    Some(u32::from_be_bytes(data.get(at..at + 4)?.try_into().ok()?) as u64)
+}
+
+fn be_u64(data: &[u8], at: usize) -> Option<u64> {
+    Some(u64::from_be_bytes(data.get(at..at + 8)?.try_into().ok()?))
+}
+
+fn mvhd_duration(data: &[u8]) -> Option<f64> {
+    let (timescale, duration) = match data.first()? {
+        1 => (be_u32(data, 20)?, be_u64(data, 24)?),
+        _ => (be_u32(data, 12)?, be_u32(data, 16)?),
+    };
+    (timescale > 0).then(|| duration as f64 / timescale as f64)
+}
+
+// Laid out the way ffmpeg reads it: version, flags, 4 more bytes from version 1 on, a one byte
+// count and then a start time and a length prefixed title for each chapter
+fn parse_chpl(data: &[u8]) -> Option<Vec<(f64, String)>> {
+    let mut at = if *data.first()? > 0 { 8 } else { 4 };
+    let count = *data.get(at)?;
+    at += 1;
+    let mut chapters = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let start = be_u64(data, at)? as f64 / CHPL_UNITS_PER_SEC;
+        let len = *data.get(at + 8)? as usize;
+        at += 9;
+        let title = String::from_utf8_lossy(data.get(at..at + len)?)
+            .trim()
+            .to_string();
+        at += len;
+        chapters.push((start, title));
+    }
+    Some(chapters)
+}
+
+// Nero chapters in moov/udta/chpl, which is what most M4B tools write
+fn mp4_chapters(path: &Path) -> Option<ChapterMarks> {
+    let mut file = fs::File::open(path).ok()?;
+    let len = file.metadata().ok()?.len();
+    let (moov, moov_end) = find_box(&mut file, 0, len, b"moov")?;
+    let duration = find_box(&mut file, moov, moov_end, b"mvhd")
+        .and_then(|(start, end)| read_at(&mut file, start, (end - start).min(MAX_CHAPTER_BOX)))
+        .and_then(|data| mvhd_duration(&data));
+    let (udta, udta_end) = find_box(&mut file, moov, moov_end, b"udta")?;
+    let (chpl, chpl_end) = find_box(&mut file, udta, udta_end, b"chpl")?;
+    let data = read_at(&mut file, chpl, (chpl_end - chpl).min(MAX_CHAPTER_BOX))?;
+    Some((parse_chpl(&data)?, duration))
+}
+
+// CHAP frames, limited to those a top level CTOC reaches when the tag has one
+fn id3_chapters(path: &Path) -> Option<ChapterMarks> {
+    let tag = id3::Tag::read_from_path(path).ok()?;
+    let tocs: HashMap<&str, &TableOfContents> = tag
+        .tables_of_contents()
+        .map(|toc| (toc.element_id.as_str(), toc))
+        .collect();
+    let mut listed = HashSet::new();
+    let mut pending: Vec<&str> = tocs
+        .values()
+        .filter(|toc| toc.top_level)
+        .flat_map(|toc| toc.elements.iter().map(|e| e.as_str()))
+        .collect();
+    while let Some(id) = pending.pop() {
+        if !listed.insert(id) {
+            continue;
+        }
+        if let Some(toc) = tocs.get(id) {
+            pending.extend(toc.elements.iter().map(|e| e.as_str()));
+        }
+    }
+
+    let chapters: Vec<&Id3Chapter> = tag
+        .chapters()
+        .filter(|c| listed.is_empty() || listed.contains(c.element_id.as_str()))
+        .collect();
+    let end = chapters
+        .iter()
+        .map(|c| c.end_time)
+        .filter(|&end| end > 0 && end != u32::MAX)
+        .max()
+        .map(|ms| ms as f64 / 1000.0);
+    let chapters = chapters
+        .iter()
+        .map(|c| {
+            let title = c
+                .frames
+                .iter()
+                .find(|f| f.id() == "TIT2")
+                .and_then(|f| f.content().text())
+                .unwrap_or_default()
+                .trim()
+                .to_string();
+            (c.start_time as f64 / 1000.0, title)
+        })
+        .collect();
+    Some((chapters, end))
+}
+
+fn cue_chapters(path: &Path) -> Option<ChapterMarks> {
+    let sheet = cue_for_audio(path)?;
+    let chapters = sheet
+        .tracks
+        .into_iter()
+        .map(|t| (t.start_secs, t.title.unwrap_or_default()))
+        .collect();
+    Some((chapters, None))
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+pub fn media_duration(path: &Path) -> Option<f64> {
+    let decoder = Decoder::try_from(fs::File::open(path).ok()?).ok()?;
+    decoder.total_duration().map(|d| d.as_secs_f64())
+}
+
+fn build_chapters(mut found: Vec<(f64, String)>, end: f64) -> Vec<Chapter> {
+    found.sort_by(|a, b| a.0.total_cmp(&b.0));
+    found.dedup_by(|b, a| (b.0 - a.0).abs() < 0.001);
+    let starts: Vec<f64> = found.iter().map(|(start, _)| *start).collect();
+    found
+        .into_iter()
+        .enumerate()
+        .map(|(i, (start_secs, title))| Chapter {
+            index: i as u32,
+            title: if title.is_empty() {
+                format!("Chapter {}", i + 1)
+            } else {
+                title
+            },
+            start_secs,
+            end_secs: starts.get(i + 1).copied().unwrap_or(end),
+        })
+        .collect()
+}
+
+// Chapters from the file's own tags, or from a CUE sheet next to it when it has none
+#[flutter_rust_bridge::frb(ignore)]
+pub fn read_chapters(path: &Path) -> Vec<Chapter> {
+    // A CUE virtual track is one of the sheet's tracks rather than something split into them
+    if split_virtual_track(&path.to_string_lossy()).is_some() {
+        return Vec::new();
+    }
+    let modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+    // No let chain, the frb attribute expands this function as edition 2021
+    let cached = CHAPTER_CACHE
+        .lock()
+        .unwrap()
+        .get(path)
+        .filter(|(cached_at, _)| *cached_at == modified)
+        .map(|(_, chapters)| chapters.clone());
+    if let Some(chapters) = cached {
+        return chapters;
+    }
+
+    let ext = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let embedded = match ext.as_str() {
+        "m4a" | "m4b" | "mp4" => mp4_chapters(path),
+        "mp3" => id3_chapters(path),
+        _ => None,
+    };
+    let chapters = match embedded
+        .filter(|(found, _)| !found.is_empty())
+        .or_else(|| cue_chapters(path))
+    {
+        Some((found, end)) => {
+            let end = end.or_else(|| media_duration(path)).unwrap_or(0.0);
+            build_chapters(found, end)
+        }
+        None => Vec::new(),
+    };
+
+    CHAPTER_CACHE
+        .lock()
+        .unwrap()
+        .insert(path.to_path_buf(), (modified, chapters.clone()));
+    chapters
+}
+
+pub fn chapter_list(path: String) -> Vec<Chapter> {
+    read_chapters(Path::new(&path))
+}
+
+fn playing_chapters() -> Option<(String, Vec<Chapter>, f64)> {
+    let path = get_current_song_path().filter(|p| !p.is_empty())?;
+    let chapters = read_chapters(Path::new(&path));
+    Some((path, chapters, get_playback_position() as f64))
+}
+
+fn chapter_at(chapters: &[Chapter], position: f64) -> Option<usize> {
+    chapters.iter().rposition(|c| c.start_secs <= position)
+}
+
+pub fn current_chapter() -> Option<Chapter> {
+    let (_, chapters, position) = playing_chapters()?;
+    chapter_at(&chapters, position).map(|i| chapters[i].clone())
+}
+
+fn seek_to(path: &str, chapter: &Chapter) -> bool {
+    seek_when_buffered(path, chapter.start_secs as f32, CHAPTER_SEEK_TIMEOUT)
+}
+
+pub fn next_chapter() -> bool {
+    let Some((path, chapters, position)) = playing_chapters() else {
+        return false;
+    };
+    match chapter_at(&chapters, position).map_or(0, |i| i + 1) {
+        next if next < chapters.len() => seek_to(&path, &chapters[next]),
+        _ => false,
+    }
+}
+
+// Like the previous button on a player: back to the start of this chapter unless it just began
+pub fn previous_chapter() -> bool {
+    let Some((path, chapters, position)) = playing_chapters() else {
+        return false;
+    };
+    let Some(current) = chapter_at(&chapters, position) else {
+        return false;
+    };
+    let target = if position - chapters[current].start_secs > RESTART_THRESHOLD_SECS {
+        current
+    } else {
+        current.saturating_sub(1)
+    };
+    seek_to(&path, &chapters[target])
+}
+
+pub fn seek_to_chapter(index: u32) -> bool {
+    let Some((path, chapters, _)) = playing_chapters() else {
+        return false;
+    };
+    chapters
+        .get(index as usize)
+        .is_some_and(|chapter| seek_to(&path, chapter))
+}
@@ -0,0 +1,307 @@
+use crate::{
+    api::{
+        download_manager::{queue_url_download, wait_for_download, ytdlp_command},
+        download_pipeline::{
+            PipelineConfig, announce_library_addition, get_pipeline_config, run_in_place,
+        },
+        music_handler::extract_metadata,
+        organizer::{move_file, sanitize_component, with_collision_suffix},
+        playlist_manager::{add_to_playlist, create_playlist},
+        smart_playlist::music_folder,
+    },
+    frb_generated::StreamSink,
+};
+use audiotags::Tag;
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    process::Stdio,
+    sync::Mutex,
+    thread,
+};
+
+// Each entry's URL and title, if yt-dlp listed one
+type Entry = (String, Option<String>);
+
+static COLLECTIONS: Lazy<Mutex<Collections>> = Lazy::new(|| {
+    Mutex::new(Collections {
+        next_id: 1,
+        downloads: BTreeMap::new(),
+        sinks: Vec::new(),
+    })
+});
+
+struct Collections {
+    next_id: u64,
+    downloads: BTreeMap<u64, CollectionDownload>,
+    sinks: Vec<StreamSink<CollectionDownload>>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CollectionOptions {
+    // Extra yt-dlp flags, as for download_to_temp
+    pub flags: Option<String>,
+    // The folder inside the music folder tracks go to, the playlist or album title by default
+    pub folder: Option<String>,
+    pub create_playlist: bool,
+    // Defaults to the playlist or album title
+    pub playlist_name: Option<String>,
+    // Runs each track through the download pipeline's tagging stages before it is moved, the
+    // pipeline's own import is skipped so the collection stays together in its folder
+    pub post_process: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct CollectionTrack {
+    // 1 based position in the playlist or album, written to the track number tag
+    pub position: u32,
+    pub title: Option<String>,
+    pub job_id: u64,
+    // Where the track ended up in the music folder
+    pub path: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CollectionDownload {
+    pub id: u64,
+    pub url: String,
+    pub title: String,
+    pub folder: String,
+    pub tracks: Vec<CollectionTrack>,
+    // Set once the playlist has been created, if one was asked for
+    pub playlist: Option<String>,
+    pub finished: bool,
+}
+
+// What yt-dlp --flat-playlist -J prints, a single video has no entries
+#[derive(Deserialize)]
+struct FlatInfo {
+    title: Option<String>,
+    album: Option<String>,
+    webpage_url: Option<String>,
+    entries: Option<Vec<FlatEntry>>,
+}
+
+#[derive(Deserialize)]
+struct FlatEntry {
+    url: Option<String>,
+    webpage_url: Option<String>,
+    title: Option<String>,
+}
+
+fn emit(id: u64) {
+    let mut collections = COLLECTIONS.lock().unwrap();
+    if let Some(download) = collections.downloads.get(&id).cloned() {
+        collections
+            .sinks
+            .retain(|sink| sink.add(download.clone()).is_ok());
+    }
+}
+
+fn update(id: u64, f: impl FnOnce(&mut CollectionDownload)) {
+    if let Some(download) = COLLECTIONS.lock().unwrap().downloads.get_mut(&id) {
+        f(download);
+    }
+    emit(id);
+}
+
+// Lists the entries without downloading anything
+fn resolve(url: &str) -> Result<(String, Vec<Entry>), String> {
+    let output = ytdlp_command()
+        .args(["--flat-playlist", "-J", "--no-warnings", "--"])
+        .arg(url)
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| format!("Failed to start yt-dlp: {e}"))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr
+            .lines()
+            .rev()
+            .find_map(|l| l.strip_prefix("ERROR:"))
+            .map(|e| e.trim().to_string())
+            .unwrap_or(format!("yt-dlp could not read {url}")));
+    }
+    let info: FlatInfo = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("Failed to parse what yt-dlp found at {url}: {e}"))?;
+
+    let title = info.album.or(info.title).unwrap_or("Downloads".to_string());
+    let entries = match info.entries {
+        Some(entries) => entries
+            .into_iter()
+            .filter_map(|e| Some((e.url.or(e.webpage_url)?, e.title)))
+            .collect(),
+        None => vec![(
+            info.webpage_url.unwrap_or(url.to_string()),
+            Some(title.clone()),
+        )],
+    };
+    if entries.is_empty() {
+        return Err(format!("{url} has nothing to download"));
+    }
+    Ok((title, entries))
+}
+
+// Numbers the track after its place in the playlist and fills in the album for playlists whose
+// videos have none
+fn tag_track(path: &Path, position: u32, total: u32, album: &str) -> Result<(), String> {
+    let path_str = path.to_string_lossy();
+    let mut tag = Tag::new()
+        .read_from_path(path)
+        .map_err(|e| format!("Failed to read the tags of {path_str}: {e}"))?;
+    tag.set_track_number(position.min(u16::MAX as u32) as u16);
+    tag.set_total_tracks(total.min(u16::MAX as u32) as u16);
+    if tag.album_title().is_none_or(|a| a.trim().is_empty()) {
+        tag.set_album_title(album);
+    }
+    tag.write_to_path(&path_str)
+        .map_err(|e| format!("Failed to tag {path_str}: {e}"))
+}
+
+fn import_file(
+    file: &str,
+    folder: &Path,
+    position: u32,
+    total: u32,
+    album: &str,
+    pipeline: Option<&PipelineConfig>,
+) -> Result<String, String> {
+    let from = Path::new(file);
+    if let Some(config) = pipeline {
+        run_in_place(from, config);
+    }
+    // A bad tag is not worth losing the download over
+    let _ = tag_track(from, position, total, album);
+    let name = from
+        .file_name()
+        .ok_or_else(|| format!("{file} is not a file"))?;
+    let target = folder.join(sanitize_component(&name.to_string_lossy()));
+    let mut to = target.clone();
+    let mut n = 1;
+    while to.exists() {
+        n += 1;
+        to = with_collision_suffix(&target, n);
+    }
+    move_file(from, &to).map_err(|e| format!("Failed to move {file} to {}: {e}", to.display()))?;
+    if let Some(meta) = extract_metadata(&to) {
+        announce_library_addition(&meta);
+    }
+    Ok(to.to_string_lossy().to_string())
+}
+
+// Waits for each track in playlist order, moving it into the folder as soon as it is done
+fn import_all(id: u64, folder: PathBuf, album: String, create: Option<String>, post_process: bool) {
+    let pipeline = post_process.then(get_pipeline_config);
+    let tracks = COLLECTIONS.lock().unwrap().downloads[&id].tracks.clone();
+    let total = tracks.len() as u32;
+    let mut imported = Vec::new();
+
+    for (i, track) in tracks.iter().enumerate() {
+        let result = wait_for_download(track.job_id).and_then(|files| {
+            files
+                .iter()
+                .map(|f| import_file(f, &folder, track.position, total, &album, pipeline.as_ref()))
+                .collect::<Result<Vec<_>, _>>()
+        });
+        update(id, |download| match result {
+            Ok(paths) => {
+                download.tracks[i].path = paths.first().cloned();
+                imported.extend(paths);
+            }
+            Err(e) => download.tracks[i].error = Some(e),
+        });
+    }
+
+    let playlist = create.and_then(|name| {
+        // Downloading the same playlist again adds to the one made last time
+        let _ = create_playlist(name.clone());
+        add_to_playlist(name.clone(), imported, None, false)
+            .ok()
+            .map(|_| name)
+    });
+    update(id, |download| {
+        download.playlist = playlist;
+        download.finished = true;
+    });
+}
+
+// Downloads every entry of a playlist, album or channel from any site yt-dlp supports. Each track
+// is its own job so the download manager's concurrency limit applies, and finished tracks are
+// tagged with their position and moved into the music folder while the rest download
+pub fn download_collection(
+    url: String,
+    options: CollectionOptions,
+) -> Result<CollectionDownload, String> {
+    let music_folder = music_folder()?;
+    let (title, entries) = resolve(&url)?;
+    let folder_name = options
+        .folder
+        .filter(|f| !f.trim().is_empty())
+        .unwrap_or(title.clone());
+    let folder = Path::new(&music_folder).join(sanitize_component(&folder_name));
+    let playlist_name = options.create_playlist.then(|| {
+        options
+            .playlist_name
+            .filter(|n| !n.trim().is_empty())
+            .unwrap_or(sanitize_component(&title))
+    });
+
+    let tracks = entries
+        .into_iter()
+        .enumerate()
+        .map(|(i, (entry_url, entry_title))| CollectionTrack {
+            position: i as u32 + 1,
+            title: entry_title,
+            job_id: queue_url_download(entry_url, options.flags.clone()),
+            path: None,
+            error: None,
+        })
+        .collect();
+
+    let download = {
+        let mut collections = COLLECTIONS.lock().unwrap();
+        let id = collections.next_id;
+        collections.next_id += 1;
+        let download = CollectionDownload {
+            id,
+            url,
+            title: title.clone(),
+            folder: folder.to_string_lossy().to_string(),
+            tracks,
+            playlist: None,
+            finished: false,
+        };
+        collections.downloads.insert(id, download.clone());
+        download
+    };
+    emit(download.id);
+
+    let id = download.id;
+    let post_process = options.post_process;
+    thread::spawn(move || import_all(id, folder, title, playlist_name, post_process));
+    Ok(download)
+}
+
+pub fn watch_collection_downloads(sink: StreamSink<CollectionDownload>) {
+    COLLECTIONS.lock().unwrap().sinks.push(sink);
+}
+
+pub fn list_collection_downloads() -> Vec<CollectionDownload> {
+    COLLECTIONS
+        .lock()
+        .unwrap()
+        .downloads
+        .values()
+        .cloned()
+        .collect()
+}
+
+pub fn clear_finished_collections() -> u32 {
+    let mut collections = COLLECTIONS.lock().unwrap();
+    let before = collections.downloads.len();
+    collections.downloads.retain(|_, d| !d.finished);
+    (before - collections.downloads.len()) as u32
+}
@@ -4,6 +4,13 @@ use std::collections::HashMap;
 // Constants for color processing
 const RESIZE_TARGET: u32 = 112; // Reduced size for processing
 const QUANTIZE_BITS: u8 = 5; // Number of bits per channel for quantization
+const PALETTE_CLUSTERS: usize = 16; // Clusters computed before roles are picked and the list is cut down
+const MAX_PALETTE_SIZE: u32 = 16;
+const KMEANS_ITERATIONS: usize = 12;
+const MIN_SWATCH_DELTA_E: f64 = 12.0; // Clusters closer than this (CIE76) look like the same colour
+const MIN_TEXT_CONTRAST: f64 = 4.5; // WCAG AA for normal text
+const MIN_UI_CONTRAST: f64 = 3.0; // WCAG AA for icons, outlines and large text
+const DEFAULT_SEED: u32 = 0xFF383770;
 
 #[derive(Debug)]
 struct ColorScore {
@@ -13,7 +20,7 @@ struct ColorScore {
 }
 
 pub fn get_dominant_color(data: Vec<u8>) -> Option<u32> {
-    // Decode base64 and load image
+    // Decode the raw image bytes (any format the image crate can guess)
     let img = image::load_from_memory(&data).ok()?;
 
     // Resize for faster processing while maintaining enough detail
@@ -50,7 +57,7 @@ pub fn get_dominant_color(data: Vec<u8>) -> Option<u32> {
             a_weight.partial_cmp(&b_weight).unwrap()
         })
         .map(|score| score.color)
-        .or(Some(0xFF383770)) // Default color if no dominant color found
+        .or(Some(DEFAULT_SEED)) // Default color if no dominant color found
 }
 
 fn quantize_color(r: u8, g: u8, b: u8) -> u32 {
@@ -101,3 +108,603 @@ fn calculate_color_score(saturation: f32, luminance: f32) -> f32 {
     let lum_score = 1.0 - (luminance - 0.5).abs() * 2.0;
     sat_score * lum_score
 }
+
+#[derive(Debug, Clone)]
+pub struct Swatch {
+    pub color: u32,
+    pub population: u32,
+    // Black or white, whichever reads better on top of color
+    pub text_color: u32,
+}
+
+// Swatches are sorted by how much of the image they cover, the named roles are picked from all
+// clusters so they can be set even if they did not make it into swatches
+#[derive(Debug, Clone)]
+pub struct Palette {
+    pub swatches: Vec<Swatch>,
+    pub vibrant: Option<Swatch>,
+    pub light_vibrant: Option<Swatch>,
+    pub dark_vibrant: Option<Swatch>,
+    pub muted: Option<Swatch>,
+    pub light_muted: Option<Swatch>,
+    pub dark_muted: Option<Swatch>,
+}
+
+// A Material 3 colour scheme, every on_* colour meets MIN_TEXT_CONTRAST against its surface
+#[derive(Debug, Clone)]
+pub struct MaterialScheme {
+    pub is_dark: bool,
+    pub seed: u32,
+    pub primary: u32,
+    pub on_primary: u32,
+    pub primary_container: u32,
+    pub on_primary_container: u32,
+    pub secondary: u32,
+    pub on_secondary: u32,
+    pub secondary_container: u32,
+    pub on_secondary_container: u32,
+    pub tertiary: u32,
+    pub on_tertiary: u32,
+    pub tertiary_container: u32,
+    pub on_tertiary_container: u32,
+    pub error: u32,
+    pub on_error: u32,
+    pub error_container: u32,
+    pub on_error_container: u32,
+    pub background: u32,
+    pub on_background: u32,
+    pub surface: u32,
+    pub on_surface: u32,
+    pub surface_variant: u32,
+    pub on_surface_variant: u32,
+    pub surface_container: u32,
+    pub outline: u32,
+    pub outline_variant: u32,
+    pub inverse_surface: u32,
+    pub inverse_on_surface: u32,
+    pub inverse_primary: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Lab {
+    l: f64,
+    a: f64,
+    b: f64,
+}
+
+// (min, target, max) for saturation and lightness, the same targets Android's Palette uses
+struct RoleTarget {
+    saturation: (f32, f32, f32),
+    lightness: (f32, f32, f32),
+}
+
+const VIBRANT: RoleTarget = RoleTarget {
+    saturation: (0.35, 1.0, 1.0),
+    lightness: (0.3, 0.5, 0.7),
+};
+const LIGHT_VIBRANT: RoleTarget = RoleTarget {
+    saturation: (0.35, 1.0, 1.0),
+    lightness: (0.55, 0.74, 1.0),
+};
+const DARK_VIBRANT: RoleTarget = RoleTarget {
+    saturation: (0.35, 1.0, 1.0),
+    lightness: (0.0, 0.26, 0.45),
+};
+const MUTED: RoleTarget = RoleTarget {
+    saturation: (0.0, 0.3, 0.4),
+    lightness: (0.3, 0.5, 0.7),
+};
+const LIGHT_MUTED: RoleTarget = RoleTarget {
+    saturation: (0.0, 0.3, 0.4),
+    lightness: (0.55, 0.74, 1.0),
+};
+const DARK_MUTED: RoleTarget = RoleTarget {
+    saturation: (0.0, 0.3, 0.4),
+    lightness: (0.0, 0.26, 0.45),
+};
+
+// D65 reference white
+const WHITE_X: f64 = 0.95047;
+const WHITE_Z: f64 = 1.08883;
+const LAB_EPSILON: f64 = 216.0 / 24389.0;
+const LAB_KAPPA: f64 = 24389.0 / 27.0;
+
+fn srgb_to_linear(c: u8) -> f64 {
+    let c = c as f64 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f64) -> u8 {
+    let c = c.clamp(0.0, 1.0);
+    let c = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (c * 255.0).round() as u8
+}
+
+fn argb_channels(argb: u32) -> (u8, u8, u8) {
+    ((argb >> 16) as u8, (argb >> 8) as u8, argb as u8)
+}
+
+fn lab_f(t: f64) -> f64 {
+    if t > LAB_EPSILON {
+        t.cbrt()
+    } else {
+        (LAB_KAPPA * t + 16.0) / 116.0
+    }
+}
+
+fn lab_f_inv(ft: f64) -> f64 {
+    let t = ft * ft * ft;
+    if t > LAB_EPSILON {
+        t
+    } else {
+        (116.0 * ft - 16.0) / LAB_KAPPA
+    }
+}
+
+fn argb_to_lab(argb: u32) -> Lab {
+    let (r, g, b) = argb_channels(argb);
+    let (r, g, b) = (srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b));
+    let x = 0.4124564 * r + 0.3575761 * g + 0.1804375 * b;
+    let y = 0.2126729 * r + 0.7151522 * g + 0.0721750 * b;
+    let z = 0.0193339 * r + 0.1191920 * g + 0.9503041 * b;
+    let (fx, fy, fz) = (lab_f(x / WHITE_X), lab_f(y), lab_f(z / WHITE_Z));
+    Lab {
+        l: 116.0 * fy - 16.0,
+        a: 500.0 * (fx - fy),
+        b: 200.0 * (fy - fz),
+    }
+}
+
+// None if the colour falls outside of sRGB
+fn lab_to_argb(lab: Lab) -> Option<u32> {
+    let fy = (lab.l + 16.0) / 116.0;
+    let x = lab_f_inv(fy + lab.a / 500.0) * WHITE_X;
+    let y = tone_to_y(lab.l);
+    let z = lab_f_inv(fy - lab.b / 200.0) * WHITE_Z;
+    let r = 3.2404542 * x - 1.5371385 * y - 0.4985314 * z;
+    let g = -0.9692660 * x + 1.8760108 * y + 0.0415560 * z;
+    let b = 0.0556434 * x - 0.2040259 * y + 1.0572252 * z;
+    let in_gamut = |c: f64| (-0.0001..=1.0001).contains(&c);
+    if !(in_gamut(r) && in_gamut(g) && in_gamut(b)) {
+        return None;
+    }
+    Some(rgb_to_argb(
+        linear_to_srgb(r),
+        linear_to_srgb(g),
+        linear_to_srgb(b),
+    ))
+}
+
+// Returns (lightness, chroma, hue in degrees)
+fn lab_to_lch(lab: Lab) -> (f64, f64, f64) {
+    (
+        lab.l,
+        lab.a.hypot(lab.b),
+        lab.b.atan2(lab.a).to_degrees().rem_euclid(360.0),
+    )
+}
+
+// The colour with the given L* and hue, keeping as much of the chroma as fits in sRGB
+fn lch_to_argb(tone: f64, chroma: f64, hue: f64) -> u32 {
+    let tone = tone.clamp(0.0, 100.0);
+    let (sin, cos) = hue.to_radians().sin_cos();
+    let at = |c: f64| {
+        lab_to_argb(Lab {
+            l: tone,
+            a: c * cos,
+            b: c * sin,
+        })
+    };
+    if let Some(color) = at(chroma) {
+        return color;
+    }
+    let (mut lo, mut hi) = (0.0, chroma);
+    for _ in 0..20 {
+        let mid = (lo + hi) / 2.0;
+        if at(mid).is_some() {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    // Zero chroma is a grey which is always in gamut
+    at(lo).unwrap_or(0xFF000000)
+}
+
+// L* only depends on Y so a tone maps to exactly one relative luminance
+fn tone_to_y(tone: f64) -> f64 {
+    if tone > LAB_KAPPA * LAB_EPSILON {
+        ((tone + 16.0) / 116.0).powi(3)
+    } else {
+        tone / LAB_KAPPA
+    }
+}
+
+fn y_to_tone(y: f64) -> f64 {
+    116.0 * lab_f(y) - 16.0
+}
+
+fn relative_luminance(argb: u32) -> f64 {
+    let (r, g, b) = argb_channels(argb);
+    0.2126 * srgb_to_linear(r) + 0.7152 * srgb_to_linear(g) + 0.0722 * srgb_to_linear(b)
+}
+
+fn delta_e(a: Lab, b: Lab) -> f64 {
+    ((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt()
+}
+
+// WCAG 2 contrast ratio between two ARGB colours, from 1 to 21
+pub fn contrast_ratio(a: u32, b: u32) -> f64 {
+    let (la, lb) = (relative_luminance(a), relative_luminance(b));
+    (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+}
+
+fn best_text_color(background: u32) -> u32 {
+    if contrast_ratio(0xFFFFFFFF, background) >= contrast_ratio(0xFF000000, background) {
+        0xFFFFFFFF
+    } else {
+        0xFF000000
+    }
+}
+
+// Moves fg's tone away from bg until the pair reaches ratio, keeping its hue and chroma
+fn ensure_contrast(fg: u32, bg: u32, ratio: f64) -> u32 {
+    if contrast_ratio(fg, bg) >= ratio {
+        return fg;
+    }
+    let (_, chroma, hue) = lab_to_lch(argb_to_lab(fg));
+    let bg_y = relative_luminance(bg);
+    let prefer_lighter = relative_luminance(fg) >= bg_y;
+    for lighter in [prefer_lighter, !prefer_lighter] {
+        let target_y = if lighter {
+            ratio * (bg_y + 0.05) - 0.05
+        } else {
+            (bg_y + 0.05) / ratio - 0.05
+        };
+        if !(0.0..=1.0).contains(&target_y) {
+            continue;
+        }
+        // Rounding to 8 bit channels can land just short so nudge a little further if needed
+        let mut tone = y_to_tone(target_y);
+        for _ in 0..10 {
+            let color = lch_to_argb(tone, chroma, hue);
+            if contrast_ratio(color, bg) >= ratio {
+                return color;
+            }
+            tone += if lighter { 0.5 } else { -0.5 };
+        }
+    }
+    best_text_color(bg)
+}
+
+// Weighted k-means over the quantised colours in Lab so clusters follow perceived difference
+fn cluster_colors(points: &[(Lab, f64)], k: usize) -> Vec<(Lab, f64)> {
+    let k = k.min(points.len());
+    if k == 0 {
+        return Vec::new();
+    }
+
+    // Seeded from the heaviest colour and then whatever is furthest from the chosen ones so the
+    // same art always gives the same palette
+    let mut centroids: Vec<Lab> = points
+        .iter()
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|p| vec![p.0])
+        .unwrap_or_default();
+    while centroids.len() < k {
+        let next = points
+            .iter()
+            .map(|(lab, weight)| {
+                let nearest = centroids
+                    .iter()
+                    .map(|c| delta_e(*lab, *c))
+                    .fold(f64::MAX, f64::min);
+                (*lab, weight * nearest * nearest)
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        match next {
+            Some((lab, score)) if score > 0.0 => centroids.push(lab),
+            _ => break,
+        }
+    }
+
+    let nearest_centroid = |lab: Lab, centroids: &[Lab]| {
+        centroids
+            .iter()
+            .enumerate()
+            .min_by(|a, b| delta_e(lab, *a.1).total_cmp(&delta_e(lab, *b.1)))
+            .map(|(i, _)| i)
+            .unwrap_or(0)
+    };
+
+    let mut assignment = vec![usize::MAX; points.len()];
+    let mut weights = vec![0.0; centroids.len()];
+    for _ in 0..KMEANS_ITERATIONS {
+        let mut changed = false;
+        for (i, (lab, _)) in points.iter().enumerate() {
+            let nearest = nearest_centroid(*lab, &centroids);
+            if assignment[i] != nearest {
+                assignment[i] = nearest;
+                changed = true;
+            }
+        }
+
+        let mut sums = vec![(0.0, 0.0, 0.0, 0.0); centroids.len()];
+        for ((lab, weight), &cluster) in points.iter().zip(&assignment) {
+            let sum = &mut sums[cluster];
+            sum.0 += lab.l * weight;
+            sum.1 += lab.a * weight;
+            sum.2 += lab.b * weight;
+            sum.3 += weight;
+        }
+        for ((centroid, sum), weight) in centroids.iter_mut().zip(&sums).zip(weights.iter_mut()) {
+            *weight = sum.3;
+            if sum.3 > 0.0 {
+                *centroid = Lab {
+                    l: sum.0 / sum.3,
+                    a: sum.1 / sum.3,
+                    b: sum.2 / sum.3,
+                };
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    centroids
+        .into_iter()
+        .zip(weights)
+        .filter(|(_, weight)| *weight > 0.0)
+        .collect()
+}
+
+fn to_swatch(lab: Lab, population: f64) -> Swatch {
+    let (l, c, h) = lab_to_lch(lab);
+    let color = lch_to_argb(l, c, h);
+    Swatch {
+        color,
+        population: population.round() as u32,
+        text_color: best_text_color(color),
+    }
+}
+
+fn pick_role(swatches: &[Swatch], used: &mut [bool], target: &RoleTarget) -> Option<Swatch> {
+    let max_population = swatches
+        .iter()
+        .map(|s| s.population)
+        .max()
+        .unwrap_or(1)
+        .max(1) as f32;
+    let (index, _) = swatches
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !used[*i])
+        .filter_map(|(i, swatch)| {
+            let (r, g, b) = argb_channels(swatch.color);
+            let (_, s, l) = rgb_to_hsl(r, g, b);
+            let s = s.clamp(0.0, 1.0);
+            let (s_min, s_target, s_max) = target.saturation;
+            let (l_min, l_target, l_max) = target.lightness;
+            if s < s_min || s > s_max || l < l_min || l > l_max {
+                return None;
+            }
+            let score = 0.24 * (1.0 - (s - s_target).abs())
+                + 0.52 * (1.0 - (l - l_target).abs())
+                + 0.24 * (swatch.population as f32 / max_population);
+            Some((i, score))
+        })
+        .max_by(|a, b| a.1.total_cmp(&b.1))?;
+    used[index] = true;
+    Some(swatches[index].clone())
+}
+
+// Returns up to n visually distinct swatches plus vibrant/muted light/dark roles for the image
+pub fn extract_palette(data: Vec<u8>, n: u32) -> Option<Palette> {
+    let img = image::load_from_memory(&data).ok()?;
+    let small_img = img.thumbnail(RESIZE_TARGET, RESIZE_TARGET);
+    let n = n.clamp(1, MAX_PALETTE_SIZE) as usize;
+
+    // Summed channels and pixel count per quantised colour
+    let mut buckets: HashMap<u32, (u64, u64, u64, u64)> = HashMap::new();
+    for pixel in small_img.pixels() {
+        let rgba = pixel.2.to_rgba();
+        // Mostly transparent pixels are not part of what the user sees
+        if rgba[3] < 128 {
+            continue;
+        }
+        let entry = buckets
+            .entry(quantize_color(rgba[0], rgba[1], rgba[2]))
+            .or_insert((0, 0, 0, 0));
+        entry.0 += rgba[0] as u64;
+        entry.1 += rgba[1] as u64;
+        entry.2 += rgba[2] as u64;
+        entry.3 += 1;
+    }
+    if buckets.is_empty() {
+        return None;
+    }
+
+    let points: Vec<(Lab, f64)> = buckets
+        .values()
+        .map(|(r, g, b, count)| {
+            let color = rgb_to_argb((r / count) as u8, (g / count) as u8, (b / count) as u8);
+            (argb_to_lab(color), *count as f64)
+        })
+        .collect();
+
+    let mut clusters = cluster_colors(&points, PALETTE_CLUSTERS.max(n));
+    clusters.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    // k-means happily splits one big area into near identical clusters so fold those together
+    let mut distinct: Vec<(Lab, f64)> = Vec::new();
+    for (lab, weight) in clusters {
+        match distinct
+            .iter_mut()
+            .find(|(kept, _)| delta_e(*kept, lab) < MIN_SWATCH_DELTA_E)
+        {
+            Some(kept) => kept.1 += weight,
+            None => distinct.push((lab, weight)),
+        }
+    }
+    distinct.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    let all: Vec<Swatch> = distinct
+        .into_iter()
+        .map(|(lab, weight)| to_swatch(lab, weight))
+        .collect();
+    let mut used = vec![false; all.len()];
+
+    Some(Palette {
+        vibrant: pick_role(&all, &mut used, &VIBRANT),
+        light_vibrant: pick_role(&all, &mut used, &LIGHT_VIBRANT),
+        dark_vibrant: pick_role(&all, &mut used, &DARK_VIBRANT),
+        muted: pick_role(&all, &mut used, &MUTED),
+        light_muted: pick_role(&all, &mut used, &LIGHT_MUTED),
+        dark_muted: pick_role(&all, &mut used, &DARK_MUTED),
+        swatches: all.into_iter().take(n).collect(),
+    })
+}
+
+// A hue and chroma that can be asked for any tone (L*) from 0 to 100
+struct TonalPalette {
+    hue: f64,
+    chroma: f64,
+}
+
+impl TonalPalette {
+    fn tone(&self, tone: f64) -> u32 {
+        lch_to_argb(tone, self.chroma, self.hue)
+    }
+}
+
+// Builds a tonal spot scheme (the Material 3 default) around seed and then fixes up any text colour
+// that would not be readable on its surface
+pub fn generate_color_scheme(seed: u32, dark: bool) -> MaterialScheme {
+    let (_, seed_chroma, hue) = lab_to_lch(argb_to_lab(seed));
+    // Greyscale art has no meaningful hue so keep the whole scheme grey instead of inventing one
+    let chroma = |c: f64| if seed_chroma < 4.0 { 0.0 } else { c };
+
+    let primary = TonalPalette {
+        hue,
+        chroma: chroma(seed_chroma.max(36.0)),
+    };
+    let secondary = TonalPalette {
+        hue,
+        chroma: chroma(16.0),
+    };
+    let tertiary = TonalPalette {
+        hue: (hue + 60.0).rem_euclid(360.0),
+        chroma: chroma(24.0),
+    };
+    let neutral = TonalPalette {
+        hue,
+        chroma: chroma(6.0),
+    };
+    let neutral_variant = TonalPalette {
+        hue,
+        chroma: chroma(8.0),
+    };
+    let error = TonalPalette {
+        hue: 25.0,
+        chroma: 84.0,
+    };
+    // (light tone, dark tone)
+    let t = |light: f64, dark_tone: f64| if dark { dark_tone } else { light };
+
+    let mut scheme = MaterialScheme {
+        is_dark: dark,
+        seed,
+        primary: primary.tone(t(40.0, 80.0)),
+        on_primary: primary.tone(t(100.0, 20.0)),
+        primary_container: primary.tone(t(90.0, 30.0)),
+        on_primary_container: primary.tone(t(10.0, 90.0)),
+        secondary: secondary.tone(t(40.0, 80.0)),
+        on_secondary: secondary.tone(t(100.0, 20.0)),
+        secondary_container: secondary.tone(t(90.0, 30.0)),
+        on_secondary_container: secondary.tone(t(10.0, 90.0)),
+        tertiary: tertiary.tone(t(40.0, 80.0)),
+        on_tertiary: tertiary.tone(t(100.0, 20.0)),
+        tertiary_container: tertiary.tone(t(90.0, 30.0)),
+        on_tertiary_container: tertiary.tone(t(10.0, 90.0)),
+        error: error.tone(t(40.0, 80.0)),
+        on_error: error.tone(t(100.0, 20.0)),
+        error_container: error.tone(t(90.0, 30.0)),
+        on_error_container: error.tone(t(10.0, 90.0)),
+        background: neutral.tone(t(99.0, 6.0)),
+        on_background: neutral.tone(t(10.0, 90.0)),
+        surface: neutral.tone(t(99.0, 6.0)),
+        on_surface: neutral.tone(t(10.0, 90.0)),
+        surface_variant: neutral_variant.tone(t(90.0, 30.0)),
+        on_surface_variant: neutral_variant.tone(t(30.0, 80.0)),
+        surface_container: neutral.tone(t(94.0, 12.0)),
+        outline: neutral_variant.tone(t(50.0, 60.0)),
+        outline_variant: neutral_variant.tone(t(80.0, 30.0)),
+        inverse_surface: neutral.tone(t(20.0, 90.0)),
+        inverse_on_surface: neutral.tone(t(95.0, 20.0)),
+        inverse_primary: primary.tone(t(80.0, 40.0)),
+    };
+
+    // The tones above already pass for almost every seed, this covers the extreme ones
+    let s = &mut scheme;
+    // primary is fixed first as everything drawn on it is checked against the final colour
+    s.primary = ensure_contrast(s.primary, s.surface, MIN_UI_CONTRAST);
+    s.on_primary = ensure_contrast(s.on_primary, s.primary, MIN_TEXT_CONTRAST);
+    s.on_primary_container = ensure_contrast(
+        s.on_primary_container,
+        s.primary_container,
+        MIN_TEXT_CONTRAST,
+    );
+    s.on_secondary = ensure_contrast(s.on_secondary, s.secondary, MIN_TEXT_CONTRAST);
+    s.on_secondary_container = ensure_contrast(
+        s.on_secondary_container,
+        s.secondary_container,
+        MIN_TEXT_CONTRAST,
+    );
+    s.on_tertiary = ensure_contrast(s.on_tertiary, s.tertiary, MIN_TEXT_CONTRAST);
+    s.on_tertiary_container = ensure_contrast(
+        s.on_tertiary_container,
+        s.tertiary_container,
+        MIN_TEXT_CONTRAST,
+    );
+    s.on_error = ensure_contrast(s.on_error, s.error, MIN_TEXT_CONTRAST);
+    s.on_error_container =
+        ensure_contrast(s.on_error_container, s.error_container, MIN_TEXT_CONTRAST);
+    s.on_background = ensure_contrast(s.on_background, s.background, MIN_TEXT_CONTRAST);
+    // on_surface is used on both the surface and its containers
+    s.on_surface = ensure_contrast(s.on_surface, s.surface, MIN_TEXT_CONTRAST);
+    s.on_surface = ensure_contrast(s.on_surface, s.surface_container, MIN_TEXT_CONTRAST);
+    s.on_surface_variant =
+        ensure_contrast(s.on_surface_variant, s.surface_variant, MIN_TEXT_CONTRAST);
+    s.inverse_on_surface =
+        ensure_contrast(s.inverse_on_surface, s.inverse_surface, MIN_TEXT_CONTRAST);
+    s.inverse_primary = ensure_contrast(s.inverse_primary, s.inverse_surface, MIN_UI_CONTRAST);
+    s.outline = ensure_contrast(s.outline, s.surface, MIN_UI_CONTRAST);
+
+    scheme
+}
+
+// Picks a seed from the album art's palette (vibrant first, like the Material dynamic colour
+// quantiser would) and builds a scheme from it
+pub fn scheme_from_art(data: Vec<u8>, dark: bool) -> MaterialScheme {
+    let seed = extract_palette(data, 5)
+        .and_then(|p| {
+            p.vibrant
+                .or(p.dark_vibrant)
+                .or(p.light_vibrant)
+                .or(p.muted)
+                .or(p.swatches.into_iter().next())
+        })
+        .map(|s| s.color)
+        .unwrap_or(DEFAULT_SEED);
+    generate_color_scheme(seed, dark)
+}
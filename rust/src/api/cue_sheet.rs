@@ -0,0 +1,306 @@
+use crate::api::music_handler::{SongMetadata, extract_metadata};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+// CUE times are minutes:seconds:frames with 75 frames a second
+const CUE_FRAMES_PER_SEC: f64 = 75.0;
+// A track of a single file album is played as <file>#track=<number>
+const VIRTUAL_TRACK_MARKER: &str = "#track=";
+
+#[derive(Debug, Clone)]
+pub struct CueTrack {
+    pub number: u32,
+    pub title: Option<String>,
+    pub performer: Option<String>,
+    // The FILE the track is in, as written in the sheet
+    pub file: String,
+    // Where INDEX 01 is, the pregap before it belongs to the track before
+    pub start_secs: f64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CueSheet {
+    pub title: Option<String>,
+    pub performer: Option<String>,
+    pub genre: Option<String>,
+    pub date: Option<String>,
+    pub tracks: Vec<CueTrack>,
+}
+
+// Splits a line into words, keeping "quoted strings" together without their quotes
+fn words(line: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut chars = line.trim().chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '"' {
+            chars.next();
+            words.push(chars.by_ref().take_while(|&c| c != '"').collect());
+        } else {
+            let mut word = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() {
+                    break;
+                }
+                word.push(c);
+                chars.next();
+            }
+            words.push(word);
+        }
+    }
+    words
+}
+
+fn parse_cue_time(text: &str) -> Option<f64> {
+    let parts: Vec<u32> = text
+        .split(':')
+        .map(|p| p.parse().ok())
+        .collect::<Option<_>>()?;
+    match parts[..] {
+        [m, s, f] => Some(m as f64 * 60.0 + s as f64 + f as f64 / CUE_FRAMES_PER_SEC),
+        _ => None,
+    }
+}
+
+fn push_track(sheet: &mut CueSheet, track: Option<CueTrack>, has_start: bool) {
+    if let Some(track) = track
+        && has_start
+    {
+        sheet.tracks.push(track);
+    }
+}
+
+// Tracks without an INDEX 01 cannot be placed and are left out
+#[flutter_rust_bridge::frb(ignore)]
+pub fn parse_cue(text: &str) -> CueSheet {
+    let mut sheet = CueSheet::default();
+    let mut file = String::new();
+    let mut track: Option<CueTrack> = None;
+    let mut has_start = false;
+
+    for line in text.lines() {
+        let words = words(line);
+        let Some(keyword) = words.first() else {
+            continue;
+        };
+        let arg = words.get(1).cloned().filter(|a| !a.is_empty());
+        match keyword.to_ascii_uppercase().as_str() {
+            "FILE" => {
+                push_track(&mut sheet, track.take(), has_start);
+                file = arg.unwrap_or_default();
+            }
+            "TRACK" => {
+                push_track(&mut sheet, track.take(), has_start);
+                has_start = false;
+                track = Some(CueTrack {
+                    number: arg.and_then(|a| a.parse().ok()).unwrap_or(0),
+                    title: None,
+                    performer: None,
+                    file: file.clone(),
+                    start_secs: 0.0,
+                });
+            }
+            // Before the first TRACK these describe the whole album
+            "TITLE" => match track.as_mut() {
+                Some(track) => track.title = arg,
+                None => sheet.title = arg,
+            },
+            "PERFORMER" => match track.as_mut() {
+                Some(track) => track.performer = arg,
+                None => sheet.performer = arg,
+            },
+            "INDEX" => {
+                // The frb attribute expands this function as edition 2021, which has no let chains
+                let start = words
+                    .get(2)
+                    .and_then(|t| parse_cue_time(t))
+                    .filter(|_| arg.is_some_and(|n| n.parse::<u32>() == Ok(1)));
+                if let (Some(track), Some(start)) = (track.as_mut(), start) {
+                    track.start_secs = start;
+                    has_start = true;
+                }
+            }
+            "REM" => {
+                let value = (words.len() > 2).then(|| words[2..].join(" "));
+                match arg.map(|a| a.to_ascii_uppercase()).as_deref() {
+                    Some("GENRE") => sheet.genre = value,
+                    Some("DATE") => sheet.date = value,
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+    push_track(&mut sheet, track, has_start);
+    sheet
+}
+
+// Sheets written on Windows are often Latin-1 rather than UTF-8
+#[flutter_rust_bridge::frb(ignore)]
+pub fn read_cue(path: &Path) -> Option<CueSheet> {
+    let bytes = fs::read(path).ok()?;
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(&bytes);
+    let text = match std::str::from_utf8(bytes) {
+        Ok(text) => text.to_string(),
+        Err(_) => bytes.iter().map(|&b| b as char).collect(),
+    };
+    Some(parse_cue(&text))
+}
+
+// Rippers often keep the FILE line from before the audio was converted, so album.wav in the sheet
+// still means album.flac on disk
+fn names_file(entry: &str, audio: &Path) -> bool {
+    let entry = PathBuf::from(entry.replace('\\', "/"));
+    let same = |a: Option<&std::ffi::OsStr>, b: Option<&std::ffi::OsStr>| {
+        a.zip(b).is_some_and(|(a, b)| {
+            a.to_string_lossy()
+                .eq_ignore_ascii_case(&b.to_string_lossy())
+        })
+    };
+    same(entry.file_name(), audio.file_name()) || same(entry.file_stem(), audio.file_stem())
+}
+
+// The sheet next to audio that describes it, holding only the tracks that are in that file. A sheet
+// named after the file may use any FILE name as long as it has just the one
+#[flutter_rust_bridge::frb(ignore)]
+pub fn cue_for_audio(audio: &Path) -> Option<CueSheet> {
+    // Playlists link to the file, the sheet is next to where the link points
+    let audio = &fs::canonicalize(audio).unwrap_or(audio.to_path_buf());
+    let dir = audio.parent()?;
+    let stem = audio.file_stem()?.to_string_lossy().to_string();
+    let name = audio.file_name()?.to_string_lossy().to_string();
+    let mut candidates = vec![
+        dir.join(format!("{stem}.cue")),
+        dir.join(format!("{name}.cue")),
+    ];
+    if let Ok(entries) = fs::read_dir(dir) {
+        let mut others: Vec<PathBuf> = entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| {
+                p.extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("cue"))
+                    && !candidates.contains(p)
+            })
+            .collect();
+        others.sort();
+        candidates.extend(others);
+    }
+
+    candidates.iter().enumerate().find_map(|(i, cue)| {
+        let mut sheet = read_cue(cue)?;
+        let named_after = i < 2;
+        let single_file = sheet
+            .tracks
+            .windows(2)
+            .all(|pair| pair[0].file == pair[1].file);
+        if !(named_after && single_file) {
+            sheet.tracks.retain(|t| names_file(&t.file, audio));
+        }
+        (!sheet.tracks.is_empty()).then_some(sheet)
+    })
+}
+
+// Where a virtual track sits in its file
+#[derive(Debug, Clone)]
+pub struct VirtualTrack {
+    pub file: String,
+    pub number: u32,
+    pub start_secs: f64,
+    // None for the last track, which plays to the end of the file
+    pub end_secs: Option<f64>,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+pub fn virtual_track_path(file: &str, number: u32) -> String {
+    format!("{file}{VIRTUAL_TRACK_MARKER}{number}")
+}
+
+// The file and track number of a virtual track path
+#[flutter_rust_bridge::frb(ignore)]
+pub fn split_virtual_track(path: &str) -> Option<(&str, u32)> {
+    let (file, number) = path.rsplit_once(VIRTUAL_TRACK_MARKER)?;
+    Some((file, number.parse().ok()?))
+}
+
+// Tags belong to the whole file, so writing them through one of its virtual tracks would change
+// every track of the sheet
+#[flutter_rust_bridge::frb(ignore)]
+pub fn reject_virtual_track(path: &str) -> Result<(), String> {
+    match split_virtual_track(path) {
+        Some((file, number)) => Err(format!(
+            "Track {number} of {file} comes from a CUE sheet and its tags can not be written"
+        )),
+        None => Ok(()),
+    }
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+pub fn resolve_virtual_track(path: &str) -> Option<VirtualTrack> {
+    let (file, number) = split_virtual_track(path)?;
+    let sheet = cue_for_audio(Path::new(file))?;
+    let index = sheet.tracks.iter().position(|t| t.number == number)?;
+    Some(VirtualTrack {
+        file: file.to_string(),
+        number,
+        start_secs: sheet.tracks[index].start_secs,
+        end_secs: sheet.tracks.get(index + 1).map(|next| next.start_secs),
+    })
+}
+
+// Tags come from the sheet where it has them and from the file otherwise
+fn track_song(sheet: &CueSheet, index: usize, file_song: &SongMetadata) -> SongMetadata {
+    let track = &sheet.tracks[index];
+    let end = sheet
+        .tracks
+        .get(index + 1)
+        .map_or(file_song.duration as f64, |next| next.start_secs);
+    SongMetadata {
+        title: track
+            .title
+            .clone()
+            .unwrap_or_else(|| format!("Track {}", track.number)),
+        artist: track
+            .performer
+            .clone()
+            .or_else(|| sheet.performer.clone())
+            .unwrap_or_else(|| file_song.artist.clone()),
+        album: sheet
+            .title
+            .clone()
+            .unwrap_or_else(|| file_song.album.clone()),
+        duration: (end - track.start_secs).max(0.0).round() as u64,
+        path: virtual_track_path(&file_song.path, track.number),
+        album_art: file_song.album_art.clone(),
+        genre: sheet
+            .genre
+            .clone()
+            .unwrap_or_else(|| file_song.genre.clone()),
+    }
+}
+
+// The songs a scanned file stands for: one per track when a CUE sheet splits it, or just itself
+#[flutter_rust_bridge::frb(ignore)]
+pub fn songs_in_file(audio: &Path, file_song: SongMetadata) -> Vec<SongMetadata> {
+    match cue_for_audio(audio) {
+        Some(sheet) if sheet.tracks.len() > 1 => (0..sheet.tracks.len())
+            .map(|i| track_song(&sheet, i, &file_song))
+            .collect(),
+        _ => vec![file_song],
+    }
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+pub fn virtual_track_metadata(path: &str) -> Option<SongMetadata> {
+    let (file, number) = split_virtual_track(path)?;
+    let sheet = cue_for_audio(Path::new(file))?;
+    let index = sheet.tracks.iter().position(|t| t.number == number)?;
+    Some(track_song(
+        &sheet,
+        index,
+        &extract_metadata(Path::new(file))?,
+    ))
+}
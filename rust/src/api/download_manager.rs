@@ -0,0 +1,653 @@
+use crate::{api::value_store::with_store, frb_generated::StreamSink};
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use std::{
+    collections::{BTreeMap, VecDeque},
+    env::temp_dir,
+    fs,
+    io::{BufRead, BufReader, Read},
+    path::PathBuf,
+    process::{Command, Stdio},
+    sync::{
+        Arc, Condvar, Mutex,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, RecvTimeoutError, Sender},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+const DEFAULT_MAX_CONCURRENT: u32 = 2;
+// Progress lines arrive many times a second, Dart only needs a few of them
+const PROGRESS_EVENT_INTERVAL: Duration = Duration::from_millis(250);
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+// Markers on the lines yt-dlp prints for us so they can be told apart from its own output
+const PROGRESS_MARKER: &str = "ADIMAN_PROGRESS ";
+const FILE_MARKER: &str = "ADIMAN_FILE ";
+const DEFAULT_AUDIO_FORMAT: &str = "m4a";
+const MAX_SEARCH_RESULTS: u32 = 50;
+
+static DOWNLOADS: Lazy<Mutex<DownloadManager>> = Lazy::new(|| {
+    Mutex::new(DownloadManager {
+        next_id: 1,
+        jobs: BTreeMap::new(),
+        queue: VecDeque::new(),
+        running: 0,
+        max_concurrent: DEFAULT_MAX_CONCURRENT,
+        sinks: Vec::new(),
+    })
+});
+// Signalled whenever a job finishes so wait_for_download can wake up
+static DOWNLOAD_FINISHED: Condvar = Condvar::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DownloadState {
+    Queued,
+    Downloading,
+    // yt-dlp is extracting audio, embedding the thumbnail or writing tags
+    Processing,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl DownloadState {
+    fn is_finished(self) -> bool {
+        matches!(
+            self,
+            DownloadState::Completed | DownloadState::Failed | DownloadState::Cancelled
+        )
+    }
+}
+
+// A snapshot of a job, sent to watch_downloads every time it changes
+#[derive(Debug, Clone)]
+pub struct DownloadJob {
+    pub id: u64,
+    pub query: String,
+    pub state: DownloadState,
+    pub title: Option<String>,
+    // 0 to 1 for the file currently downloading, None while yt-dlp does not know the size
+    pub progress: Option<f32>,
+    pub downloaded_bytes: u64,
+    pub total_bytes: Option<u64>,
+    // Bytes per second
+    pub speed: Option<f64>,
+    pub eta_secs: Option<u64>,
+    pub temp_dir: String,
+    // The finished files, exactly as yt-dlp reported them after moving them into place
+    pub files: Vec<String>,
+    pub error: Option<String>,
+}
+
+// What yt-dlp should fetch and how, kept with the job until a worker picks it up
+#[derive(Clone)]
+struct JobSpec {
+    input: String,
+    // Search results come back as a playlist of which only the first is wanted
+    first_only: bool,
+    audio_format: String,
+    flags: Vec<String>,
+}
+
+// A search result, enough to tell a cover or live version apart from the original
+#[derive(Debug, Clone)]
+pub struct RemoteCandidate {
+    pub id: String,
+    pub title: String,
+    pub uploader: Option<String>,
+    pub duration_secs: Option<f64>,
+    pub thumbnail_url: Option<String>,
+    pub url: String,
+}
+
+// What yt-dlp --flat-playlist --dump-json prints for each search result
+#[derive(Deserialize)]
+struct FlatResult {
+    id: String,
+    title: Option<String>,
+    uploader: Option<String>,
+    channel: Option<String>,
+    duration: Option<f64>,
+    url: Option<String>,
+    thumbnail: Option<String>,
+    #[serde(default)]
+    thumbnails: Vec<FlatThumbnail>,
+}
+
+#[derive(Deserialize)]
+struct FlatThumbnail {
+    url: String,
+    width: Option<u32>,
+}
+
+impl From<FlatResult> for RemoteCandidate {
+    fn from(result: FlatResult) -> Self {
+        // The widest thumbnail looks best in the preview, flat results rarely set thumbnail
+        let thumbnail_url = result
+            .thumbnails
+            .into_iter()
+            .max_by_key(|t| t.width.unwrap_or(0))
+            .map(|t| t.url)
+            .or(result.thumbnail);
+        Self {
+            url: result
+                .url
+                .unwrap_or(format!("https://www.youtube.com/watch?v={}", result.id)),
+            title: result.title.unwrap_or(result.id.clone()),
+            id: result.id,
+            uploader: result.uploader.or(result.channel),
+            duration_secs: result.duration,
+            thumbnail_url,
+        }
+    }
+}
+
+struct Job {
+    info: DownloadJob,
+    spec: JobSpec,
+    cancel: Arc<AtomicBool>,
+}
+
+struct DownloadManager {
+    next_id: u64,
+    jobs: BTreeMap<u64, Job>,
+    queue: VecDeque<u64>,
+    running: u32,
+    max_concurrent: u32,
+    sinks: Vec<StreamSink<DownloadJob>>,
+}
+
+impl DownloadManager {
+    // Sinks Dart has closed fail to send and are dropped
+    fn emit(&mut self, id: u64) {
+        if let Some(job) = self.jobs.get(&id) {
+            let info = job.info.clone();
+            self.sinks.retain(|sink| sink.add(info.clone()).is_ok());
+        }
+    }
+
+    fn start_queued(&mut self) {
+        while self.running < self.max_concurrent {
+            let Some(id) = self.queue.pop_front() else {
+                break;
+            };
+            let Some(job) = self.jobs.get_mut(&id) else {
+                continue;
+            };
+            if job.info.state != DownloadState::Queued {
+                continue;
+            }
+            job.info.state = DownloadState::Downloading;
+            let spec = job.spec.clone();
+            let cancel = job.cancel.clone();
+            let dir = PathBuf::from(&job.info.temp_dir);
+            self.running += 1;
+            self.emit(id);
+            thread::spawn(move || {
+                let result = run_ytdlp(id, &spec, &dir, &cancel);
+                finish_job(id, result, &cancel);
+            });
+        }
+    }
+}
+
+// The parts of yt-dlp's progress dict we use, see --progress-template
+#[derive(Deserialize)]
+struct YtdlpProgressLine {
+    progress: YtdlpProgress,
+    title: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct YtdlpProgress {
+    status: Option<String>,
+    downloaded_bytes: Option<u64>,
+    total_bytes: Option<u64>,
+    total_bytes_estimate: Option<f64>,
+    speed: Option<f64>,
+    eta: Option<f64>,
+    postprocessor: Option<String>,
+}
+
+enum YtdlpOutput {
+    Progress(YtdlpProgressLine),
+    File(String),
+    Error(String),
+}
+
+fn update_job(id: u64, f: impl FnOnce(&mut DownloadJob)) {
+    let mut manager = DOWNLOADS.lock().unwrap();
+    if let Some(job) = manager.jobs.get_mut(&id) {
+        f(&mut job.info);
+        manager.emit(id);
+    }
+}
+
+fn finish_job(id: u64, result: Result<Vec<String>, String>, cancel: &AtomicBool) {
+    let mut manager = DOWNLOADS.lock().unwrap();
+    manager.running = manager.running.saturating_sub(1);
+    if let Some(job) = manager.jobs.get_mut(&id) {
+        match result {
+            Ok(files) => {
+                job.info.state = DownloadState::Completed;
+                job.info.progress = Some(1.0);
+                job.info.files = files;
+            }
+            Err(_) if cancel.load(Ordering::SeqCst) => {
+                job.info.state = DownloadState::Cancelled;
+            }
+            Err(e) => {
+                job.info.state = DownloadState::Failed;
+                job.info.error = Some(e);
+            }
+        }
+        job.info.speed = None;
+        job.info.eta_secs = None;
+        manager.emit(id);
+    }
+    manager.start_queued();
+    DOWNLOAD_FINISHED.notify_all();
+}
+
+// Sends every line of one of yt-dlp's outputs to the job's worker, we print to stdout but progress
+// can end up on stderr when yt-dlp is quiet
+fn forward_lines(reader: impl Read + Send + 'static, tx: Sender<YtdlpOutput>) {
+    thread::spawn(move || {
+        for line in BufReader::new(reader).lines().map_while(Result::ok) {
+            let message = if let Some(json) = line.strip_prefix(PROGRESS_MARKER) {
+                match serde_json::from_str(json) {
+                    Ok(progress) => YtdlpOutput::Progress(progress),
+                    Err(_) => continue,
+                }
+            } else if let Some(json) = line.strip_prefix(FILE_MARKER) {
+                match serde_json::from_str(json) {
+                    Ok(path) => YtdlpOutput::File(path),
+                    Err(_) => continue,
+                }
+            } else if let Some(error) = line.strip_prefix("ERROR:") {
+                YtdlpOutput::Error(error.trim().to_string())
+            } else {
+                continue;
+            };
+            if tx.send(message).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+fn apply_progress(job: &mut DownloadJob, line: YtdlpProgressLine) {
+    if line.title.is_some() {
+        job.title = line.title;
+    }
+    let progress = line.progress;
+    if progress.postprocessor.is_some() {
+        job.state = DownloadState::Processing;
+        return;
+    }
+    job.state = match progress.status.as_deref() {
+        Some("finished") => DownloadState::Processing,
+        _ => DownloadState::Downloading,
+    };
+    job.downloaded_bytes = progress.downloaded_bytes.unwrap_or(job.downloaded_bytes);
+    job.total_bytes = progress
+        .total_bytes
+        .or(progress.total_bytes_estimate.map(|t| t as u64));
+    job.progress = job
+        .total_bytes
+        .filter(|&t| t > 0)
+        .map(|t| (job.downloaded_bytes as f32 / t as f32).clamp(0.0, 1.0));
+    job.speed = progress.speed;
+    job.eta_secs = progress.eta.map(|e| e as u64);
+}
+
+fn build_command(spec: &JobSpec, dir: &std::path::Path) -> Command {
+    let progress_template =
+        format!("{PROGRESS_MARKER}{{\"progress\":%(progress)j,\"title\":%(info.title)j}}");
+    let download_template = format!("download:{progress_template}");
+    let postprocess_template = format!("postprocess:{progress_template}");
+    let print_template = format!("after_move:{FILE_MARKER}%(filepath)j");
+
+    #[rustfmt::skip]
+    let args = [
+        "-f", "bestaudio",
+        "--extract-audio",
+        "--audio-quality", "0",
+        "--audio-format", &spec.audio_format,
+        "--embed-thumbnail",
+        "--convert-thumbnails", "jpg",
+        "--ppa", "ThumbnailsConvertor:-vf scale=640:640:force_original_aspect_ratio=increase,crop=640:640 -q:v 1",
+        "--add-metadata",
+        "--embed-metadata",
+        "--parse-metadata", "title:%(artist)s - %(track)s",
+        "--replace-in-metadata", "title,track", r"(?i)\s*(?:[\[\(【{<].*?(?:official|video|audio|lyric|visualizer).*?[\]\)】}>]|\s*[-|]\s*(?:official\s*video|music\s*video|official\s*audio|lyric\s*video|official\s*visualizer).*)", "",
+        "--no-post-overwrites",
+        "--no-playlist",
+        "--newline",
+        "--progress",
+        "--progress-template", &download_template,
+        "--progress-template", &postprocess_template,
+        "--print", &print_template,
+    ];
+
+    let mut cmd = ytdlp_command();
+    cmd.args(args);
+    if spec.first_only {
+        cmd.args(["--playlist-items", "1"]);
+    }
+    cmd.arg("--output").arg(dir.join("%(title)s.%(ext)s"));
+    cmd.args(&spec.flags);
+    cmd.arg("--").arg(&spec.input);
+    cmd.stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    cmd
+}
+
+fn run_ytdlp(
+    id: u64,
+    spec: &JobSpec,
+    dir: &std::path::Path,
+    cancel: &AtomicBool,
+) -> Result<Vec<String>, String> {
+    fs::create_dir_all(dir).map_err(|e| format!("Failed to create {}: {e}", dir.display()))?;
+    let mut child = build_command(spec, dir)
+        .spawn()
+        .map_err(|e| format!("Failed to start yt-dlp: {e}"))?;
+
+    let (tx, rx) = mpsc::channel();
+    if let Some(stdout) = child.stdout.take() {
+        forward_lines(stdout, tx.clone());
+    }
+    if let Some(stderr) = child.stderr.take() {
+        forward_lines(stderr, tx);
+    }
+
+    let mut files = Vec::new();
+    let mut last_error = None;
+    let mut last_emit: Option<Instant> = None;
+    let status = loop {
+        if cancel.load(Ordering::SeqCst) {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err("Download cancelled".to_string());
+        }
+
+        match rx.recv_timeout(POLL_INTERVAL) {
+            Ok(YtdlpOutput::Progress(line)) => {
+                let mut manager = DOWNLOADS.lock().unwrap();
+                if let Some(job) = manager.jobs.get_mut(&id) {
+                    let before = job.info.state;
+                    apply_progress(&mut job.info, line);
+                    if job.info.state != before
+                        || last_emit.is_none_or(|t| t.elapsed() >= PROGRESS_EVENT_INTERVAL)
+                    {
+                        last_emit = Some(Instant::now());
+                        manager.emit(id);
+                    }
+                }
+            }
+            Ok(YtdlpOutput::File(path)) => {
+                files.push(path.clone());
+                update_job(id, |job| job.files.push(path));
+            }
+            Ok(YtdlpOutput::Error(e)) => last_error = Some(e),
+            // Both outputs closed, yt-dlp is exiting
+            Err(RecvTimeoutError::Disconnected) => {
+                break child
+                    .wait()
+                    .map_err(|e| format!("Error waiting for yt-dlp: {e}"))?;
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+        }
+    };
+
+    if !status.success() {
+        return Err(last_error.unwrap_or(format!("yt-dlp failed with {status}")));
+    }
+    if files.is_empty() {
+        return Err(last_error.unwrap_or("yt-dlp did not produce a file".to_string()));
+    }
+    Ok(files)
+}
+
+// Splits the user's extra yt-dlp flags, pulling --audio-format out since it decides the output
+fn parse_flags(flags: Option<String>) -> (String, Vec<String>) {
+    let mut audio_format = DEFAULT_AUDIO_FORMAT.to_string();
+    let mut parsed = Vec::new();
+    if let Some(flags) = flags {
+        let mut iter = shlex::Shlex::new(&flags);
+        while let Some(arg) = iter.next() {
+            if arg == "--audio-format" {
+                if let Some(format) = iter.next() {
+                    audio_format = format;
+                }
+            } else if let Some(format) = arg.strip_prefix("--audio-format=") {
+                audio_format = format.to_string();
+            } else {
+                parsed.push(arg);
+            }
+        }
+    }
+    (audio_format, parsed)
+}
+
+// Every yt-dlp run goes through here so the executable can be swapped with set_ytdlp_path
+#[flutter_rust_bridge::frb(ignore)]
+pub fn ytdlp_command() -> Command {
+    let path = with_store(|store| store.ytdlp_path.clone()).flatten();
+    Command::new(path.as_deref().unwrap_or("yt-dlp"))
+}
+
+fn enqueue(query: String, input: String, first_only: bool, flags: Option<String>) -> u64 {
+    let (audio_format, flags) = parse_flags(flags);
+    let mut manager = DOWNLOADS.lock().unwrap();
+    let id = manager.next_id;
+    manager.next_id += 1;
+    let dir = temp_dir()
+        .join("adiman-downloads")
+        .join(format!("{}-{id}", std::process::id()));
+    manager.jobs.insert(
+        id,
+        Job {
+            info: DownloadJob {
+                id,
+                query,
+                state: DownloadState::Queued,
+                title: None,
+                progress: None,
+                downloaded_bytes: 0,
+                total_bytes: None,
+                speed: None,
+                eta_secs: None,
+                temp_dir: dir.to_string_lossy().to_string(),
+                files: Vec::new(),
+                error: None,
+            },
+            spec: JobSpec {
+                input,
+                first_only,
+                audio_format,
+                flags,
+            },
+            cancel: Arc::new(AtomicBool::new(false)),
+        },
+    );
+    manager.queue.push_back(id);
+    manager.emit(id);
+    manager.start_queued();
+    id
+}
+
+// Queues the first search result for query and returns the job id straight away
+pub fn queue_download(query: String, flags: Option<String>) -> u64 {
+    let input = format!("ytsearch1:{query}");
+    enqueue(query, input, true, flags)
+}
+
+// Queues a single video or track by URL, from any site yt-dlp supports. A link to a video inside a
+// playlist only fetches the video, download_collection fetches whole playlists
+pub fn queue_url_download(url: String, flags: Option<String>) -> u64 {
+    enqueue(url.clone(), url, false, flags)
+}
+
+// Searches without downloading anything so the user can pick the right version before
+// download_by_id. Flat mode only reads the results page, which keeps it fast
+pub fn search_remote(query: String, count: u32) -> Result<Vec<RemoteCandidate>, String> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Err("The search query is empty".to_string());
+    }
+    let output = ytdlp_command()
+        .args(["--flat-playlist", "--dump-json", "--no-warnings", "--"])
+        .arg(format!(
+            "ytsearch{}:{query}",
+            count.clamp(1, MAX_SEARCH_RESULTS)
+        ))
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| format!("Failed to start yt-dlp: {e}"))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(stderr
+            .lines()
+            .rev()
+            .find_map(|l| l.strip_prefix("ERROR:"))
+            .map(|e| e.trim().to_string())
+            .unwrap_or(format!("yt-dlp could not search for {query}")));
+    }
+
+    // One JSON object per result, anything that is not one is yt-dlp chatter
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| serde_json::from_str::<FlatResult>(line).ok())
+        .map(RemoteCandidate::from)
+        .collect())
+}
+
+// Queues the search result search_remote returned with this id
+pub fn download_by_id(id: String, flags: Option<String>) -> Result<u64, String> {
+    if id.is_empty()
+        || !id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(format!("{id} is not a video id"));
+    }
+    let url = format!("https://www.youtube.com/watch?v={id}");
+    Ok(enqueue(id, url, false, flags))
+}
+
+// Blocks until the job is done and returns its files
+pub fn wait_for_download(id: u64) -> Result<Vec<String>, String> {
+    let mut manager = DOWNLOADS.lock().unwrap();
+    loop {
+        let job = manager
+            .jobs
+            .get(&id)
+            .ok_or_else(|| format!("There is no download with id {id}"))?;
+        match job.info.state {
+            DownloadState::Completed => return Ok(job.info.files.clone()),
+            DownloadState::Cancelled => return Err("Download cancelled".to_string()),
+            DownloadState::Failed => {
+                return Err(job
+                    .info
+                    .error
+                    .clone()
+                    .unwrap_or("Download failed".to_string()));
+            }
+            _ => manager = DOWNLOAD_FINISHED.wait(manager).unwrap(),
+        }
+    }
+}
+
+// Receives a snapshot of a job every time it is queued, makes progress or finishes
+pub fn watch_downloads(sink: StreamSink<DownloadJob>) {
+    DOWNLOADS.lock().unwrap().sinks.push(sink);
+}
+
+pub fn list_downloads() -> Vec<DownloadJob> {
+    let manager = DOWNLOADS.lock().unwrap();
+    manager.jobs.values().map(|j| j.info.clone()).collect()
+}
+
+pub fn get_download(id: u64) -> Option<DownloadJob> {
+    let manager = DOWNLOADS.lock().unwrap();
+    manager.jobs.get(&id).map(|j| j.info.clone())
+}
+
+// Returns false when the job does not exist or has already finished
+pub fn cancel_download_job(id: u64) -> bool {
+    let mut manager = DOWNLOADS.lock().unwrap();
+    let Some(job) = manager.jobs.get_mut(&id) else {
+        return false;
+    };
+    match job.info.state {
+        state if state.is_finished() => false,
+        // Never started, so no worker is around to notice the flag
+        DownloadState::Queued => {
+            job.info.state = DownloadState::Cancelled;
+            job.cancel.store(true, Ordering::SeqCst);
+            manager.queue.retain(|&q| q != id);
+            manager.emit(id);
+            DOWNLOAD_FINISHED.notify_all();
+            true
+        }
+        _ => {
+            job.cancel.store(true, Ordering::SeqCst);
+            true
+        }
+    }
+}
+
+pub fn cancel_all_downloads() {
+    let ids: Vec<u64> = DOWNLOADS.lock().unwrap().jobs.keys().copied().collect();
+    for id in ids {
+        cancel_download_job(id);
+    }
+}
+
+// Forgets a job once it finished, for callers that waited on it themselves. Its temp folder is
+// deleted unless it completed, then the files are the caller's to delete along with the folder
+#[flutter_rust_bridge::frb(ignore)]
+pub fn forget_download(id: u64) {
+    let mut manager = DOWNLOADS.lock().unwrap();
+    if !manager
+        .jobs
+        .get(&id)
+        .is_some_and(|j| j.info.state.is_finished())
+    {
+        return;
+    }
+    let job = manager.jobs.remove(&id).unwrap();
+    if job.info.state != DownloadState::Completed {
+        let _ = fs::remove_dir_all(&job.info.temp_dir);
+    }
+}
+
+// Forgets finished jobs. The temp folders of failed and cancelled ones are deleted, completed ones
+// are left since their files may still be in use
+pub fn clear_finished_downloads() -> u32 {
+    let mut manager = DOWNLOADS.lock().unwrap();
+    let finished: Vec<u64> = manager
+        .jobs
+        .iter()
+        .filter(|(_, j)| j.info.state.is_finished())
+        .map(|(&id, _)| id)
+        .collect();
+    for id in &finished {
+        if let Some(job) = manager.jobs.remove(id)
+            && job.info.state != DownloadState::Completed
+        {
+            let _ = fs::remove_dir_all(&job.info.temp_dir);
+        }
+    }
+    finished.len() as u32
+}
+
+pub fn set_max_concurrent_downloads(max: u32) {
+    let mut manager = DOWNLOADS.lock().unwrap();
+    manager.max_concurrent = max.max(1);
+    manager.start_queued();
+}
@@ -0,0 +1,303 @@
+use crate::{
+    api::{
+        acoustid::lookup_candidates,
+        download_manager::{queue_download, wait_for_download},
+        music_handler::{SongMetadata, extract_metadata, write_meta},
+        organizer::organize_files,
+        replay_gain::{ReplayGain, compute_replay_gain, write_replay_gain},
+        utils::get_data_dir,
+    },
+    frb_generated::StreamSink,
+};
+use audiotags::Tag;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+static LIBRARY_SINKS: Mutex<Vec<StreamSink<SongMetadata>>> = Mutex::new(Vec::new());
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TitleRule {
+    pub pattern: String,
+    // May refer to groups in the pattern as $1 or ${name}
+    pub replacement: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PipelineConfig {
+    pub clean_titles: bool,
+    // Run in order on the title, after the artist has been split off
+    pub title_rules: Vec<TitleRule>,
+    // Turns "Artist - Title" into separate tags when the artist is missing or repeated
+    pub split_artist_title: bool,
+    pub identify: bool,
+    // AcoustID matches below this score leave the tags alone
+    pub min_identify_score: f64,
+    pub replay_gain: bool,
+    pub import: bool,
+    // An organize_files template, relative to the music folder
+    pub template: String,
+}
+
+impl Default for PipelineConfig {
+    fn default() -> Self {
+        Self {
+            clean_titles: true,
+            title_rules: vec![
+                TitleRule {
+                    pattern: r"(?i)\s*[\[\(](?:hd|hq|4k|8k|explicit|clean)[\]\)]".to_string(),
+                    replacement: String::new(),
+                },
+                TitleRule {
+                    pattern: r"(?i)\s*[\[\(](?:official\s*)?(?:music\s*)?video[\]\)]".to_string(),
+                    replacement: String::new(),
+                },
+            ],
+            split_artist_title: true,
+            identify: false,
+            min_identify_score: 0.8,
+            replay_gain: true,
+            import: true,
+            template: "{artist}/{album}/{title}.{ext}".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PipelineResult {
+    pub original_path: String,
+    // Where the file ended up, the same as original_path unless it was imported
+    pub path: String,
+    pub metadata: Option<SongMetadata>,
+    pub identified: bool,
+    pub replay_gain: Option<ReplayGain>,
+    // Stages that failed, the rest of the pipeline still runs after one does
+    pub warnings: Vec<String>,
+}
+
+fn config_path() -> PathBuf {
+    get_data_dir("downloads").join("pipeline.json")
+}
+
+pub fn get_pipeline_config() -> PipelineConfig {
+    fs::read_to_string(config_path())
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+pub fn set_pipeline_config(config: PipelineConfig) -> Result<(), String> {
+    compile_rules(&config.title_rules)?;
+    let path = config_path();
+    let tmp = path.with_extension("json.tmp");
+    let json = serde_json::to_string(&config)
+        .map_err(|e| format!("Failed to serialise the pipeline config: {e}"))?;
+    fs::write(&tmp, json)
+        .and_then(|_| fs::rename(&tmp, &path))
+        .map_err(|e| format!("Failed to save the pipeline config: {e}"))
+}
+
+fn compile_rules(rules: &[TitleRule]) -> Result<Vec<(Regex, &str)>, String> {
+    rules
+        .iter()
+        .map(|rule| {
+            Regex::new(&rule.pattern)
+                .map(|re| (re, rule.replacement.as_str()))
+                .map_err(|e| format!("Invalid title rule {}: {e}", rule.pattern))
+        })
+        .collect()
+}
+
+fn fold(s: &str) -> String {
+    s.trim().to_lowercase()
+}
+
+// Returns whether anything changed, files without a title get one from their file name
+fn clean_tags(path: &Path, config: &PipelineConfig) -> Result<bool, String> {
+    let path_str = path.to_string_lossy();
+    let mut tag = Tag::new()
+        .read_from_path(path)
+        .map_err(|e| format!("Failed to read the tags of {path_str}: {e}"))?;
+    let old_title = tag.title().unwrap_or_default().to_string();
+    let old_artist = tag.artist().unwrap_or_default().to_string();
+
+    let mut title = if old_title.trim().is_empty() {
+        path.file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default()
+    } else {
+        old_title.clone()
+    };
+    // YouTube's auto generated channels are named "Artist - Topic"
+    let mut artist = old_artist.trim().trim_end_matches(" - Topic").to_string();
+    if config.split_artist_title
+        && let Some((left, right)) = title.split_once(" - ")
+        && (artist.is_empty() || fold(left) == fold(&artist))
+        && !right.trim().is_empty()
+    {
+        artist = left.trim().to_string();
+        title = right.to_string();
+    }
+    for (re, replacement) in compile_rules(&config.title_rules)? {
+        title = re.replace_all(&title, replacement).to_string();
+    }
+    let title = title.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if title.is_empty() || (title == old_title && artist == old_artist) {
+        return Ok(false);
+    }
+    tag.set_title(&title);
+    if !artist.is_empty() {
+        tag.set_artist(&artist);
+    }
+    tag.write_to_path(&path_str)
+        .map_err(|e| format!("Failed to tag {path_str}: {e}"))?;
+    Ok(true)
+}
+
+// Overwrites the tags with the best AcoustID match, write_meta keeps the cover the download came
+// with
+fn identify(path: &Path, min_score: f64) -> Result<bool, String> {
+    let path_str = path.to_string_lossy().to_string();
+    let Some(best) = lookup_candidates(path_str.clone(), 1)?.into_iter().next() else {
+        return Ok(false);
+    };
+    if best.score < min_score {
+        return Ok(false);
+    }
+    let current = extract_metadata(path).unwrap_or_default();
+    let defaults = SongMetadata::default();
+    let mut meta = best.metadata;
+    meta.path = path_str;
+    if meta.album == defaults.album {
+        meta.album = current.album;
+    }
+    if meta.genre == defaults.genre {
+        meta.genre = current.genre;
+    }
+    write_meta(&meta)?;
+    Ok(true)
+}
+
+// Imports are announced through watch_library_additions so the library can add the song without
+// a rescan
+pub(crate) fn announce_library_addition(meta: &SongMetadata) {
+    LIBRARY_SINKS
+        .lock()
+        .unwrap()
+        .retain(|sink| sink.add(meta.clone()).is_ok());
+}
+
+fn run(path: &Path, config: &PipelineConfig) -> PipelineResult {
+    let mut result = PipelineResult {
+        original_path: path.to_string_lossy().to_string(),
+        path: path.to_string_lossy().to_string(),
+        metadata: None,
+        identified: false,
+        replay_gain: None,
+        warnings: Vec::new(),
+    };
+
+    if config.clean_titles
+        && let Err(e) = clean_tags(path, config)
+    {
+        result.warnings.push(e);
+    }
+    if config.identify {
+        match identify(path, config.min_identify_score) {
+            Ok(identified) => result.identified = identified,
+            Err(e) => result.warnings.push(format!("Identification failed: {e}")),
+        }
+    }
+    if config.replay_gain {
+        match compute_replay_gain(result.path.clone())
+            .and_then(|gain| write_replay_gain(result.path.clone(), gain).map(|_| gain))
+        {
+            Ok(gain) => result.replay_gain = Some(gain),
+            Err(e) => result.warnings.push(format!("ReplayGain failed: {e}")),
+        }
+    }
+    if config.import {
+        match organize_files(
+            vec![result.path.clone()],
+            config.template.clone(),
+            None,
+            false,
+        ) {
+            Ok(organized) => {
+                if let Some(moved) = organized.plan.moves.first() {
+                    result.path = moved.to.clone();
+                    // Leaves nothing behind in the download manager's temp folder
+                    if let Some(parent) = path.parent()
+                        && parent.starts_with(std::env::temp_dir())
+                    {
+                        let _ = fs::remove_dir(parent);
+                    }
+                }
+                if let Some(skipped) = organized.plan.skipped.first() {
+                    result.warnings.push(skipped.clone());
+                }
+            }
+            Err(e) => result.warnings.push(format!("Import failed: {e}")),
+        }
+    }
+
+    result.metadata = extract_metadata(Path::new(&result.path));
+    if config.import
+        && result.path != result.original_path
+        && let Some(meta) = &result.metadata
+    {
+        announce_library_addition(meta);
+    }
+    result
+}
+
+// The tagging stages only, for callers that put the file somewhere themselves
+#[flutter_rust_bridge::frb(ignore)]
+pub fn run_in_place(path: &Path, config: &PipelineConfig) -> PipelineResult {
+    let config = PipelineConfig {
+        import: false,
+        ..config.clone()
+    };
+    run(path, &config)
+}
+
+// Runs a downloaded file through the post-processing stages: title cleanup, AcoustID
+// identification, ReplayGain and importing into the music folder. Uses the saved config unless
+// one is given
+pub fn process_download(
+    path: String,
+    config: Option<PipelineConfig>,
+) -> Result<PipelineResult, String> {
+    let config = config.unwrap_or_else(get_pipeline_config);
+    let path = Path::new(&path);
+    if !path.is_file() {
+        return Err(format!("{} does not exist", path.display()));
+    }
+    compile_rules(&config.title_rules)?;
+    Ok(run(path, &config))
+}
+
+// Searches, downloads and post-processes in one go
+pub fn download_and_import(
+    query: String,
+    flags: Option<String>,
+    config: Option<PipelineConfig>,
+) -> Result<Vec<PipelineResult>, String> {
+    let config = config.unwrap_or_else(get_pipeline_config);
+    compile_rules(&config.title_rules)?;
+    let files = wait_for_download(queue_download(query, flags))?;
+    Ok(files
+        .iter()
+        .map(|file| run(Path::new(file), &config))
+        .collect())
+}
+
+pub fn watch_library_additions(sink: StreamSink<SongMetadata>) {
+    LIBRARY_SINKS.lock().unwrap().push(sink);
+}
@@ -0,0 +1,198 @@
+use crate::api::{
+    cue_sheet::reject_virtual_track,
+    synced_lyrics::{format_timestamp, parse_lrc_document},
+};
+use id3::{
+    ErrorKind, TagLike, Version,
+    frame::{Lyrics, SynchronisedLyrics, SynchronisedLyricsType, TimestampFormat},
+};
+use std::path::Path;
+
+// ID3 wants a language on lyric frames, XXX is its code for an unknown one
+const ID3_LANGUAGE: &str = "XXX";
+// Vorbis comment keys players put lyrics under, in order of preference
+const VORBIS_KEYS: [&str; 2] = ["LYRICS", "UNSYNCEDLYRICS"];
+
+enum Container {
+    Id3,
+    Flac,
+    Mp4,
+}
+
+fn container(path: &Path) -> Result<Container, String> {
+    let ext = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    match ext.as_str() {
+        "mp3" => Ok(Container::Id3),
+        "flac" => Ok(Container::Flac),
+        "m4a" | "m4b" | "mp4" => Ok(Container::Mp4),
+        _ => Err(format!(
+            "Embedded lyrics are not supported for .{ext} files"
+        )),
+    }
+}
+
+// SYLT frames either hold a line per entry or a syllable per entry with a newline in front of
+// the first syllable of every line. The second kind becomes Enhanced LRC
+fn sylt_to_lrc(content: &[(u32, String)]) -> String {
+    let per_syllable = content
+        .iter()
+        .any(|(_, text)| text.starts_with(['\n', '\r']));
+    if !per_syllable {
+        return content
+            .iter()
+            .map(|(ms, text)| format!("[{}]{}", format_timestamp(*ms as u64), text.trim()))
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+
+    let mut lines: Vec<String> = Vec::new();
+    for (ms, text) in content {
+        let stamp = format_timestamp(*ms as u64);
+        let word = text.trim();
+        match lines.last_mut() {
+            Some(line) if !text.starts_with(['\n', '\r']) => {
+                line.push_str(&format!(" <{stamp}>{word}"));
+            }
+            _ => lines.push(format!("[{stamp}]<{stamp}>{word}")),
+        }
+    }
+    lines.join("\n")
+}
+
+fn read_id3(path: &Path) -> Result<Option<String>, String> {
+    let tag = match id3::Tag::read_from_path(path) {
+        Ok(tag) => tag,
+        Err(e) if matches!(e.kind, ErrorKind::NoTag) => return Ok(None),
+        Err(e) => return Err(format!("Failed to read the ID3 tag: {e}")),
+    };
+    // Millisecond SYLT frames keep the timing, MPEG frame timestamps need the bitrate to convert
+    if let Some(synced) = tag
+        .synchronised_lyrics()
+        .find(|s| matches!(s.timestamp_format, TimestampFormat::Ms) && !s.content.is_empty())
+    {
+        return Ok(Some(sylt_to_lrc(&synced.content)));
+    }
+    Ok(tag
+        .lyrics()
+        .map(|l| l.text.trim())
+        .find(|t| !t.is_empty())
+        .map(str::to_string))
+}
+
+fn write_id3(path: &Path, lyrics: Option<&str>) -> Result<(), String> {
+    let mut tag = match id3::Tag::read_from_path(path) {
+        Ok(tag) => tag,
+        Err(e) if matches!(e.kind, ErrorKind::NoTag) => id3::Tag::new(),
+        Err(e) => return Err(format!("Failed to read the ID3 tag: {e}")),
+    };
+    tag.remove_all_lyrics();
+    tag.remove_all_synchronised_lyrics();
+
+    // USLT gets the plain words so players without SYLT support show them cleanly, SYLT the timing
+    if let Some(lyrics) = lyrics {
+        let doc = parse_lrc_document(lyrics);
+        let text = doc
+            .lines
+            .iter()
+            .map(|l| l.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        tag.add_frame(Lyrics {
+            lang: ID3_LANGUAGE.to_string(),
+            description: String::new(),
+            text,
+        });
+        if doc.synced {
+            tag.add_frame(SynchronisedLyrics {
+                lang: ID3_LANGUAGE.to_string(),
+                timestamp_format: TimestampFormat::Ms,
+                content_type: SynchronisedLyricsType::Lyrics,
+                description: String::new(),
+                content: doc
+                    .lines
+                    .iter()
+                    .map(|l| (doc.apply_offset(l.start_ms) as u32, l.text.clone()))
+                    .collect(),
+            });
+        }
+    }
+
+    tag.write_to_path(path, Version::Id3v24)
+        .map_err(|e| format!("Failed to write the ID3 tag: {e}"))
+}
+
+fn read_flac(path: &Path) -> Result<Option<String>, String> {
+    let tag = metaflac::Tag::read_from_path(path)
+        .map_err(|e| format!("Failed to read the FLAC tags: {e}"))?;
+    Ok(VORBIS_KEYS.iter().find_map(|key| {
+        tag.get_vorbis(key)?
+            .map(str::trim)
+            .find(|t| !t.is_empty())
+            .map(str::to_string)
+    }))
+}
+
+// FLAC and MP4 keep the LRC text as is, players that understand it read the timestamps from there
+fn write_flac(path: &Path, lyrics: Option<&str>) -> Result<(), String> {
+    let mut tag = metaflac::Tag::read_from_path(path)
+        .map_err(|e| format!("Failed to read the FLAC tags: {e}"))?;
+    for key in VORBIS_KEYS {
+        tag.remove_vorbis(key);
+    }
+    if let Some(lyrics) = lyrics {
+        tag.set_vorbis(VORBIS_KEYS[0], vec![lyrics]);
+    }
+    tag.save()
+        .map_err(|e| format!("Failed to write the FLAC tags: {e}"))
+}
+
+fn read_mp4(path: &Path) -> Result<Option<String>, String> {
+    let tag = mp4ameta::Tag::read_from_path(path)
+        .map_err(|e| format!("Failed to read the MP4 tags: {e}"))?;
+    Ok(tag
+        .lyrics()
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(str::to_string))
+}
+
+fn write_mp4(path: &Path, lyrics: Option<&str>) -> Result<(), String> {
+    let mut tag = mp4ameta::Tag::read_from_path(path)
+        .map_err(|e| format!("Failed to read the MP4 tags: {e}"))?;
+    match lyrics {
+        Some(lyrics) => tag.set_lyrics(lyrics),
+        None => tag.remove_lyrics(),
+    }
+    tag.write_to_path(path)
+        .map_err(|e| format!("Failed to write the MP4 tags: {e}"))
+}
+
+// The lyrics stored in the file's tags as LRC text, synced SYLT frames are converted. None when the
+// file has no lyrics
+pub fn read_embedded_lyrics(path: String) -> Result<Option<String>, String> {
+    let path = Path::new(&path);
+    match container(path)? {
+        Container::Id3 => read_id3(path),
+        Container::Flac => read_flac(path),
+        Container::Mp4 => read_mp4(path),
+    }
+}
+
+// Replaces whatever lyrics the file has with these, given as LRC or plain text
+pub fn write_embedded_lyrics(path: String, lyrics: String) -> Result<(), String> {
+    reject_virtual_track(&path)?;
+    let path = Path::new(&path);
+    let lyrics = Some(lyrics.trim()).filter(|l| !l.is_empty());
+    match container(path)? {
+        Container::Id3 => write_id3(path, lyrics),
+        Container::Flac => write_flac(path, lyrics),
+        Container::Mp4 => write_mp4(path, lyrics),
+    }
+}
+
+pub fn remove_embedded_lyrics(path: String) -> Result<(), String> {
+    write_embedded_lyrics(path, String::new())
+}
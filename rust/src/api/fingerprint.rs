@@ -0,0 +1,320 @@
+use crate::api::{
+    acoustid::{calc_fingerprint, get_duration},
+    utils::get_cache_dir,
+};
+use anyhow::Context;
+use once_cell::sync::Lazy;
+use rayon::prelude::*;
+use rusty_chromaprint::{Configuration, match_fingerprints};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::HashMap,
+    fs,
+    io::BufReader,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::SystemTime,
+};
+use walkdir::WalkDir;
+
+// Tracks whose durations differ by more than this are never compared
+const DURATION_TOLERANCE_SECS: f64 = 7.0;
+// How much of the shorter track has to line up with the other before they count as the same recording
+const MIN_MATCH_COVERAGE: f64 = 0.7;
+
+// Path to (size, modified time, content hash)
+type HashMemo = HashMap<PathBuf, (u64, SystemTime, String)>;
+
+// Remembers which content hash a path had so unchanged files are not hashed again every call
+static HASH_MEMO: Lazy<Mutex<HashMemo>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Serialize, Deserialize)]
+struct CachedFingerprint {
+    fingerprint: Vec<u32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DuplicateFile {
+    pub path: String,
+    pub format: String,
+    pub size_bytes: u64,
+    pub duration: f64,
+    pub bitrate_kbps: u32,
+    pub sample_rate: Option<u32>,
+    pub bits_per_sample: Option<u32>,
+    pub lossless: bool,
+}
+
+// Files in a group are sorted best quality first so the first one is the sensible one to keep
+#[derive(Debug, Clone)]
+pub struct DuplicateGroup {
+    pub files: Vec<DuplicateFile>,
+    pub similarity: f64,
+}
+
+fn get_fingerprint_cache_dir() -> PathBuf {
+    get_cache_dir("fingerprints")
+}
+
+fn content_hash(path: &Path) -> anyhow::Result<String> {
+    let meta = fs::metadata(path).context("Failed to read file metadata")?;
+    let modified = meta.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+    if let Some((len, mtime, hash)) = HASH_MEMO.lock().unwrap().get(path)
+        && *len == meta.len()
+        && *mtime == modified
+    {
+        return Ok(hash.clone());
+    }
+
+    let file = fs::File::open(path).context("Failed to open file")?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut BufReader::new(file), &mut hasher).context("Failed to hash file")?;
+    let hash = format!("{:x}", hasher.finalize());
+
+    HASH_MEMO
+        .lock()
+        .unwrap()
+        .insert(path.to_path_buf(), (meta.len(), modified, hash.clone()));
+    Ok(hash)
+}
+
+// Returns the chromaprint fingerprint of a file, only decoding it if its contents have not been
+// fingerprinted before
+#[flutter_rust_bridge::frb(ignore)]
+pub fn cached_fingerprint(path: impl AsRef<Path>) -> anyhow::Result<Vec<u32>> {
+    let path = path.as_ref();
+    let hash = content_hash(path)?;
+    let cache_path = get_fingerprint_cache_dir().join(format!("{hash}.json"));
+
+    let cached = fs::read_to_string(&cache_path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<CachedFingerprint>(&content).ok());
+    if let Some(cached) = cached {
+        return Ok(cached.fingerprint);
+    }
+
+    let fingerprint = calc_fingerprint(path)?;
+    // A failed write only costs us a decode next time so it is not worth failing over
+    if let Ok(json) = serde_json::to_string(&CachedFingerprint {
+        fingerprint: fingerprint.clone(),
+    }) {
+        let _ = fs::write(&cache_path, json);
+    }
+    Ok(fingerprint)
+}
+
+pub fn clear_fingerprint_cache() -> bool {
+    HASH_MEMO.lock().unwrap().clear();
+    let cache_dir = get_fingerprint_cache_dir();
+    if cache_dir.exists() {
+        fs::remove_dir_all(&cache_dir).is_ok()
+    } else {
+        true
+    }
+}
+
+// Reads format details from the container without decoding any audio
+fn probe_quality(path: &Path) -> DuplicateFile {
+    let format = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let size_bytes = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+    let mut file = DuplicateFile {
+        path: path.to_string_lossy().to_string(),
+        lossless: matches!(format.as_str(), "flac" | "wav" | "alac" | "aiff"),
+        format,
+        size_bytes,
+        duration: 0.0,
+        bitrate_kbps: 0,
+        sample_rate: None,
+        bits_per_sample: None,
+    };
+
+    let Ok(src) = fs::File::open(path) else {
+        return file;
+    };
+    let mss = symphonia::core::io::MediaSourceStream::new(Box::new(src), Default::default());
+    let mut hint = symphonia::core::probe::Hint::new();
+    hint.with_extension(&file.format);
+    let Ok(probed) = symphonia::default::get_probe().format(
+        &hint,
+        mss,
+        &Default::default(),
+        &Default::default(),
+    ) else {
+        return file;
+    };
+
+    if let Some(track) = probed
+        .format
+        .tracks()
+        .iter()
+        .find(|t| t.codec_params.codec != symphonia::core::codecs::CODEC_TYPE_NULL)
+    {
+        let params = &track.codec_params;
+        file.sample_rate = params.sample_rate;
+        file.bits_per_sample = params.bits_per_sample;
+        if let (Some(frames), Some(rate)) = (params.n_frames, params.sample_rate)
+            && rate > 0
+        {
+            file.duration = frames as f64 / rate as f64;
+        }
+        // ALAC hides in m4a containers so the extension alone is not enough
+        if params.codec == symphonia::core::codecs::CODEC_TYPE_ALAC {
+            file.lossless = true;
+        }
+    }
+    // Some containers (mostly mp3 without a xing header) do not store a frame count
+    if file.duration <= 0.0 {
+        file.duration = get_duration(path) as f64;
+    }
+    if file.duration > 0.0 {
+        file.bitrate_kbps = ((size_bytes as f64 * 8.0) / file.duration / 1000.0) as u32;
+    }
+    file
+}
+
+fn quality_rank(file: &DuplicateFile) -> (bool, u32, u32, u32) {
+    (
+        file.lossless,
+        file.bits_per_sample.unwrap_or(0),
+        file.sample_rate.unwrap_or(0),
+        file.bitrate_kbps,
+    )
+}
+
+fn find_root(parents: &mut [usize], i: usize) -> usize {
+    let mut root = i;
+    while parents[root] != root {
+        root = parents[root];
+    }
+    // Flatten the path so later lookups are quick
+    let mut cur = i;
+    while parents[cur] != root {
+        let next = parents[cur];
+        parents[cur] = root;
+        cur = next;
+    }
+    root
+}
+
+// Fingerprints every track under music_folder (using the cache where possible) and groups the
+// ones that are the same recording, even across formats and bitrates
+pub fn find_duplicates(music_folder: String) -> Result<Vec<DuplicateGroup>, String> {
+    if !Path::new(&music_folder).is_dir() {
+        return Err("The provided folder is not a folder or does not exist".to_string());
+    }
+
+    let paths: Vec<PathBuf> = WalkDir::new(&music_folder)
+        .follow_links(true)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| !e.path().components().any(|c| c.as_os_str() == ".adilists"))
+        .filter(|e| {
+            e.path()
+                .extension()
+                .map(|ext| {
+                    matches!(
+                        ext.to_string_lossy().to_lowercase().as_str(),
+                        "mp3" | "m4a" | "flac" | "ogg" | "wav"
+                    )
+                })
+                .unwrap_or(false)
+        })
+        .map(|e| e.into_path())
+        .collect();
+
+    let mut tracks: Vec<(DuplicateFile, Vec<u32>)> = paths
+        .par_iter()
+        .filter_map(|path| {
+            let fingerprint = match cached_fingerprint(path) {
+                Ok(f) if !f.is_empty() => f,
+                Ok(_) => return None,
+                Err(e) => {
+                    eprintln!(
+                        "Skipping {} while looking for duplicates: {e}",
+                        path.display()
+                    );
+                    return None;
+                }
+            };
+            Some((probe_quality(path), fingerprint))
+        })
+        .collect();
+
+    // Sorted by duration so each track only has to be compared with its near neighbours
+    tracks.sort_by(|a, b| a.0.duration.total_cmp(&b.0.duration));
+
+    let config = Configuration::default();
+    let matches: Vec<(usize, usize, f64)> = (0..tracks.len())
+        .into_par_iter()
+        .flat_map_iter(|i| {
+            let (file_a, fp_a) = &tracks[i];
+            let mut found = Vec::new();
+            for (j, (file_b, fp_b)) in tracks.iter().enumerate().skip(i + 1) {
+                if file_b.duration - file_a.duration > DURATION_TOLERANCE_SECS {
+                    break;
+                }
+                let Ok(segments) = match_fingerprints(fp_a, fp_b, &config) else {
+                    continue;
+                };
+                let shorter = file_a.duration.min(file_b.duration);
+                if segments.is_empty() || shorter <= 0.0 {
+                    continue;
+                }
+                let matched: f64 = segments.iter().map(|s| s.duration(&config) as f64).sum();
+                if matched / shorter >= MIN_MATCH_COVERAGE {
+                    // Segment scores are the average number of differing bits out of 32
+                    let score =
+                        segments.iter().map(|s| s.score).sum::<f64>() / segments.len() as f64;
+                    found.push((i, j, (1.0 - score / 32.0).clamp(0.0, 1.0)));
+                }
+            }
+            found
+        })
+        .collect();
+
+    let mut parents: Vec<usize> = (0..tracks.len()).collect();
+    for (a, b, _) in &matches {
+        let (ra, rb) = (find_root(&mut parents, *a), find_root(&mut parents, *b));
+        if ra != rb {
+            parents[rb] = ra;
+        }
+    }
+
+    let mut grouped: HashMap<usize, (Vec<usize>, f64, usize)> = HashMap::new();
+    for i in 0..tracks.len() {
+        let root = find_root(&mut parents, i);
+        grouped
+            .entry(root)
+            .or_insert((Vec::new(), 0.0, 0))
+            .0
+            .push(i);
+    }
+    for (a, _, similarity) in &matches {
+        let root = find_root(&mut parents, *a);
+        if let Some(group) = grouped.get_mut(&root) {
+            group.1 += similarity;
+            group.2 += 1;
+        }
+    }
+
+    let mut groups: Vec<DuplicateGroup> = grouped
+        .into_values()
+        .filter(|(members, _, _)| members.len() > 1)
+        .map(|(members, similarity_sum, match_count)| {
+            let mut files: Vec<DuplicateFile> =
+                members.into_iter().map(|i| tracks[i].0.clone()).collect();
+            files.sort_by_key(|f| std::cmp::Reverse(quality_rank(f)));
+            DuplicateGroup {
+                files,
+                similarity: similarity_sum / match_count.max(1) as f64,
+            }
+        })
+        .collect();
+    groups.sort_by(|a, b| a.files[0].path.cmp(&b.files[0].path));
+
+    Ok(groups)
+}
@@ -1,5 +1,6 @@
 use crate::api::{
     music_handler::get_cvol,
+    playlist_manager::{self, PlaylistSortKey},
     utils::{check_unsafe_api, fpre, validate_path},
     value_store::{acquire_read_lock, check_value_store_state},
 };
@@ -775,65 +776,118 @@ host_fn!(call_plugin_func(user_data: (); func: String, plugin_name: String) -> b
 });
 
 #[frb(ignore)]
-host_fn!(create_playlist(user_data: (); name: String) -> bool {
-    if !check_value_store_state() {
-        return Ok(false);
-    }
-    let mf: String = match acquire_read_lock() {
-        Ok(guard) => {
-            if let Some(state) = guard.as_ref() {
-                state.music_folder.clone()
-            } else {
-                return Ok(false);
-            }
-        },
-        Err(_) => return Ok(false),
-    };
-    if let Ok(_) = fs::create_dir_all(PathBuf::from(mf).join(".adilists").join(name)) {
-        return Ok(true);
-    } else {
-        return Ok(false);
-    }
+#[derive(Serialize, Deserialize, ToBytes, FromBytes)]
+#[encoding(Json)]
+pub struct PluginPlaylist {
+    pub name: String,
+    pub track_count: u32,
+    pub is_smart: bool,
+}
+
+#[frb(ignore)]
+#[derive(Serialize, Deserialize, ToBytes, FromBytes)]
+#[encoding(Json)]
+pub struct PluginPlaylists {
+    pub playlists: Vec<PluginPlaylist>,
+}
+
+#[frb(ignore)]
+#[derive(Serialize, Deserialize, ToBytes, FromBytes)]
+#[encoding(Json)]
+pub struct PlaylistTracks {
+    pub tracks: Vec<String>,
+}
+
+#[frb(ignore)]
+#[derive(Serialize, Deserialize, ToBytes, FromBytes)]
+#[encoding(Json)]
+pub struct PlaylistIndices {
+    pub indices: Vec<u32>,
+}
+
+#[frb(ignore)]
+#[derive(Serialize, Deserialize, ToBytes, FromBytes)]
+#[encoding(Json)]
+pub struct PlaylistNames {
+    pub names: Vec<String>,
+}
+
+#[frb(ignore)]
+host_fn!(list_playlists() -> PluginPlaylists {
+    let playlists = playlist_manager::list_playlists()
+        .into_iter()
+        .map(|p| PluginPlaylist { name: p.name, track_count: p.track_count, is_smart: p.is_smart })
+        .collect();
+    Ok(PluginPlaylists { playlists })
 });
 
 #[frb(ignore)]
-host_fn!(add_to_playlist(user_data: (); song_path: String, playlist: String) -> bool {
-    if !check_value_store_state() {
-        return Ok(false);
-    }
-    let mf: String = match acquire_read_lock() {
-        Ok(guard) => {
-            if let Some(state) = guard.as_ref() {
-                state.music_folder.clone()
-            } else {
-                return Ok(false);
-            }
-        },
-        Err(_) => return Ok(false),
+host_fn!(get_playlist_tracks(user_data: (); playlist: String) -> PlaylistTracks {
+    let tracks = match playlist_manager::get_playlist_entries(playlist) {
+        Ok(entries) => entries.into_iter().map(|e| e.path).collect(),
+        Err(_) => Vec::new(),
     };
+    Ok(PlaylistTracks { tracks })
+});
+
+#[frb(ignore)]
+host_fn!(create_playlist(user_data: (); name: String) -> bool {
+    Ok(playlist_manager::create_playlist(name).is_ok())
+});
 
-    let playlist_dir = PathBuf::from(&mf).join(".adilists").join(&playlist);
-    let song_path_buf = PathBuf::from(&song_path);
+#[frb(ignore)]
+host_fn!(rename_playlist(user_data: (); name: String, new_name: String) -> bool {
+    Ok(playlist_manager::rename_playlist(name, new_name).is_ok())
+});
 
-    if !playlist_dir.exists() || !song_path_buf.exists() {
-        return Ok(false);
-    }
+#[frb(ignore)]
+host_fn!(delete_playlist(user_data: (); name: String) -> bool {
+    Ok(playlist_manager::delete_playlist(name).is_ok())
+});
 
-    let filename = match song_path_buf.file_name() {
-        Some(name) => name,
-        None => return Ok(false),
-    };
+#[frb(ignore)]
+host_fn!(duplicate_playlist(user_data: (); name: String, new_name: String) -> bool {
+    Ok(playlist_manager::duplicate_playlist(name, new_name).is_ok())
+});
 
-    let symlink_path = playlist_dir.join(filename);
+#[frb(ignore)]
+host_fn!(add_to_playlist(user_data: (); song_path: String, playlist: String) -> bool {
+    Ok(matches!(playlist_manager::add_to_playlist(playlist, vec![song_path], None, false), Ok(n) if n > 0))
+});
 
-    if symlink_path.exists() {
-        return Ok(false);
-    }
+#[frb(ignore)]
+host_fn!(remove_from_playlist(user_data: (); playlist: String, indices: PlaylistIndices) -> bool {
+    Ok(playlist_manager::remove_from_playlist(playlist, indices.indices).is_ok())
+});
 
-    match std::os::unix::fs::symlink(&song_path_buf, &symlink_path) {
-        Ok(()) => Ok(true),
-        Err(_) => Ok(false),
-    }
+#[frb(ignore)]
+host_fn!(move_playlist_entry(user_data: (); playlist: String, from: u64, to: u64) -> bool {
+    Ok(playlist_manager::move_playlist_entry(playlist, from as u32, to as u32).is_ok())
+});
+
+#[frb(ignore)]
+host_fn!(reorder_playlist(user_data: (); playlist: String, order: PlaylistIndices) -> bool {
+    Ok(playlist_manager::reorder_playlist(playlist, order.indices).is_ok())
+});
+
+#[frb(ignore)]
+host_fn!(merge_playlists(user_data: (); sources: PlaylistNames, target: String) -> bool {
+    Ok(playlist_manager::merge_playlists(sources.names, target, false).is_ok())
+});
+
+// key is one of title, artist, album, duration, file_name or path
+#[frb(ignore)]
+host_fn!(sort_playlist(user_data: (); playlist: String, key: String, descending: bool) -> bool {
+    let key = match key.to_lowercase().as_str() {
+        "title" => PlaylistSortKey::Title,
+        "artist" => PlaylistSortKey::Artist,
+        "album" => PlaylistSortKey::Album,
+        "duration" => PlaylistSortKey::Duration,
+        "file_name" | "filename" => PlaylistSortKey::FileName,
+        "path" => PlaylistSortKey::Path,
+        _ => return Ok(false),
+    };
+    Ok(playlist_manager::sort_playlist(playlist, key, descending).is_ok())
 });
 
 // A macro to decide how to format the functions for me
@@ -947,8 +1001,18 @@ pub fn add_functions(b: PluginBuilder) -> PluginBuilder {
         // Other plugin functions
         generic_func!(call_plugin_func(func: String, plugin_name: String) -> bool),
         // Functions to interact with the music player
+        generic_func!(list_playlists() -> PluginPlaylists),
+        generic_func!(get_playlist_tracks(playlist: String) -> PlaylistTracks),
         generic_func!(create_playlist(name: String) -> bool),
+        generic_func!(rename_playlist(name: String, new_name: String) -> bool),
+        generic_func!(delete_playlist(name: String) -> bool),
+        generic_func!(duplicate_playlist(name: String, new_name: String) -> bool),
         generic_func!(add_to_playlist(song_path: String, playlist: String) -> bool),
+        generic_func!(remove_from_playlist(playlist: String, indices: PlaylistIndices) -> bool),
+        generic_func!(move_playlist_entry(playlist: String, from: u64, to: u64) -> bool),
+        generic_func!(reorder_playlist(playlist: String, order: PlaylistIndices) -> bool),
+        generic_func!(merge_playlists(sources: PlaylistNames, target: String) -> bool),
+        generic_func!(sort_playlist(playlist: String, key: String, descending: bool) -> bool),
     ];
     b.with_functions(f)
 }
@@ -0,0 +1,888 @@
+use crate::{
+    api::{
+        music_handler::SongMetadata,
+        plugin_man::call_func_plugins,
+        value_store::{update_store, with_store},
+    },
+    frb_generated::StreamSink,
+};
+use once_cell::sync::Lazy;
+use reqwest::{Url, blocking::Response, header::CONTENT_TYPE};
+use rodio::Source;
+use std::{
+    collections::VecDeque,
+    io::{self, Cursor, Read},
+    sync::{
+        Arc, Condvar, Mutex,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        mpsc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+use symphonia::core::{
+    audio::SampleBuffer,
+    codecs::CODEC_TYPE_NULL,
+    errors::Error as SymphoniaError,
+    io::{MediaSourceStream, ReadOnlySource},
+    probe::Hint,
+};
+
+const STREAM_USER_AGENT: &str = concat!(
+    "Adiman/",
+    env!("CARGO_PKG_VERSION"),
+    " (https://github.com/ChaosTheChaotic/Adiman)"
+);
+// How long play_song may wait for a station to start sending audio
+const OPEN_TIMEOUT: Duration = Duration::from_secs(20);
+// Playback starts, and restarts after running dry, once this much audio is buffered
+const PREBUFFER_SECS: usize = 2;
+// Decoding pauses once this much is waiting to be played
+const MAX_BUFFER_SECS: usize = 10;
+// Consecutive failed reconnects before giving up, waiting twice as long after each one
+const MAX_RECONNECTS: u32 = 5;
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+// .pls and .m3u links point at the real stream, possibly through another playlist
+const MAX_PLAYLIST_HOPS: u32 = 3;
+// Live HLS playback starts this many segments from the end, like most players do
+const HLS_LIVE_SEGMENTS: usize = 3;
+const TS_PACKET_LEN: usize = 188;
+
+// No overall timeout, a radio stream is one response that never finishes
+static STREAM_CLIENT: Lazy<reqwest::blocking::Client> = Lazy::new(|| {
+    reqwest::blocking::Client::builder()
+        .user_agent(STREAM_USER_AGENT)
+        .connect_timeout(Duration::from_secs(10))
+        .timeout(None::<Duration>)
+        .build()
+        .unwrap_or_default()
+});
+static NOW_PLAYING: Mutex<Option<StreamMetadata>> = Mutex::new(None);
+static METADATA_SINKS: Mutex<Vec<StreamSink<StreamMetadata>>> = Mutex::new(Vec::new());
+// Bumped for every stream opened so a stream that is still shutting down cannot overwrite what
+// the new one reports
+static STREAM_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+// What is known about the stream playing now, sent to watch_stream_metadata whenever it changes
+#[derive(Debug, Clone, Default)]
+pub struct StreamMetadata {
+    pub url: String,
+    // From the icy-name, icy-genre and icy-br headers
+    pub station_name: Option<String>,
+    pub genre: Option<String>,
+    pub bitrate_kbps: Option<u32>,
+    // The raw StreamTitle, usually "Artist - Title"
+    pub now_playing: Option<String>,
+    pub artist: Option<String>,
+    pub title: Option<String>,
+    // Set while the stream is reconnecting after dropping
+    pub reconnecting: bool,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+pub fn is_stream_url(path: &str) -> bool {
+    let lower = path.to_ascii_lowercase();
+    lower.starts_with("http://") || lower.starts_with("https://")
+}
+
+fn publish(generation: u64, f: impl FnOnce(&mut StreamMetadata)) {
+    if STREAM_GENERATION.load(Ordering::SeqCst) != generation {
+        return;
+    }
+    let metadata = {
+        let mut now_playing = NOW_PLAYING.lock().unwrap();
+        let Some(metadata) = now_playing.as_mut() else {
+            return;
+        };
+        f(metadata);
+        metadata.clone()
+    };
+    METADATA_SINKS
+        .lock()
+        .unwrap()
+        .retain(|sink| sink.add(metadata.clone()).is_ok());
+}
+
+// The current song follows the stream's title so plugins and the now playing screen see it
+fn announce_title(generation: u64, now_playing: String) {
+    let (artist, title) = match now_playing.split_once(" - ") {
+        Some((artist, title)) => (Some(artist.trim().to_string()), title.trim().to_string()),
+        None => (None, now_playing.trim().to_string()),
+    };
+    publish(generation, |metadata| {
+        metadata.now_playing = Some(now_playing.clone());
+        metadata.artist = artist.clone();
+        metadata.title = Some(title.clone());
+    });
+    if STREAM_GENERATION.load(Ordering::SeqCst) != generation {
+        return;
+    }
+    if let Some(mut song) = with_store(|store| store.current_song.clone()).flatten() {
+        song.title = title;
+        if let Some(artist) = artist {
+            song.artist = artist;
+        }
+        let mut updater = update_store();
+        updater.set_current_song(song);
+        if let Err(e) = updater.apply() {
+            println!("Failed to apply changes to the store: {e}");
+        }
+    }
+    call_func_plugins("stream_title_changed".to_string());
+}
+
+// Samples decoded from the network waiting for the audio thread
+struct StreamBuffer {
+    samples: Mutex<VecDeque<f32>>,
+    space: Condvar,
+    finished: AtomicBool,
+    stop: AtomicBool,
+    // The server sent all of a stream with an end (a file, or an HLS playlist with an ENDLIST), so
+    // running out of data is the end of it rather than a dropped connection
+    complete: AtomicBool,
+}
+
+impl StreamBuffer {
+    // Blocks while the buffer is full, false once the stream has been stopped
+    fn push(&self, samples: &[f32], max_len: usize) -> bool {
+        let mut buffered = self.samples.lock().unwrap();
+        while buffered.len() >= max_len {
+            if self.stop.load(Ordering::SeqCst) {
+                return false;
+            }
+            buffered = self
+                .space
+                .wait_timeout(buffered, Duration::from_millis(100))
+                .unwrap()
+                .0;
+        }
+        buffered.extend(samples);
+        !self.stop.load(Ordering::SeqCst)
+    }
+}
+
+// A live stream as a rodio source. It plays silence while buffering rather than ending so the
+// sink survives network hiccups, and only ends once the stream has given up or was played to the
+// end of a file
+#[flutter_rust_bridge::frb(ignore)]
+pub struct HttpStreamSource {
+    buffer: Arc<StreamBuffer>,
+    local: VecDeque<f32>,
+    sample_rate: u32,
+    channels: u16,
+    buffering: bool,
+    // Samples into the current frame, silence is only inserted between whole frames
+    frame_pos: u16,
+}
+
+impl HttpStreamSource {
+    fn refill(&mut self) {
+        let prebuffer = self.sample_rate as usize * self.channels as usize * PREBUFFER_SECS;
+        let mut buffered = self.buffer.samples.lock().unwrap();
+        if self.buffering
+            && buffered.len() < prebuffer
+            && !self.buffer.finished.load(Ordering::SeqCst)
+        {
+            return;
+        }
+        self.buffering = false;
+        let channels = self.channels as usize;
+        let take = buffered.len().min(4096) / channels * channels;
+        self.local.extend(buffered.drain(..take));
+        drop(buffered);
+        self.buffer.space.notify_one();
+    }
+}
+
+impl Source for HttpStreamSource {
+    fn current_span_len(&self) -> Option<usize> {
+        None
+    }
+    fn channels(&self) -> u16 {
+        self.channels
+    }
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+}
+
+impl Iterator for HttpStreamSource {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.frame_pos == 0 && self.local.is_empty() {
+            self.refill();
+            if self.local.is_empty() {
+                if self.buffer.finished.load(Ordering::SeqCst) {
+                    return None;
+                }
+                self.buffering = true;
+            }
+        }
+        self.frame_pos = (self.frame_pos + 1) % self.channels;
+        Some(self.local.pop_front().unwrap_or(0.0))
+    }
+}
+
+impl Drop for HttpStreamSource {
+    fn drop(&mut self) {
+        self.buffer.stop.store(true, Ordering::SeqCst);
+        self.buffer.space.notify_all();
+    }
+}
+
+// Wraps a reader that is only Send so symphonia, which wants Sync, can own it
+struct LockedReader<R>(Mutex<R>);
+
+impl<R: Read> Read for LockedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.get_mut().unwrap().read(buf)
+    }
+}
+
+// Strips the ICY metadata blocks Icecast and Shoutcast put between every icy-metaint bytes of
+// audio and reports StreamTitle changes
+struct IcyReader {
+    inner: Response,
+    metaint: Option<usize>,
+    until_meta: usize,
+    last_title: Option<String>,
+    generation: u64,
+    // Bytes of the body still to come when the server sent a Content-Length
+    remaining: Option<u64>,
+    buffer: Arc<StreamBuffer>,
+}
+
+fn parse_stream_title(block: &[u8]) -> Option<String> {
+    let text = String::from_utf8_lossy(block);
+    let start = text.find("StreamTitle='")? + "StreamTitle='".len();
+    let rest = &text[start..];
+    // Titles may contain quotes themselves, the field ends at the quote before a semicolon
+    let end = rest.find("';").unwrap_or(rest.trim_end_matches('\0').len());
+    let title = rest[..end].trim_end_matches(['\0', '\'']).trim();
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+impl IcyReader {
+    fn consumed(&mut self, len: usize) {
+        if let Some(remaining) = self.remaining.as_mut() {
+            *remaining = remaining.saturating_sub(len as u64);
+            if *remaining == 0 {
+                self.buffer.complete.store(true, Ordering::SeqCst);
+            }
+        }
+    }
+
+    fn read_body(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.consumed(read);
+        Ok(read)
+    }
+
+    fn read_metadata(&mut self) -> io::Result<()> {
+        let mut len = [0u8];
+        self.inner.read_exact(&mut len)?;
+        self.consumed(len.len());
+        if len[0] == 0 {
+            return Ok(());
+        }
+        let mut block = vec![0; len[0] as usize * 16];
+        self.inner.read_exact(&mut block)?;
+        self.consumed(block.len());
+        if let Some(title) = parse_stream_title(&block)
+            && self.last_title.as_ref() != Some(&title)
+        {
+            self.last_title = Some(title.clone());
+            announce_title(self.generation, title);
+        }
+        Ok(())
+    }
+}
+
+impl Read for IcyReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let Some(metaint) = self.metaint else {
+            return self.read_body(buf);
+        };
+        if self.until_meta == 0 {
+            self.read_metadata()?;
+            self.until_meta = metaint;
+        }
+        let len = buf.len().min(self.until_meta);
+        let read = self.read_body(&mut buf[..len])?;
+        self.until_meta -= read;
+        Ok(read)
+    }
+}
+
+enum Playlist {
+    // Variant streams by bandwidth, plus audio only renditions when there are any
+    Master {
+        variants: Vec<(u64, Url)>,
+        audio: Vec<Url>,
+    },
+    Media {
+        target_duration: f64,
+        media_sequence: u64,
+        segments: Vec<Url>,
+        ended: bool,
+    },
+}
+
+fn attribute<'a>(line: &'a str, name: &str) -> Option<&'a str> {
+    let pattern = format!("{name}=");
+    let start = line.find(&pattern)? + pattern.len();
+    let rest = &line[start..];
+    match rest.strip_prefix('"') {
+        Some(quoted) => quoted.split('"').next(),
+        None => rest.split(',').next(),
+    }
+}
+
+fn parse_hls(base: &Url, text: &str) -> Result<Playlist, String> {
+    let mut variants = Vec::new();
+    let mut audio = Vec::new();
+    let mut segments = Vec::new();
+    let mut target_duration = 6.0;
+    let mut media_sequence = 0;
+    let mut ended = false;
+    let mut pending_bandwidth = None;
+
+    for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        if let Some(attrs) = line.strip_prefix("#EXT-X-STREAM-INF:") {
+            pending_bandwidth = Some(
+                attribute(attrs, "BANDWIDTH")
+                    .and_then(|b| b.parse().ok())
+                    .unwrap_or(0),
+            );
+        } else if let Some(attrs) = line.strip_prefix("#EXT-X-MEDIA:") {
+            if attribute(attrs, "TYPE") == Some("AUDIO")
+                && let Some(uri) = attribute(attrs, "URI").and_then(|u| base.join(u).ok())
+            {
+                audio.push(uri);
+            }
+        } else if let Some(value) = line.strip_prefix("#EXT-X-TARGETDURATION:") {
+            target_duration = value.parse().unwrap_or(target_duration);
+        } else if let Some(value) = line.strip_prefix("#EXT-X-MEDIA-SEQUENCE:") {
+            media_sequence = value.parse().unwrap_or(0);
+        } else if line == "#EXT-X-ENDLIST" {
+            ended = true;
+        } else if let Some(attrs) = line.strip_prefix("#EXT-X-KEY:") {
+            if attribute(attrs, "METHOD").is_some_and(|m| m != "NONE") {
+                return Err("Encrypted HLS streams are not supported".to_string());
+            }
+        } else if line.starts_with("#EXT-X-MAP:") {
+            return Err("HLS streams made of fragmented MP4 are not supported".to_string());
+        } else if !line.starts_with('#') {
+            let Ok(url) = base.join(line) else {
+                continue;
+            };
+            match pending_bandwidth.take() {
+                Some(bandwidth) => variants.push((bandwidth, url)),
+                None => segments.push(url),
+            }
+        }
+    }
+
+    if !variants.is_empty() || !audio.is_empty() {
+        Ok(Playlist::Master { variants, audio })
+    } else {
+        Ok(Playlist::Media {
+            target_duration,
+            media_sequence,
+            segments,
+            ended,
+        })
+    }
+}
+
+fn fetch_text(url: &Url) -> Result<String, String> {
+    STREAM_CLIENT
+        .get(url.clone())
+        .send()
+        .and_then(|r| r.error_for_status())
+        .and_then(|r| r.text())
+        .map_err(|e| format!("Failed to fetch {url}: {e}"))
+}
+
+// Pulls the audio out of an MPEG-TS segment: the PAT names the PMT, the PMT names the first MP3 or
+// AAC stream, and the payloads of that stream's PES packets are its ADTS or MP3 frames
+fn demux_ts(data: &[u8]) -> Vec<u8> {
+    let mut pmt_pid = None;
+    let mut audio_pid = None;
+    let mut out = Vec::with_capacity(data.len());
+
+    for packet in data.chunks_exact(TS_PACKET_LEN) {
+        if packet[0] != 0x47 {
+            continue;
+        }
+        let unit_start = packet[1] & 0x40 != 0;
+        let pid = u16::from(packet[1] & 0x1f) << 8 | u16::from(packet[2]);
+        let offset = match (packet[3] >> 4) & 0x3 {
+            1 => 4,
+            3 => 5 + packet[4] as usize,
+            _ => continue,
+        };
+        let Some(payload) = packet.get(offset..) else {
+            continue;
+        };
+        // Tables start after a pointer field
+        let section = || {
+            payload
+                .first()
+                .and_then(|&pointer| payload.get(1 + pointer as usize..))
+        };
+        let section_end = |s: &[u8]| {
+            let len = (usize::from(s[1] & 0x0f) << 8 | usize::from(s[2])) + 3;
+            // The last 4 bytes are the CRC
+            len.saturating_sub(4).min(s.len())
+        };
+
+        if pid == 0 && unit_start {
+            let Some(s) = section().filter(|s| s.len() > 8) else {
+                continue;
+            };
+            let end = section_end(s);
+            pmt_pid = s
+                .get(8..end)
+                .unwrap_or_default()
+                .chunks_exact(4)
+                .find(|p| p[0] != 0 || p[1] != 0)
+                .map(|p| u16::from(p[2] & 0x1f) << 8 | u16::from(p[3]));
+        } else if Some(pid) == pmt_pid && unit_start && audio_pid.is_none() {
+            let Some(s) = section().filter(|s| s.len() > 12) else {
+                continue;
+            };
+            let end = section_end(s);
+            let mut i = 12 + (usize::from(s[10] & 0x0f) << 8 | usize::from(s[11]));
+            while i + 5 <= end {
+                let stream_type = s[i];
+                let es_pid = u16::from(s[i + 1] & 0x1f) << 8 | u16::from(s[i + 2]);
+                // MPEG-1 and MPEG-2 audio, and AAC in ADTS
+                if matches!(stream_type, 0x03 | 0x04 | 0x0f) {
+                    audio_pid = Some(es_pid);
+                    break;
+                }
+                i += 5 + (usize::from(s[i + 3] & 0x0f) << 8 | usize::from(s[i + 4]));
+            }
+        } else if Some(pid) == audio_pid {
+            if !unit_start {
+                out.extend_from_slice(payload);
+            } else if payload.len() >= 9
+                && payload[..3] == [0, 0, 1]
+                && let Some(frames) = payload.get(9 + payload[8] as usize..)
+            {
+                // Skips the PES header
+                out.extend_from_slice(frames);
+            }
+        }
+    }
+    out
+}
+
+// Reads an HLS media playlist as one continuous stream, refreshing it for new segments while the
+// stream is live
+struct HlsReader {
+    playlist: Url,
+    pending: VecDeque<Url>,
+    next_sequence: Option<u64>,
+    target_duration: f64,
+    ended: bool,
+    last_refresh: Option<Instant>,
+    current: Cursor<Vec<u8>>,
+    buffer: Arc<StreamBuffer>,
+}
+
+impl HlsReader {
+    fn refresh(&mut self) -> io::Result<()> {
+        if let Some(last) = self.last_refresh {
+            let interval = Duration::from_secs_f64(self.target_duration / 2.0);
+            if let Some(wait) = interval.checked_sub(last.elapsed()) {
+                thread::sleep(wait);
+            }
+        }
+        self.last_refresh = Some(Instant::now());
+        let text = fetch_text(&self.playlist).map_err(io::Error::other)?;
+        let Playlist::Media {
+            target_duration,
+            media_sequence,
+            segments,
+            ended,
+        } = parse_hls(&self.playlist, &text).map_err(io::Error::other)?
+        else {
+            return Err(io::Error::other(
+                "The HLS playlist turned into a master playlist",
+            ));
+        };
+        self.target_duration = target_duration.max(1.0);
+        self.ended = ended;
+
+        let skip = match self.next_sequence {
+            Some(next) => next.saturating_sub(media_sequence) as usize,
+            None if ended => 0,
+            None => segments.len().saturating_sub(HLS_LIVE_SEGMENTS),
+        };
+        let count = segments.len();
+        self.pending.extend(segments.into_iter().skip(skip));
+        self.next_sequence = Some(media_sequence + count as u64);
+        Ok(())
+    }
+
+    fn fetch_segment(&mut self, url: Url) -> io::Result<()> {
+        let bytes = STREAM_CLIENT
+            .get(url)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .and_then(|r| r.bytes())
+            .map_err(io::Error::other)?;
+        let data = if bytes.first() == Some(&0x47) && bytes.len() % TS_PACKET_LEN == 0 {
+            demux_ts(&bytes)
+        } else {
+            bytes.to_vec()
+        };
+        self.current = Cursor::new(data);
+        Ok(())
+    }
+}
+
+impl Read for HlsReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        loop {
+            let read = self.current.read(buf)?;
+            if read > 0 || self.buffer.stop.load(Ordering::SeqCst) {
+                return Ok(read);
+            }
+            if let Some(segment) = self.pending.pop_front() {
+                self.fetch_segment(segment)?;
+            } else if self.ended {
+                self.buffer.complete.store(true, Ordering::SeqCst);
+                return Ok(0);
+            } else {
+                self.refresh()?;
+            }
+        }
+    }
+}
+
+fn header(response: &Response, name: &str) -> Option<String> {
+    response
+        .headers()
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+// The first stream a .pls (File1=...) or plain .m3u playlist lists
+fn playlist_target(base: &Url, text: &str, is_pls: bool) -> Option<Url> {
+    text.lines().map(str::trim).find_map(|line| {
+        let target = if is_pls {
+            let (key, value) = line.split_once('=')?;
+            key.to_ascii_lowercase()
+                .starts_with("file")
+                .then_some(value)?
+        } else if line.is_empty() || line.starts_with('#') {
+            return None;
+        } else {
+            line
+        };
+        base.join(target.trim()).ok()
+    })
+}
+
+fn hint_for(content_type: &str, url: &Url) -> Hint {
+    let mut hint = Hint::new();
+    let ext = match content_type {
+        "audio/mpeg" | "audio/mp3" => Some("mp3"),
+        "audio/aac" | "audio/aacp" | "audio/x-aac" => Some("aac"),
+        "audio/ogg" | "application/ogg" | "audio/opus" => Some("ogg"),
+        "audio/flac" | "audio/x-flac" => Some("flac"),
+        _ => None,
+    };
+    match ext {
+        Some(ext) => {
+            hint.with_extension(ext);
+        }
+        None => {
+            if let Some(ext) = url.path().rsplit_once('.').map(|(_, ext)| ext) {
+                hint.with_extension(ext);
+            }
+        }
+    }
+    hint
+}
+
+// Connects to url and follows any playlists in front of the actual audio
+fn connect(
+    url: &str,
+    buffer: &Arc<StreamBuffer>,
+    generation: u64,
+) -> Result<(Box<dyn Read + Send>, Hint), String> {
+    let mut url = Url::parse(url).map_err(|e| format!("{url} is not a valid url: {e}"))?;
+    for _ in 0..=MAX_PLAYLIST_HOPS {
+        let response = STREAM_CLIENT
+            .get(url.clone())
+            .header("Icy-MetaData", "1")
+            .send()
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| format!("Failed to connect to {url}: {e}"))?;
+        let url_now = response.url().clone();
+        let content_type = header(&response, CONTENT_TYPE.as_str())
+            .map(|c| c.split(';').next().unwrap_or_default().to_ascii_lowercase())
+            .unwrap_or_default();
+        let path = url_now.path().to_ascii_lowercase();
+        let is_m3u =
+            content_type.contains("mpegurl") || path.ends_with(".m3u8") || path.ends_with(".m3u");
+        let is_pls = content_type == "audio/x-scpls" || path.ends_with(".pls");
+
+        if is_m3u || is_pls {
+            let text = response
+                .text()
+                .map_err(|e| format!("Failed to read {url_now}: {e}"))?;
+            if is_m3u && text.contains("#EXT-X-") {
+                let media = match parse_hls(&url_now, &text)? {
+                    Playlist::Master { variants, audio } => audio
+                        .into_iter()
+                        .next()
+                        .or_else(|| variants.into_iter().max_by_key(|(b, _)| *b).map(|(_, u)| u))
+                        .ok_or(format!("{url_now} has no streams"))?,
+                    Playlist::Media { .. } => url_now.clone(),
+                };
+                let mut hint = Hint::new();
+                hint.with_extension("aac");
+                let reader = HlsReader {
+                    playlist: media,
+                    pending: VecDeque::new(),
+                    next_sequence: None,
+                    target_duration: 6.0,
+                    ended: false,
+                    last_refresh: None,
+                    current: Cursor::new(Vec::new()),
+                    buffer: Arc::clone(buffer),
+                };
+                return Ok((Box::new(reader), hint));
+            }
+            url = playlist_target(&url_now, &text, is_pls)
+                .ok_or(format!("{url_now} does not list any streams"))?;
+            continue;
+        }
+
+        publish(generation, |metadata| {
+            metadata.station_name = header(&response, "icy-name");
+            metadata.genre = header(&response, "icy-genre");
+            metadata.bitrate_kbps = header(&response, "icy-br")
+                .and_then(|b| b.split(',').next().and_then(|b| b.parse().ok()));
+        });
+        let hint = hint_for(&content_type, &url_now);
+        let metaint = header(&response, "icy-metaint").and_then(|m| m.parse().ok());
+        let reader = IcyReader {
+            remaining: response.content_length(),
+            inner: response,
+            metaint,
+            until_meta: metaint.unwrap_or(0),
+            last_title: None,
+            generation,
+            buffer: Arc::clone(buffer),
+        };
+        return Ok((Box::new(reader), hint));
+    }
+    Err(format!("{url} goes through too many playlists"))
+}
+
+// Decodes one connection into the buffer. Returns whether any audio came out of it, so a stream
+// that keeps dropping after playing for a while is not given up on
+fn decode_connection(
+    reader: Box<dyn Read + Send>,
+    hint: Hint,
+    buffer: &StreamBuffer,
+    format: &mut Option<(u32, u16)>,
+    format_tx: &mut Option<mpsc::Sender<Result<(u32, u16), String>>>,
+) -> Result<bool, String> {
+    let source = ReadOnlySource::new(LockedReader(Mutex::new(reader)));
+    let mss = MediaSourceStream::new(Box::new(source), Default::default());
+    let probed = symphonia::default::get_probe()
+        .format(&hint, mss, &Default::default(), &Default::default())
+        .map_err(|e| format!("Unsupported stream: {e}"))?;
+    let mut reader = probed.format;
+    let track = reader
+        .tracks()
+        .iter()
+        .find(|t| t.codec_params.codec != CODEC_TYPE_NULL)
+        .ok_or("The stream has no audio")?;
+    let track_id = track.id;
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &Default::default())
+        .map_err(|e| format!("Unsupported codec: {e}"))?;
+
+    let mut samples: Option<SampleBuffer<f32>> = None;
+    let mut got_audio = false;
+    loop {
+        if buffer.stop.load(Ordering::SeqCst) {
+            return Ok(got_audio);
+        }
+        let packet = match reader.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(_)) => return Ok(got_audio),
+            Err(e) => return Err(format!("Failed to read the stream: {e}")),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+        let decoded = match decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            Err(SymphoniaError::DecodeError(_)) => continue,
+            Err(e) => return Err(format!("Failed to decode the stream: {e}")),
+        };
+        let spec = *decoded.spec();
+        let this_format = (spec.rate, spec.channels.count().max(1) as u16);
+        match format {
+            Some(established) if *established != this_format => {
+                return Err("The stream changed its sample rate or channels".to_string());
+            }
+            Some(_) => {}
+            None => {
+                *format = Some(this_format);
+                if let Some(tx) = format_tx.take() {
+                    let _ = tx.send(Ok(this_format));
+                }
+            }
+        }
+        let buf = samples.get_or_insert_with(|| SampleBuffer::new(decoded.capacity() as u64, spec));
+        if buf.capacity() < decoded.capacity() * spec.channels.count() {
+            *buf = SampleBuffer::new(decoded.capacity() as u64, spec);
+        }
+        buf.copy_interleaved_ref(decoded);
+        let max_len = this_format.0 as usize * this_format.1 as usize * MAX_BUFFER_SECS;
+        if !buffer.push(buf.samples(), max_len) {
+            return Ok(got_audio);
+        }
+        got_audio = true;
+    }
+}
+
+fn stream_worker(
+    url: String,
+    buffer: Arc<StreamBuffer>,
+    generation: u64,
+    format_tx: mpsc::Sender<Result<(u32, u16), String>>,
+) {
+    let mut format_tx = Some(format_tx);
+    let mut format = None;
+    let mut failures = 0;
+
+    loop {
+        let result = connect(&url, &buffer, generation).and_then(|(reader, hint)| {
+            publish(generation, |metadata| metadata.reconnecting = false);
+            decode_connection(reader, hint, &buffer, &mut format, &mut format_tx)
+        });
+        // A file or finished HLS stream played to its end, only dropped connections are retried
+        if buffer.stop.load(Ordering::SeqCst)
+            || (result.is_ok() && buffer.complete.load(Ordering::SeqCst))
+        {
+            break;
+        }
+        failures = match &result {
+            Ok(true) => 0,
+            _ => failures + 1,
+        };
+        if let Err(e) = &result {
+            println!("Stream {url} failed: {e}");
+        }
+        if failures > MAX_RECONNECTS {
+            if let (Some(tx), Err(e)) = (format_tx.take(), result) {
+                let _ = tx.send(Err(e));
+            }
+            break;
+        }
+        // Nothing is playing yet, so there is nobody to keep waiting
+        if format.is_none()
+            && let Err(e) = result
+            && let Some(tx) = format_tx.take()
+        {
+            let _ = tx.send(Err(e));
+            break;
+        }
+
+        publish(generation, |metadata| metadata.reconnecting = true);
+        let delay = RECONNECT_DELAY * 2u32.pow(failures.saturating_sub(1));
+        let deadline = Instant::now() + delay;
+        while Instant::now() < deadline && !buffer.stop.load(Ordering::SeqCst) {
+            thread::sleep(Duration::from_millis(100));
+        }
+        if buffer.stop.load(Ordering::SeqCst) {
+            break;
+        }
+    }
+    buffer.finished.store(true, Ordering::SeqCst);
+}
+
+// Starts streaming url on its own thread and waits until the first audio is decoded so the
+// player knows the sample rate. Plain Icecast/Shoutcast streams, .pls and .m3u links to them and
+// HLS (MPEG-TS or ADTS segments) are supported
+#[flutter_rust_bridge::frb(ignore)]
+pub fn open_stream(url: &str) -> Result<HttpStreamSource, String> {
+    let generation = STREAM_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    *NOW_PLAYING.lock().unwrap() = Some(StreamMetadata {
+        url: url.to_string(),
+        ..Default::default()
+    });
+    let buffer = Arc::new(StreamBuffer {
+        samples: Mutex::new(VecDeque::new()),
+        space: Condvar::new(),
+        finished: AtomicBool::new(false),
+        stop: AtomicBool::new(false),
+        complete: AtomicBool::new(false),
+    });
+    let (tx, rx) = mpsc::channel();
+    let worker_buffer = Arc::clone(&buffer);
+    let worker_url = url.to_string();
+    thread::spawn(move || stream_worker(worker_url, worker_buffer, generation, tx));
+
+    let (sample_rate, channels) = match rx.recv_timeout(OPEN_TIMEOUT) {
+        Ok(Ok(format)) => format,
+        Ok(Err(e)) => return Err(e),
+        Err(_) => {
+            buffer.stop.store(true, Ordering::SeqCst);
+            return Err(format!("{url} did not send any audio"));
+        }
+    };
+    publish(generation, |_| {});
+    Ok(HttpStreamSource {
+        buffer,
+        local: VecDeque::new(),
+        sample_rate,
+        channels,
+        buffering: true,
+        frame_pos: 0,
+    })
+}
+
+// What play_song stores as the current song for a stream until its metadata arrives
+#[flutter_rust_bridge::frb(ignore)]
+pub fn stream_song(url: &str, name: Option<String>) -> SongMetadata {
+    SongMetadata {
+        title: name.unwrap_or(url.to_string()),
+        artist: "Internet Radio".to_string(),
+        album: "Internet Radio".to_string(),
+        path: url.to_string(),
+        ..Default::default()
+    }
+}
+
+// Forgets the stream that was playing, called when something else starts
+#[flutter_rust_bridge::frb(ignore)]
+pub fn clear_stream_metadata() {
+    STREAM_GENERATION.fetch_add(1, Ordering::SeqCst);
+    *NOW_PLAYING.lock().unwrap() = None;
+}
+
+pub fn get_stream_metadata() -> Option<StreamMetadata> {
+    NOW_PLAYING.lock().unwrap().clone()
+}
+
+pub fn watch_stream_metadata(sink: StreamSink<StreamMetadata>) {
+    METADATA_SINKS.lock().unwrap().push(sink);
+}
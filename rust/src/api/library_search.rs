@@ -0,0 +1,751 @@
+use crate::api::{
+    cue_sheet::split_virtual_track,
+    music_handler::{SongMetadata, scan_music_directory},
+    smart_playlist::{music_folder, tag_year},
+};
+use once_cell::sync::Lazy;
+use rayon::prelude::*;
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    path::Path,
+    sync::{Mutex, RwLock},
+};
+
+// How much a match in each field counts towards a song's rank
+const FIELD_WEIGHTS: [f32; 5] = [3.0, 2.0, 1.5, 1.0, 0.5];
+const EXACT_SCORE: f32 = 1.0;
+const PREFIX_SCORE: f32 = 0.8;
+const FUZZY_SCORE: f32 = 0.6;
+// Each typo costs this much on top of falling back to a fuzzy match
+const TYPO_PENALTY: f32 = 0.15;
+const DEFAULT_PAGE_SIZE: u32 = 50;
+
+static SEARCH_INDEX: Lazy<RwLock<Option<SearchIndex>>> = Lazy::new(|| RwLock::new(None));
+// The ranked result of the last query so paging through it does not search again
+static LAST_RESULT: Mutex<Option<RankedQuery>> = Mutex::new(None);
+
+// Index generation, query and the matching songs best first
+type RankedQuery = (u64, String, Vec<(u32, f32)>);
+// A distinct word and the (song, field) pairs it appears in
+type Postings = (Vec<char>, Vec<(u32, Field)>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Field {
+    Title,
+    Artist,
+    Album,
+    Genre,
+    Path,
+}
+
+const FIELDS: [Field; 5] = [
+    Field::Title,
+    Field::Artist,
+    Field::Album,
+    Field::Genre,
+    Field::Path,
+];
+
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    // album_art is left out to keep pages small, use get_cached_album_art for covers
+    pub song: SongMetadata,
+    pub score: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchPage {
+    pub total: u32,
+    pub page: u32,
+    pub page_size: u32,
+    pub hits: Vec<SearchHit>,
+}
+
+struct Doc {
+    song: SongMetadata,
+    // Folded text of each field in FIELDS order, for quoted phrases
+    folded: [String; 5],
+    // Read from the tags when the index is built so year queries never touch the files
+    year: Option<i32>,
+}
+
+struct SearchIndex {
+    generation: u64,
+    docs: Vec<Doc>,
+    by_path: HashMap<String, u32>,
+    // Every distinct word in the library
+    vocabulary: Vec<Postings>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Range {
+    min: i64,
+    max: i64,
+}
+
+#[derive(Debug, Clone)]
+enum Node {
+    Term(String, Option<Field>),
+    Phrase(String, Option<Field>),
+    Year(Range),
+    Duration(Range),
+    And(Vec<Node>),
+    Or(Vec<Node>),
+    Not(Box<Node>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    // A field prefix and its raw value, like artist:"Daft Punk" or year:1990..1999
+    Prefixed(String, String, bool),
+    LParen,
+    RParen,
+    Or,
+    Not,
+}
+
+impl Field {
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "title" => Some(Self::Title),
+            "artist" => Some(Self::Artist),
+            "album" => Some(Self::Album),
+            "genre" => Some(Self::Genre),
+            "path" | "file" => Some(Self::Path),
+            _ => None,
+        }
+    }
+}
+
+fn fold_char(c: char, out: &mut String) {
+    const TABLE: [(&str, &str); 24] = [
+        ("àáâãäåāăą", "a"),
+        ("çćĉċč", "c"),
+        ("ďđð", "d"),
+        ("èéêëēĕėęě", "e"),
+        ("ĝğġģ", "g"),
+        ("ĥħ", "h"),
+        ("ìíîïĩīĭįı", "i"),
+        ("ĵ", "j"),
+        ("ķ", "k"),
+        ("ĺļľŀł", "l"),
+        ("ñńņňŉ", "n"),
+        ("òóôõöøōŏő", "o"),
+        ("ŕŗř", "r"),
+        ("śŝşšș", "s"),
+        ("ţťŧț", "t"),
+        ("ùúûüũūŭůűų", "u"),
+        ("ŵ", "w"),
+        ("ýÿŷ", "y"),
+        ("źżž", "z"),
+        ("ß", "ss"),
+        ("æ", "ae"),
+        ("œ", "oe"),
+        ("þ", "th"),
+        ("ĳ", "ij"),
+    ];
+    if c.is_ascii() {
+        out.push(c);
+        return;
+    }
+    match TABLE.iter().find(|(from, _)| from.contains(c)) {
+        Some((_, to)) => out.push_str(to),
+        None => out.push(c),
+    }
+}
+
+// Lowercases and strips accents so "Björk" and "bjork" are the same word
+#[flutter_rust_bridge::frb(ignore)]
+pub fn fold(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars().flat_map(char::to_lowercase) {
+        fold_char(c, &mut out);
+    }
+    out
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+pub fn words(folded: &str) -> impl Iterator<Item = &str> {
+    folded
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+}
+
+// Punctuation on its own (a stray "&" or "-") has nothing to match
+fn has_words(s: &str) -> bool {
+    words(&fold(s)).next().is_some()
+}
+
+// Optimal string alignment distance, None once it is certain to go over max
+fn edit_distance(a: &[char], b: &[char], max: usize) -> Option<usize> {
+    if a.len().abs_diff(b.len()) > max {
+        return None;
+    }
+    let mut prev2 = vec![0; b.len() + 1];
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for i in 1..=a.len() {
+        current[0] = i;
+        let mut row_min = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            current[j] = (prev[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(prev[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(prev2[j - 2] + 1);
+            }
+            row_min = row_min.min(current[j]);
+        }
+        if row_min > max {
+            return None;
+        }
+        std::mem::swap(&mut prev2, &mut prev);
+        std::mem::swap(&mut prev, &mut current);
+    }
+    (prev[b.len()] <= max).then_some(prev[b.len()])
+}
+
+// Short words have to be spelt right, longer ones may have a typo or two
+fn allowed_typos(len: usize) -> usize {
+    match len {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    }
+}
+
+fn word_score(term: &[char], word: &[char]) -> f32 {
+    if word == term {
+        return EXACT_SCORE;
+    }
+    // Type-ahead, the word being typed only has to start a word in the song
+    if term.len() >= 2 && word.starts_with(term) {
+        return PREFIX_SCORE;
+    }
+    let max = allowed_typos(term.len());
+    if max == 0 {
+        return 0.0;
+    }
+    if let Some(distance) = edit_distance(term, word, max) {
+        return FUZZY_SCORE - TYPO_PENALTY * distance as f32;
+    }
+    // A typo in something only half typed
+    if word.len() > term.len()
+        && let Some(distance) = edit_distance(term, &word[..term.len()], max)
+    {
+        return FUZZY_SCORE - TYPO_PENALTY * (distance + 1) as f32;
+    }
+    0.0
+}
+
+impl SearchIndex {
+    fn build(songs: &[SongMetadata], generation: u64) -> Self {
+        let mut postings: HashMap<String, Vec<(u32, Field)>> = HashMap::new();
+        // A virtual track has the year of the file it is in
+        let years: Vec<Option<i32>> = songs
+            .par_iter()
+            .map(|s| tag_year(split_virtual_track(&s.path).map_or(s.path.as_str(), |(f, _)| f)))
+            .collect();
+        let docs = songs
+            .iter()
+            .zip(years)
+            .enumerate()
+            .map(|(i, (song, year))| {
+                let file_name = Path::new(&song.path)
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_default();
+                let folded = [
+                    fold(&song.title),
+                    fold(&song.artist),
+                    fold(&song.album),
+                    fold(&song.genre),
+                    fold(&file_name),
+                ];
+                for field in FIELDS {
+                    let mut seen = HashSet::new();
+                    for word in words(&folded[field.index()]) {
+                        if seen.insert(word) {
+                            postings
+                                .entry(word.to_string())
+                                .or_default()
+                                .push((i as u32, field));
+                        }
+                    }
+                }
+                Doc {
+                    song: SongMetadata {
+                        album_art: None,
+                        ..song.clone()
+                    },
+                    folded,
+                    year,
+                }
+            })
+            .collect();
+        Self {
+            generation,
+            by_path: songs
+                .iter()
+                .enumerate()
+                .map(|(i, song)| (song.path.clone(), i as u32))
+                .collect(),
+            docs,
+            vocabulary: postings
+                .into_iter()
+                .map(|(word, postings)| (word.chars().collect(), postings))
+                .collect(),
+        }
+    }
+
+    fn all(&self) -> HashMap<u32, f32> {
+        (0..self.docs.len() as u32).map(|i| (i, 0.0)).collect()
+    }
+
+    fn term(&self, term: &str, field: Option<Field>) -> HashMap<u32, f32> {
+        let mut scores = HashMap::new();
+        // A term like "ac/dc" or "9-5" is several words that all have to match
+        let parts: Vec<Vec<char>> = words(term).map(|w| w.chars().collect()).collect();
+        for (n, part) in parts.iter().enumerate() {
+            let matched: Vec<(&Vec<(u32, Field)>, f32)> = self
+                .vocabulary
+                .par_iter()
+                .filter_map(|(word, postings)| {
+                    let score = word_score(part, word);
+                    (score > 0.0).then_some((postings, score))
+                })
+                .collect();
+
+            let mut part_scores: HashMap<u32, f32> = HashMap::new();
+            for (postings, score) in matched {
+                for &(doc, doc_field) in postings {
+                    if field.is_some_and(|f| f != doc_field) {
+                        continue;
+                    }
+                    let weighted = score * FIELD_WEIGHTS[doc_field.index()];
+                    let best = part_scores.entry(doc).or_default();
+                    *best = best.max(weighted);
+                }
+            }
+            if n == 0 {
+                scores = part_scores;
+            } else {
+                scores.retain(|doc, score| match part_scores.get(doc) {
+                    Some(s) => {
+                        *score += s;
+                        true
+                    }
+                    None => false,
+                });
+            }
+        }
+        scores
+    }
+
+    fn phrase(&self, phrase: &str, field: Option<Field>) -> HashMap<u32, f32> {
+        let phrase = fold(phrase);
+        self.docs
+            .iter()
+            .enumerate()
+            .filter_map(|(i, doc)| {
+                FIELDS
+                    .iter()
+                    .filter(|f| field.is_none_or(|wanted| wanted == **f))
+                    .filter(|f| doc.folded[f.index()].contains(&phrase))
+                    .map(|f| FIELD_WEIGHTS[f.index()] * 1.5)
+                    .reduce(f32::max)
+                    .map(|score| (i as u32, score))
+            })
+            .collect()
+    }
+
+    fn evaluate(&self, node: &Node) -> HashMap<u32, f32> {
+        match node {
+            Node::Term(term, field) => self.term(term, *field),
+            Node::Phrase(phrase, field) => self.phrase(phrase, *field),
+            Node::Year(range) => self
+                .docs
+                .iter()
+                .enumerate()
+                .filter(|(_, d)| {
+                    d.year
+                        .is_some_and(|y| (range.min..=range.max).contains(&(y as i64)))
+                })
+                .map(|(i, _)| (i as u32, 0.0))
+                .collect(),
+            Node::Duration(range) => self
+                .docs
+                .iter()
+                .enumerate()
+                .filter(|(_, d)| (range.min..=range.max).contains(&(d.song.duration as i64)))
+                .map(|(i, _)| (i as u32, 0.0))
+                .collect(),
+            Node::And(nodes) => {
+                let mut nodes = nodes.iter();
+                let Some(first) = nodes.next() else {
+                    return self.all();
+                };
+                let mut scores = self.evaluate(first);
+                for node in nodes {
+                    if scores.is_empty() {
+                        break;
+                    }
+                    let other = self.evaluate(node);
+                    scores.retain(|doc, score| match other.get(doc) {
+                        Some(s) => {
+                            *score += s;
+                            true
+                        }
+                        None => false,
+                    });
+                }
+                scores
+            }
+            Node::Or(nodes) => {
+                let mut scores: HashMap<u32, f32> = HashMap::new();
+                for node in nodes {
+                    for (doc, score) in self.evaluate(node) {
+                        let best = scores.entry(doc).or_default();
+                        *best = best.max(score);
+                    }
+                }
+                scores
+            }
+            Node::Not(node) => {
+                let excluded = self.evaluate(node);
+                (0..self.docs.len() as u32)
+                    .filter(|i| !excluded.contains_key(i))
+                    .map(|i| (i, 0.0))
+                    .collect()
+            }
+        }
+    }
+}
+
+fn tokenize(query: &str) -> Vec<Token> {
+    let chars: Vec<char> = query.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    let read_quoted = |i: &mut usize| {
+        // Unclosed quotes run to the end so a phrase can be typed one letter at a time
+        let mut s = String::new();
+        *i += 1;
+        while *i < chars.len() && chars[*i] != '"' {
+            s.push(chars[*i]);
+            *i += 1;
+        }
+        *i += 1;
+        s
+    };
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            c if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            '|' => {
+                tokens.push(Token::Or);
+                i += 1 + usize::from(chars.get(i + 1) == Some(&'|'));
+            }
+            '-' | '!' if chars.get(i + 1).is_some_and(|n| !n.is_whitespace()) => {
+                tokens.push(Token::Not);
+                i += 1;
+            }
+            '"' => tokens.push(Token::Quoted(read_quoted(&mut i))),
+            _ => {
+                let start = i;
+                while i < chars.len()
+                    && !chars[i].is_whitespace()
+                    && !matches!(chars[i], '(' | ')' | '"')
+                {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                if let Some((prefix, value)) = word.split_once(':')
+                    && (Field::from_name(&prefix.to_lowercase()).is_some()
+                        || matches!(prefix.to_lowercase().as_str(), "year" | "duration"))
+                {
+                    let prefix = prefix.to_lowercase();
+                    if value.is_empty() && chars.get(i) == Some(&'"') {
+                        tokens.push(Token::Prefixed(prefix, read_quoted(&mut i), true));
+                    } else {
+                        tokens.push(Token::Prefixed(prefix, value.to_string(), false));
+                    }
+                    continue;
+                }
+                tokens.push(match word.as_str() {
+                    "OR" => Token::Or,
+                    "AND" | "&&" => continue,
+                    "NOT" => Token::Not,
+                    _ => Token::Word(word),
+                });
+            }
+        }
+    }
+    tokens
+}
+
+// "5m", "3:30", "1h2m", "200s" and plain seconds
+fn parse_duration(s: &str) -> Option<i64> {
+    let s = s.trim().to_lowercase();
+    if s.is_empty() {
+        return None;
+    }
+    if s.contains(':') {
+        return s.split(':').try_fold(0i64, |total, part| {
+            Some(total * 60 + part.parse::<i64>().ok()?)
+        });
+    }
+    let mut total = 0.0;
+    let mut number = String::new();
+    for c in s.chars() {
+        match c {
+            '0'..='9' | '.' => number.push(c),
+            'h' | 'm' | 's' => {
+                let unit = match c {
+                    'h' => 3600.0,
+                    'm' => 60.0,
+                    _ => 1.0,
+                };
+                total += number.parse::<f64>().ok()? * unit;
+                number.clear();
+            }
+            _ => return None,
+        }
+    }
+    if !number.is_empty() {
+        total += number.parse::<f64>().ok()?;
+    }
+    Some(total.round() as i64)
+}
+
+// "1990", "1990..1999", "..1999", ">1990", "<=5m" and so on
+fn parse_range(s: &str, value: fn(&str) -> Option<i64>) -> Option<Range> {
+    let s = s.trim();
+    if let Some((lo, hi)) = s.split_once("..") {
+        return Some(Range {
+            min: if lo.is_empty() { i64::MIN } else { value(lo)? },
+            max: if hi.is_empty() { i64::MAX } else { value(hi)? },
+        });
+    }
+    let (op, rest) = [">=", "<=", ">", "<", "="]
+        .iter()
+        .find_map(|op| s.strip_prefix(op).map(|rest| (*op, rest)))
+        .unwrap_or(("=", s));
+    let v = value(rest)?;
+    Some(match op {
+        ">=" => Range {
+            min: v,
+            max: i64::MAX,
+        },
+        ">" => Range {
+            min: v.saturating_add(1),
+            max: i64::MAX,
+        },
+        "<=" => Range {
+            min: i64::MIN,
+            max: v,
+        },
+        "<" => Range {
+            min: i64::MIN,
+            max: v.saturating_sub(1),
+        },
+        _ => Range { min: v, max: v },
+    })
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+// Never fails, anything that does not make sense yet (like an "artist:" with nothing after it) is
+// left out so results keep coming while the user types
+impl Parser {
+    fn parse_or(&mut self) -> Option<Node> {
+        let mut nodes: Vec<Node> = self.parse_and().into_iter().collect();
+        while self.tokens.get(self.pos) == Some(&Token::Or) {
+            self.pos += 1;
+            nodes.extend(self.parse_and());
+        }
+        match nodes.len() {
+            0 => None,
+            1 => nodes.pop(),
+            _ => Some(Node::Or(nodes)),
+        }
+    }
+
+    fn parse_and(&mut self) -> Option<Node> {
+        let mut nodes = Vec::new();
+        while let Some(token) = self.tokens.get(self.pos) {
+            if matches!(token, Token::Or | Token::RParen) {
+                break;
+            }
+            nodes.extend(self.parse_unary());
+        }
+        match nodes.len() {
+            0 => None,
+            1 => nodes.pop(),
+            _ => Some(Node::And(nodes)),
+        }
+    }
+
+    fn parse_unary(&mut self) -> Option<Node> {
+        let token = self.tokens.get(self.pos)?.clone();
+        self.pos += 1;
+        match token {
+            Token::Not => self.parse_unary().map(|n| Node::Not(Box::new(n))),
+            Token::LParen => {
+                let node = self.parse_or();
+                // A missing ) is fine, it is probably about to be typed
+                if self.tokens.get(self.pos) == Some(&Token::RParen) {
+                    self.pos += 1;
+                }
+                node
+            }
+            Token::Word(word) if has_words(&word) => Some(Node::Term(fold(&word), None)),
+            Token::Quoted(phrase) if !phrase.trim().is_empty() => Some(Node::Phrase(phrase, None)),
+            Token::Prefixed(prefix, value, quoted) => match prefix.as_str() {
+                "year" => parse_range(&value, |v| v.trim().parse().ok()).map(Node::Year),
+                "duration" => parse_range(&value, parse_duration).map(Node::Duration),
+                _ if !has_words(&value) => None,
+                _ if quoted => Some(Node::Phrase(value, Field::from_name(&prefix))),
+                _ => Some(Node::Term(fold(&value), Field::from_name(&prefix))),
+            },
+            Token::Word(_) | Token::Quoted(_) | Token::Or | Token::RParen => None,
+        }
+    }
+}
+
+fn parse(query: &str) -> Option<Node> {
+    let mut parser = Parser {
+        tokens: tokenize(query),
+        pos: 0,
+    };
+    let mut nodes = Vec::new();
+    while parser.pos < parser.tokens.len() {
+        nodes.extend(parser.parse_or());
+        // A stray ) is skipped rather than ending the query
+        parser.pos += 1;
+    }
+    match nodes.len() {
+        0 => None,
+        1 => nodes.pop(),
+        _ => Some(Node::And(nodes)),
+    }
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+pub fn index_library(songs: &[SongMetadata]) {
+    let mut index = SEARCH_INDEX.write().unwrap();
+    let generation = index.as_ref().map(|i| i.generation + 1).unwrap_or(0);
+    *index = Some(SearchIndex::build(songs, generation));
+}
+
+// The indexed song at path, if the library has been scanned
+#[flutter_rust_bridge::frb(ignore)]
+pub fn indexed_song(path: &str) -> Option<SongMetadata> {
+    let guard = SEARCH_INDEX.read().unwrap();
+    let index = guard.as_ref()?;
+    let doc = *index.by_path.get(path)?;
+    Some(index.docs[doc as usize].song.clone())
+}
+
+// Every indexed song, if the library has been scanned
+#[flutter_rust_bridge::frb(ignore)]
+pub fn indexed_library() -> Option<Vec<SongMetadata>> {
+    let guard = SEARCH_INDEX.read().unwrap();
+    let index = guard.as_ref()?;
+    Some(index.docs.iter().map(|d| d.song.clone()).collect())
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+pub fn is_library_indexed() -> bool {
+    SEARCH_INDEX.read().unwrap().is_some()
+}
+
+// Scans the music folder and indexes it, scanning it any other way also refreshes the index.
+// Returns how many songs were indexed
+pub fn rebuild_search_index() -> Result<u32, String> {
+    let songs = scan_music_directory(music_folder()?, false);
+    index_library(&songs);
+    Ok(songs.len() as u32)
+}
+
+fn ranked(index: &SearchIndex, query: &str) -> Vec<(u32, f32)> {
+    let mut last = LAST_RESULT.lock().unwrap();
+    if let Some((generation, last_query, result)) = last.as_ref()
+        && *generation == index.generation
+        && last_query == query
+    {
+        return result.clone();
+    }
+
+    let mut result: Vec<(u32, f32)> = match parse(query) {
+        Some(node) => index.evaluate(&node).into_iter().collect(),
+        None => (0..index.docs.len() as u32).map(|i| (i, 0.0)).collect(),
+    };
+    // Best first, equal scores fall back to artist, album and title order
+    result.sort_by(|(a, sa), (b, sb)| {
+        sb.partial_cmp(sa).unwrap_or(Ordering::Equal).then_with(|| {
+            let (a, b) = (&index.docs[*a as usize], &index.docs[*b as usize]);
+            a.folded[1]
+                .cmp(&b.folded[1])
+                .then_with(|| a.folded[2].cmp(&b.folded[2]))
+                .then_with(|| a.folded[0].cmp(&b.folded[0]))
+        })
+    });
+    *last = Some((index.generation, query.to_string(), result.clone()));
+    result
+}
+
+// Searches the library with words, "quoted phrases", field prefixes (title:, artist:, album:,
+// genre:, path:, year:1990..1999, duration:>5m), OR, NOT or -word and parentheses. Words are
+// matched ignoring case and accents, as prefixes while typing and with a typo or two once they are
+// long enough. Pages start at 0
+pub fn search_library(query: String, page: u32, page_size: Option<u32>) -> SearchPage {
+    let page_size = page_size.unwrap_or(DEFAULT_PAGE_SIZE).max(1);
+    if SEARCH_INDEX.read().unwrap().is_none() {
+        let _ = rebuild_search_index();
+    }
+    let guard = SEARCH_INDEX.read().unwrap();
+    let Some(index) = guard.as_ref() else {
+        return SearchPage {
+            total: 0,
+            page,
+            page_size,
+            hits: Vec::new(),
+        };
+    };
+
+    let result = ranked(index, query.trim());
+    let hits = result
+        .iter()
+        .skip(page as usize * page_size as usize)
+        .take(page_size as usize)
+        .map(|&(doc, score)| SearchHit {
+            song: index.docs[doc as usize].song.clone(),
+            score,
+        })
+        .collect();
+    SearchPage {
+        total: result.len() as u32,
+        page,
+        page_size,
+        hits,
+    }
+}
@@ -0,0 +1,179 @@
+use crate::api::value_store::{DEFAULT_LRCLIB_BASE_URL, with_store};
+use anyhow::{Context, bail};
+use once_cell::sync::Lazy;
+use reqwest::{StatusCode, Url};
+use serde::Deserialize;
+use std::time::Duration;
+
+// lrclib asks clients to identify themselves, it has no published rate limit
+const LRCLIB_USER_AGENT: &str = concat!(
+    "Adiman/",
+    env!("CARGO_PKG_VERSION"),
+    " (https://github.com/ChaosTheChaotic/Adiman)"
+);
+// How far off a search result's duration can be before it is probably a different recording
+const DURATION_TOLERANCE_SECS: f64 = 3.0;
+
+static LRCLIB_CLIENT: Lazy<reqwest::blocking::Client> = Lazy::new(|| {
+    reqwest::blocking::Client::builder()
+        .user_agent(LRCLIB_USER_AGENT)
+        .timeout(Duration::from_secs(15))
+        .build()
+        .unwrap_or_default()
+});
+
+#[derive(Debug, Clone)]
+pub struct LrclibLyrics {
+    pub id: i64,
+    pub title: String,
+    pub artist: String,
+    pub album: String,
+    pub duration: f64,
+    pub instrumental: bool,
+    pub plain_lyrics: Option<String>,
+    pub synced_lyrics: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LrclibRecord {
+    id: i64,
+    #[serde(default)]
+    track_name: Option<String>,
+    #[serde(default)]
+    artist_name: Option<String>,
+    #[serde(default)]
+    album_name: Option<String>,
+    #[serde(default)]
+    duration: Option<f64>,
+    #[serde(default)]
+    instrumental: bool,
+    #[serde(default)]
+    plain_lyrics: Option<String>,
+    #[serde(default)]
+    synced_lyrics: Option<String>,
+}
+
+impl From<LrclibRecord> for LrclibLyrics {
+    fn from(record: LrclibRecord) -> Self {
+        let non_empty = |s: Option<String>| s.filter(|s| !s.trim().is_empty());
+        Self {
+            id: record.id,
+            title: record.track_name.unwrap_or_default(),
+            artist: record.artist_name.unwrap_or_default(),
+            album: record.album_name.unwrap_or_default(),
+            duration: record.duration.unwrap_or(0.0),
+            instrumental: record.instrumental,
+            plain_lyrics: non_empty(record.plain_lyrics),
+            synced_lyrics: non_empty(record.synced_lyrics),
+        }
+    }
+}
+
+impl LrclibLyrics {
+    // Synced lyrics when there are any, plain ones otherwise
+    #[flutter_rust_bridge::frb(ignore)]
+    pub fn best_lyrics(&self) -> Option<&str> {
+        self.synced_lyrics
+            .as_deref()
+            .or(self.plain_lyrics.as_deref())
+    }
+}
+
+fn base_url() -> String {
+    with_store(|store| store.lrclib_base_url.clone())
+        .unwrap_or_else(|| DEFAULT_LRCLIB_BASE_URL.to_string())
+}
+
+// None when lrclib answered 404, which is how it says it has nothing
+fn get_json<T: serde::de::DeserializeOwned>(
+    endpoint: &str,
+    params: &[(&str, String)],
+) -> anyhow::Result<Option<T>> {
+    let url = Url::parse_with_params(&format!("{}/{endpoint}", base_url()), params)
+        .context("Invalid lrclib base url")?;
+    let response = LRCLIB_CLIENT
+        .get(url)
+        .send()
+        .context("Failed to send request to lrclib")?;
+
+    if response.status() == StatusCode::NOT_FOUND {
+        return Ok(None);
+    } else if response.status().is_client_error() {
+        bail!("lrclib responded with a client error {}", response.status())
+    } else if response.status().is_server_error() {
+        bail!("lrclib responded with a server error, try again later")
+    }
+
+    response
+        .json()
+        .map(Some)
+        .context("Failed to parse lrclib response")
+}
+
+// Looks up the exact recording, falling back to a search when lrclib has no entry with that
+// album and duration. Search results have to be within a few seconds of the duration when it is
+// known
+#[flutter_rust_bridge::frb(ignore)]
+pub fn fetch_lrclib(
+    title: &str,
+    artist: &str,
+    album: &str,
+    duration: u64,
+) -> anyhow::Result<Option<LrclibLyrics>> {
+    if title.trim().is_empty() || artist.trim().is_empty() {
+        return Ok(None);
+    }
+
+    let mut params = vec![
+        ("track_name", title.to_string()),
+        ("artist_name", artist.to_string()),
+    ];
+    if !album.trim().is_empty() {
+        params.push(("album_name", album.to_string()));
+    }
+    if duration > 0 {
+        params.push(("duration", duration.to_string()));
+    }
+    if let Some(record) = get_json::<LrclibRecord>("get", &params)? {
+        return Ok(Some(record.into()));
+    }
+
+    let results = search_rs(title, artist)?;
+    Ok(results
+        .into_iter()
+        .filter(|r| {
+            duration == 0
+                || r.duration == 0.0
+                || (r.duration - duration as f64).abs() <= DURATION_TOLERANCE_SECS
+        })
+        .filter(|r| r.best_lyrics().is_some())
+        // Synced lyrics first, then the closest duration
+        .min_by(|a, b| {
+            let distance = |r: &LrclibLyrics| (r.duration - duration as f64).abs();
+            a.synced_lyrics
+                .is_none()
+                .cmp(&b.synced_lyrics.is_none())
+                .then(distance(a).total_cmp(&distance(b)))
+        }))
+}
+
+fn search_rs(title: &str, artist: &str) -> anyhow::Result<Vec<LrclibLyrics>> {
+    let mut params = vec![("track_name", title.to_string())];
+    if !artist.trim().is_empty() {
+        params.push(("artist_name", artist.to_string()));
+    }
+    Ok(get_json::<Vec<LrclibRecord>>("search", &params)?
+        .unwrap_or_default()
+        .into_iter()
+        .map(LrclibLyrics::from)
+        .collect())
+}
+
+// Every lrclib entry for a title, so the user can pick when the automatic match is wrong
+pub fn search_lrclib(title: String, artist: String) -> Result<Vec<LrclibLyrics>, String> {
+    if title.trim().is_empty() {
+        return Err("A title is needed to search lrclib".to_string());
+    }
+    search_rs(&title, &artist).map_err(|e| format!("{e:#}"))
+}
@@ -0,0 +1,174 @@
+use crate::api::{
+    music_handler::get_playback_position,
+    synced_lyrics::{ACTIVE_LYRICS, ActiveLyrics, LrcDocument, LrcLine},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[derive(Serialize, Deserialize, Default)]
+struct TrackOffset {
+    offset_ms: i64,
+}
+
+// song.lrc keeps its offset in song.lrc.offset so the lyrics themselves can be replaced freely
+fn offset_file(lrc_path: &Path) -> PathBuf {
+    let mut name = lrc_path.as_os_str().to_owned();
+    name.push(".offset");
+    PathBuf::from(name)
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+pub fn saved_offset(lrc_path: &Path) -> i64 {
+    fs::read_to_string(offset_file(lrc_path))
+        .ok()
+        .and_then(|json| serde_json::from_str::<TrackOffset>(&json).ok())
+        .unwrap_or_default()
+        .offset_ms
+}
+
+fn save_offset(lrc_path: &Path, offset_ms: i64) -> Result<(), String> {
+    let path = offset_file(lrc_path);
+    if offset_ms == 0 {
+        return match fs::remove_file(&path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                Err(format!("Failed to reset the lyrics offset: {e}"))
+            }
+            _ => Ok(()),
+        };
+    }
+    let json = serde_json::to_string(&TrackOffset { offset_ms })
+        .map_err(|e| format!("Failed to serialise the lyrics offset: {e}"))?;
+    fs::write(&path, json).map_err(|e| format!("Failed to save the lyrics offset: {e}"))
+}
+
+fn with_active<T>(f: impl FnOnce(&mut ActiveLyrics) -> Result<T, String>) -> Result<T, String> {
+    let mut active = ACTIVE_LYRICS.write().unwrap();
+    let active = active
+        .as_mut()
+        .ok_or("No lyrics are loaded, load some with load_synced_lyrics first")?;
+    f(active)
+}
+
+fn line_mut(doc: &mut LrcDocument, line_index: u32) -> Result<&mut LrcLine, String> {
+    let count = doc.lines.len();
+    doc.lines
+        .get_mut(line_index as usize)
+        .ok_or_else(|| format!("Line {line_index} is out of range, the lyrics have {count} lines"))
+}
+
+// Moves a line and its word timings together
+fn move_line(line: &mut LrcLine, start_ms: u64) {
+    let delta = start_ms as i64 - line.start_ms as i64;
+    line.start_ms = start_ms;
+    for word in &mut line.words {
+        word.start_ms = (word.start_ms as i64 + delta).max(0) as u64;
+    }
+}
+
+fn playback_ms() -> u64 {
+    (get_playback_position().max(0.0) * 1000.0) as u64
+}
+
+// Moves every timestamp by delta_ms, positive makes the lyrics come later. Changes the lyrics
+// themselves, set_lyrics_offset adjusts timing without touching the file
+pub fn shift_lyrics(delta_ms: i64) -> Result<LrcDocument, String> {
+    with_active(|active| {
+        for line in &mut active.doc.lines {
+            let start = (line.start_ms as i64 + delta_ms).max(0) as u64;
+            move_line(line, start);
+        }
+        Ok(active.doc.clone())
+    })
+}
+
+// Sets when a line is sung, time_ms is where it is heard in the song with the offsets applied
+pub fn set_lyric_line_time(line_index: u32, time_ms: u64) -> Result<LrcDocument, String> {
+    with_active(|active| {
+        let start = active.doc.remove_offset(time_ms);
+        move_line(line_mut(&mut active.doc, line_index)?, start);
+        active.doc.synced = true;
+        active.doc.lines.sort_by_key(|l| l.start_ms);
+        Ok(active.doc.clone())
+    })
+}
+
+// Stamps a line with the current playback position
+pub fn sync_lyric_line(line_index: u32) -> Result<LrcDocument, String> {
+    set_lyric_line_time(line_index, playback_ms())
+}
+
+// Tap to sync: every tap_sync from here on stamps the next line, starting at from_line
+pub fn start_tap_sync(from_line: u32) -> Result<(), String> {
+    with_active(|active| {
+        line_mut(&mut active.doc, from_line)?;
+        active.tap_cursor = from_line as usize;
+        Ok(())
+    })
+}
+
+// Stamps the next line with the current playback position and returns its index, None once every
+// line is done. Later lines that would now come before it are pushed along so the order the lyrics
+// are sung in never changes, which is also what lets plain lyrics be synced from scratch
+pub fn tap_sync() -> Result<Option<u32>, String> {
+    let position = playback_ms();
+    with_active(|active| {
+        let index = active.tap_cursor;
+        if index >= active.doc.lines.len() {
+            return Ok(None);
+        }
+        if !active.doc.synced {
+            // Plain lyrics all sit at 0 until they are tapped
+            active.doc.synced = true;
+            for line in &mut active.doc.lines {
+                line.start_ms = 0;
+            }
+        }
+        let start = active.doc.remove_offset(position);
+        for (i, line) in active.doc.lines[index..].iter_mut().enumerate() {
+            if i == 0 || line.start_ms < start {
+                move_line(line, start);
+            }
+        }
+        active.tap_cursor = index + 1;
+        Ok(Some(index as u32))
+    })
+}
+
+// A per-track adjustment on top of the lyrics' own [offset:], positive makes them show up earlier.
+// Saved next to the lyric file when the lyrics came from one
+pub fn set_lyrics_offset(offset_ms: i64) -> Result<LrcDocument, String> {
+    with_active(|active| {
+        if let Some(path) = &active.path {
+            save_offset(path, offset_ms)?;
+        }
+        active.doc.user_offset_ms = offset_ms;
+        Ok(active.doc.clone())
+    })
+}
+
+pub fn get_lyrics_offset(lrc_path: String) -> i64 {
+    saved_offset(Path::new(&lrc_path))
+}
+
+// Writes the edited lyrics as LRC to path, or back to the file they were loaded from. The offset
+// file follows the lyrics when they are saved somewhere new
+pub fn save_synced_lyrics(path: Option<String>) -> Result<String, String> {
+    with_active(|active| {
+        let target = path
+            .map(PathBuf::from)
+            .or_else(|| active.path.clone())
+            .ok_or("These lyrics were not loaded from a file, pass a path to save them to")?;
+        let tmp = target.with_extension("lrc.tmp");
+        fs::write(&tmp, active.doc.to_lrc())
+            .and_then(|_| fs::rename(&tmp, &target))
+            .map_err(|e| format!("Failed to save {}: {e}", target.display()))?;
+        if active.path.as_ref() != Some(&target) {
+            save_offset(&target, active.doc.user_offset_ms)?;
+            active.path = Some(target.clone());
+        }
+        Ok(target.to_string_lossy().to_string())
+    })
+}
@@ -0,0 +1,461 @@
+use crate::api::{
+    library_search::{fold, index_library, indexed_song, is_library_indexed, words},
+    music_handler::{SongMetadata, scan_music_directory},
+    synced_lyrics::parse_lrc_document,
+};
+use once_cell::sync::Lazy;
+use rayon::prelude::*;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::{Duration, Instant, SystemTime},
+};
+
+// Type-ahead searches come in quick succession, the folder is only checked for changes this often
+const SYNC_INTERVAL: Duration = Duration::from_secs(2);
+const MAX_SNIPPETS: usize = 3;
+// A line holding every word of the query is worth this many scattered matches
+const WHOLE_LINE_BONUS: f32 = 2.0;
+const PREFIX_WEIGHT: f32 = 0.7;
+
+static LYRICS_INDEX: Lazy<Mutex<Option<LyricsIndex>>> = Lazy::new(|| Mutex::new(None));
+
+#[derive(Debug, Clone)]
+pub struct LyricMatchLine {
+    pub line: u32,
+    // Where the line is sung, None for lyrics without timestamps
+    pub timestamp_ms: Option<u64>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct LyricSearchResult {
+    pub song: SongMetadata,
+    pub score: f32,
+    pub lines: Vec<LyricMatchLine>,
+}
+
+struct LyricLine {
+    timestamp_ms: Option<u64>,
+    text: String,
+    // Folded words separated by single spaces
+    folded: String,
+}
+
+struct LyricsDoc {
+    lrc_path: PathBuf,
+    size: u64,
+    modified: SystemTime,
+    title: String,
+    artist: String,
+    album: String,
+    genre: String,
+    song_path: String,
+    length_ms: Option<u64>,
+    lines: Vec<LyricLine>,
+}
+
+struct LyricsIndex {
+    dir: PathBuf,
+    last_sync: Option<Instant>,
+    docs: Vec<Option<LyricsDoc>>,
+    free: Vec<u32>,
+    by_file: HashMap<PathBuf, u32>,
+    // Folded word to the lines of each song it is sung in, ordered so prefixes are a range
+    postings: BTreeMap<String, HashMap<u32, Vec<u32>>>,
+}
+
+fn parse_lrc(lrc_path: &Path) -> Option<LyricsDoc> {
+    let meta = fs::metadata(lrc_path).ok()?;
+    let content = fs::read_to_string(lrc_path).ok()?;
+    let lrc = parse_lrc_document(&content);
+    let lines = lrc
+        .lines
+        .iter()
+        .filter(|l| !l.text.is_empty())
+        .map(|l| LyricLine {
+            // A chorus tagged [01:00][02:30] comes back once per time and can be jumped to either
+            timestamp_ms: lrc.synced.then(|| lrc.apply_offset(l.start_ms)),
+            text: l.text.clone(),
+            // Just the words, so phrases match whatever punctuation the line has
+            folded: words(&fold(&l.text)).collect::<Vec<_>>().join(" "),
+        })
+        .collect();
+    Some(LyricsDoc {
+        lrc_path: lrc_path.to_path_buf(),
+        size: meta.len(),
+        modified: meta.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+        title: lrc.title.unwrap_or_default(),
+        artist: lrc.artist.unwrap_or_default(),
+        album: lrc.album.unwrap_or_default(),
+        genre: lrc.genre.unwrap_or_default(),
+        song_path: lrc.song_path.unwrap_or_default(),
+        length_ms: lrc.length_ms,
+        lines,
+    })
+}
+
+impl LyricsIndex {
+    fn new(dir: &Path) -> Self {
+        Self {
+            dir: dir.to_path_buf(),
+            last_sync: None,
+            docs: Vec::new(),
+            free: Vec::new(),
+            by_file: HashMap::new(),
+            postings: BTreeMap::new(),
+        }
+    }
+
+    fn remove(&mut self, id: u32) {
+        let Some(doc) = self.docs[id as usize].take() else {
+            return;
+        };
+        self.by_file.remove(&doc.lrc_path);
+        let doc_words: HashSet<&str> = doc.lines.iter().flat_map(|l| words(&l.folded)).collect();
+        for word in doc_words {
+            if let Some(songs) = self.postings.get_mut(word) {
+                songs.remove(&id);
+                if songs.is_empty() {
+                    self.postings.remove(word);
+                }
+            }
+        }
+        self.free.push(id);
+    }
+
+    fn insert(&mut self, doc: LyricsDoc) {
+        let id = self.free.pop().unwrap_or(self.docs.len() as u32);
+        for (n, line) in doc.lines.iter().enumerate() {
+            for word in words(&line.folded) {
+                let lines = self
+                    .postings
+                    .entry(word.to_string())
+                    .or_default()
+                    .entry(id)
+                    .or_default();
+                if lines.last() != Some(&(n as u32)) {
+                    lines.push(n as u32);
+                }
+            }
+        }
+        self.by_file.insert(doc.lrc_path.clone(), id);
+        if id as usize == self.docs.len() {
+            self.docs.push(Some(doc));
+        } else {
+            self.docs[id as usize] = Some(doc);
+        }
+    }
+
+    // Reparses only the .lrc files that were added or changed since the last sync and drops the
+    // ones that were deleted. Returns how many files changed
+    fn sync(&mut self) -> u32 {
+        self.last_sync = Some(Instant::now());
+        let on_disk: Vec<(PathBuf, u64, SystemTime)> = fs::read_dir(&self.dir)
+            .map(|entries| {
+                entries
+                    .filter_map(|e| e.ok())
+                    .map(|e| e.path())
+                    .filter(|p| p.extension().is_some_and(|e| e == "lrc"))
+                    .filter_map(|p| {
+                        let meta = fs::metadata(&p).ok()?;
+                        let modified = meta.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                        Some((p, meta.len(), modified))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let present: HashSet<&PathBuf> = on_disk.iter().map(|(p, _, _)| p).collect();
+        let gone: Vec<u32> = self
+            .by_file
+            .iter()
+            .filter(|(path, _)| !present.contains(path))
+            .map(|(_, id)| *id)
+            .collect();
+
+        let changed: Vec<&PathBuf> = on_disk
+            .iter()
+            .filter(|(path, size, modified)| {
+                self.by_file
+                    .get(path)
+                    .and_then(|id| self.docs[*id as usize].as_ref())
+                    .is_none_or(|doc| doc.size != *size || doc.modified != *modified)
+            })
+            .map(|(path, _, _)| path)
+            .collect();
+        let parsed: Vec<(PathBuf, Option<LyricsDoc>)> = changed
+            .par_iter()
+            .map(|path| ((*path).clone(), parse_lrc(path)))
+            .collect();
+
+        let count = (gone.len() + parsed.len()) as u32;
+        for id in gone {
+            self.remove(id);
+        }
+        for (path, doc) in parsed {
+            if let Some(id) = self.by_file.get(&path).copied() {
+                self.remove(id);
+            }
+            if let Some(doc) = doc {
+                self.insert(doc);
+            }
+        }
+        count
+    }
+
+    // Songs and the lines within them that hold the term, or words starting with it while the
+    // last word is still being typed
+    fn term_lines(&self, term: &str, allow_prefix: bool) -> HashMap<u32, (f32, HashSet<u32>)> {
+        let mut found: HashMap<u32, (f32, HashSet<u32>)> = HashMap::new();
+        for (word, songs) in self.postings.range(term.to_string()..) {
+            if !word.starts_with(term) {
+                break;
+            }
+            let exact = word == term;
+            if !exact && !allow_prefix {
+                continue;
+            }
+            let weight = if exact { 1.0 } else { PREFIX_WEIGHT };
+            for (id, lines) in songs {
+                let entry = found.entry(*id).or_default();
+                entry.0 = entry.0.max(weight);
+                entry.1.extend(lines);
+            }
+        }
+        found
+    }
+
+    fn search(&self, query: &str) -> Vec<(u32, f32, Vec<u32>)> {
+        let (phrases, terms) = parse_query(query);
+        if terms.is_empty() {
+            return Vec::new();
+        }
+        let live = self.docs.iter().filter(|d| d.is_some()).count().max(1) as f32;
+
+        let mut candidates: Option<HashMap<u32, f32>> = None;
+        let mut term_lines: Vec<HashMap<u32, (f32, HashSet<u32>)>> = Vec::new();
+        for (term, prefix) in &terms {
+            let lines = self.term_lines(term, *prefix);
+            // Rare words say more about a song than common ones
+            let idf = (1.0 + live / (lines.len() as f32 + 1.0)).ln();
+            let scores: HashMap<u32, f32> = lines
+                .iter()
+                .map(|(id, (weight, hits))| (*id, weight * idf * (1.0 + (hits.len() as f32).ln())))
+                .collect();
+            candidates = Some(match candidates {
+                None => scores,
+                Some(mut c) => {
+                    c.retain(|id, score| match scores.get(id) {
+                        Some(s) => {
+                            *score += s;
+                            true
+                        }
+                        None => false,
+                    });
+                    c
+                }
+            });
+            term_lines.push(lines);
+        }
+
+        let mut results: Vec<(u32, f32, Vec<u32>)> = candidates
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(id, mut score)| {
+                let doc = self.docs[id as usize].as_ref()?;
+                let in_line = |n: &u32| {
+                    let padded = format!(" {} ", doc.lines[*n as usize].folded);
+                    phrases.iter().all(|p| padded.contains(&format!(" {p} ")))
+                };
+                let any_line: HashSet<u32> = term_lines
+                    .iter()
+                    .filter_map(|t| t.get(&id))
+                    .flat_map(|(_, lines)| lines.iter().copied())
+                    .collect();
+                // Lines where every word (and every phrase) of the query is sung together
+                let mut whole: Vec<u32> = any_line
+                    .iter()
+                    .copied()
+                    .filter(|n| {
+                        term_lines
+                            .iter()
+                            .all(|t| t.get(&id).is_some_and(|(_, lines)| lines.contains(n)))
+                    })
+                    .filter(in_line)
+                    .collect();
+                if !phrases.is_empty() && whole.is_empty() {
+                    return None;
+                }
+                whole.sort_unstable();
+                score += WHOLE_LINE_BONUS * whole.len() as f32;
+
+                let mut lines = whole;
+                if lines.is_empty() {
+                    let mut scattered: Vec<u32> = any_line.into_iter().collect();
+                    scattered.sort_unstable();
+                    lines = scattered;
+                }
+                Some((id, score, lines))
+            })
+            .collect();
+        results.sort_by(|a, b| b.1.total_cmp(&a.1));
+        results
+    }
+
+    fn song(&self, doc: &LyricsDoc) -> SongMetadata {
+        indexed_song(&doc.song_path).unwrap_or_else(|| {
+            let default = SongMetadata::default();
+            let or_default = |v: &str, d: String| if v.is_empty() { d } else { v.to_string() };
+            SongMetadata {
+                title: or_default(&doc.title, default.title),
+                artist: or_default(&doc.artist, default.artist),
+                album: or_default(&doc.album, default.album),
+                genre: or_default(&doc.genre, default.genre),
+                path: doc.song_path.clone(),
+                ..SongMetadata::default()
+            }
+        })
+    }
+}
+
+// Quoted parts are phrases that have to be sung in one line, every word anywhere in the query has to
+// be in the song. The last word may be unfinished unless it is followed by a space
+fn parse_query(query: &str) -> (Vec<String>, Vec<(String, bool)>) {
+    let mut phrases = Vec::new();
+    let mut loose = String::new();
+    for (i, part) in query.split('"').enumerate() {
+        if i % 2 == 1 {
+            let phrase = words(&fold(part)).collect::<Vec<_>>().join(" ");
+            if !phrase.is_empty() {
+                phrases.push(phrase);
+            }
+        }
+        loose.push(' ');
+        loose.push_str(part);
+    }
+    let folded = fold(&loose);
+    let all: Vec<&str> = words(&folded).collect();
+    let typing = !query.ends_with(char::is_whitespace) && !query.ends_with('"');
+    let mut seen = HashSet::new();
+    let terms = all
+        .iter()
+        .enumerate()
+        .filter(|(_, w)| seen.insert(**w))
+        .map(|(i, w)| (w.to_string(), typing && i == all.len() - 1))
+        .collect();
+    (phrases, terms)
+}
+
+fn with_index<T>(lyrics_dir: &Path, f: impl FnOnce(&mut LyricsIndex) -> T) -> T {
+    let mut guard = LYRICS_INDEX.lock().unwrap();
+    if guard.as_ref().is_none_or(|index| index.dir != lyrics_dir) {
+        *guard = Some(LyricsIndex::new(lyrics_dir));
+    }
+    let index = guard.as_mut().unwrap();
+    if index.last_sync.is_none_or(|t| t.elapsed() >= SYNC_INTERVAL) {
+        index.sync();
+    }
+    f(index)
+}
+
+// Rescans lyrics_dir for added, changed and deleted .lrc files right away rather than on the next
+// search. Returns how many files changed
+pub fn refresh_lyrics_index(lyrics_dir: String) -> u32 {
+    let dir = PathBuf::from(lyrics_dir);
+    let mut guard = LYRICS_INDEX.lock().unwrap();
+    if guard.as_ref().is_none_or(|index| index.dir != dir) {
+        *guard = Some(LyricsIndex::new(&dir));
+    }
+    guard.as_mut().unwrap().sync()
+}
+
+// Finds songs whose lyrics hold every word of query ("quoted phrases" must be sung in one line),
+// best first, with up to three of the matching lines and when they are sung
+pub fn search_lyrics_lines(
+    lyrics_dir: String,
+    query: String,
+    limit: Option<u32>,
+) -> Vec<LyricSearchResult> {
+    let dir = PathBuf::from(lyrics_dir);
+    if !dir.is_dir() {
+        return Vec::new();
+    }
+    with_index(&dir, |index| {
+        let mut seen = HashSet::new();
+        index
+            .search(&query)
+            .into_iter()
+            .filter_map(|(id, score, lines)| {
+                let doc = index.docs[id as usize].as_ref()?;
+                // Several .lrc files may belong to the same song
+                if !doc.song_path.is_empty() && !seen.insert(doc.song_path.clone()) {
+                    return None;
+                }
+                Some(LyricSearchResult {
+                    song: index.song(doc),
+                    score,
+                    lines: lines
+                        .into_iter()
+                        .take(MAX_SNIPPETS)
+                        .map(|n| {
+                            let line = &doc.lines[n as usize];
+                            LyricMatchLine {
+                                line: n,
+                                timestamp_ms: line.timestamp_ms,
+                                text: line.text.clone(),
+                            }
+                        })
+                        .collect(),
+                })
+            })
+            .take(limit.map(|l| l as usize).unwrap_or(usize::MAX))
+            .collect()
+    })
+}
+
+// The cached lyrics written for song_path, otherwise the first whose title, artist and length
+// (in ms, when known) same_song accepts. Files are only read again after they change
+#[flutter_rust_bridge::frb(ignore)]
+pub fn find_indexed_lrc(
+    lyrics_dir: &Path,
+    song_path: &str,
+    same_song: impl Fn(&str, &str, Option<u64>) -> bool,
+) -> Option<PathBuf> {
+    if !lyrics_dir.is_dir() {
+        return None;
+    }
+    let found = with_index(lyrics_dir, |index| {
+        let docs = || index.docs.iter().flatten();
+        docs()
+            .find(|doc| doc.song_path == song_path)
+            .or_else(|| docs().find(|doc| same_song(&doc.title, &doc.artist, doc.length_ms)))
+            .map(|doc| doc.lrc_path.clone())
+    });
+    // The index may be a sync behind a file that was just deleted
+    found.filter(|path| path.is_file())
+}
+
+// Songs are looked up in the library search index, song_dir is only scanned if nothing has
+// indexed the library yet
+#[flutter_rust_bridge::frb(ignore)]
+pub fn search_indexed_lyrics(
+    lyrics_dir: String,
+    query: String,
+    song_dir: String,
+) -> Vec<SongMetadata> {
+    if !is_library_indexed() {
+        let songs = scan_music_directory(song_dir, false);
+        // Scanning the music folder indexes it already, any other folder has to be done here
+        if !is_library_indexed() {
+            index_library(&songs);
+        }
+    }
+    // Lyrics with no song in the library have nothing to play
+    search_lyrics_lines(lyrics_dir, query, None)
+        .into_iter()
+        .filter_map(|r| indexed_song(&r.song.path))
+        .collect()
+}
@@ -0,0 +1,253 @@
+use crate::api::{
+    embedded_lyrics::read_embedded_lyrics,
+    library_search::fold,
+    lrclib::fetch_lrclib,
+    lyrics_editor::saved_offset,
+    lyrics_index::find_indexed_lrc,
+    music_handler::SongMetadata,
+    synced_lyrics::{LrcDocument, format_timestamp, parse_lrc_document},
+};
+use sha2::{Digest, Sha256};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+// Cached lyrics with a [length:] this far from the song's duration belong to another recording
+const DURATION_TOLERANCE_MS: u64 = 3000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LyricsSource {
+    // The app's lyrics folder
+    Cache,
+    // A .lrc file next to the song
+    Sidecar,
+    // USLT/SYLT frames, Vorbis LYRICS comments or the MP4 lyrics atom
+    Embedded,
+    Lrclib,
+}
+
+#[derive(Debug, Clone)]
+pub struct FoundLyrics {
+    pub source: LyricsSource,
+    pub content: String,
+    pub document: LrcDocument,
+    // The cache file the lyrics are stored in, None if caching them failed
+    pub cache_path: Option<String>,
+}
+
+// Somewhere lyrics can come from. Providers are asked in order until one has lyrics for the song
+#[flutter_rust_bridge::frb(ignore)]
+pub trait LyricsProvider {
+    fn source(&self) -> LyricsSource;
+    fn fetch(&self, song: &SongMetadata) -> Result<Option<String>, String>;
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+pub struct CacheProvider<'a> {
+    pub lyrics_dir: &'a Path,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+pub struct SidecarProvider;
+
+#[flutter_rust_bridge::frb(ignore)]
+pub struct EmbeddedProvider;
+
+#[flutter_rust_bridge::frb(ignore)]
+pub struct LrclibProvider;
+
+impl LyricsProvider for CacheProvider<'_> {
+    fn source(&self) -> LyricsSource {
+        LyricsSource::Cache
+    }
+
+    fn fetch(&self, song: &SongMetadata) -> Result<Option<String>, String> {
+        Ok(find_cached(self.lyrics_dir, song).and_then(|path| fs::read_to_string(path).ok()))
+    }
+}
+
+impl LyricsProvider for SidecarProvider {
+    fn source(&self) -> LyricsSource {
+        LyricsSource::Sidecar
+    }
+
+    fn fetch(&self, song: &SongMetadata) -> Result<Option<String>, String> {
+        let sidecar = Path::new(&song.path).with_extension("lrc");
+        Ok(fs::read_to_string(sidecar)
+            .ok()
+            .filter(|c| !c.trim().is_empty()))
+    }
+}
+
+impl LyricsProvider for EmbeddedProvider {
+    fn source(&self) -> LyricsSource {
+        LyricsSource::Embedded
+    }
+
+    fn fetch(&self, song: &SongMetadata) -> Result<Option<String>, String> {
+        // Formats without tag support simply have no embedded lyrics
+        Ok(read_embedded_lyrics(song.path.clone()).unwrap_or(None))
+    }
+}
+
+impl LyricsProvider for LrclibProvider {
+    fn source(&self) -> LyricsSource {
+        LyricsSource::Lrclib
+    }
+
+    fn fetch(&self, song: &SongMetadata) -> Result<Option<String>, String> {
+        // The placeholders scanning fills in would only find some other song
+        let defaults = SongMetadata::default();
+        if song.title == defaults.title || song.artist == defaults.artist {
+            return Ok(None);
+        }
+        let album = if song.album == defaults.album {
+            ""
+        } else {
+            &song.album
+        };
+        let found = fetch_lrclib(&song.title, &song.artist, album, song.duration)
+            .map_err(|e| format!("{e:#}"))?;
+        Ok(found.and_then(|l| l.best_lyrics().map(str::to_string)))
+    }
+}
+
+fn cache_file(lyrics_dir: &Path, song_path: &str) -> PathBuf {
+    let hash = format!("{:x}", Sha256::digest(song_path.as_bytes()));
+    lyrics_dir.join(format!("{}.lrc", &hash[..32]))
+}
+
+fn same_text(a: Option<&str>, b: &str) -> bool {
+    a.is_some_and(|a| !a.is_empty() && fold(a) == fold(b))
+}
+
+// Files the app or older versions wrote, or that were dropped in by hand, may be missing any of the
+// headers. The song path decides when it is there, otherwise title and artist have to match and the
+// length has to be close when both sides know it
+fn find_cached(lyrics_dir: &Path, song: &SongMetadata) -> Option<PathBuf> {
+    let named = cache_file(lyrics_dir, &song.path);
+    if named.is_file() {
+        return Some(named);
+    }
+    // Lyrics cached for a different file of the same song are still a fallback
+    find_indexed_lrc(lyrics_dir, &song.path, |title, artist, length_ms| {
+        same_text(Some(title), &song.title)
+            && same_text(Some(artist), &song.artist)
+            && length_ms.is_none_or(|len| {
+                song.duration == 0 || len.abs_diff(song.duration * 1000) <= DURATION_TOLERANCE_MS
+            })
+    })
+}
+
+// The header the app has always written in front of cached lyrics, plus [length:] so other
+// recordings of the song can be told apart. Headers already in the lyrics are replaced
+fn with_headers(song: &SongMetadata, content: &str) -> String {
+    let mut out = format!(
+        "#TITLE: {}\n#ARTIST: {}\n#PATH: {}\n#GENRE: {}\n#ALBUM: {}\n",
+        song.title, song.artist, song.path, song.genre, song.album
+    );
+    let has_length = content
+        .lines()
+        .any(|l| l.trim_start().to_lowercase().starts_with("[length:"));
+    if song.duration > 0 && !has_length {
+        out.push_str(&format!(
+            "[length:{}]\n",
+            format_timestamp(song.duration * 1000)
+        ));
+    }
+    for line in content.lines() {
+        if !line.trim_start().starts_with('#') {
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+// Saves lyrics to the lyrics folder for the song, replacing any cached before
+pub fn save_lyrics(
+    lyrics_dir: String,
+    song: SongMetadata,
+    content: String,
+) -> Result<String, String> {
+    let dir = Path::new(&lyrics_dir);
+    fs::create_dir_all(dir).map_err(|e| format!("Failed to create {lyrics_dir}: {e}"))?;
+    let target = cache_file(dir, &song.path);
+    // Older files under another name would otherwise keep being found for the song
+    if let Some(old) = find_cached(dir, &song)
+        && old != target
+    {
+        let _ = fs::remove_file(old);
+    }
+    fs::write(&target, with_headers(&song, &content))
+        .map_err(|e| format!("Failed to write {}: {e}", target.display()))?;
+    Ok(target.to_string_lossy().to_string())
+}
+
+pub fn find_cached_lyrics(lyrics_dir: String, song: SongMetadata) -> Option<String> {
+    find_cached(Path::new(&lyrics_dir), &song).map(|p| p.to_string_lossy().to_string())
+}
+
+pub fn delete_cached_lyrics(lyrics_dir: String, song: SongMetadata) -> Result<bool, String> {
+    match find_cached(Path::new(&lyrics_dir), &song) {
+        Some(path) => fs::remove_file(&path)
+            .map(|_| true)
+            .map_err(|e| format!("Failed to delete {}: {e}", path.display())),
+        None => Ok(false),
+    }
+}
+
+// Looks for lyrics in the cache, next to the song, in its tags and, when allowed, on lrclib.
+// Anything not already cached is saved to the lyrics folder so the next lookup is local
+pub fn get_lyrics(
+    lyrics_dir: String,
+    song: SongMetadata,
+    allow_online: bool,
+) -> Result<Option<FoundLyrics>, String> {
+    let dir = Path::new(&lyrics_dir);
+    let mut providers: Vec<Box<dyn LyricsProvider + '_>> = vec![
+        Box::new(CacheProvider { lyrics_dir: dir }),
+        Box::new(SidecarProvider),
+        Box::new(EmbeddedProvider),
+    ];
+    if allow_online {
+        providers.push(Box::new(LrclibProvider));
+    }
+
+    let mut last_error = None;
+    for provider in providers {
+        let content = match provider.fetch(&song) {
+            Ok(Some(content)) => content,
+            Ok(None) => continue,
+            Err(e) => {
+                last_error = Some(e);
+                continue;
+            }
+        };
+        let mut document = parse_lrc_document(&content);
+        if document.lines.is_empty() {
+            continue;
+        }
+        let source = provider.source();
+        let cache_path = match source {
+            LyricsSource::Cache => find_cached_lyrics(lyrics_dir.clone(), song.clone()),
+            _ => save_lyrics(lyrics_dir.clone(), song.clone(), content.clone()).ok(),
+        };
+        document.user_offset_ms = cache_path
+            .as_deref()
+            .map_or(0, |p| saved_offset(Path::new(p)));
+        return Ok(Some(FoundLyrics {
+            source,
+            content,
+            document,
+            cache_path,
+        }));
+    }
+
+    // Only worth reporting when nothing was found, a network error with local lyrics is fine
+    match last_error {
+        Some(e) => Err(e),
+        None => Ok(None),
+    }
+}
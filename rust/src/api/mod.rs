@@ -1,8 +1,37 @@
 pub mod acoustid;
+pub mod art_cache;
+pub mod audiobooks;
+pub mod chapters;
+pub mod collection_download;
 pub mod color_extractor;
+pub mod cue_sheet;
+pub mod download_manager;
+pub mod download_pipeline;
+pub mod embedded_lyrics;
+pub mod fingerprint;
 /// flutter_rust_bridge:ignore
 pub mod host_func_interface;
+pub mod http_stream;
+pub mod library_search;
+pub mod lrclib;
+pub mod lyrics_editor;
+pub mod lyrics_index;
+pub mod lyrics_store;
 pub mod music_handler;
+pub mod musicbrainz;
+pub mod organizer;
+pub mod play_stats;
+pub mod playlist_io;
+pub mod playlist_manager;
+pub mod podcast_feed;
+pub mod podcasts;
 pub mod plugin_man;
+pub mod radio_stations;
+pub mod replay_gain;
+pub mod smart_playlist;
+pub mod synced_lyrics;
+pub mod tag_editor;
 pub mod utils;
 pub mod value_store;
+/// flutter_rust_bridge:ignore
+pub mod xml;
@@ -1,6 +1,26 @@
-use crate::api::{plugin_man::call_func_plugins, utils::fpre, value_store::update_store};
+use crate::api::{
+    art_cache::{album_art_for, register_track_art},
+    audiobooks::track_book,
+    cue_sheet::{
+        reject_virtual_track, resolve_virtual_track, songs_in_file, split_virtual_track,
+        virtual_track_metadata,
+    },
+    download_manager::{cancel_download_job, forget_download, queue_download, wait_for_download},
+    http_stream::{
+        HttpStreamSource, clear_stream_metadata, is_stream_url, open_stream, stream_song,
+    },
+    library_search::index_library,
+    lyrics_index::search_indexed_lyrics,
+    play_stats::record_play,
+    playlist_manager::order_playlist_songs,
+    plugin_man::call_func_plugins,
+    radio_stations::station_name_for_url,
+    smart_playlist::songs_for_dir,
+    utils::fpre,
+    value_store::{update_store, with_store},
+};
 use atomic_float::AtomicF32;
-use audiotags::Tag;
+use audiotags::{MimeType, Picture, Tag};
 use cd_audio::{
     SCDStream, sget_cd_stream_first_sector, sget_cd_stream_last_sector, sget_devices,
     sget_track_meta, sopen_cd_stream, sread_cd_stream, sseek_cd_stream, strack_duration,
@@ -20,16 +40,14 @@ use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::{
     cmp::max,
-    collections::{HashMap, HashSet},
     env::temp_dir,
-    fmt,
-    fs::{self, read_dir},
+    fmt, fs,
     io::{BufReader, Cursor, Read, Seek, SeekFrom},
     path::{Path, PathBuf},
-    process::{Child, Command},
+    process::Command,
     sync::{
         Arc, Mutex, RwLock,
-        atomic::{AtomicBool, Ordering},
+        atomic::{AtomicBool, AtomicU64, Ordering},
         mpsc::{self, Receiver, Sender},
     },
     thread,
@@ -222,6 +240,76 @@ struct StreamingBuffer {
     sample_rate: u32,
     channels: u16,
     total_duration: Duration,
+    continuation: Arc<Mutex<Continuation>>,
+}
+
+// Lets the CUE virtual track right after this one carry on from its decoder and its source, so back
+// to back tracks of a file meet on the exact sample
+#[derive(Default)]
+struct Continuation {
+    // Length of the whole file, for a next track that plays to the end of it
+    file_duration: Option<Duration>,
+    // Set once the whole span is in chunks
+    decoded: bool,
+    // Left at the end of the span once it is decoded, for the next track to pick up
+    decoder: Option<Decoder<Cursor<Vec<u8>>>>,
+    // The next track's buffer and length (None to the end of the file), decoding moves on to it
+    next: Option<(StreamingBuffer, Option<Duration>)>,
+    // Playback moves on to the next track too, cleared when it is skipped to early instead
+    play_on: bool,
+}
+
+impl StreamingBuffer {
+    // Sets up the track that starts where this one ends to continue from it. None when this one's
+    // decoder has already been used up, that track is then preloaded from its file as usual
+    fn continue_with(&self, start: Duration, end: Option<Duration>) -> Option<StreamingBuffer> {
+        let mut continuation = self.continuation.lock().unwrap();
+        if continuation.decoded && continuation.decoder.is_none() {
+            return None;
+        }
+        let next = StreamingBuffer {
+            chunks: Arc::new(Mutex::new(vec![AudioChunk {
+                samples: Vec::new(),
+            }])),
+            sample_rate: self.sample_rate,
+            channels: self.channels,
+            total_duration: end
+                .or(continuation.file_duration)
+                .unwrap_or(Duration::from_secs(0))
+                .saturating_sub(start),
+            continuation: Arc::new(Mutex::new(Continuation {
+                file_duration: continuation.file_duration,
+                ..Default::default()
+            })),
+        };
+        let len = end.map(|end| end.saturating_sub(start));
+        continuation.next = Some((next.clone(), len));
+        continuation.play_on = true;
+        // Still decoding otherwise, it carries on into the next track by itself
+        if let Some(decoder) = continuation.decoder.take() {
+            let next = next.clone();
+            thread::spawn(move || decode_into(decoder, len, next));
+        }
+        Some(next)
+    }
+
+    fn runs_into(&self, next: &StreamingBuffer) -> bool {
+        let continuation = self.continuation.lock().unwrap();
+        continuation.play_on
+            && continuation
+                .next
+                .as_ref()
+                .is_some_and(|(buffer, _)| Arc::ptr_eq(&buffer.chunks, &next.chunks))
+    }
+
+    // Another song was preloaded, this one must end where it is again
+    fn unlink(&self) {
+        let mut continuation = self.continuation.lock().unwrap();
+        continuation.play_on = false;
+        if !continuation.decoded {
+            continuation.next = None;
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -230,8 +318,19 @@ struct AudioChunk {
 }
 
 enum PlayerMessage {
-    Load { path: String, position: f32 },
-    PreloadNext { path: String },
+    Load {
+        path: String,
+        position: f32,
+    },
+    // A stream opened off the message thread, load is the Load it was opened for
+    StreamReady {
+        path: String,
+        load: u64,
+        source: HttpStreamSource,
+    },
+    PreloadNext {
+        path: String,
+    },
     Seek(f32),
     Stop,
     SwitchToPreloaded,
@@ -277,6 +376,12 @@ static PLAYER: Lazy<Mutex<Option<AudioPlayer>>> = Lazy::new(|| Mutex::new(None))
 static PLAYER_STATE: Lazy<Mutex<PlayerState>> = Lazy::new(|| Mutex::new(PlayerState::default()));
 static FADE_IN: AtomicBool = AtomicBool::new(false);
 static CUR_VOL: AtomicF32 = AtomicF32::new(1.0);
+// Bumped by every Load so a stream that finishes opening after something else was played is dropped
+static LOAD_GENERATION: AtomicU64 = AtomicU64::new(0);
+// The job download_to_temp is waiting on, cancel_download leaves every other download running
+static TEMP_DOWNLOAD: Mutex<Option<u64>> = Mutex::new(None);
+// How close to its end a CUE track has to be for switching to the next one to let it play into it
+const CONTINUE_WINDOW_SECS: f32 = 1.0;
 
 pub fn set_fadein(value: bool) {
     FADE_IN.store(value, Ordering::SeqCst);
@@ -297,12 +402,89 @@ pub fn list_audio_devices() -> Vec<String> {
     }
 }
 
+// CUE virtual tracks play a span of their file, anything else plays the whole file
+fn track_span(path: &str) -> (String, Duration, Option<Duration>) {
+    match resolve_virtual_track(path) {
+        Some(track) => (
+            track.file,
+            Duration::from_secs_f64(track.start_secs),
+            track.end_secs.map(Duration::from_secs_f64),
+        ),
+        None => (path.to_string(), Duration::ZERO, None),
+    }
+}
+
+// Back to back virtual tracks need to meet on the exact sample to stay gapless, so when seeking
+// fails the decoder is run up to the start instead
+fn decode_span(
+    mut decoder: Decoder<Cursor<Vec<u8>>>,
+    start: Duration,
+    end: Option<Duration>,
+    buffer: StreamingBuffer,
+) {
+    if !start.is_zero() && decoder.try_seek(start).is_err() {
+        let skip = span_samples(&decoder, start);
+        decoder.by_ref().take(skip).for_each(drop);
+    }
+    decode_into(decoder, end.map(|end| end.saturating_sub(start)), buffer);
+}
+
+fn span_samples(decoder: &Decoder<Cursor<Vec<u8>>>, len: Duration) -> usize {
+    (len.as_secs_f64() * decoder.sample_rate() as f64) as usize * decoder.channels() as usize
+}
+
+// Buffers len of audio (or the rest of the file) a second per chunk, then goes on with the next
+// virtual track if one was preloaded or keeps the decoder where it stopped for one that may be
+fn decode_into(
+    mut decoder: Decoder<Cursor<Vec<u8>>>,
+    mut len: Option<Duration>,
+    mut buffer: StreamingBuffer,
+) {
+    let chunk_len = decoder.sample_rate() as usize * decoder.channels() as usize;
+    loop {
+        let take = len.map_or(usize::MAX, |len| span_samples(&decoder, len));
+        let mut samples = Vec::with_capacity(chunk_len);
+        for sample in decoder.by_ref().take(take) {
+            samples.push(sample);
+            if samples.len() >= chunk_len {
+                if let Ok(mut guard) = buffer.chunks.lock() {
+                    guard.push(AudioChunk {
+                        samples: samples.clone(),
+                    });
+                }
+                samples.clear();
+            }
+        }
+        if !samples.is_empty()
+            && let Ok(mut guard) = buffer.chunks.lock()
+        {
+            guard.push(AudioChunk { samples });
+        }
+
+        let next = {
+            let mut continuation = buffer.continuation.lock().unwrap();
+            continuation.decoded = true;
+            if len.is_none() {
+                // Ran to the end of the file, nothing can follow
+                return;
+            }
+            match continuation.next.clone() {
+                Some(next) => next,
+                None => {
+                    continuation.decoder = Some(decoder);
+                    return;
+                }
+            }
+        };
+        (buffer, len) = next;
+    }
+}
+
 struct AudioPlayer {
     sink: Mutex<Option<Arc<Sink>>>,
     current_file: Mutex<String>,
     start_time: Mutex<Instant>,
     playing: Mutex<bool>,
-    album_art_cache: Mutex<HashMap<String, Vec<u8>>>,
     sender: Mutex<Sender<PlayerMessage>>,
     buffer: Arc<Mutex<Option<StreamingBuffer>>>,
     paused_position: Mutex<f32>,
@@ -336,12 +518,13 @@ impl AudioPlayer {
             let preload_monitor_clone = Arc::clone(&preload_monitor);
             let transition_threshold_clone = Arc::clone(&transition_threshold);
             let tx_clone = tx.clone();
+            let tx_worker = tx.clone();
 
             thread::spawn(move || {
                 Self::position_monitor(preload_monitor_clone, transition_threshold_clone, tx_clone)
             });
             // Spawn the background worker with a cloned mixer and buffer.
-            thread::spawn(move || Self::background_worker(rx, mixer, buffer_clone));
+            thread::spawn(move || Self::background_worker(rx, tx_worker, mixer, buffer_clone));
             if let Ok(mut stream_guard) = STREAM.lock() {
                 *stream_guard = Some(StreamWrapper(stream));
                 return Some(Self {
@@ -349,7 +532,6 @@ impl AudioPlayer {
                     current_file: Mutex::new(String::new()),
                     start_time: Mutex::new(Instant::now()),
                     playing: Mutex::new(false),
-                    album_art_cache: Mutex::new(HashMap::new()),
                     sender: Mutex::new(tx),
                     buffer,
                     paused_position: Mutex::new(0.0),
@@ -402,6 +584,8 @@ impl AudioPlayer {
     }
 
     fn stop(&self) -> bool {
+        // A stream still opening must not start once it is ready
+        LOAD_GENERATION.fetch_add(1, Ordering::SeqCst);
         if let Some(old_sink) = self.sink.lock().unwrap().take() {
             old_sink.stop();
             *self.playing.lock().unwrap() = false;
@@ -455,12 +639,15 @@ impl AudioPlayer {
 
     fn background_worker(
         receiver: Receiver<PlayerMessage>,
+        sender: Sender<PlayerMessage>,
         mixer: rodio::mixer::Mixer,
         buffer: Arc<Mutex<Option<StreamingBuffer>>>,
     ) {
         while let Ok(message) = receiver.recv() {
             match message {
                 PlayerMessage::Load { path, position } => {
+                    let load = LOAD_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+                    let (file_path, start, end) = track_span(&path);
                     if let Some(path_after_scheme) = path.strip_prefix("cdda://") {
                         // Parse CD path
                         let track_start = path_after_scheme.rfind("track");
@@ -518,7 +705,18 @@ impl AudioPlayer {
                                 Err(e) => println!("Failed to open CD stream: {}", e),
                             }
                         }
-                    } else if let Ok(file) = fs::File::open(&path) {
+                    } else if is_stream_url(&path) {
+                        // Opening blocks until the stream has sent enough to know its format, so it
+                        // happens on its own thread and the source comes back as StreamReady
+                        let sender = sender.clone();
+                        thread::spawn(move || match open_stream(&path) {
+                            Ok(source) => {
+                                let _ =
+                                    sender.send(PlayerMessage::StreamReady { path, load, source });
+                            }
+                            Err(e) => println!("Failed to open stream {}: {}", path, e),
+                        });
+                    } else if let Ok(file) = fs::File::open(&file_path) {
                         let mut reader = BufReader::new(file);
                         if position > 0.0 {
                             let bytes_pos = (position * 44100.0 * 2.0) as u64;
@@ -529,6 +727,7 @@ impl AudioPlayer {
                         let mut sample_rate = 44100;
                         let mut channels = 2;
                         let mut total_duration = Duration::from_secs(0);
+                        let mut file_duration = None;
                         let mut initial_data = Vec::new();
                         if reader.read_to_end(&mut initial_data).is_ok() && !initial_data.is_empty()
                         {
@@ -536,8 +735,11 @@ impl AudioPlayer {
                             if let Ok(dec) = Decoder::try_from(cursor) {
                                 sample_rate = dec.sample_rate();
                                 channels = dec.channels();
-                                total_duration =
-                                    dec.total_duration().unwrap_or(Duration::from_secs(0));
+                                total_duration = end
+                                    .or(dec.total_duration())
+                                    .unwrap_or(Duration::from_secs(0))
+                                    .saturating_sub(start);
+                                file_duration = dec.total_duration();
                                 decoder = Some(dec);
                             }
                         }
@@ -555,7 +757,12 @@ impl AudioPlayer {
                             sample_rate,
                             channels,
                             total_duration,
+                            continuation: Arc::new(Mutex::new(Continuation {
+                                file_duration,
+                                ..Default::default()
+                            })),
                         };
+                        let span_buffer = streaming_buffer.clone();
                         {
                             let mut buf = buffer.lock().unwrap();
                             *buf = Some(streaming_buffer.clone());
@@ -583,8 +790,7 @@ impl AudioPlayer {
                             *player.is_paused.lock().unwrap() = false;
                         }
                         // Spawn thread to buffer the rest of the audio in chunks.
-                        let chunks_clone = Arc::clone(&chunks);
-                        let file_path = path.clone();
+                        let file_path = file_path.clone();
                         thread::spawn(move || {
                             if let Ok(file) = fs::File::open(&file_path) {
                                 let mut reader = BufReader::new(file);
@@ -592,25 +798,7 @@ impl AudioPlayer {
                                 if reader.read_to_end(&mut file_data).is_ok() {
                                     let cursor = Cursor::new(file_data);
                                     if let Ok(decoder) = Decoder::try_from(cursor) {
-                                        let mut samples = Vec::with_capacity(sample_rate as usize);
-                                        for sample in decoder {
-                                            samples.push(sample);
-                                            if samples.len()
-                                                >= (sample_rate as usize * channels as usize)
-                                            {
-                                                if let Ok(mut guard) = chunks_clone.lock() {
-                                                    guard.push(AudioChunk {
-                                                        samples: samples.clone(),
-                                                    });
-                                                }
-                                                samples.clear();
-                                            }
-                                        }
-                                        if !samples.is_empty()
-                                            && let Ok(mut guard) = chunks_clone.lock()
-                                        {
-                                            guard.push(AudioChunk { samples });
-                                        }
+                                        decode_span(decoder, start, end, span_buffer);
                                     }
                                 }
                             }
@@ -619,17 +807,77 @@ impl AudioPlayer {
                         println!("Failed to open file: {}", &path);
                     }
                 }
+                PlayerMessage::StreamReady { path, load, source } => {
+                    if LOAD_GENERATION.load(Ordering::SeqCst) != load {
+                        continue;
+                    }
+                    let new_sink = Arc::new(Sink::connect_new(&mixer));
+                    new_sink.set_volume(0.0);
+                    new_sink.append(source);
+                    new_sink.play();
+
+                    // Streams cannot seek so there is nothing to buffer for it
+                    {
+                        let mut buf = buffer.lock().unwrap();
+                        *buf = None;
+                    }
+
+                    if let Ok(player_lock) = PLAYER.lock()
+                        && let Some(player) = player_lock.as_ref()
+                    {
+                        let old_sink = player.sink.lock().unwrap().take();
+                        AudioPlayer::crossfade(old_sink, Arc::clone(&new_sink));
+                        *player.sink.lock().unwrap() = Some(Arc::clone(&new_sink));
+                        *player.current_file.lock().unwrap() = path.clone();
+                        *player.start_time.lock().unwrap() = Instant::now();
+                        *player.playing.lock().unwrap() = true;
+                        *player.is_paused.lock().unwrap() = false;
+                    }
+                }
                 PlayerMessage::PreloadNext { path } => {
                     if path.starts_with("cdda://") {
                         break;
                     }
-                    if let Ok(file) = fs::File::open(&path) {
+                    let (file_path, start, end) = track_span(&path);
+                    let current = buffer.lock().unwrap().clone();
+                    if let Some(ref current) = current {
+                        current.unlink();
+                    }
+                    let current_path = PLAYER
+                        .lock()
+                        .ok()
+                        .and_then(|p| Some(p.as_ref()?.current_file.lock().unwrap().clone()))
+                        .unwrap_or_default();
+                    let (current_file, _, current_end) = track_span(&current_path);
+                    // The next track of the CUE sheet playing now carries on from the current one
+                    // instead of opening and seeking the file again
+                    let continued = current
+                        .filter(|_| current_file == file_path && current_end == Some(start))
+                        .and_then(|current| current.continue_with(start, end));
+                    if let Some(next) = continued {
+                        let new_sink = Arc::new(Sink::connect_new(&mixer));
+                        new_sink.set_volume(0.0);
+                        new_sink.append(StreamingSource {
+                            buffer: Arc::new(Mutex::new(Some(next.clone()))),
+                            current_chunk: 0,
+                            position: 0,
+                            chunks_processed: 0,
+                        });
+                        new_sink.pause();
+                        if let Ok(player_lock) = PLAYER.lock()
+                            && let Some(player) = player_lock.as_ref()
+                        {
+                            *player.next_sink.lock().unwrap() = Some(Arc::clone(&new_sink));
+                            *player.next_buffer.lock().unwrap() = Some(next);
+                        }
+                    } else if let Ok(file) = fs::File::open(&file_path) {
                         let mut reader = BufReader::new(file);
                         let chunks = Arc::new(Mutex::new(Vec::new()));
                         let mut decoder: Option<Decoder<Cursor<Vec<u8>>>> = None;
                         let mut sample_rate = 44100;
                         let mut channels = 2;
                         let mut total_duration = Duration::from_secs(0);
+                        let mut file_duration = None;
                         let mut initial_data = Vec::new();
                         if reader.read_to_end(&mut initial_data).is_ok() && !initial_data.is_empty()
                         {
@@ -637,8 +885,11 @@ impl AudioPlayer {
                             if let Ok(dec) = Decoder::try_from(cursor) {
                                 sample_rate = dec.sample_rate();
                                 channels = dec.channels();
-                                total_duration =
-                                    dec.total_duration().unwrap_or(Duration::from_secs(0));
+                                total_duration = end
+                                    .or(dec.total_duration())
+                                    .unwrap_or(Duration::from_secs(0))
+                                    .saturating_sub(start);
+                                file_duration = dec.total_duration();
                                 decoder = Some(dec);
                             }
                         }
@@ -656,7 +907,12 @@ impl AudioPlayer {
                             sample_rate,
                             channels,
                             total_duration,
+                            continuation: Arc::new(Mutex::new(Continuation {
+                                file_duration,
+                                ..Default::default()
+                            })),
                         };
+                        let span_buffer = streaming_buffer.clone();
                         let stbuf_clone = streaming_buffer.clone();
                         // Create sink and append a streaming source.
                         let new_sink = Arc::new(Sink::connect_new(&mixer));
@@ -676,8 +932,7 @@ impl AudioPlayer {
                             *player.next_buffer.lock().unwrap() = Some(stbuf_clone);
                         }
                         // Spawn thread to buffer the rest of the audio in chunks.
-                        let chunks_clone = Arc::clone(&chunks);
-                        let file_path = path.clone();
+                        let file_path = file_path.clone();
                         thread::spawn(move || {
                             if let Ok(file) = fs::File::open(&file_path) {
                                 let mut reader = BufReader::new(file);
@@ -685,25 +940,7 @@ impl AudioPlayer {
                                 if reader.read_to_end(&mut file_data).is_ok() {
                                     let cursor = Cursor::new(file_data);
                                     if let Ok(decoder) = Decoder::try_from(cursor) {
-                                        let mut samples = Vec::with_capacity(sample_rate as usize);
-                                        for sample in decoder {
-                                            samples.push(sample);
-                                            if samples.len()
-                                                >= (sample_rate as usize * channels as usize)
-                                            {
-                                                if let Ok(mut guard) = chunks_clone.lock() {
-                                                    guard.push(AudioChunk {
-                                                        samples: samples.clone(),
-                                                    });
-                                                }
-                                                samples.clear();
-                                            }
-                                        }
-                                        if !samples.is_empty()
-                                            && let Ok(mut guard) = chunks_clone.lock()
-                                        {
-                                            guard.push(AudioChunk { samples });
-                                        }
+                                        decode_span(decoder, start, end, span_buffer);
                                     }
                                 }
                             }
@@ -850,6 +1087,25 @@ impl AudioPlayer {
             // Stop monitoring temporarily
             self.preload_monitor.store(false, Ordering::SeqCst);
 
+            // The playing source runs into a CUE track that follows it by itself, so near the end
+            // only the state moves on. Skipping to it earlier starts it on its own sink as usual
+            let current = self.buffer.lock().unwrap().clone();
+            if let Some(current) = current
+                && current.runs_into(&buffer)
+            {
+                let remaining = current.total_duration.as_secs_f32() - self.get_position();
+                if !*self.is_paused.lock().unwrap() && remaining <= CONTINUE_WINDOW_SECS {
+                    sink.stop();
+                    let started = *self.start_time.lock().unwrap();
+                    *self.buffer.lock().unwrap() = Some(buffer);
+                    *self.current_file.lock().unwrap() = path;
+                    *self.start_time.lock().unwrap() = started + current.total_duration;
+                    self.preload_monitor.store(true, Ordering::SeqCst);
+                    return true;
+                }
+                current.continuation.lock().unwrap().play_on = false;
+            }
+
             // Switch to preloaded track
             let old_sink = self.sink.lock().unwrap().take();
 
@@ -1055,6 +1311,25 @@ impl Iterator for StreamingSource {
             }
             break;
         }
+        // A finished CUE track goes straight on with the next one when that was preloaded
+        if self.current_chunk >= guard.len() {
+            let next = {
+                let continuation = buf.continuation.lock().unwrap();
+                continuation
+                    .next
+                    .as_ref()
+                    .filter(|_| continuation.decoded && continuation.play_on)
+                    .map(|(next, _)| next.clone())
+            };
+            if let Some(next) = next {
+                drop(guard);
+                *self.buffer.lock().unwrap() = Some(next);
+                self.current_chunk = 0;
+                self.position = 0;
+                self.chunks_processed = 0;
+                return self.next();
+            }
+        }
         // If we’ve processed some chunks, wait a moment and then try again.
         if self.chunks_processed > 0 && self.current_chunk >= guard.len() {
             drop(guard);
@@ -1083,6 +1358,10 @@ pub fn initialize_player() -> bool {
     false
 }
 
+// Covers bigger than this are scaled down before being embedded
+const MAX_EMBEDDED_ART_SIZE: u32 = 1200;
+const EMBEDDED_ART_QUALITY: u8 = 90;
+
 static MP3_CONVERSION_POOL: Lazy<ThreadPool> =
     Lazy::new(|| ThreadPoolBuilder::new().num_threads(2).build().unwrap());
 
@@ -1091,6 +1370,11 @@ pub fn scan_music_directory(dir_path: String, auto_convert: bool) -> Vec<SongMet
     let mut conversion_paths = Vec::new();
     let in_playlist_mode = dir_path.contains(".adilists");
 
+    // Smart playlists hold a rule instead of links, so they are worked out from the whole library
+    if in_playlist_mode && let Some(songs) = songs_for_dir(Path::new(&dir_path), auto_convert) {
+        return songs;
+    }
+
     // First pass: collect existing files and non-MP3s needing conversion
     for entry in WalkDir::new(&dir_path)
         .follow_links(true)
@@ -1109,9 +1393,10 @@ pub fn scan_music_directory(dir_path: String, auto_convert: bool) -> Vec<SongMet
             let ext_lower = ext.to_str().unwrap_or("").to_lowercase();
             match ext_lower.as_str() {
                 "mp3" | "m4a" | "flac" => {
-                    // Directly read metadata and add to songs
+                    // Directly read metadata and add to songs, split into its tracks if a CUE sheet
+                    // says it is a whole album
                     if let Some(metadata) = extract_metadata(entry.path()) {
-                        songs.push(metadata);
+                        songs.extend(songs_in_file(entry.path(), metadata));
                     }
                 }
                 "ogg" | "wav" => {
@@ -1172,10 +1457,21 @@ pub fn scan_music_directory(dir_path: String, auto_convert: bool) -> Vec<SongMet
         }
     }
 
+    if in_playlist_mode {
+        order_playlist_songs(Path::new(&dir_path), &mut songs);
+    } else if with_store(|store| Path::new(&store.music_folder) == Path::new(&dir_path))
+        .unwrap_or(true)
+    {
+        // A full scan is the freshest view of the library there is, so search uses it
+        index_library(&songs);
+    }
     songs
 }
 
+// album_art None leaves the cover alone, use write_song_art to remove it. A new cover is only
+// written when it differs from what the song has now so ordinary edits never recompress the art
 pub fn write_meta(meta: &SongMetadata) -> Result<(), String> {
+    reject_virtual_track(&meta.path)?;
     let mut tag = match Tag::new().read_from_path(meta.path.clone()) {
         Ok(t) => t,
         Err(e) => {
@@ -1187,6 +1483,15 @@ pub fn write_meta(meta: &SongMetadata) -> Result<(), String> {
     tag.set_artist(&meta.artist);
     tag.set_genre(&meta.genre);
     tag.set_album_title(&meta.album);
+
+    let cover;
+    if let Some(art) = &meta.album_art
+        && album_art_for(Path::new(&meta.path), 0).as_deref() != Some(art)
+    {
+        cover = prepare_cover(art)?;
+        tag.set_album_cover(Picture::new(&cover, MimeType::Jpeg));
+    }
+
     match tag.write_to_path(&meta.path.clone()) {
         Ok(_) => Ok(()),
         Err(e) => {
@@ -1196,7 +1501,76 @@ pub fn write_meta(meta: &SongMetadata) -> Result<(), String> {
     }
 }
 
-fn extract_metadata(path: &Path) -> Option<SongMetadata> {
+// Turns any image the image crate can read into a JPEG no bigger than MAX_EMBEDDED_ART_SIZE, JPEGs
+// that already fit are kept byte for byte
+fn prepare_cover(data: &[u8]) -> Result<Vec<u8>, String> {
+    let format =
+        image::guess_format(data).map_err(|e| format!("Unrecognised image format: {e}"))?;
+    let img = image::load_from_memory_with_format(data, format)
+        .map_err(|e| format!("Failed to decode image: {e}"))?;
+    let too_big = img.width().max(img.height()) > MAX_EMBEDDED_ART_SIZE;
+    if format == image::ImageFormat::Jpeg && !too_big {
+        return Ok(data.to_vec());
+    }
+    let img = if too_big {
+        img.thumbnail(MAX_EMBEDDED_ART_SIZE, MAX_EMBEDDED_ART_SIZE)
+    } else {
+        img
+    };
+    let mut out = Cursor::new(Vec::new());
+    image::codecs::jpeg::JpegEncoder::new_with_quality(&mut out, EMBEDDED_ART_QUALITY)
+        .encode_image(&img.to_rgb8())
+        .map_err(|e| format!("Failed to encode cover: {e}"))?;
+    Ok(out.into_inner())
+}
+
+// audiotags picks the right frame for the format (APIC for mp3, PICTURE for flac, covr for m4a)
+fn write_cover(path: &str, cover: Option<&[u8]>) -> Result<(), String> {
+    reject_virtual_track(path)?;
+    let mut tag = Tag::new()
+        .read_from_path(path)
+        .map_err(|e| format!("Error reading tag: {e}"))?;
+    match cover {
+        Some(cover) => tag.set_album_cover(Picture::new(cover, MimeType::Jpeg)),
+        None => tag.remove_album_cover(),
+    }
+    tag.write_to_path(path)
+        .map_err(|e| format!("Error writing tag to path: {e}"))
+}
+
+#[derive(Debug, Clone)]
+pub struct ArtWriteResult {
+    pub path: String,
+    pub error: Option<String>,
+}
+
+// Embeds a front cover in a single song, None removes it
+pub fn write_song_art(path: String, art: Option<Vec<u8>>) -> Result<(), String> {
+    let cover = art.as_deref().map(prepare_cover).transpose()?;
+    write_cover(&path, cover.as_deref())
+}
+
+// Embeds the same cover (or removes it with None) in every song of an album. The image is only
+// prepared once and a failure on one song does not stop the rest
+pub fn write_album_art(
+    paths: Vec<String>,
+    art: Option<Vec<u8>>,
+) -> Result<Vec<ArtWriteResult>, String> {
+    let cover = art.as_deref().map(prepare_cover).transpose()?;
+    Ok(paths
+        .into_par_iter()
+        .map(|path| {
+            let error = write_cover(&path, cover.as_deref()).err();
+            ArtWriteResult { path, error }
+        })
+        .collect())
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+pub fn extract_metadata(path: &Path) -> Option<SongMetadata> {
+    if let Some(song) = virtual_track_metadata(&path.to_string_lossy()) {
+        return Some(song);
+    }
     let tag = Tag::default().read_from_path(path).ok();
 
     let title = tag
@@ -1246,16 +1620,13 @@ fn extract_metadata(path: &Path) -> Option<SongMetadata> {
         .and_then(|t| t.genre().map(|s| s.to_string()))
         .unwrap_or_else(|| "Unknown Genre".to_string());
 
-    let album_art = tag.as_ref().and_then(|t| t.album_cover()).map(|pic| {
-        let art_bytes = pic.data.to_vec();
-        if let Ok(player) = PLAYER.lock()
-            && let Some(p) = player.as_ref()
-        {
-            let mut cache = p.album_art_cache.lock().unwrap();
-            cache.insert(path.to_string_lossy().to_string(), art_bytes.clone());
-        }
-        art_bytes
-    });
+    // Shared with every other track using the same cover, falls back to cover.jpg and friends. The
+    // art is left out of the metadata, get_cached_album_art loads it from the cache
+    let embedded_art = tag
+        .as_ref()
+        .and_then(|t| t.album_cover())
+        .map(|pic| pic.data.to_vec());
+    register_track_art(path, embedded_art);
 
     // Extract duration (fallback to 0 if decoding fails)
     let duration = {
@@ -1275,21 +1646,33 @@ fn extract_metadata(path: &Path) -> Option<SongMetadata> {
         album,
         duration,
         path: path.to_string_lossy().to_string(),
-        album_art,
+        album_art: None,
         genre,
     })
 }
 
 pub fn play_song(path: String) -> bool {
+    let is_stream = is_stream_url(&path);
     let mut updater = update_store();
-    updater.set_current_song(extract_metadata(PathBuf::from(path.clone()).as_path()).unwrap());
+    if is_stream {
+        updater.set_current_song(stream_song(&path, station_name_for_url(&path)));
+    } else {
+        clear_stream_metadata();
+        updater.set_current_song(extract_metadata(PathBuf::from(path.clone()).as_path()).unwrap());
+    }
     let r = updater.apply();
     if r.is_err() {
         println!("Failed to apply changes to the store: {}", r.unwrap_err());
     }
     call_func_plugins("play_song".to_string());
     if let Some(player) = PLAYER.lock().unwrap().as_ref() {
-        player.play(&path)
+        let playing = player.play(&path);
+        // A station is not a song, its plays are not counted
+        if playing && !is_stream {
+            record_play(&path);
+            track_book(&path);
+        }
+        playing
     } else {
         false
     }
@@ -1348,6 +1731,37 @@ pub fn seek_to_position(position: f32) -> bool {
     }
 }
 
+// Seeks in path once it is playing and decoded past position. The decoder runs ahead of playback
+// on its own thread, so seeking straight after play_song would land in audio that is not there yet
+#[flutter_rust_bridge::frb(ignore)]
+pub fn seek_when_buffered(path: &str, position: f32, timeout: Duration) -> bool {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let buffered_secs = PLAYER.lock().unwrap().as_ref().and_then(|player| {
+            if *player.current_file.lock().unwrap() != path {
+                return None;
+            }
+            let buffer = player.buffer.lock().unwrap();
+            let buf = buffer.as_ref()?;
+            let samples: usize = buf
+                .chunks
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|c| c.samples.len())
+                .sum();
+            Some(samples as f32 / (buf.sample_rate as f32 * buf.channels.max(1) as f32))
+        });
+        if buffered_secs.is_some_and(|secs| secs > position) {
+            return seek_to_position(position);
+        }
+        if Instant::now() >= deadline {
+            return false;
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
 pub fn skip_to_next(songs: Vec<String>, current_index: usize) -> bool {
     if current_index + 1 < songs.len()
         && let Some(player) = PLAYER.lock().unwrap().as_ref()
@@ -1367,11 +1781,15 @@ pub fn skip_to_previous(songs: Vec<String>, current_index: usize) -> bool {
 }
 
 pub fn get_cached_album_art(path: String) -> Option<Vec<u8>> {
-    if let Some(player) = PLAYER.lock().unwrap().as_ref() {
-        player.album_art_cache.lock().unwrap().get(&path).cloned()
-    } else {
-        None
-    }
+    get_cached_album_art_sized(path, 0)
+}
+
+// size is the wanted length of the longest side in pixels (snapped up to 64, 256 or 640), 0 for the
+// full image
+pub fn get_cached_album_art_sized(path: String, size: u32) -> Option<Vec<u8>> {
+    // A virtual track has the art of the file it is in
+    let file = split_virtual_track(&path).map_or(path.as_str(), |(file, _)| file);
+    album_art_for(Path::new(file), size).map(|art| art.to_vec())
 }
 
 pub fn get_current_song_path() -> Option<String> {
@@ -1425,13 +1843,18 @@ pub fn extract_waveform_from_mp3(
 ) -> Result<Vec<f64>, String> {
     let sample_count = sample_count.unwrap_or(1000) as usize;
     let channels = channels.unwrap_or(2);
+    // A virtual track only draws its own part of the file
+    let (file_path, start, end) = track_span(&mp3_path);
+    let mut span = vec!["-ss".to_string(), start.as_secs_f64().to_string()];
+    if let Some(end) = end {
+        span.extend(["-to".to_string(), end.as_secs_f64().to_string()]);
+    }
     let output = Command::new("ffmpeg")
+        .args(["-hide_banner", "-loglevel", "error"])
+        .args(&span)
         .args([
-            "-hide_banner",
-            "-loglevel",
-            "error",
             "-i",
-            &mp3_path,
+            &file_path,
             "-f",
             "s16le",
             "-acodec",
@@ -1539,119 +1962,31 @@ pub struct Track {
     pub cover_url: String,
 }
 
-// A global flag to cancel the download.
-static CANCEL_DOWNLOAD: Lazy<AtomicBool> = Lazy::new(|| AtomicBool::new(false));
-
+// Downloads the first search result for query and blocks until it is done. Each download gets its
+// own folder so the returned path is always the file yt-dlp just wrote, whoever plays it deletes
+// that folder when done with it
 pub fn download_to_temp(query: String, flags: Option<String>) -> Result<String, String> {
-    // Reset cancellation flag at the start of the download.
-    CANCEL_DOWNLOAD.store(false, Ordering::SeqCst);
-    let (tx, rx) = mpsc::channel();
-
-    thread::spawn(move || {
-        let result = (|| {
-            let temp_dir = temp_dir();
-            let temp_path = temp_dir
-                .to_str()
-                .ok_or("Failed to get temp directory")?
-                .to_string();
-
-            let mut audio_format = "m4a".to_string();
-            let mut parsed_flags = Vec::new();
-
-            if let Some(flags_str) = flags {
-                let mut iter = shlex::Shlex::new(&flags_str);
-                while let Some(arg) = iter.next() {
-                    if arg == "--audio-format" {
-                        if let Some(fmt) = iter.next() {
-                            audio_format = fmt;
-                        }
-                    } else if arg.starts_with("--audio-format=") {
-                        audio_format = arg.trim_start_matches("--audio-format=").to_string();
-                    } else {
-                        parsed_flags.push(arg);
-                    }
-                }
-            }
-
-            let output_path = format!("{}/%(title)s.%(ext)s", temp_path);
-            let search_query = format!("ytsearch1:{}", query);
-
-            let mut cmd = Command::new("yt-dlp");
-            
-            cmd.args([
-                "-f", "bestaudio",
-                "--extract-audio",
-                "--audio-quality", "0",
-                "--audio-format", &audio_format,
-                "--embed-thumbnail",
-                "--convert-thumbnails", "jpg",
-                "--ppa", "ThumbnailsConvertor:-vf scale=640:640:force_original_aspect_ratio=increase,crop=640:640 -q:v 1",
-                "--add-metadata",
-                "--embed-metadata",
-                "--parse-metadata", "title:%(artist)s - %(track)s",
-                "--replace-in-metadata", "title,track", r"(?i)\s*(?:[\[\(【{<].*?(?:official|video|audio|lyric|visualizer).*?[\]\)】}>]|\s*[-|]\s*(?:official\s*video|music\s*video|official\s*audio|lyric\s*video|official\s*visualizer).*)", "",
-                "--no-post-overwrites",
-                "--no-playlist",
-                "--playlist-items", "1",
-                "--output", &output_path,
-                "--verbose",
-                &search_query,
-            ]);
-
-            // Add the remaining parsed flags
-            for flag in parsed_flags {
-                cmd.arg(flag);
-            }
-            
-            let mut child: Child = cmd
-                .spawn()
-                .map_err(|e| format!("Failed to start download: {}", e))?;
-
-            loop {
-                // Check cancellation flag
-                if CANCEL_DOWNLOAD.load(Ordering::SeqCst) {
-                    // If cancellation is requested, kill the process and return early.
-                    let _ = child.kill();
-                    return Err("Download cancelled".into());
-                }
-
-                match child.try_wait() {
-                    Ok(Some(status)) => {
-                        if !status.success() {
-                            return Err(format!("Download failed with exit code: {}", status));
-                        }
-                        break;
-                    }
-                    Ok(None) => {
-                        // Process still running, sleep briefly before polling again.
-                        thread::sleep(Duration::from_millis(100));
-                    }
-                    Err(e) => return Err(format!("Error waiting for download process: {}", e)),
-                }
-            }
-
-            let dir = read_dir(&temp_path).map_err(|e| format!("Error reading temp dir: {}", e))?;
-
-            // Look for the file with the matching audio format extension
-            for entry in dir {
-                let entry = entry.map_err(|e| format!("Error reading entry: {}", e))?;
-                if let Some(ext) = entry.path().extension()
-                    && ext == std::ffi::OsStr::new(&audio_format)
-                {
-                    return Ok(entry.path().to_string_lossy().into_owned());
-                }
-            }
-
-            Err(format!("No {} file found after download", audio_format.to_uppercase()).into())
-        })();
-        tx.send(result).unwrap();
-    });
-
-    rx.recv().unwrap()
+    let id = queue_download(query, flags);
+    *TEMP_DOWNLOAD.lock().unwrap() = Some(id);
+    let result = wait_for_download(id);
+    {
+        let mut current = TEMP_DOWNLOAD.lock().unwrap();
+        if *current == Some(id) {
+            *current = None;
+        }
+    }
+    // Nothing else waits on the job, so it would only pile up in list_downloads
+    forget_download(id);
+    result?
+        .into_iter()
+        .next()
+        .ok_or("yt-dlp did not produce a file".to_string())
 }
 
 pub fn cancel_download() {
-    CANCEL_DOWNLOAD.store(true, Ordering::SeqCst);
+    if let Some(id) = *TEMP_DOWNLOAD.lock().unwrap() {
+        cancel_download_job(id);
+    }
 }
 
 // Currently unused because it is an absolute pain to wait for all the songs to come back but is
@@ -1708,38 +2043,6 @@ pub fn get_artist_via_ffprobe(file_path: String) -> Result<Vec<String>, String>
         .collect())
 }
 
-fn parse_lrc_metadata(
-    content: &str,
-) -> Option<(String, String, String, String, String, Vec<String>)> {
-    let mut title = None;
-    let mut artist = None;
-    let mut path = None;
-    let mut genre = None;
-    let mut album = None;
-    let mut lyrics = Vec::new();
-
-    for line in content.lines() {
-        if let Some(stripped) = line.strip_prefix("#TITLE: ") {
-            title = Some(stripped.trim().to_string());
-        } else if let Some(stripped) = line.strip_prefix("#ARTIST: ") {
-            artist = Some(stripped.trim().to_string());
-        } else if let Some(stripped) = line.strip_prefix("#PATH: ") {
-            path = Some(stripped.trim().to_string());
-        } else if let Some(stripped) = line.strip_prefix("#GENRE: ") {
-            genre = Some(stripped.trim().to_string());
-        } else if let Some(stripped) = line.strip_prefix("#ALBUM: ") {
-            album = Some(stripped.trim().to_string());
-        } else if !line.starts_with('#') {
-            lyrics.push(line.to_string());
-        }
-    }
-
-    match (title, artist, path, genre, album) {
-        (Some(t), Some(a), Some(p), Some(g), Some(alb)) => Some((t, a, p, g, alb, lyrics)),
-        _ => None,
-    }
-}
-
 pub fn search_lyrics(
     lyrics_dir: String,
     query: String,
@@ -1751,73 +2054,12 @@ pub fn search_lyrics(
     if !lyrics_path.exists() || !lyrics_path.is_dir() {
         return Ok(Vec::new());
     }
-    let query_lower = query.to_lowercase();
-
-    // Build lookup maps
-    let path_map: HashMap<String, SongMetadata> = scan_music_directory(song_dir, false)
-        .into_iter()
-        .map(|sm| (sm.path.clone(), sm))
-        .collect();
-
-    let mut title_artist_map: HashMap<(String, String, String, String), Vec<SongMetadata>> =
-        HashMap::new();
-    for sm in path_map.values() {
-        title_artist_map
-            .entry((
-                sm.title.clone(),
-                sm.artist.clone(),
-                sm.genre.clone(),
-                sm.album.clone(),
-            ))
-            .or_default()
-            .push(sm.clone());
-    }
-
-    let path_map = Arc::new(path_map);
-    let title_artist_map = Arc::new(title_artist_map);
-
-    // Process LRC files in parallel
-    let entries: Vec<_> = read_dir(lyrics_path)?.collect::<Result<Vec<_>, _>>()?;
-
-    let results: Vec<SongMetadata> = entries
-        .par_iter()
-        .filter_map(|entry| {
-            let path = entry.path();
-            if path.extension().map(|e| e == "lrc").unwrap_or(false) {
-                let content = fs::read_to_string(&path).ok()?;
-                let (title, artist, lrc_path, genre, album, lyrics) = parse_lrc_metadata(&content)?;
-
-                // Check if any lyric matches
-                let has_match = lyrics
-                    .iter()
-                    .any(|line| line.to_lowercase().contains(&query_lower));
-
-                if !has_match {
-                    return None;
-                }
-
-                // Try to find matching metadata
-                path_map.get(&lrc_path).cloned().or_else(|| {
-                    title_artist_map
-                        .get(&(title, artist, genre, album))
-                        .and_then(|v| v.first().cloned())
-                })
-            } else {
-                None
-            }
-        })
-        .collect();
-
-    // Deduplicate results
-    let mut seen = HashSet::new();
-    Ok(results
-        .into_iter()
-        .filter(|sm| seen.insert(sm.path.clone()))
-        .collect())
+    // Indexed once and kept up to date as .lrc files change instead of reading them all per query
+    Ok(search_indexed_lyrics(lyrics_dir, query, song_dir))
 }
 
 pub fn preload_next_song(path: String) -> bool {
-    if path.starts_with("cdda://") {
+    if path.starts_with("cdda://") || is_stream_url(&path) {
         return false;
     }
     if let Some(player) = PLAYER.lock().unwrap().as_ref() {
@@ -0,0 +1,346 @@
+use crate::api::{
+    acoustid::{AcoustIdCandidate, lookup_candidates},
+    music_handler::SongMetadata,
+    utils::RateLimiter,
+    value_store::{DEFAULT_COVERART_BASE_URL, DEFAULT_MUSICBRAINZ_BASE_URL, with_store},
+};
+use anyhow::{Context, bail};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, de::DeserializeOwned};
+use std::time::Duration;
+
+// MusicBrainz asks for no more than one request per second and a descriptive user agent
+const MUSICBRAINZ_REQUEST_INTERVAL: Duration = Duration::from_secs(1);
+const MUSICBRAINZ_USER_AGENT: &str = concat!(
+    "Adiman/",
+    env!("CARGO_PKG_VERSION"),
+    " ( https://github.com/ChaosTheChaotic/Adiman )"
+);
+
+static MUSICBRAINZ_CLIENT: Lazy<reqwest::blocking::Client> = Lazy::new(|| {
+    reqwest::blocking::Client::builder()
+        .user_agent(MUSICBRAINZ_USER_AGENT)
+        .timeout(Duration::from_secs(30))
+        .build()
+        .unwrap_or_default()
+});
+static MUSICBRAINZ_LIMITER: Lazy<RateLimiter> =
+    Lazy::new(|| RateLimiter::new(MUSICBRAINZ_REQUEST_INTERVAL));
+
+// The AcoustID match filled in with everything MusicBrainz and the Cover Art Archive know about it
+#[derive(Debug, Clone)]
+pub struct EnrichedMetadata {
+    pub metadata: SongMetadata,
+    pub recording_id: Option<String>,
+    pub release_id: Option<String>,
+    pub album_artist: Option<String>,
+    pub track_number: Option<u32>,
+    pub total_tracks: Option<u32>,
+    pub disc_number: Option<u32>,
+    pub date: Option<String>,
+    pub year: Option<i32>,
+    pub genres: Vec<String>,
+    pub isrc: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct MbArtistCredit {
+    name: String,
+    #[serde(default)]
+    joinphrase: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct MbTag {
+    name: String,
+    #[serde(default)]
+    count: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct MbReleaseGroup {
+    id: String,
+    title: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct MbReleaseSummary {
+    id: String,
+    status: Option<String>,
+    date: Option<String>,
+    #[serde(rename = "release-group")]
+    release_group: Option<MbReleaseGroup>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MbRecording {
+    title: Option<String>,
+    length: Option<u64>,
+    #[serde(rename = "first-release-date")]
+    first_release_date: Option<String>,
+    #[serde(rename = "artist-credit", default)]
+    artist_credit: Vec<MbArtistCredit>,
+    #[serde(default)]
+    releases: Vec<MbReleaseSummary>,
+    #[serde(default)]
+    isrcs: Vec<String>,
+    #[serde(default)]
+    genres: Vec<MbTag>,
+    #[serde(default)]
+    tags: Vec<MbTag>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MbTrackRecording {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct MbTrack {
+    position: Option<u32>,
+    recording: Option<MbTrackRecording>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MbMedium {
+    position: Option<u32>,
+    #[serde(rename = "track-count")]
+    track_count: Option<u32>,
+    #[serde(default)]
+    tracks: Vec<MbTrack>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MbRelease {
+    title: Option<String>,
+    date: Option<String>,
+    #[serde(rename = "artist-credit", default)]
+    artist_credit: Vec<MbArtistCredit>,
+    #[serde(rename = "release-group")]
+    release_group: Option<MbReleaseGroup>,
+    #[serde(default)]
+    media: Vec<MbMedium>,
+}
+
+fn endpoints() -> (String, String) {
+    with_store(|store| {
+        (
+            store.musicbrainz_base_url.clone(),
+            store.coverart_base_url.clone(),
+        )
+    })
+    .unwrap_or((
+        DEFAULT_MUSICBRAINZ_BASE_URL.to_string(),
+        DEFAULT_COVERART_BASE_URL.to_string(),
+    ))
+}
+
+fn get_json<T: DeserializeOwned>(url: &str) -> anyhow::Result<T> {
+    MUSICBRAINZ_LIMITER.wait();
+    let response = MUSICBRAINZ_CLIENT
+        .get(url)
+        .header(reqwest::header::ACCEPT, "application/json")
+        .send()
+        .context("Failed to send request to MusicBrainz")?;
+
+    if response.status().is_client_error() {
+        bail!(
+            "MusicBrainz responded with a client error {}",
+            response.status()
+        )
+    } else if response.status().is_server_error() {
+        bail!("MusicBrainz responded with a server error, try again later")
+    }
+
+    response
+        .json()
+        .context("Failed to parse MusicBrainz response")
+}
+
+fn join_artist_credit(credits: &[MbArtistCredit]) -> Option<String> {
+    if credits.is_empty() {
+        return None;
+    }
+    Some(
+        credits
+            .iter()
+            .map(|c| format!("{}{}", c.name, c.joinphrase))
+            .collect::<String>()
+            .trim()
+            .to_string(),
+    )
+}
+
+// MusicBrainz genres are all lowercase, tags are used when nobody has voted on a genre yet
+fn collect_genres(recording: &MbRecording) -> Vec<String> {
+    let mut tags = if recording.genres.is_empty() {
+        recording.tags.clone()
+    } else {
+        recording.genres.clone()
+    };
+    tags.sort_by_key(|t| std::cmp::Reverse(t.count));
+    tags.into_iter()
+        .map(|t| {
+            t.name
+                .split(' ')
+                .map(|w| {
+                    let mut chars = w.chars();
+                    match chars.next() {
+                        Some(first) => first.to_uppercase().chain(chars).collect(),
+                        None => String::new(),
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join(" ")
+        })
+        .collect()
+}
+
+// Prefers the release whose group AcoustID matched, then official releases, then the oldest one
+fn pick_release<'a>(
+    releases: &'a [MbReleaseSummary],
+    album_hint: &str,
+) -> Option<&'a MbReleaseSummary> {
+    releases.iter().min_by_key(|r| {
+        let group_matches = r
+            .release_group
+            .as_ref()
+            .and_then(|g| g.title.as_deref())
+            .is_some_and(|t| t.eq_ignore_ascii_case(album_hint));
+        let official = r.status.as_deref() == Some("Official");
+        (
+            !group_matches,
+            !official,
+            r.date
+                .clone()
+                .filter(|d| !d.is_empty())
+                .unwrap_or("9999".to_string()),
+        )
+    })
+}
+
+fn fetch_cover_art(
+    base_url: &str,
+    release_id: &str,
+    release_group_id: Option<&str>,
+) -> Option<Vec<u8>> {
+    let mut urls = vec![format!("{base_url}/release/{release_id}/front-500")];
+    if let Some(rg) = release_group_id {
+        urls.push(format!("{base_url}/release-group/{rg}/front-500"));
+    }
+    for url in urls {
+        let Ok(response) = MUSICBRAINZ_CLIENT.get(&url).send() else {
+            continue;
+        };
+        if !response.status().is_success() {
+            continue;
+        }
+        if let Ok(bytes) = response.bytes()
+            && !bytes.is_empty()
+        {
+            return Some(bytes.to_vec());
+        }
+    }
+    None
+}
+
+fn enrich_rs(candidate: AcoustIdCandidate) -> anyhow::Result<EnrichedMetadata> {
+    let recording_id = candidate
+        .recording_id
+        .clone()
+        .context("The candidate has no MusicBrainz recording id")?;
+    let (mb_url, caa_url) = endpoints();
+
+    let recording: MbRecording = get_json(&format!(
+        "{mb_url}/recording/{recording_id}?inc=artist-credits+releases+release-groups+isrcs+genres+tags&fmt=json"
+    ))?;
+
+    let mut metadata = candidate.metadata;
+    if let Some(title) = &recording.title {
+        metadata.title = title.clone();
+    }
+    if let Some(artist) = join_artist_credit(&recording.artist_credit) {
+        metadata.artist = artist;
+    }
+    if let Some(length) = recording.length {
+        metadata.duration = length / 1000;
+    }
+
+    let genres = collect_genres(&recording);
+    if let Some(genre) = genres.first() {
+        metadata.genre = genre.clone();
+    }
+
+    let mut enriched = EnrichedMetadata {
+        metadata,
+        recording_id: Some(recording_id.clone()),
+        release_id: None,
+        album_artist: None,
+        track_number: None,
+        total_tracks: None,
+        disc_number: None,
+        date: recording.first_release_date.clone(),
+        year: None,
+        genres,
+        isrc: recording.isrcs.first().cloned(),
+    };
+
+    if let Some(summary) = pick_release(&recording.releases, &enriched.metadata.album) {
+        let release: MbRelease = get_json(&format!(
+            "{mb_url}/release/{}?inc=artist-credits+recordings+release-groups&fmt=json",
+            summary.id
+        ))?;
+
+        if let Some(title) = &release.title {
+            enriched.metadata.album = title.clone();
+        }
+        enriched.album_artist = join_artist_credit(&release.artist_credit);
+        if release.date.as_ref().is_some_and(|d| !d.is_empty()) {
+            enriched.date = release.date.clone();
+        }
+        for medium in &release.media {
+            if let Some(track) = medium
+                .tracks
+                .iter()
+                .find(|t| t.recording.as_ref().is_some_and(|r| r.id == recording_id))
+            {
+                enriched.track_number = track.position;
+                enriched.total_tracks = medium.track_count;
+                enriched.disc_number = medium.position;
+                break;
+            }
+        }
+
+        let release_group_id = release
+            .release_group
+            .as_ref()
+            .or(summary.release_group.as_ref())
+            .map(|g| g.id.clone());
+        enriched.metadata.album_art =
+            fetch_cover_art(&caa_url, &summary.id, release_group_id.as_deref());
+        enriched.release_id = Some(summary.id.clone());
+    }
+
+    // Dates come back as YYYY, YYYY-MM or YYYY-MM-DD
+    enriched.year = enriched
+        .date
+        .as_deref()
+        .and_then(|d| d.get(..4))
+        .and_then(|y| y.parse().ok());
+
+    Ok(enriched)
+}
+
+// Fills in album artist, track/disc numbers, date, genres, ISRC and cover art for an AcoustID
+// candidate using its recording id
+pub fn enrich_candidate(candidate: AcoustIdCandidate) -> Result<EnrichedMetadata, String> {
+    enrich_rs(candidate).map_err(|e| format!("{e:#}"))
+}
+
+// Identifies a file and enriches the best match in one go
+pub fn lookup_enriched(path: String) -> Result<Option<EnrichedMetadata>, String> {
+    let Some(candidate) = lookup_candidates(path, 1)?.into_iter().next() else {
+        return Ok(None);
+    };
+    enrich_candidate(candidate).map(Some)
+}
@@ -0,0 +1,668 @@
+use crate::api::{
+    music_handler::SongMetadata, playlist_manager::rename_link_entry, utils::get_data_dir,
+    value_store::with_store,
+};
+use audiotags::Tag;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    fs, io,
+    os::unix::fs::symlink,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+// Most filesystems cap a single name at 255 bytes
+const MAX_COMPONENT_BYTES: usize = 255;
+// Room left in file names for a " (99)" collision suffix
+const COLLISION_SUFFIX_RESERVE: usize = 8;
+const MAX_JOURNAL_ENTRIES: usize = 20;
+const TEMPLATE_FIELDS: [&str; 12] = [
+    "title",
+    "artist",
+    "album",
+    "album_artist",
+    "genre",
+    "year",
+    "track",
+    "total_tracks",
+    "disc",
+    "total_discs",
+    "ext",
+    "filename",
+];
+
+// Organizing and rolling back both move files and rewrite the journal so they must not overlap
+static ORGANIZE_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone)]
+pub struct PlannedMove {
+    pub from: String,
+    pub to: String,
+    // The template gave a path that was already taken so a " (n)" suffix was added
+    pub renamed_for_collision: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct OrganizePlan {
+    pub moves: Vec<PlannedMove>,
+    // Files that are already where the template puts them
+    pub unchanged: u32,
+    // Files that could not be planned and why
+    pub skipped: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OrganizeResult {
+    pub plan: OrganizePlan,
+    pub links_updated: u32,
+    // None for dry runs, otherwise the journal entry rollback_last_organize will undo
+    pub journal_id: Option<i64>,
+}
+
+enum Segment {
+    Literal(String),
+    Field { name: String, width: usize },
+}
+
+enum FieldValue {
+    Text(String),
+    Number(u32),
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct LinkChange {
+    old_link: String,
+    new_link: String,
+    old_target: String,
+    new_target: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct OrganizeEntry {
+    id: i64,
+    moves: Vec<(String, String)>,
+    links: Vec<LinkChange>,
+}
+
+fn parse_template(template: &str) -> Result<Vec<Segment>, String> {
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut inner = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => inner.push(c),
+                        None => return Err(format!("Unclosed {{ in template {template}")),
+                    }
+                }
+                let (name, spec) = inner.split_once(':').unwrap_or((&inner, ""));
+                let name = name.trim();
+                if !TEMPLATE_FIELDS.contains(&name) {
+                    return Err(format!(
+                        "Unknown template field {{{name}}}, expected one of {}",
+                        TEMPLATE_FIELDS.join(", ")
+                    ));
+                }
+                let width = if spec.is_empty() {
+                    0
+                } else {
+                    spec.parse::<usize>()
+                        .map_err(|_| format!("Invalid width {spec} for {{{name}}}"))?
+                };
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                }
+                segments.push(Segment::Field {
+                    name: name.to_string(),
+                    width,
+                });
+            }
+            '}' => return Err(format!("Unmatched }} in template {template}")),
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+    if template.starts_with('/') {
+        return Err("The template must be relative to the destination folder".to_string());
+    }
+    Ok(segments)
+}
+
+// Reads what the template can use from the file's tags, with the same fallbacks SongMetadata uses
+fn track_fields(path: &Path) -> HashMap<&'static str, FieldValue> {
+    let tag = Tag::new().read_from_path(path).ok();
+    let defaults = SongMetadata::default();
+    let text = |value: Option<&str>, fallback: &str| {
+        FieldValue::Text(
+            value
+                .map(|v| v.replace('\0', " ").trim().to_string())
+                .filter(|v| !v.is_empty())
+                .unwrap_or_else(|| fallback.to_string()),
+        )
+    };
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let ext = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let t = tag.as_deref();
+
+    let artist = t.and_then(|t| t.artist());
+    let mut fields = HashMap::new();
+    fields.insert("title", text(t.and_then(|t| t.title()), &stem));
+    fields.insert("artist", text(artist, &defaults.artist));
+    fields.insert(
+        "album",
+        text(t.and_then(|t| t.album_title()), &defaults.album),
+    );
+    fields.insert(
+        "album_artist",
+        text(
+            t.and_then(|t| t.album_artist()).or(artist),
+            &defaults.artist,
+        ),
+    );
+    fields.insert("genre", text(t.and_then(|t| t.genre()), &defaults.genre));
+    fields.insert(
+        "year",
+        match t.and_then(|t| t.year()) {
+            Some(y) if y > 0 => FieldValue::Number(y as u32),
+            _ => FieldValue::Text("Unknown Year".to_string()),
+        },
+    );
+    let number = |n: Option<u16>, fallback: u32| {
+        FieldValue::Number(n.map(u32::from).filter(|n| *n > 0).unwrap_or(fallback))
+    };
+    fields.insert("track", number(t.and_then(|t| t.track_number()), 0));
+    fields.insert("total_tracks", number(t.and_then(|t| t.total_tracks()), 0));
+    fields.insert("disc", number(t.and_then(|t| t.disc_number()), 1));
+    fields.insert("total_discs", number(t.and_then(|t| t.total_discs()), 1));
+    fields.insert("ext", FieldValue::Text(ext));
+    fields.insert("filename", FieldValue::Text(stem));
+    fields
+}
+
+fn truncate_bytes(s: &str, max: usize) -> &str {
+    if s.len() <= max {
+        return s;
+    }
+    let mut end = max;
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    &s[..end]
+}
+
+// Replaces characters that are illegal (or just trouble) on common filesystems and stops a
+// component from being empty, hidden, or a . / .. that would escape the destination
+#[flutter_rust_bridge::frb(ignore)]
+pub fn sanitize_component(component: &str) -> String {
+    let cleaned: String = component
+        .chars()
+        .map(|c| {
+            if c.is_control() || matches!(c, '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|') {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect();
+    let cleaned = cleaned
+        .trim()
+        .trim_start_matches('.')
+        .trim_end_matches('.')
+        .trim();
+    if cleaned.is_empty() {
+        "_".to_string()
+    } else {
+        truncate_bytes(cleaned, MAX_COMPONENT_BYTES).to_string()
+    }
+}
+
+fn render(segments: &[Segment], fields: &HashMap<&'static str, FieldValue>) -> PathBuf {
+    let mut rendered = String::new();
+    for segment in segments {
+        match segment {
+            Segment::Literal(text) => rendered.push_str(text),
+            Segment::Field { name, width } => match fields.get(name.as_str()) {
+                // Values can not be allowed to add folders of their own
+                Some(FieldValue::Text(text)) => rendered.push_str(&text.replace('/', "_")),
+                Some(FieldValue::Number(n)) => rendered.push_str(&format!("{n:0width$}")),
+                None => {}
+            },
+        }
+    }
+
+    let ext = match fields.get("ext") {
+        Some(FieldValue::Text(ext)) => ext.clone(),
+        _ => String::new(),
+    };
+    let mut components: Vec<String> = rendered
+        .split('/')
+        .filter(|c| !c.trim().is_empty())
+        .map(sanitize_component)
+        .collect();
+    if components.is_empty() {
+        components.push("_".to_string());
+    }
+
+    // The file always keeps its real extension even if the template forgot it
+    let last = components.pop().unwrap_or_default();
+    let suffix = format!(".{ext}");
+    let split = last.len().saturating_sub(suffix.len());
+    let stem = if !ext.is_empty()
+        && last.is_char_boundary(split)
+        && last[split..].eq_ignore_ascii_case(&suffix)
+    {
+        last[..split].to_string()
+    } else {
+        last
+    };
+    let stem = truncate_bytes(
+        &stem,
+        MAX_COMPONENT_BYTES.saturating_sub(suffix.len() + COLLISION_SUFFIX_RESERVE),
+    )
+    .to_string();
+    components.push(if ext.is_empty() {
+        stem
+    } else {
+        format!("{stem}{suffix}")
+    });
+
+    components.iter().collect()
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+pub fn with_collision_suffix(path: &Path, n: u32) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let name = match path.extension() {
+        Some(ext) => format!("{stem} ({n}).{}", ext.to_string_lossy()),
+        None => format!("{stem} ({n})"),
+    };
+    path.with_file_name(name)
+}
+
+fn music_folder() -> Option<String> {
+    with_store(|store| store.music_folder.clone())
+}
+
+fn build_plan(
+    paths: Vec<String>,
+    template: &str,
+    dest_root: Option<String>,
+) -> Result<OrganizePlan, String> {
+    let segments = parse_template(template)?;
+    let root = dest_root
+        .or_else(music_folder)
+        .ok_or("No destination folder given and the music folder is not set")?;
+    let root = fs::canonicalize(&root)
+        .ok()
+        .filter(|r| r.is_dir())
+        .ok_or(format!("{root} is not a folder"))?;
+
+    let mut plan = OrganizePlan {
+        moves: Vec::new(),
+        unchanged: 0,
+        skipped: Vec::new(),
+    };
+    let mut taken: HashSet<PathBuf> = HashSet::new();
+    let mut seen: HashSet<PathBuf> = HashSet::new();
+
+    for path in paths {
+        let from = match fs::canonicalize(&path) {
+            Ok(p) if p.is_file() => p,
+            _ => {
+                plan.skipped
+                    .push(format!("{path}: does not exist or is not a file"));
+                continue;
+            }
+        };
+        if !seen.insert(from.clone()) {
+            continue;
+        }
+
+        let target = root.join(render(&segments, &track_fields(&from)));
+        if target == from {
+            plan.unchanged += 1;
+            taken.insert(target);
+            continue;
+        }
+
+        let mut to = target.clone();
+        let mut n = 1;
+        while taken.contains(&to) || (to.exists() && to != from) {
+            n += 1;
+            to = with_collision_suffix(&target, n);
+        }
+        taken.insert(to.clone());
+        if to == from {
+            plan.unchanged += 1;
+            continue;
+        }
+        plan.moves.push(PlannedMove {
+            from: from.to_string_lossy().to_string(),
+            renamed_for_collision: to != target,
+            to: to.to_string_lossy().to_string(),
+        });
+    }
+
+    Ok(plan)
+}
+
+// rename is atomic but can not cross filesystems (yt-dlp leaves files in /tmp), in that case the
+// file is copied next to its destination and renamed into place so the target is never half there
+#[flutter_rust_bridge::frb(ignore)]
+pub fn move_file(from: &Path, to: &Path) -> io::Result<()> {
+    if to.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", to.display()),
+        ));
+    }
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    match fs::rename(from, to) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == io::ErrorKind::CrossesDevices => {
+            let name = to
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let part = to.with_file_name(format!(".{name}.part"));
+            let copied = fs::copy(from, &part)
+                .and_then(|_| fs::File::open(&part)?.sync_all())
+                .and_then(|_| fs::rename(&part, to));
+            if let Err(e) = copied {
+                let _ = fs::remove_file(&part);
+                return Err(e);
+            }
+            fs::remove_file(from)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+// Removes folders left empty by a move, stopping at (and never removing) the music folder
+fn prune_empty_dirs(start: Option<&Path>, stop: &Path) {
+    if stop.as_os_str().is_empty() {
+        return;
+    }
+    let mut dir = start;
+    while let Some(d) = dir {
+        if d == stop || !d.starts_with(stop) || fs::remove_dir(d).is_err() {
+            break;
+        }
+        dir = d.parent();
+    }
+}
+
+// Canonical like the moved paths it is compared with. The file itself has usually been moved away
+// by now, so only the folder it was in is canonicalized
+fn resolve_link(link: &Path) -> Option<PathBuf> {
+    let target = fs::read_link(link).ok()?;
+    let target = if target.is_absolute() {
+        target
+    } else {
+        link.parent()?.join(target)
+    };
+    let canonical = target
+        .parent()
+        .and_then(|dir| fs::canonicalize(dir).ok())
+        .zip(target.file_name())
+        .map(|(dir, name)| dir.join(name));
+    Some(canonical.unwrap_or(target))
+}
+
+// Playlist links are named after the song's file so they get renamed along with it
+fn update_playlist_links(moved: &HashMap<PathBuf, PathBuf>) -> Vec<LinkChange> {
+    let Some(mf) = music_folder() else {
+        return Vec::new();
+    };
+    let Ok(playlists) = fs::read_dir(PathBuf::from(mf).join(".adilists")) else {
+        return Vec::new();
+    };
+
+    let mut changes = Vec::new();
+    for playlist in playlists.filter_map(|e| e.ok()).map(|e| e.path()) {
+        let Ok(entries) = fs::read_dir(&playlist) else {
+            continue;
+        };
+        for link in entries.filter_map(|e| e.ok()).map(|e| e.path()) {
+            if !link.is_symlink() {
+                continue;
+            }
+            let Some(old_target) = resolve_link(&link) else {
+                continue;
+            };
+            let Some(new_target) = moved.get(&old_target) else {
+                continue;
+            };
+            let Some(name) = new_target.file_name() else {
+                continue;
+            };
+
+            let wanted = playlist.join(name);
+            let mut new_link = wanted.clone();
+            let mut n = 1;
+            while new_link != link && new_link.symlink_metadata().is_ok() {
+                n += 1;
+                new_link = with_collision_suffix(&wanted, n);
+            }
+            if fs::remove_file(&link).is_err() {
+                continue;
+            }
+            if let Err(e) = symlink(new_target, &new_link) {
+                eprintln!("Failed to relink {}: {e}", link.display());
+                // Put the old (now dangling) link back rather than silently dropping the song
+                let _ = symlink(&old_target, &link);
+                continue;
+            }
+            if let (Some(old_name), Some(new_name)) = (link.file_name(), new_link.file_name()) {
+                rename_link_entry(
+                    &playlist,
+                    &old_name.to_string_lossy(),
+                    &new_name.to_string_lossy(),
+                );
+            }
+            changes.push(LinkChange {
+                old_link: link.to_string_lossy().to_string(),
+                new_link: new_link.to_string_lossy().to_string(),
+                old_target: old_target.to_string_lossy().to_string(),
+                new_target: new_target.to_string_lossy().to_string(),
+            });
+        }
+    }
+    changes
+}
+
+fn journal_path() -> PathBuf {
+    get_data_dir("organizer").join("journal.json")
+}
+
+fn load_journal() -> Vec<OrganizeEntry> {
+    fs::read_to_string(journal_path())
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_journal(journal: &[OrganizeEntry]) -> Result<(), String> {
+    let path = journal_path();
+    let tmp = path.with_extension("json.tmp");
+    let json = serde_json::to_string(journal)
+        .map_err(|e| format!("Failed to serialise the organizer journal: {e}"))?;
+    fs::write(&tmp, json).map_err(|e| format!("Failed to write the organizer journal: {e}"))?;
+    fs::rename(&tmp, &path).map_err(|e| format!("Failed to write the organizer journal: {e}"))
+}
+
+// Safe to run on a partly applied entry, anything that is not where the entry left it is skipped
+fn revert_entry(entry: &OrganizeEntry) -> Vec<String> {
+    let mut errors = Vec::new();
+    let stop = music_folder()
+        .and_then(|mf| fs::canonicalize(mf).ok())
+        .unwrap_or_default();
+
+    for change in entry.links.iter().rev() {
+        let new_link = Path::new(&change.new_link);
+        if resolve_link(new_link).as_deref() == Some(Path::new(&change.new_target)) {
+            let _ = fs::remove_file(new_link);
+        }
+        let old_link = Path::new(&change.old_link);
+        if old_link.symlink_metadata().is_err() {
+            if let Err(e) = symlink(&change.old_target, old_link) {
+                errors.push(format!("{}: {e}", change.old_link));
+            } else if let (Some(dir), Some(old_name), Some(new_name)) = (
+                old_link.parent(),
+                old_link.file_name(),
+                new_link.file_name(),
+            ) {
+                rename_link_entry(
+                    dir,
+                    &new_name.to_string_lossy(),
+                    &old_name.to_string_lossy(),
+                );
+            }
+        }
+    }
+
+    for (from, to) in entry.moves.iter().rev() {
+        let (from, to) = (Path::new(from), Path::new(to));
+        if !to.exists() || from.exists() {
+            continue;
+        }
+        match move_file(to, from) {
+            Ok(()) => prune_empty_dirs(to.parent(), &stop),
+            Err(e) => errors.push(format!("{}: {e}", to.display())),
+        }
+    }
+    errors
+}
+
+// Moves (and renames) tracks to where template says they belong under dest_root (the music
+// folder by default), e.g. "{album_artist}/{year} - {album}/{disc}-{track:02} {title}.{ext}".
+// Fields are title, artist, album, album_artist, genre, year, track, total_tracks, disc,
+// total_discs, ext and filename, numbers take a zero padded width after a colon. With dry_run
+// nothing is touched and only the plan is returned. If any move fails every earlier move is undone
+pub fn organize_files(
+    paths: Vec<String>,
+    template: String,
+    dest_root: Option<String>,
+    dry_run: bool,
+) -> Result<OrganizeResult, String> {
+    let _guard = ORGANIZE_LOCK.lock().unwrap();
+    let plan = build_plan(paths, &template, dest_root)?;
+    if dry_run || plan.moves.is_empty() {
+        return Ok(OrganizeResult {
+            plan,
+            links_updated: 0,
+            journal_id: None,
+        });
+    }
+
+    let mut entry = OrganizeEntry {
+        id: chrono::Utc::now().timestamp_millis(),
+        moves: plan
+            .moves
+            .iter()
+            .map(|m| (m.from.clone(), m.to.clone()))
+            .collect(),
+        links: Vec::new(),
+    };
+    // Journaled before anything moves so a crash half way through can still be rolled back
+    let mut journal = load_journal();
+    journal.push(entry.clone());
+    if journal.len() > MAX_JOURNAL_ENTRIES {
+        journal.drain(..journal.len() - MAX_JOURNAL_ENTRIES);
+    }
+    save_journal(&journal)?;
+
+    let stop = music_folder()
+        .and_then(|mf| fs::canonicalize(mf).ok())
+        .unwrap_or_default();
+    let mut moved = HashMap::new();
+    for planned in &plan.moves {
+        let (from, to) = (Path::new(&planned.from), Path::new(&planned.to));
+        if let Err(e) = move_file(from, to) {
+            let rollback_errors = revert_entry(&entry);
+            if rollback_errors.is_empty() {
+                journal.pop();
+                let _ = save_journal(&journal);
+                return Err(format!(
+                    "Failed to move {}: {e}. Every file was moved back",
+                    planned.from
+                ));
+            }
+            return Err(format!(
+                "Failed to move {}: {e}. Some files could not be moved back, use rollback to retry:\n{}",
+                planned.from,
+                rollback_errors.join("\n")
+            ));
+        }
+        prune_empty_dirs(from.parent(), &stop);
+        moved.insert(from.to_path_buf(), to.to_path_buf());
+    }
+
+    entry.links = update_playlist_links(&moved);
+    let links_updated = entry.links.len() as u32;
+    let journal_id = entry.id;
+    if let Some(last) = journal.last_mut() {
+        *last = entry;
+    }
+    save_journal(&journal)?;
+
+    Ok(OrganizeResult {
+        plan,
+        links_updated,
+        journal_id: Some(journal_id),
+    })
+}
+
+// Moves the files from the most recent organize_files call back and restores their playlist links,
+// returns how many files were moved back
+pub fn rollback_last_organize() -> Result<u32, String> {
+    let _guard = ORGANIZE_LOCK.lock().unwrap();
+    let mut journal = load_journal();
+    let Some(entry) = journal.pop() else {
+        return Err("There is nothing to roll back".to_string());
+    };
+
+    let restorable = entry
+        .moves
+        .iter()
+        .filter(|(from, to)| Path::new(to).exists() && !Path::new(from).exists())
+        .count() as u32;
+    let errors = revert_entry(&entry);
+    if !errors.is_empty() {
+        // Kept so the rollback can be retried once whatever blocked it is fixed
+        journal.push(entry);
+        save_journal(&journal)?;
+        return Err(format!(
+            "Some files could not be moved back:\n{}",
+            errors.join("\n")
+        ));
+    }
+    save_journal(&journal)?;
+    Ok(restorable)
+}
@@ -0,0 +1,118 @@
+use crate::api::{
+    cue_sheet::{split_virtual_track, virtual_track_path},
+    utils::get_data_dir,
+};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs,
+    path::PathBuf,
+    sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
+    thread,
+    time::Duration,
+};
+
+// Plays are written out together this long after the first one that is not saved yet
+const SAVE_DELAY: Duration = Duration::from_secs(30);
+
+static SAVE_PENDING: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PlayStat {
+    pub play_count: u32,
+    // Unix timestamp in seconds, 0 if never played
+    pub last_played: i64,
+}
+
+// Loaded from disk the first time anything asks for it
+static PLAY_STATS: Lazy<Mutex<HashMap<String, PlayStat>>> = Lazy::new(|| {
+    Mutex::new(
+        fs::read_to_string(stats_path())
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default(),
+    )
+});
+
+fn stats_path() -> PathBuf {
+    get_data_dir("stats").join("play_counts.json")
+}
+
+fn save(stats: &HashMap<String, PlayStat>) {
+    let path = stats_path();
+    let tmp = path.with_extension("json.tmp");
+    if let Ok(json) = serde_json::to_string(stats)
+        && fs::write(&tmp, json).is_ok()
+    {
+        let _ = fs::rename(&tmp, &path);
+    }
+}
+
+// Stats are kept under the real path of a song so plays from a playlist, which links to the
+// file, count for the song itself
+#[flutter_rust_bridge::frb(ignore)]
+pub fn stat_key(path: &str) -> String {
+    let (file, track) = match split_virtual_track(path) {
+        Some((file, number)) => (file, Some(number)),
+        None => (path, None),
+    };
+    let file = fs::canonicalize(file)
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|_| file.to_string());
+    match track {
+        Some(number) => virtual_track_path(&file, number),
+        None => file,
+    }
+}
+
+fn schedule_save() {
+    if SAVE_PENDING.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    thread::spawn(|| {
+        thread::sleep(SAVE_DELAY);
+        flush_play_stats();
+    });
+}
+
+// Writes plays still waiting for the delayed save, the app calls this before it exits
+pub fn flush_play_stats() {
+    if SAVE_PENDING.swap(false, Ordering::SeqCst) {
+        save(&PLAY_STATS.lock().unwrap());
+    }
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+pub fn record_play(path: &str) {
+    let key = stat_key(path);
+    let mut stats = PLAY_STATS.lock().unwrap();
+    let stat = stats.entry(key).or_default();
+    stat.play_count += 1;
+    stat.last_played = chrono::Utc::now().timestamp();
+    schedule_save();
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+pub fn play_stats_snapshot() -> HashMap<String, PlayStat> {
+    PLAY_STATS.lock().unwrap().clone()
+}
+
+pub fn get_play_stat(path: String) -> PlayStat {
+    PLAY_STATS
+        .lock()
+        .unwrap()
+        .get(&stat_key(&path))
+        .cloned()
+        .unwrap_or_default()
+}
+
+pub fn reset_play_stats() -> bool {
+    let mut stats = PLAY_STATS.lock().unwrap();
+    stats.clear();
+    save(&stats);
+    true
+}
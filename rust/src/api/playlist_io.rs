@@ -0,0 +1,592 @@
+use crate::api::{
+    music_handler::{SongMetadata, scan_music_directory},
+    playlist_manager::{add_links, linked_songs},
+    smart_playlist::{is_smart_playlist, music_folder, playlist_dir},
+    utils::fpre,
+    xml::{parse_xml, xml_escape},
+};
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+// Other players round durations differently so a few seconds either way is still the same song
+const DURATION_TOLERANCE_SECS: u64 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaylistFormat {
+    M3u,
+    M3u8,
+    Pls,
+    Xspf,
+}
+
+// A playlist entry that was found in the library by its tags or file name instead of its path
+#[derive(Debug, Clone)]
+pub struct MatchedEntry {
+    pub location: String,
+    pub path: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct MissingEntry {
+    pub location: String,
+    pub title: Option<String>,
+    pub artist: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PlaylistImportResult {
+    pub playlist: String,
+    pub total_entries: u32,
+    // Entries whose path pointed straight at a file
+    pub linked: u32,
+    pub matched: Vec<MatchedEntry>,
+    // Entries that were already in the playlist
+    pub duplicates: u32,
+    pub missing: Vec<MissingEntry>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PlaylistExportResult {
+    pub written: u32,
+    // Links in the playlist whose file no longer exists
+    pub missing: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+struct Entry {
+    location: String,
+    title: Option<String>,
+    artist: Option<String>,
+    duration: Option<u64>,
+}
+
+impl PlaylistFormat {
+    fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_string_lossy().to_lowercase();
+        match ext.as_str() {
+            "m3u" => Some(Self::M3u),
+            "m3u8" => Some(Self::M3u8),
+            "pls" => Some(Self::Pls),
+            "xspf" => Some(Self::Xspf),
+            _ => None,
+        }
+    }
+}
+
+// .m3u files from older players are often Latin-1 rather than UTF-8
+fn decode(bytes: Vec<u8>) -> String {
+    let text = match String::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(e) => e.into_bytes().iter().map(|&b| b as char).collect(),
+    };
+    text.trim_start_matches('\u{feff}').to_string()
+}
+
+// "Artist - Title" is how nearly every player writes EXTINF and PLS titles
+fn split_display_title(display: &str) -> (Option<String>, Option<String>) {
+    let display = display.trim();
+    if display.is_empty() {
+        return (None, None);
+    }
+    match display.split_once(" - ") {
+        Some((artist, title)) if !artist.trim().is_empty() && !title.trim().is_empty() => (
+            Some(artist.trim().to_string()),
+            Some(title.trim().to_string()),
+        ),
+        _ => (None, Some(display.to_string())),
+    }
+}
+
+// Negative lengths mean unknown in both EXTINF and PLS
+fn parse_seconds(s: &str) -> Option<u64> {
+    let secs = s.trim().parse::<f64>().ok()?;
+    (secs > 0.0).then_some(secs.round() as u64)
+}
+
+fn parse_m3u(text: &str) -> Vec<Entry> {
+    let mut entries = Vec::new();
+    let mut pending = Entry::default();
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(info) = line.strip_prefix("#EXTINF:") {
+            let (length, display) = info.split_once(',').unwrap_or((info, ""));
+            // Attributes like tvg-id="..." may follow the length
+            pending.duration = length.split_whitespace().next().and_then(parse_seconds);
+            (pending.artist, pending.title) = split_display_title(display);
+        } else if line.starts_with('#') {
+            continue;
+        } else {
+            pending.location = line.to_string();
+            entries.push(std::mem::take(&mut pending));
+        }
+    }
+    entries
+}
+
+fn parse_pls(text: &str) -> Vec<Entry> {
+    let mut by_index: HashMap<u32, Entry> = HashMap::new();
+    for line in text.lines().map(str::trim) {
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim().to_lowercase();
+        let (field, index) = key.split_at(key.trim_end_matches(|c: char| c.is_ascii_digit()).len());
+        let Ok(index) = index.parse::<u32>() else {
+            continue;
+        };
+        let entry = by_index.entry(index).or_default();
+        match field {
+            "file" => entry.location = value.trim().to_string(),
+            "title" => (entry.artist, entry.title) = split_display_title(value),
+            "length" => entry.duration = parse_seconds(value),
+            _ => {}
+        }
+    }
+    let mut indexed: Vec<(u32, Entry)> = by_index
+        .into_iter()
+        .filter(|(_, e)| !e.location.is_empty())
+        .collect();
+    indexed.sort_by_key(|(i, _)| *i);
+    indexed.into_iter().map(|(_, e)| e).collect()
+}
+
+fn parse_xspf(text: &str) -> Vec<Entry> {
+    let document = parse_xml(text);
+    let Some(tracks) = document
+        .child("playlist")
+        .and_then(|p| p.child("trackList"))
+    else {
+        return Vec::new();
+    };
+    tracks
+        .children_named("track")
+        .filter_map(|track| {
+            Some(Entry {
+                location: track.child_text("location")?,
+                title: track.child_text("title"),
+                artist: track.child_text("creator"),
+                // XSPF durations are in milliseconds
+                duration: track
+                    .child_text("duration")
+                    .and_then(|d| d.parse::<u64>().ok())
+                    .filter(|ms| *ms > 0)
+                    .map(|ms| (ms + 500) / 1000),
+            })
+        })
+        .collect()
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && let Some(byte) = s
+                .get(i + 1..i + 3)
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+        {
+            out.push(byte);
+            i += 3;
+            continue;
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).to_string()
+}
+
+fn percent_encode_path(path: &str) -> String {
+    let mut out = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || b"/-_.~".contains(&byte) {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    out
+}
+
+// Turns an entry into a local path, None for streams and other things that are not files
+fn entry_path(location: &str, base: &Path) -> Option<PathBuf> {
+    let location = location.trim();
+    if let Some(uri) = location.strip_prefix("file://") {
+        // file://localhost/music/a.mp3 and file:///music/a.mp3 are the same file
+        let uri = uri.strip_prefix("localhost").unwrap_or(uri);
+        return Some(PathBuf::from(percent_decode(uri)));
+    }
+    if location.contains("://") {
+        return None;
+    }
+    // Playlists written on Windows use backslashes and may carry a drive letter we cannot use
+    let location = location.replace('\\', "/");
+    let path = Path::new(&location);
+    Some(if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        base.join(path)
+    })
+}
+
+fn normalize(s: &str) -> String {
+    s.to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+struct LibraryIndex {
+    songs: Vec<SongMetadata>,
+    by_file_name: HashMap<String, Vec<usize>>,
+    by_title: HashMap<String, Vec<usize>>,
+}
+
+impl LibraryIndex {
+    fn build(songs: Vec<SongMetadata>) -> Self {
+        let mut by_file_name: HashMap<String, Vec<usize>> = HashMap::new();
+        let mut by_title: HashMap<String, Vec<usize>> = HashMap::new();
+        for (i, song) in songs.iter().enumerate() {
+            if let Some(name) = Path::new(&song.path).file_name() {
+                by_file_name
+                    .entry(name.to_string_lossy().to_lowercase())
+                    .or_default()
+                    .push(i);
+            }
+            by_title.entry(normalize(&song.title)).or_default().push(i);
+        }
+        Self {
+            songs,
+            by_file_name,
+            by_title,
+        }
+    }
+
+    fn duration_fits(&self, i: usize, duration: Option<u64>) -> bool {
+        let known = self.songs[i].duration;
+        match duration {
+            Some(d) if known > 0 => known.abs_diff(d) <= DURATION_TOLERANCE_SECS,
+            _ => true,
+        }
+    }
+
+    // Closest duration wins when several songs fit, unknown durations rank last
+    fn best(
+        &self,
+        candidates: impl Iterator<Item = usize>,
+        duration: Option<u64>,
+    ) -> Option<usize> {
+        candidates
+            .filter(|&i| self.duration_fits(i, duration))
+            .min_by_key(|&i| match (duration, self.songs[i].duration) {
+                (Some(d), known) if known > 0 => known.abs_diff(d),
+                _ => u64::MAX,
+            })
+    }
+
+    fn find(&self, entry: &Entry) -> Option<&SongMetadata> {
+        // The same file name somewhere in the library is the most reliable hint
+        let file_name = entry
+            .location
+            .replace('\\', "/")
+            .rsplit('/')
+            .next()
+            .map(|n| percent_decode(n).to_lowercase());
+        if let Some(i) = file_name
+            .as_ref()
+            .and_then(|n| self.by_file_name.get(n))
+            .and_then(|c| self.best(c.iter().copied(), entry.duration))
+        {
+            return Some(&self.songs[i]);
+        }
+
+        // Without any title info, "Artist - Title.mp3" file names are the next best thing
+        let (artist, title) = match (&entry.artist, &entry.title) {
+            (artist, Some(title)) => (artist.clone(), title.clone()),
+            (_, None) => {
+                let stem = fpre(Path::new(file_name.as_deref()?))?
+                    .to_string_lossy()
+                    .to_string();
+                match split_display_title(&stem) {
+                    (artist, Some(title)) => (artist, title),
+                    _ => return None,
+                }
+            }
+        };
+        let candidates = self.by_title.get(&normalize(&title))?;
+        let artist = artist.map(|a| normalize(&a)).filter(|a| !a.is_empty());
+        let i = self.best(
+            candidates.iter().copied().filter(|&i| match &artist {
+                // Library artists are joined as "A, B" so either side may hold more names
+                Some(a) => {
+                    let known = normalize(&self.songs[i].artist);
+                    known.contains(a.as_str()) || a.contains(known.as_str())
+                }
+                None => true,
+            }),
+            entry.duration,
+        )?;
+        Some(&self.songs[i])
+    }
+}
+
+fn link_target(link: &Path) -> Option<PathBuf> {
+    let target = fs::read_link(link).ok()?;
+    Some(if target.is_absolute() {
+        target
+    } else {
+        link.parent()?.join(target)
+    })
+}
+
+// Creates (or adds to) .adilists/<name> from an M3U/M3U8, PLS or XSPF file. Entries whose paths
+// do not exist here are looked up in the library by file name, then title, artist and duration
+pub fn import_playlist(file: String, name: Option<String>) -> Result<PlaylistImportResult, String> {
+    let file = PathBuf::from(file);
+    let format = PlaylistFormat::from_path(&file).ok_or(format!(
+        "{} is not an m3u, m3u8, pls or xspf file",
+        file.display()
+    ))?;
+    let name = match name {
+        Some(name) => name,
+        None => fpre(&file)
+            .map(|s| s.to_string_lossy().to_string())
+            .ok_or("The playlist file has no name")?,
+    };
+    if is_smart_playlist(name.clone()) {
+        return Err(format!(
+            "{name} is a smart playlist so songs cannot be added to it"
+        ));
+    }
+    let dir = playlist_dir(&name)?;
+
+    let text =
+        decode(fs::read(&file).map_err(|e| format!("Failed to read {}: {e}", file.display()))?);
+    let entries = match format {
+        PlaylistFormat::M3u | PlaylistFormat::M3u8 => parse_m3u(&text),
+        PlaylistFormat::Pls => parse_pls(&text),
+        PlaylistFormat::Xspf => parse_xspf(&text),
+    };
+    let base = file.parent().map(Path::to_path_buf).unwrap_or_default();
+    let mf = music_folder()?;
+
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create the playlist folder: {e}"))?;
+    let mut result = PlaylistImportResult {
+        playlist: name,
+        total_entries: entries.len() as u32,
+        linked: 0,
+        matched: Vec::new(),
+        duplicates: 0,
+        missing: Vec::new(),
+    };
+    // Only scanned the first time an entry cannot be found by its path
+    let mut library: Option<LibraryIndex> = None;
+    // Linked all at once at the end, every add_links call rewrites the whole order
+    let mut present = linked_songs(&dir);
+    let mut songs = Vec::new();
+
+    for entry in entries {
+        let direct = entry_path(&entry.location, &base)
+            .filter(|p| p.is_file())
+            .and_then(|p| fs::canonicalize(p).ok());
+        let (song, by_path) = match direct {
+            Some(path) => (Some(path), true),
+            None => {
+                let library = library.get_or_insert_with(|| {
+                    LibraryIndex::build(scan_music_directory(mf.clone(), false))
+                });
+                let found = library.find(&entry).map(|s| PathBuf::from(&s.path));
+                (found.map(|p| fs::canonicalize(&p).unwrap_or(p)), false)
+            }
+        };
+
+        let Some(song) = song else {
+            result.missing.push(MissingEntry {
+                location: entry.location,
+                title: entry.title,
+                artist: entry.artist,
+            });
+            continue;
+        };
+        if !present.insert(song.clone()) {
+            result.duplicates += 1;
+            continue;
+        }
+        if by_path {
+            result.linked += 1;
+        } else {
+            result.matched.push(MatchedEntry {
+                location: entry.location,
+                path: song.to_string_lossy().to_string(),
+            });
+        }
+        songs.push(song);
+    }
+    add_links(&dir, &songs, false)?;
+    Ok(result)
+}
+
+// Path of target as seen from the folder base, for playlists that move along with the music
+fn relative_path(target: &Path, base: &Path) -> PathBuf {
+    let target: Vec<Component> = target.components().collect();
+    let base: Vec<Component> = base.components().collect();
+    let common = target.iter().zip(&base).take_while(|(a, b)| a == b).count();
+    let mut relative = PathBuf::new();
+    for _ in common..base.len() {
+        relative.push("..");
+    }
+    for component in &target[common..] {
+        relative.push(component);
+    }
+    relative
+}
+
+fn display_title(song: &SongMetadata) -> String {
+    if song.artist.is_empty() || song.artist == "Unknown Artist" {
+        song.title.clone()
+    } else {
+        format!("{} - {}", song.artist, song.title)
+    }
+}
+
+fn render(format: PlaylistFormat, name: &str, songs: &[(SongMetadata, String)]) -> String {
+    let mut out = String::new();
+    match format {
+        PlaylistFormat::M3u | PlaylistFormat::M3u8 => {
+            out.push_str("#EXTM3U\n");
+            out.push_str(&format!("#PLAYLIST:{name}\n"));
+            for (song, location) in songs {
+                let length = if song.duration > 0 {
+                    song.duration as i64
+                } else {
+                    -1
+                };
+                out.push_str(&format!(
+                    "#EXTINF:{length},{}\n{location}\n",
+                    display_title(song)
+                ));
+            }
+        }
+        PlaylistFormat::Pls => {
+            out.push_str("[playlist]\n");
+            for (i, (song, location)) in songs.iter().enumerate() {
+                let n = i + 1;
+                let length = if song.duration > 0 {
+                    song.duration as i64
+                } else {
+                    -1
+                };
+                out.push_str(&format!(
+                    "File{n}={location}\nTitle{n}={}\nLength{n}={length}\n",
+                    display_title(song)
+                ));
+            }
+            out.push_str(&format!("NumberOfEntries={}\nVersion=2\n", songs.len()));
+        }
+        PlaylistFormat::Xspf => {
+            out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+            out.push_str("<playlist version=\"1\" xmlns=\"http://xspf.org/ns/0/\">\n");
+            out.push_str(&format!(
+                "  <title>{}</title>\n  <trackList>\n",
+                xml_escape(name)
+            ));
+            for (song, location) in songs {
+                out.push_str("    <track>\n");
+                out.push_str(&format!(
+                    "      <location>{}</location>\n",
+                    xml_escape(location)
+                ));
+                out.push_str(&format!(
+                    "      <title>{}</title>\n",
+                    xml_escape(&song.title)
+                ));
+                out.push_str(&format!(
+                    "      <creator>{}</creator>\n",
+                    xml_escape(&song.artist)
+                ));
+                out.push_str(&format!(
+                    "      <album>{}</album>\n",
+                    xml_escape(&song.album)
+                ));
+                if song.duration > 0 {
+                    out.push_str(&format!(
+                        "      <duration>{}</duration>\n",
+                        song.duration * 1000
+                    ));
+                }
+                out.push_str("    </track>\n");
+            }
+            out.push_str("  </trackList>\n</playlist>\n");
+        }
+    }
+    out
+}
+
+// Writes the playlist (smart ones included, as they are right now) for other players to use.
+// Relative paths are relative to the folder the playlist file is written to
+pub fn export_playlist(
+    name: String,
+    destination: String,
+    format: Option<PlaylistFormat>,
+    relative_paths: bool,
+) -> Result<PlaylistExportResult, String> {
+    let dir = playlist_dir(&name)?;
+    if !dir.is_dir() {
+        return Err(format!("There is no playlist called {name}"));
+    }
+    let destination = PathBuf::from(destination);
+    let format = format
+        .or_else(|| PlaylistFormat::from_path(&destination))
+        .ok_or("Could not tell the playlist format from the file extension")?;
+    let base = destination
+        .parent()
+        .map(|p| fs::canonicalize(p).unwrap_or_else(|_| p.to_path_buf()))
+        .unwrap_or_default();
+
+    // Links that point nowhere are skipped by the scan so they are collected here for the report
+    let missing: Vec<String> = fs::read_dir(&dir)
+        .map_err(|e| format!("Failed to read the playlist: {e}"))?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_symlink() && !p.exists())
+        .map(|p| link_target(&p).unwrap_or(p).to_string_lossy().to_string())
+        .collect();
+
+    let songs: Vec<(SongMetadata, String)> =
+        scan_music_directory(dir.to_string_lossy().to_string(), false)
+            .into_iter()
+            .map(|song| {
+                // Normal playlists give back the link, other players need the real file
+                let real =
+                    fs::canonicalize(&song.path).unwrap_or_else(|_| PathBuf::from(&song.path));
+                let path = if relative_paths {
+                    relative_path(&real, &base)
+                } else {
+                    real
+                };
+                let path = path.to_string_lossy().to_string();
+                let location = match format {
+                    PlaylistFormat::Xspf if relative_paths => percent_encode_path(&path),
+                    PlaylistFormat::Xspf => format!("file://{}", percent_encode_path(&path)),
+                    _ => path,
+                };
+                (song, location)
+            })
+            .collect();
+
+    fs::write(&destination, render(format, &name, &songs))
+        .map_err(|e| format!("Failed to write {}: {e}", destination.display()))?;
+
+    Ok(PlaylistExportResult {
+        written: songs.len() as u32,
+        missing,
+    })
+}
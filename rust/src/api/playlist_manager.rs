@@ -0,0 +1,581 @@
+use crate::api::{
+    music_handler::{SongMetadata, scan_music_directory},
+    smart_playlist::{load_smart_playlist, music_folder, playlist_dir},
+    utils::fpre,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    os::unix::fs::symlink,
+    path::{Path, PathBuf},
+    sync::{Mutex, Once},
+};
+
+// Playlists stay .adilists/<name>/ folders of links so scan_music_directory keeps working, this file
+// holds the link names in play order. Folders without one are migrated in file name order
+const ORDER_FILE: &str = ".order";
+const SMART_RULE_FILE: &str = ".smart";
+
+// Every change reads and rewrites the order file so only one may run at a time
+static PLAYLIST_LOCK: Mutex<()> = Mutex::new(());
+// Playlists from before the manager get their order file the first time any playlist is opened
+static MIGRATION: Once = Once::new();
+
+#[derive(Serialize, Deserialize, Default)]
+struct PlaylistOrder {
+    entries: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PlaylistInfo {
+    pub name: String,
+    pub track_count: u32,
+    pub is_smart: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct PlaylistEntry {
+    pub index: u32,
+    // Name of the link inside the playlist folder, unique even when file names are not
+    pub link: String,
+    pub path: String,
+    // The file the link points at no longer exists
+    pub missing: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaylistSortKey {
+    Title,
+    Artist,
+    Album,
+    Duration,
+    FileName,
+    Path,
+}
+
+fn link_target(link: &Path) -> Option<PathBuf> {
+    let target = fs::read_link(link).ok()?;
+    Some(if target.is_absolute() {
+        target
+    } else {
+        link.parent()?.join(target)
+    })
+}
+
+// Links on disk that are not hidden bookkeeping files like .order and .smart
+fn links_on_disk(dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_symlink())
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .filter(|n| !n.starts_with('.'))
+        .collect();
+    names.sort();
+    names
+}
+
+// The saved order with links that were deleted behind our back dropped and links that were added
+// behind our back (or every link, for folders from before the order file) appended
+fn load_order(dir: &Path) -> Vec<String> {
+    let saved = fs::read_to_string(dir.join(ORDER_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_str::<PlaylistOrder>(&content).ok())
+        .unwrap_or_default();
+    let on_disk = links_on_disk(dir);
+    let present: HashSet<&String> = on_disk.iter().collect();
+
+    let mut seen = HashSet::new();
+    let mut order: Vec<String> = saved
+        .entries
+        .into_iter()
+        .filter(|n| present.contains(n) && seen.insert(n.clone()))
+        .collect();
+    order.extend(on_disk.into_iter().filter(|n| !seen.contains(n)));
+    order
+}
+
+fn save_order(dir: &Path, entries: &[String]) -> Result<(), String> {
+    let path = dir.join(ORDER_FILE);
+    let tmp = dir.join(format!("{ORDER_FILE}.tmp"));
+    let json = serde_json::to_string(&PlaylistOrder {
+        entries: entries.to_vec(),
+    })
+    .map_err(|e| format!("Failed to serialise the playlist order: {e}"))?;
+    fs::write(&tmp, json).map_err(|e| format!("Failed to save the playlist order: {e}"))?;
+    fs::rename(&tmp, &path).map_err(|e| format!("Failed to save the playlist order: {e}"))
+}
+
+fn existing_playlist(name: &str) -> Result<PathBuf, String> {
+    let dir = playlist_dir(name)?;
+    if dir.is_dir() {
+        Ok(dir)
+    } else {
+        Err(format!("There is no playlist called {name}"))
+    }
+}
+
+// Smart playlists are worked out from their rule so their contents cannot be edited by hand
+fn editable_playlist(name: &str) -> Result<PathBuf, String> {
+    let dir = existing_playlist(name)?;
+    if dir.join(SMART_RULE_FILE).exists() {
+        return Err(format!("{name} is a smart playlist, edit its rule instead"));
+    }
+    Ok(dir)
+}
+
+// Songs that share a file name (every album has an "01 Intro.mp3") get a " (n)" suffix
+fn unique_link_name(song: &Path, taken: &HashSet<String>) -> Result<String, String> {
+    let name = song
+        .file_name()
+        .ok_or(format!("{} has no file name", song.display()))?
+        .to_string_lossy()
+        .to_string();
+    if !taken.contains(&name) {
+        return Ok(name);
+    }
+    let stem = fpre(song)
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or(name.clone());
+    let ext = song.extension().map(|e| e.to_string_lossy().to_string());
+    let mut n = 2;
+    loop {
+        let candidate = match &ext {
+            Some(ext) => format!("{stem} ({n}).{ext}"),
+            None => format!("{stem} ({n})"),
+        };
+        if !taken.contains(&candidate) {
+            return Ok(candidate);
+        }
+        n += 1;
+    }
+}
+
+fn targets(dir: &Path, order: &[String]) -> HashSet<PathBuf> {
+    order
+        .iter()
+        .filter_map(|n| fs::canonicalize(dir.join(n)).ok())
+        .collect()
+}
+
+// Links songs into the playlist at position (the end if None) and returns how many were added
+// and how many were skipped because they were already in it
+fn insert_links(
+    dir: &Path,
+    songs: &[PathBuf],
+    position: Option<usize>,
+    allow_duplicates: bool,
+) -> Result<(u32, u32), String> {
+    let mut order = load_order(dir);
+    // Anything on disk counts as taken, including stale .order.tmp style leftovers
+    let mut taken: HashSet<String> = fs::read_dir(dir)
+        .map_err(|e| format!("Failed to read the playlist: {e}"))?
+        .filter_map(|e| e.ok())
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .collect();
+    let mut present = targets(dir, &order);
+
+    let mut added = Vec::new();
+    let mut duplicates = 0;
+    for song in songs {
+        let song = fs::canonicalize(song)
+            .map_err(|e| format!("{} cannot be added: {e}", song.display()))?;
+        if !song.is_file() {
+            return Err(format!("{} is not a file", song.display()));
+        }
+        if !allow_duplicates && !present.insert(song.clone()) {
+            duplicates += 1;
+            continue;
+        }
+        let name = unique_link_name(&song, &taken)?;
+        symlink(&song, dir.join(&name))
+            .map_err(|e| format!("Failed to link {}: {e}", song.display()))?;
+        taken.insert(name.clone());
+        added.push(name);
+    }
+
+    let count = added.len() as u32;
+    let at = position.unwrap_or(order.len()).min(order.len());
+    order.splice(at..at, added);
+    save_order(dir, &order)?;
+    Ok((count, duplicates))
+}
+
+// Appends songs to the playlist folder for other modules, see insert_links
+#[flutter_rust_bridge::frb(ignore)]
+pub fn add_links(
+    dir: &Path,
+    songs: &[PathBuf],
+    allow_duplicates: bool,
+) -> Result<(u32, u32), String> {
+    let _guard = PLAYLIST_LOCK.lock().unwrap();
+    insert_links(dir, songs, None, allow_duplicates)
+}
+
+// The songs the playlist folder links to, for other modules
+#[flutter_rust_bridge::frb(ignore)]
+pub fn linked_songs(dir: &Path) -> HashSet<PathBuf> {
+    let _guard = PLAYLIST_LOCK.lock().unwrap();
+    targets(dir, &load_order(dir))
+}
+
+// Keeps a link's place in the order when something else (like the organizer) renames it
+#[flutter_rust_bridge::frb(ignore)]
+pub fn rename_link_entry(dir: &Path, old: &str, new: &str) {
+    let _guard = PLAYLIST_LOCK.lock().unwrap();
+    let saved = fs::read_to_string(dir.join(ORDER_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_str::<PlaylistOrder>(&content).ok());
+    let Some(mut saved) = saved else {
+        return;
+    };
+    for entry in saved.entries.iter_mut() {
+        if entry.as_str() == old {
+            *entry = new.to_string();
+        }
+    }
+    let _ = save_order(dir, &saved.entries);
+}
+
+// Puts the songs scan_music_directory found in a playlist folder into the playlist's order.
+// Converted WAV/OGG songs point at the cache rather than the link so they keep their place at
+// the end
+#[flutter_rust_bridge::frb(ignore)]
+pub fn order_playlist_songs(dir: &Path, songs: &mut [SongMetadata]) {
+    MIGRATION.call_once(|| {
+        migrate_playlists();
+    });
+    let position: HashMap<String, usize> = load_order(dir)
+        .into_iter()
+        .enumerate()
+        .map(|(i, n)| (n, i))
+        .collect();
+    songs.sort_by_key(|song| {
+        let song = Path::new(&song.path);
+        song.parent()
+            .filter(|p| *p == dir)
+            .and(song.file_name())
+            .and_then(|n| position.get(&*n.to_string_lossy()))
+            .copied()
+            .unwrap_or(usize::MAX)
+    });
+}
+
+pub fn list_playlists() -> Vec<PlaylistInfo> {
+    let Ok(mf) = music_folder() else {
+        return Vec::new();
+    };
+    let Ok(entries) = fs::read_dir(PathBuf::from(mf).join(".adilists")) else {
+        return Vec::new();
+    };
+    let mut playlists: Vec<PlaylistInfo> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_dir())
+        .filter_map(|p| {
+            let name = p.file_name()?.to_string_lossy().to_string();
+            let is_smart = p.join(SMART_RULE_FILE).exists();
+            Some(PlaylistInfo {
+                track_count: if is_smart {
+                    0
+                } else {
+                    links_on_disk(&p).len() as u32
+                },
+                name,
+                is_smart,
+            })
+        })
+        .collect();
+    playlists.sort_by_key(|p| p.name.to_lowercase());
+    playlists
+}
+
+pub fn create_playlist(name: String) -> Result<(), String> {
+    let _guard = PLAYLIST_LOCK.lock().unwrap();
+    let dir = playlist_dir(&name)?;
+    if dir.exists() {
+        return Err(format!("{name} already exists"));
+    }
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create the playlist folder: {e}"))?;
+    save_order(&dir, &[])
+}
+
+pub fn rename_playlist(name: String, new_name: String) -> Result<(), String> {
+    let _guard = PLAYLIST_LOCK.lock().unwrap();
+    let dir = existing_playlist(&name)?;
+    let new_dir = playlist_dir(&new_name)?;
+    if new_dir.exists() {
+        return Err(format!("{new_name} already exists"));
+    }
+    fs::rename(&dir, &new_dir).map_err(|e| format!("Failed to rename {name}: {e}"))
+}
+
+// Only the links are removed, never the songs they point at
+pub fn delete_playlist(name: String) -> Result<(), String> {
+    let _guard = PLAYLIST_LOCK.lock().unwrap();
+    let dir = existing_playlist(&name)?;
+    fs::remove_dir_all(&dir).map_err(|e| format!("Failed to delete {name}: {e}"))
+}
+
+pub fn duplicate_playlist(name: String, new_name: String) -> Result<(), String> {
+    let _guard = PLAYLIST_LOCK.lock().unwrap();
+    let dir = existing_playlist(&name)?;
+    let new_dir = playlist_dir(&new_name)?;
+    if new_dir.exists() {
+        return Err(format!("{new_name} already exists"));
+    }
+    fs::create_dir_all(&new_dir)
+        .map_err(|e| format!("Failed to create the playlist folder: {e}"))?;
+
+    if dir.join(SMART_RULE_FILE).exists() {
+        return fs::copy(dir.join(SMART_RULE_FILE), new_dir.join(SMART_RULE_FILE))
+            .map(|_| ())
+            .map_err(|e| format!("Failed to copy the rule: {e}"));
+    }
+    let order = load_order(&dir);
+    for link in &order {
+        // Copied as is so links to missing songs stay in the copy too
+        let Ok(target) = fs::read_link(dir.join(link)) else {
+            continue;
+        };
+        symlink(&target, new_dir.join(link)).map_err(|e| format!("Failed to copy {link}: {e}"))?;
+    }
+    save_order(&new_dir, &order)
+}
+
+pub fn get_playlist_entries(name: String) -> Result<Vec<PlaylistEntry>, String> {
+    let dir = existing_playlist(&name)?;
+    if dir.join(SMART_RULE_FILE).exists() {
+        return Ok(load_smart_playlist(name)?
+            .into_iter()
+            .enumerate()
+            .map(|(i, song)| PlaylistEntry {
+                index: i as u32,
+                link: Path::new(&song.path)
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+                path: song.path,
+                missing: false,
+            })
+            .collect());
+    }
+    Ok(load_order(&dir)
+        .into_iter()
+        .enumerate()
+        .map(|(i, link)| {
+            let target = link_target(&dir.join(&link)).unwrap_or_default();
+            PlaylistEntry {
+                index: i as u32,
+                missing: !target.exists(),
+                path: target.to_string_lossy().to_string(),
+                link,
+            }
+        })
+        .collect())
+}
+
+// Inserts the songs before position, or appends them when it is None or past the end. Songs already
+// in the playlist are skipped unless allow_duplicates is set. Returns how many were added
+pub fn add_to_playlist(
+    name: String,
+    song_paths: Vec<String>,
+    position: Option<u32>,
+    allow_duplicates: bool,
+) -> Result<u32, String> {
+    let _guard = PLAYLIST_LOCK.lock().unwrap();
+    let dir = editable_playlist(&name)?;
+    let songs: Vec<PathBuf> = song_paths.into_iter().map(PathBuf::from).collect();
+    insert_links(&dir, &songs, position.map(|p| p as usize), allow_duplicates)
+        .map(|(added, _)| added)
+}
+
+// Removes the entries at the given positions (as returned by get_playlist_entries)
+pub fn remove_from_playlist(name: String, indices: Vec<u32>) -> Result<u32, String> {
+    let _guard = PLAYLIST_LOCK.lock().unwrap();
+    let dir = editable_playlist(&name)?;
+    let order = load_order(&dir);
+    let remove: HashSet<usize> = indices.into_iter().map(|i| i as usize).collect();
+    if let Some(bad) = remove.iter().find(|&&i| i >= order.len()) {
+        return Err(format!("{name} has no entry {bad}"));
+    }
+
+    let mut kept = Vec::with_capacity(order.len());
+    let mut removed = 0;
+    for (i, link) in order.into_iter().enumerate() {
+        if !remove.contains(&i) {
+            kept.push(link);
+            continue;
+        }
+        match fs::remove_file(dir.join(&link)) {
+            Ok(()) => removed += 1,
+            Err(e) => {
+                eprintln!("Failed to remove {link} from {name}: {e}");
+                kept.push(link);
+            }
+        }
+    }
+    save_order(&dir, &kept)?;
+    Ok(removed)
+}
+
+pub fn move_playlist_entry(name: String, from: u32, to: u32) -> Result<(), String> {
+    let _guard = PLAYLIST_LOCK.lock().unwrap();
+    let dir = editable_playlist(&name)?;
+    let mut order = load_order(&dir);
+    let (from, to) = (from as usize, to as usize);
+    if from >= order.len() || to >= order.len() {
+        return Err(format!("{name} only has {} entries", order.len()));
+    }
+    let link = order.remove(from);
+    order.insert(to, link);
+    save_order(&dir, &order)
+}
+
+// new_order lists every current position once, in the order they should end up in
+pub fn reorder_playlist(name: String, new_order: Vec<u32>) -> Result<(), String> {
+    let _guard = PLAYLIST_LOCK.lock().unwrap();
+    let dir = editable_playlist(&name)?;
+    let order = load_order(&dir);
+    let mut seen = HashSet::new();
+    if new_order.len() != order.len()
+        || !new_order
+            .iter()
+            .all(|&i| (i as usize) < order.len() && seen.insert(i))
+    {
+        return Err(format!(
+            "The new order must list each of the {} entries exactly once",
+            order.len()
+        ));
+    }
+    let reordered: Vec<String> = new_order
+        .into_iter()
+        .map(|i| order[i as usize].clone())
+        .collect();
+    save_order(&dir, &reordered)
+}
+
+// Appends every source playlist (smart ones as they are right now) to target, creating it if
+// needed. Returns how many songs were added
+pub fn merge_playlists(
+    sources: Vec<String>,
+    target: String,
+    allow_duplicates: bool,
+) -> Result<u32, String> {
+    let _guard = PLAYLIST_LOCK.lock().unwrap();
+    let target_dir = playlist_dir(&target)?;
+    if target_dir.join(SMART_RULE_FILE).exists() {
+        return Err(format!(
+            "{target} is a smart playlist, edit its rule instead"
+        ));
+    }
+
+    let mut songs = Vec::new();
+    for source in sources.iter().filter(|s| **s != target) {
+        let dir = existing_playlist(source)?;
+        if dir.join(SMART_RULE_FILE).exists() {
+            songs.extend(
+                load_smart_playlist(source.clone())?
+                    .into_iter()
+                    .map(|s| PathBuf::from(s.path)),
+            );
+        } else {
+            songs.extend(
+                load_order(&dir)
+                    .iter()
+                    .filter_map(|link| link_target(&dir.join(link)))
+                    .filter(|p| p.exists()),
+            );
+        }
+    }
+
+    fs::create_dir_all(&target_dir)
+        .map_err(|e| format!("Failed to create the playlist folder: {e}"))?;
+    insert_links(&target_dir, &songs, None, allow_duplicates).map(|(added, _)| added)
+}
+
+// Sorts the saved order once, songs added later still go to the end
+pub fn sort_playlist(name: String, key: PlaylistSortKey, descending: bool) -> Result<(), String> {
+    let dir = editable_playlist(&name)?;
+    // Scanned before taking the lock, the first scan of any playlist migrates the old ones and
+    // that takes it too
+    let metadata: HashMap<String, SongMetadata> = match key {
+        PlaylistSortKey::FileName | PlaylistSortKey::Path => HashMap::new(),
+        _ => scan_music_directory(dir.to_string_lossy().to_string(), false)
+            .into_iter()
+            .filter_map(|song| {
+                let link = Path::new(&song.path)
+                    .file_name()?
+                    .to_string_lossy()
+                    .to_string();
+                Some((link, song))
+            })
+            .collect(),
+    };
+    let _guard = PLAYLIST_LOCK.lock().unwrap();
+    let mut order = load_order(&dir);
+    let text = |link: &String, field: fn(&SongMetadata) -> &str| {
+        metadata
+            .get(link)
+            .map(|song| field(song).to_lowercase())
+            .unwrap_or_default()
+    };
+    let target = |link: &String| {
+        link_target(&dir.join(link))
+            .map(|p| p.to_string_lossy().to_lowercase())
+            .unwrap_or_default()
+    };
+
+    // Stable so songs that compare equal keep their current order
+    order.sort_by(|a, b| {
+        let ordering = match key {
+            PlaylistSortKey::Title => {
+                text(a, |s| s.title.as_str()).cmp(&text(b, |s| s.title.as_str()))
+            }
+            PlaylistSortKey::Artist => {
+                text(a, |s| s.artist.as_str()).cmp(&text(b, |s| s.artist.as_str()))
+            }
+            PlaylistSortKey::Album => {
+                text(a, |s| s.album.as_str()).cmp(&text(b, |s| s.album.as_str()))
+            }
+            PlaylistSortKey::Duration => {
+                let duration = |link: &String| metadata.get(link).map(|s| s.duration);
+                duration(a).cmp(&duration(b))
+            }
+            PlaylistSortKey::FileName => a.to_lowercase().cmp(&b.to_lowercase()),
+            PlaylistSortKey::Path => target(a).cmp(&target(b)),
+        };
+        if descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+    save_order(&dir, &order)
+}
+
+// Writes an order file for every playlist folder from before the playlist manager so its current
+// (file name) order is kept from now on. Returns how many playlists were migrated
+pub fn migrate_playlists() -> u32 {
+    let _guard = PLAYLIST_LOCK.lock().unwrap();
+    let Ok(mf) = music_folder() else {
+        return 0;
+    };
+    let Ok(entries) = fs::read_dir(PathBuf::from(mf).join(".adilists")) else {
+        return 0;
+    };
+    let mut migrated = 0;
+    for dir in entries.filter_map(|e| e.ok()).map(|e| e.path()) {
+        if !dir.is_dir() || dir.join(SMART_RULE_FILE).exists() || dir.join(ORDER_FILE).exists() {
+            continue;
+        }
+        match save_order(&dir, &load_order(&dir)) {
+            Ok(()) => migrated += 1,
+            Err(e) => eprintln!("Failed to migrate {}: {e}", dir.display()),
+        }
+    }
+    migrated
+}
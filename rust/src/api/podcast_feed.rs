@@ -0,0 +1,256 @@
+use crate::api::xml::{Element, parse_xml};
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PodcastChapter {
+    pub start_secs: f64,
+    pub title: String,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedEpisode {
+    // The guid, or the enclosure url for feeds without one
+    pub guid: String,
+    pub title: String,
+    pub description: Option<String>,
+    // Unix seconds
+    pub published: Option<i64>,
+    pub duration_secs: Option<u64>,
+    pub enclosure_url: Option<String>,
+    pub enclosure_type: Option<String>,
+    pub enclosure_length: Option<u64>,
+    pub image_url: Option<String>,
+    // Podlove Simple Chapters written into the feed itself
+    pub chapters: Vec<PodcastChapter>,
+    // Podcasting 2.0 JSON chapters, fetched when the chapters are asked for
+    pub chapters_url: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Feed {
+    pub title: String,
+    pub author: Option<String>,
+    pub description: Option<String>,
+    pub link: Option<String>,
+    pub image_url: Option<String>,
+    pub episodes: Vec<FeedEpisode>,
+}
+
+// "01:02:03.5", "62:03" or plain seconds
+#[flutter_rust_bridge::frb(ignore)]
+pub fn parse_clock(text: &str) -> Option<f64> {
+    let parts: Vec<f64> = text
+        .trim()
+        .split(':')
+        .map(|p| p.trim().parse::<f64>())
+        .collect::<Result<_, _>>()
+        .ok()?;
+    if parts.is_empty() || parts.len() > 3 || parts.iter().any(|p| *p < 0.0) {
+        return None;
+    }
+    Some(parts.iter().fold(0.0, |total, part| total * 60.0 + part))
+}
+
+fn parse_date(text: &str) -> Option<i64> {
+    let text = text.trim();
+    DateTime::parse_from_rfc2822(text)
+        .or_else(|_| DateTime::parse_from_rfc3339(text))
+        .ok()
+        .map(|d| d.timestamp())
+}
+
+fn psc_chapters(item: &Element) -> Vec<PodcastChapter> {
+    let mut chapters: Vec<PodcastChapter> = item
+        .children_named("psc:chapters")
+        .flat_map(|c| c.children_named("psc:chapter"))
+        .filter_map(|c| {
+            Some(PodcastChapter {
+                start_secs: parse_clock(c.attr("start")?)?,
+                title: c.attr("title").unwrap_or_default().trim().to_string(),
+                url: c.attr("href").map(str::to_string),
+            })
+        })
+        .collect();
+    chapters.sort_by(|a, b| a.start_secs.total_cmp(&b.start_secs));
+    chapters
+}
+
+fn rss_episode(item: &Element) -> Option<FeedEpisode> {
+    let enclosure = item
+        .child("enclosure")
+        .or_else(|| item.child("media:content"));
+    let enclosure_url = enclosure.and_then(|e| e.attr("url")).map(str::to_string);
+    let guid = item
+        .child_text("guid")
+        .or(enclosure_url.clone())
+        .or(item.child_text("link"))?;
+    Some(FeedEpisode {
+        title: item.child_text("title").unwrap_or(guid.clone()),
+        guid,
+        description: item
+            .child_text("content:encoded")
+            .or(item.child_text("description"))
+            .or(item.child_text("itunes:summary")),
+        published: item
+            .child_text("pubDate")
+            .or(item.child_text("dc:date"))
+            .and_then(|d| parse_date(&d)),
+        duration_secs: item
+            .child_text("itunes:duration")
+            .or(enclosure
+                .and_then(|e| e.attr("duration"))
+                .map(str::to_string))
+            .and_then(|d| parse_clock(&d))
+            .map(|d| d as u64),
+        enclosure_url,
+        enclosure_type: enclosure.and_then(|e| e.attr("type")).map(str::to_string),
+        enclosure_length: enclosure
+            .and_then(|e| e.attr("length").or(e.attr("fileSize")))
+            .and_then(|l| l.trim().parse().ok())
+            .filter(|&l| l > 0),
+        image_url: item
+            .child("itunes:image")
+            .and_then(|i| i.attr("href"))
+            .map(str::to_string),
+        chapters: psc_chapters(item),
+        chapters_url: item
+            .child("podcast:chapters")
+            .and_then(|c| c.attr("url"))
+            .map(str::to_string),
+    })
+}
+
+fn parse_rss(rss: &Element) -> Result<Feed, String> {
+    let channel = rss.child("channel").ok_or("The RSS feed has no channel")?;
+    Ok(Feed {
+        title: channel
+            .child_text("title")
+            .unwrap_or("Untitled podcast".to_string()),
+        author: channel
+            .child_text("itunes:author")
+            .or(channel.child_text("author"))
+            .or(channel.child_text("managingEditor")),
+        description: channel
+            .child_text("description")
+            .or(channel.child_text("itunes:summary")),
+        link: channel.child_text("link"),
+        image_url: channel
+            .child("itunes:image")
+            .and_then(|i| i.attr("href"))
+            .map(str::to_string)
+            .or(channel.child("image").and_then(|i| i.child_text("url"))),
+        episodes: channel
+            .children_named("item")
+            .filter_map(rss_episode)
+            .collect(),
+    })
+}
+
+fn atom_link<'a>(element: &'a Element, rel: &str) -> Option<&'a Element> {
+    element
+        .children_named("link")
+        .find(|l| l.attr("rel").unwrap_or("alternate") == rel)
+}
+
+fn atom_episode(entry: &Element) -> Option<FeedEpisode> {
+    let enclosure = atom_link(entry, "enclosure");
+    let enclosure_url = enclosure.and_then(|e| e.attr("href")).map(str::to_string);
+    let guid = entry.child_text("id").or(enclosure_url.clone())?;
+    Some(FeedEpisode {
+        title: entry.child_text("title").unwrap_or(guid.clone()),
+        guid,
+        description: entry.child_text("content").or(entry.child_text("summary")),
+        published: entry
+            .child_text("published")
+            .or(entry.child_text("updated"))
+            .and_then(|d| parse_date(&d)),
+        duration_secs: entry
+            .child_text("itunes:duration")
+            .and_then(|d| parse_clock(&d))
+            .map(|d| d as u64),
+        enclosure_url,
+        enclosure_type: enclosure.and_then(|e| e.attr("type")).map(str::to_string),
+        enclosure_length: enclosure
+            .and_then(|e| e.attr("length"))
+            .and_then(|l| l.trim().parse().ok())
+            .filter(|&l| l > 0),
+        image_url: entry
+            .child("itunes:image")
+            .and_then(|i| i.attr("href"))
+            .map(str::to_string),
+        chapters: psc_chapters(entry),
+        chapters_url: entry
+            .child("podcast:chapters")
+            .and_then(|c| c.attr("url"))
+            .map(str::to_string),
+    })
+}
+
+fn parse_atom(feed: &Element) -> Feed {
+    Feed {
+        title: feed
+            .child_text("title")
+            .unwrap_or("Untitled podcast".to_string()),
+        author: feed.child("author").and_then(|a| a.child_text("name")),
+        description: feed.child_text("subtitle"),
+        link: atom_link(feed, "alternate")
+            .and_then(|l| l.attr("href"))
+            .map(str::to_string),
+        image_url: feed.child_text("logo").or(feed.child_text("icon")),
+        episodes: feed
+            .children_named("entry")
+            .filter_map(atom_episode)
+            .collect(),
+    }
+}
+
+// Reads an RSS 2.0 or Atom feed. Episodes without anything to play are kept, some feeds add the
+// enclosure later
+#[flutter_rust_bridge::frb(ignore)]
+pub fn parse_feed(xml: &str) -> Result<Feed, String> {
+    let document = parse_xml(xml);
+    if let Some(rss) = document.child("rss") {
+        parse_rss(rss)
+    } else if let Some(feed) = document.child("feed") {
+        Ok(parse_atom(feed))
+    } else {
+        Err("This is not an RSS or Atom feed".to_string())
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonChapters {
+    chapters: Vec<JsonChapter>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonChapter {
+    start_time: f64,
+    title: Option<String>,
+    url: Option<String>,
+    // Chapters that only carry artwork or links for apps, not a place to jump to
+    toc: Option<bool>,
+}
+
+// Podcasting 2.0 chapters (application/json+chapters)
+#[flutter_rust_bridge::frb(ignore)]
+pub fn parse_json_chapters(json: &str) -> Result<Vec<PodcastChapter>, String> {
+    let parsed: JsonChapters =
+        serde_json::from_str(json).map_err(|e| format!("Invalid chapters file: {e}"))?;
+    let mut chapters: Vec<PodcastChapter> = parsed
+        .chapters
+        .into_iter()
+        .filter(|c| c.toc != Some(false) && c.start_time >= 0.0)
+        .map(|c| PodcastChapter {
+            start_secs: c.start_time,
+            title: c.title.unwrap_or_default(),
+            url: c.url,
+        })
+        .collect();
+    chapters.sort_by(|a, b| a.start_secs.total_cmp(&b.start_secs));
+    Ok(chapters)
+}
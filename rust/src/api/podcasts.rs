@@ -0,0 +1,767 @@
+use crate::{
+    api::{
+        music_handler::{
+            get_current_song_path, get_playback_position, is_playing, play_song, seek_when_buffered,
+        },
+        organizer::{sanitize_component, with_collision_suffix},
+        podcast_feed::{Feed, FeedEpisode, PodcastChapter, parse_feed, parse_json_chapters},
+        utils::get_data_dir,
+        value_store::with_store,
+    },
+    frb_generated::StreamSink,
+};
+use once_cell::sync::Lazy;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+    fs,
+    io::{Read, Write},
+    path::{Path, PathBuf},
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+const PODCAST_USER_AGENT: &str = concat!(
+    "Adiman/",
+    env!("CARGO_PKG_VERSION"),
+    " (https://github.com/ChaosTheChaotic/Adiman)"
+);
+const PROGRESS_EVENT_INTERVAL: Duration = Duration::from_millis(500);
+// How often the position of the episode playing is looked at
+const POSITION_POLL_INTERVAL: Duration = Duration::from_secs(1);
+// How often the position is saved while the episode keeps playing
+const POSITION_SAVE_INTERVAL: Duration = Duration::from_secs(30);
+// Stopping this close to the end counts as having listened to the episode
+const PLAYED_MARGIN_SECS: f64 = 30.0;
+// How long resuming waits for a downloaded episode to decode up to where it left off
+const RESUME_TIMEOUT: Duration = Duration::from_secs(30);
+
+// Podcast id and episode guid
+type EpisodeKey = (u64, String);
+
+static FEED_CLIENT: Lazy<reqwest::blocking::Client> = Lazy::new(|| {
+    reqwest::blocking::Client::builder()
+        .user_agent(PODCAST_USER_AGENT)
+        .timeout(Duration::from_secs(30))
+        .build()
+        .unwrap_or_default()
+});
+// Episodes can take a long time to download, so only connecting has a timeout
+static DOWNLOAD_CLIENT: Lazy<reqwest::blocking::Client> = Lazy::new(|| {
+    reqwest::blocking::Client::builder()
+        .user_agent(PODCAST_USER_AGENT)
+        .connect_timeout(Duration::from_secs(15))
+        .timeout(None::<Duration>)
+        .build()
+        .unwrap_or_default()
+});
+// Every change reads and rewrites the subscriptions file so only one may run at a time
+static PODCAST_LOCK: Mutex<()> = Mutex::new(());
+static DOWNLOAD_SINKS: Mutex<Vec<StreamSink<EpisodeDownload>>> = Mutex::new(Vec::new());
+static ACTIVE_DOWNLOADS: Lazy<Mutex<HashMap<EpisodeKey, Arc<AtomicBool>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+// Bumped to stop the running schedule or position tracker
+static SCHEDULE_GENERATION: AtomicU64 = AtomicU64::new(0);
+static TRACK_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PodcastEpisode {
+    pub feed: FeedEpisode,
+    pub downloaded_path: Option<String>,
+    // Where to resume from. Saved for streamed episodes too, but only a downloaded episode is
+    // resumed there, streams always start from the beginning
+    pub position_secs: f64,
+    pub played: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Podcast {
+    pub id: u64,
+    // An http(s) url, a file:// url or a plain path to a local feed
+    pub feed_url: String,
+    pub title: String,
+    pub author: Option<String>,
+    pub description: Option<String>,
+    pub link: Option<String>,
+    pub image_url: Option<String>,
+    // New episodes are downloaded when the schedule refreshes the feed
+    pub auto_download: bool,
+    // Older downloads beyond this many are deleted by the schedule, None keeps everything
+    pub keep_downloaded: Option<u32>,
+    // Unix seconds
+    pub last_refreshed: Option<i64>,
+    // Newest first
+    pub episodes: Vec<PodcastEpisode>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PodcastRefresh {
+    pub podcast_id: u64,
+    pub new_episodes: Vec<String>,
+    pub error: Option<String>,
+}
+
+// Sent to watch_episode_downloads while an episode downloads and once it is done
+#[derive(Debug, Clone)]
+pub struct EpisodeDownload {
+    pub podcast_id: u64,
+    pub guid: String,
+    pub downloaded_bytes: u64,
+    pub total_bytes: Option<u64>,
+    pub finished: bool,
+    pub path: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct Subscriptions {
+    next_id: u64,
+    podcasts: Vec<Podcast>,
+}
+
+fn subscriptions_path() -> PathBuf {
+    get_data_dir("podcasts").join("subscriptions.json")
+}
+
+fn load_subscriptions() -> Subscriptions {
+    fs::read_to_string(subscriptions_path())
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_subscriptions(subscriptions: &Subscriptions) -> Result<(), String> {
+    let path = subscriptions_path();
+    let tmp = path.with_extension("json.tmp");
+    let json = serde_json::to_string(subscriptions)
+        .map_err(|e| format!("Failed to serialise the podcast subscriptions: {e}"))?;
+    fs::write(&tmp, json)
+        .and_then(|_| fs::rename(&tmp, &path))
+        .map_err(|e| format!("Failed to save the podcast subscriptions: {e}"))
+}
+
+// Loads, changes and saves the subscriptions. Nothing slow (like the network) belongs in f
+fn with_subscriptions<T>(
+    f: impl FnOnce(&mut Subscriptions) -> Result<T, String>,
+) -> Result<T, String> {
+    let _guard = PODCAST_LOCK.lock().unwrap();
+    let mut subscriptions = load_subscriptions();
+    let result = f(&mut subscriptions)?;
+    save_subscriptions(&subscriptions)?;
+    Ok(result)
+}
+
+fn podcast_mut(subscriptions: &mut Subscriptions, id: u64) -> Result<&mut Podcast, String> {
+    subscriptions
+        .podcasts
+        .iter_mut()
+        .find(|p| p.id == id)
+        .ok_or_else(|| format!("There is no podcast with id {id}"))
+}
+
+fn episode_mut<'a>(
+    subscriptions: &'a mut Subscriptions,
+    id: u64,
+    guid: &str,
+) -> Result<&'a mut PodcastEpisode, String> {
+    podcast_mut(subscriptions, id)?
+        .episodes
+        .iter_mut()
+        .find(|e| e.feed.guid == guid)
+        .ok_or_else(|| format!("The podcast has no episode {guid}"))
+}
+
+fn find_episode(id: u64, guid: &str) -> Result<(Podcast, PodcastEpisode), String> {
+    let podcast = get_podcast(id)?;
+    let episode = podcast
+        .episodes
+        .iter()
+        .find(|e| e.feed.guid == guid)
+        .cloned()
+        .ok_or_else(|| format!("The podcast has no episode {guid}"))?;
+    Ok((podcast, episode))
+}
+
+fn is_http(url: &str) -> bool {
+    let lower = url.to_ascii_lowercase();
+    lower.starts_with("http://") || lower.starts_with("https://")
+}
+
+fn local_path(url: &str) -> &Path {
+    Path::new(url.strip_prefix("file://").unwrap_or(url))
+}
+
+// Feeds and chapter files may also be local, which is how they are tested
+fn fetch_text(url: &str) -> Result<String, String> {
+    if is_http(url) {
+        FEED_CLIENT
+            .get(url)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .and_then(|r| r.text())
+            .map_err(|e| format!("Failed to fetch {url}: {e}"))
+    } else {
+        fs::read_to_string(local_path(url)).map_err(|e| format!("Failed to read {url}: {e}"))
+    }
+}
+
+// Links in a feed may be relative to it
+fn resolve(base: &str, link: &str) -> String {
+    if is_http(link) || link.starts_with("file://") || link.starts_with('/') {
+        link.to_string()
+    } else if is_http(base) {
+        Url::parse(base)
+            .and_then(|b| b.join(link))
+            .map(|u| u.to_string())
+            .unwrap_or(link.to_string())
+    } else {
+        local_path(base)
+            .parent()
+            .map(|dir| dir.join(link).to_string_lossy().to_string())
+            .unwrap_or(link.to_string())
+    }
+}
+
+fn fetch_feed(feed_url: &str) -> Result<Feed, String> {
+    let mut feed = parse_feed(&fetch_text(feed_url)?)?;
+    for episode in &mut feed.episodes {
+        episode.enclosure_url = episode
+            .enclosure_url
+            .as_deref()
+            .map(|u| resolve(feed_url, u));
+        episode.chapters_url = episode
+            .chapters_url
+            .as_deref()
+            .map(|u| resolve(feed_url, u));
+    }
+    Ok(feed)
+}
+
+// Keeps what the user did with episodes that are still in the feed, and downloaded episodes the
+// feed has since dropped. Returns the guids that are new
+fn merge_episodes(podcast: &mut Podcast, episodes: Vec<FeedEpisode>) -> Vec<String> {
+    let mut old: HashMap<String, PodcastEpisode> = podcast
+        .episodes
+        .drain(..)
+        .map(|e| (e.feed.guid.clone(), e))
+        .collect();
+    let mut seen = HashSet::new();
+    let mut new = Vec::new();
+    for feed in episodes {
+        if !seen.insert(feed.guid.clone()) {
+            continue;
+        }
+        let episode = match old.remove(&feed.guid) {
+            Some(existing) => PodcastEpisode {
+                // Chapters fetched from chapters_url are kept unless the feed now has its own
+                feed: FeedEpisode {
+                    chapters: if feed.chapters.is_empty() {
+                        existing.feed.chapters
+                    } else {
+                        feed.chapters.clone()
+                    },
+                    ..feed
+                },
+                ..existing
+            },
+            None => {
+                new.push(feed.guid.clone());
+                PodcastEpisode {
+                    feed,
+                    downloaded_path: None,
+                    position_secs: 0.0,
+                    played: false,
+                }
+            }
+        };
+        podcast.episodes.push(episode);
+    }
+    podcast
+        .episodes
+        .extend(old.into_values().filter(|e| e.downloaded_path.is_some()));
+    podcast.episodes.sort_by_key(|e| Reverse(e.feed.published));
+    new
+}
+
+fn now() -> i64 {
+    chrono::Utc::now().timestamp()
+}
+
+pub fn subscribe_podcast(feed_url: String) -> Result<Podcast, String> {
+    let feed_url = feed_url.trim().to_string();
+    let feed = fetch_feed(&feed_url)?;
+    with_subscriptions(|subscriptions| {
+        if subscriptions
+            .podcasts
+            .iter()
+            .any(|p| p.feed_url == feed_url)
+        {
+            return Err(format!("You are already subscribed to {feed_url}"));
+        }
+        subscriptions.next_id += 1;
+        let mut podcast = Podcast {
+            id: subscriptions.next_id,
+            feed_url,
+            title: feed.title,
+            author: feed.author,
+            description: feed.description,
+            link: feed.link,
+            image_url: feed.image_url,
+            auto_download: false,
+            keep_downloaded: None,
+            last_refreshed: Some(now()),
+            episodes: Vec::new(),
+        };
+        merge_episodes(&mut podcast, feed.episodes);
+        subscriptions.podcasts.push(podcast.clone());
+        Ok(podcast)
+    })
+}
+
+// Downloaded episodes are only deleted with delete_downloads
+pub fn unsubscribe_podcast(id: u64, delete_downloads: bool) -> Result<(), String> {
+    let podcast = with_subscriptions(|subscriptions| {
+        let index = subscriptions
+            .podcasts
+            .iter()
+            .position(|p| p.id == id)
+            .ok_or_else(|| format!("There is no podcast with id {id}"))?;
+        Ok(subscriptions.podcasts.remove(index))
+    })?;
+    if delete_downloads {
+        for path in podcast
+            .episodes
+            .iter()
+            .filter_map(|e| e.downloaded_path.as_ref())
+        {
+            let _ = fs::remove_file(path);
+        }
+    }
+    Ok(())
+}
+
+pub fn list_podcasts() -> Vec<Podcast> {
+    let _guard = PODCAST_LOCK.lock().unwrap();
+    load_subscriptions().podcasts
+}
+
+pub fn get_podcast(id: u64) -> Result<Podcast, String> {
+    list_podcasts()
+        .into_iter()
+        .find(|p| p.id == id)
+        .ok_or_else(|| format!("There is no podcast with id {id}"))
+}
+
+pub fn set_podcast_auto_download(
+    id: u64,
+    enabled: bool,
+    keep_downloaded: Option<u32>,
+) -> Result<(), String> {
+    with_subscriptions(|subscriptions| {
+        let podcast = podcast_mut(subscriptions, id)?;
+        podcast.auto_download = enabled;
+        podcast.keep_downloaded = keep_downloaded;
+        Ok(())
+    })
+}
+
+// Fetches the feed again and returns the guids of episodes that were not there before
+pub fn refresh_podcast(id: u64) -> Result<Vec<String>, String> {
+    let feed_url = get_podcast(id)?.feed_url;
+    let episodes = fetch_feed(&feed_url)?.episodes;
+    with_subscriptions(|subscriptions| {
+        let podcast = podcast_mut(subscriptions, id)?;
+        podcast.last_refreshed = Some(now());
+        Ok(merge_episodes(podcast, episodes))
+    })
+}
+
+pub fn refresh_all_podcasts() -> Vec<PodcastRefresh> {
+    list_podcasts()
+        .into_iter()
+        .map(|podcast| match refresh_podcast(podcast.id) {
+            Ok(new_episodes) => PodcastRefresh {
+                podcast_id: podcast.id,
+                new_episodes,
+                error: None,
+            },
+            Err(e) => PodcastRefresh {
+                podcast_id: podcast.id,
+                new_episodes: Vec::new(),
+                error: Some(e),
+            },
+        })
+        .collect()
+}
+
+fn podcast_folder() -> Result<PathBuf, String> {
+    with_store(|store| PathBuf::from(&store.podcast_folder))
+        .ok_or("The value store is not initialised".to_string())
+}
+
+// Enclosure urls often end in tracking redirects, the mime type is the fallback
+fn episode_extension(episode: &FeedEpisode) -> String {
+    let from_url = episode.enclosure_url.as_deref().and_then(|url| {
+        let path = url.split(['?', '#']).next()?;
+        let ext = path.rsplit_once('.')?.1;
+        (ext.len() <= 4 && !ext.is_empty() && ext.chars().all(|c| c.is_ascii_alphanumeric()))
+            .then(|| ext.to_ascii_lowercase())
+    });
+    from_url.unwrap_or_else(|| {
+        match episode.enclosure_type.as_deref().unwrap_or_default() {
+            "audio/mp4" | "audio/x-m4a" | "audio/m4a" => "m4a",
+            "audio/x-m4b" => "m4b",
+            "audio/ogg" => "ogg",
+            "audio/opus" => "opus",
+            "audio/aac" => "aac",
+            "audio/flac" => "flac",
+            "audio/wav" | "audio/x-wav" => "wav",
+            _ => "mp3",
+        }
+        .to_string()
+    })
+}
+
+fn emit_download(event: EpisodeDownload) {
+    DOWNLOAD_SINKS
+        .lock()
+        .unwrap()
+        .retain(|sink| sink.add(event.clone()).is_ok());
+}
+
+fn copy_enclosure(
+    url: &str,
+    to: &Path,
+    cancel: &AtomicBool,
+    mut progress: impl FnMut(u64, Option<u64>),
+) -> Result<(), String> {
+    if !is_http(url) {
+        fs::copy(local_path(url), to).map_err(|e| format!("Failed to copy {url}: {e}"))?;
+        return Ok(());
+    }
+    let mut response = DOWNLOAD_CLIENT
+        .get(url)
+        .send()
+        .and_then(|r| r.error_for_status())
+        .map_err(|e| format!("Failed to download {url}: {e}"))?;
+    let total = response.content_length();
+    let mut file =
+        fs::File::create(to).map_err(|e| format!("Failed to create {}: {e}", to.display()))?;
+    let mut buf = vec![0; 64 * 1024];
+    let mut downloaded = 0;
+    loop {
+        if cancel.load(Ordering::SeqCst) {
+            return Err("Download cancelled".to_string());
+        }
+        let read = response
+            .read(&mut buf)
+            .map_err(|e| format!("Failed to download {url}: {e}"))?;
+        if read == 0 {
+            break;
+        }
+        file.write_all(&buf[..read])
+            .map_err(|e| format!("Failed to write {}: {e}", to.display()))?;
+        downloaded += read as u64;
+        progress(downloaded, total);
+    }
+    file.flush()
+        .map_err(|e| format!("Failed to write {}: {e}", to.display()))
+}
+
+// Downloads an episode into <podcast folder>/<podcast>/ and returns where it went. Blocks until
+// the download is done, an episode that is already downloaded returns straight away
+pub fn download_episode(podcast_id: u64, guid: String) -> Result<String, String> {
+    let (podcast, episode) = find_episode(podcast_id, &guid)?;
+    if let Some(path) = episode.downloaded_path.filter(|p| Path::new(p).is_file()) {
+        return Ok(path);
+    }
+    let url = episode
+        .feed
+        .enclosure_url
+        .clone()
+        .ok_or(format!("{} has nothing to download", episode.feed.title))?;
+
+    let folder = podcast_folder()?.join(sanitize_component(&podcast.title));
+    fs::create_dir_all(&folder)
+        .map_err(|e| format!("Failed to create {}: {e}", folder.display()))?;
+    let date = episode
+        .feed
+        .published
+        .and_then(|p| chrono::DateTime::from_timestamp(p, 0))
+        .map(|d| d.format("%Y-%m-%d ").to_string())
+        .unwrap_or_default();
+    let name = format!(
+        "{date}{}.{}",
+        episode.feed.title,
+        episode_extension(&episode.feed)
+    );
+    let target = folder.join(sanitize_component(&name));
+    let mut to = target.clone();
+    let mut n = 1;
+    while to.exists() {
+        n += 1;
+        to = with_collision_suffix(&target, n);
+    }
+    let mut part = to.clone().into_os_string();
+    part.push(".part");
+    let part = PathBuf::from(part);
+
+    let key = (podcast_id, guid.clone());
+    let cancel = Arc::new(AtomicBool::new(false));
+    {
+        let mut active = ACTIVE_DOWNLOADS.lock().unwrap();
+        if active.contains_key(&key) {
+            return Err(format!("{} is already downloading", episode.feed.title));
+        }
+        active.insert(key.clone(), Arc::clone(&cancel));
+    }
+
+    let event = |downloaded_bytes, total_bytes| EpisodeDownload {
+        podcast_id,
+        guid: guid.clone(),
+        downloaded_bytes,
+        total_bytes,
+        finished: false,
+        path: None,
+        error: None,
+    };
+    let mut last_emit: Option<Instant> = None;
+    let mut last_progress = (0, None);
+    let result = copy_enclosure(&url, &part, &cancel, |downloaded, total| {
+        last_progress = (downloaded, total);
+        if last_emit.is_none_or(|t| t.elapsed() >= PROGRESS_EVENT_INTERVAL) {
+            last_emit = Some(Instant::now());
+            emit_download(event(downloaded, total));
+        }
+    })
+    .and_then(|_| {
+        fs::rename(&part, &to).map_err(|e| format!("Failed to move the download into place: {e}"))
+    })
+    .and_then(|_| {
+        let path = to.to_string_lossy().to_string();
+        with_subscriptions(|subscriptions| {
+            episode_mut(subscriptions, podcast_id, &guid)?.downloaded_path = Some(path.clone());
+            Ok(path)
+        })
+    });
+    ACTIVE_DOWNLOADS.lock().unwrap().remove(&key);
+    if result.is_err() {
+        let _ = fs::remove_file(&part);
+    }
+
+    emit_download(EpisodeDownload {
+        finished: true,
+        path: result.as_ref().ok().cloned(),
+        error: result.as_ref().err().cloned(),
+        ..event(last_progress.0, last_progress.1)
+    });
+    result
+}
+
+pub fn cancel_episode_download(podcast_id: u64, guid: String) -> bool {
+    match ACTIVE_DOWNLOADS.lock().unwrap().get(&(podcast_id, guid)) {
+        Some(cancel) => {
+            cancel.store(true, Ordering::SeqCst);
+            true
+        }
+        None => false,
+    }
+}
+
+pub fn delete_episode_download(podcast_id: u64, guid: String) -> Result<(), String> {
+    let path = with_subscriptions(|subscriptions| {
+        Ok(episode_mut(subscriptions, podcast_id, &guid)?
+            .downloaded_path
+            .take())
+    })?;
+    if let Some(path) = path {
+        match fs::remove_file(&path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                return Err(format!("Failed to delete {path}: {e}"));
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+pub fn watch_episode_downloads(sink: StreamSink<EpisodeDownload>) {
+    DOWNLOAD_SINKS.lock().unwrap().push(sink);
+}
+
+// Chapters written into the feed, or the episode's Podcasting 2.0 chapters file which is fetched
+// the first time and kept
+pub fn get_episode_chapters(podcast_id: u64, guid: String) -> Result<Vec<PodcastChapter>, String> {
+    let (_, episode) = find_episode(podcast_id, &guid)?;
+    if !episode.feed.chapters.is_empty() {
+        return Ok(episode.feed.chapters);
+    }
+    let Some(url) = episode.feed.chapters_url else {
+        return Ok(Vec::new());
+    };
+    let chapters = parse_json_chapters(&fetch_text(&url)?)?;
+    with_subscriptions(|subscriptions| {
+        episode_mut(subscriptions, podcast_id, &guid)?.feed.chapters = chapters.clone();
+        Ok(chapters)
+    })
+}
+
+// Saves where an episode was left, which also marks it played when that is at the very end
+pub fn set_episode_position(
+    podcast_id: u64,
+    guid: String,
+    position_secs: f64,
+) -> Result<(), String> {
+    with_subscriptions(|subscriptions| {
+        let episode = episode_mut(subscriptions, podcast_id, &guid)?;
+        episode.position_secs = position_secs.max(0.0);
+        if let Some(duration) = episode.feed.duration_secs
+            && duration > 0
+            && position_secs >= duration as f64 - PLAYED_MARGIN_SECS
+        {
+            episode.played = true;
+            episode.position_secs = 0.0;
+        }
+        Ok(())
+    })
+}
+
+pub fn get_episode_position(podcast_id: u64, guid: String) -> Result<f64, String> {
+    Ok(find_episode(podcast_id, &guid)?.1.position_secs)
+}
+
+pub fn mark_episode_played(podcast_id: u64, guid: String, played: bool) -> Result<(), String> {
+    with_subscriptions(|subscriptions| {
+        let episode = episode_mut(subscriptions, podcast_id, &guid)?;
+        episode.played = played;
+        episode.position_secs = 0.0;
+        Ok(())
+    })
+}
+
+// Saves the position once the episode is paused, stopped or replaced. Saving rewrites every
+// subscription so while the episode plays on it is only saved every POSITION_SAVE_INTERVAL, in
+// case the app is quit or dies
+fn track_position(podcast_id: u64, guid: String, path: String, resume_at: f64) {
+    let generation = TRACK_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    thread::spawn(move || {
+        if resume_at > 0.0 {
+            seek_when_buffered(&path, resume_at as f32, RESUME_TIMEOUT);
+        }
+        let mut position = resume_at;
+        let mut saved = resume_at;
+        let mut last_save = Instant::now();
+        loop {
+            thread::sleep(POSITION_POLL_INTERVAL);
+            let current = TRACK_GENERATION.load(Ordering::SeqCst) == generation
+                && get_current_song_path().as_deref() == Some(path.as_str());
+            if current {
+                // Once something else plays the player position is not this episode's
+                position = get_playback_position() as f64;
+                if is_playing() && last_save.elapsed() < POSITION_SAVE_INTERVAL {
+                    continue;
+                }
+            }
+            if position != saved {
+                if let Err(e) = set_episode_position(podcast_id, guid.clone(), position) {
+                    println!("Failed to save the episode position: {e}");
+                    break;
+                }
+                saved = position;
+                last_save = Instant::now();
+            }
+            if !current {
+                break;
+            }
+        }
+    });
+}
+
+// Plays the downloaded file, or streams the episode when it has not been downloaded, and picks
+// up where it was left. Only downloaded episodes can resume part way through, a stream cannot
+// seek so a streamed episode always starts from the beginning and only its position is saved
+pub fn play_episode(podcast_id: u64, guid: String) -> Result<bool, String> {
+    let (_, episode) = find_episode(podcast_id, &guid)?;
+    let downloaded = episode.downloaded_path.filter(|p| Path::new(p).is_file());
+    let resume_at = if downloaded.is_some() && !episode.played {
+        episode.position_secs
+    } else {
+        0.0
+    };
+    let path = downloaded
+        .or(episode.feed.enclosure_url)
+        .ok_or(format!("{} has nothing to play", episode.feed.title))?;
+    if !play_song(path.clone()) {
+        return Ok(false);
+    }
+    track_position(podcast_id, guid, path, resume_at);
+    Ok(true)
+}
+
+fn prune_downloads(podcast: &Podcast, keep: u32) {
+    let old: Vec<(String, String)> = podcast
+        .episodes
+        .iter()
+        .filter_map(|e| Some((e.feed.guid.clone(), e.downloaded_path.clone()?)))
+        .skip(keep as usize)
+        .collect();
+    for (guid, _) in old {
+        if let Err(e) = delete_episode_download(podcast.id, guid) {
+            println!("Failed to remove an old episode of {}: {e}", podcast.title);
+        }
+    }
+}
+
+fn run_schedule() {
+    for refresh in refresh_all_podcasts() {
+        if let Some(e) = refresh.error {
+            println!("Failed to refresh podcast {}: {e}", refresh.podcast_id);
+            continue;
+        }
+        let Ok(podcast) = get_podcast(refresh.podcast_id) else {
+            continue;
+        };
+        if !podcast.auto_download {
+            continue;
+        }
+        for guid in refresh.new_episodes {
+            if let Err(e) = download_episode(podcast.id, guid) {
+                println!("Failed to download an episode of {}: {e}", podcast.title);
+            }
+        }
+        if let Some(keep) = podcast.keep_downloaded
+            && let Ok(podcast) = get_podcast(podcast.id)
+        {
+            prune_downloads(&podcast, keep);
+        }
+    }
+}
+
+// Refreshes every feed now and then every interval_minutes, downloading new episodes of podcasts
+// with auto_download. Starting it again replaces the running schedule
+pub fn start_podcast_schedule(interval_minutes: u32) {
+    let generation = SCHEDULE_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let interval = Duration::from_secs(interval_minutes.max(1) as u64 * 60);
+    thread::spawn(move || {
+        while SCHEDULE_GENERATION.load(Ordering::SeqCst) == generation {
+            run_schedule();
+            let next = Instant::now() + interval;
+            while Instant::now() < next {
+                if SCHEDULE_GENERATION.load(Ordering::SeqCst) != generation {
+                    return;
+                }
+                thread::sleep(Duration::from_secs(1));
+            }
+        }
+    });
+}
+
+pub fn stop_podcast_schedule() {
+    SCHEDULE_GENERATION.fetch_add(1, Ordering::SeqCst);
+}
@@ -0,0 +1,117 @@
+use crate::api::{
+    http_stream::is_stream_url, music_handler::play_song, smart_playlist::music_folder,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+// Stations live next to the playlists as .adiradio/<name>.json, one file each
+const STATIONS_DIR: &str = ".adiradio";
+
+static STATION_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RadioStation {
+    pub name: String,
+    // The stream itself, or a .pls, .m3u or HLS .m3u8 link to it
+    pub url: String,
+    pub genre: Option<String>,
+    pub homepage: Option<String>,
+}
+
+fn stations_dir() -> Result<PathBuf, String> {
+    Ok(PathBuf::from(music_folder()?).join(STATIONS_DIR))
+}
+
+fn station_file(name: &str) -> Result<PathBuf, String> {
+    if name.trim().is_empty() || name.contains('/') || name.starts_with('.') {
+        return Err(format!("{name} is not a valid station name"));
+    }
+    Ok(stations_dir()?.join(format!("{name}.json")))
+}
+
+fn read_station(path: &Path) -> Option<RadioStation> {
+    serde_json::from_str(&fs::read_to_string(path).ok()?).ok()
+}
+
+fn write_station(station: &RadioStation) -> Result<(), String> {
+    if !is_stream_url(&station.url) {
+        return Err(format!("{} is not an http or https url", station.url));
+    }
+    let path = station_file(&station.name)?;
+    fs::create_dir_all(stations_dir()?)
+        .map_err(|e| format!("Failed to create the stations folder: {e}"))?;
+    let tmp = path.with_extension("json.tmp");
+    let json = serde_json::to_string(station)
+        .map_err(|e| format!("Failed to serialise the station: {e}"))?;
+    fs::write(&tmp, json)
+        .and_then(|_| fs::rename(&tmp, &path))
+        .map_err(|e| format!("Failed to save {}: {e}", station.name))
+}
+
+pub fn list_stations() -> Vec<RadioStation> {
+    let Ok(entries) = stations_dir().and_then(|dir| fs::read_dir(dir).map_err(|e| e.to_string()))
+    else {
+        return Vec::new();
+    };
+    let mut stations: Vec<RadioStation> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|p| read_station(&p))
+        .collect();
+    stations.sort_by_key(|s| s.name.to_lowercase());
+    stations
+}
+
+pub fn get_station(name: String) -> Result<RadioStation, String> {
+    read_station(&station_file(&name)?).ok_or(format!("There is no station called {name}"))
+}
+
+pub fn add_station(station: RadioStation) -> Result<(), String> {
+    let _guard = STATION_LOCK.lock().unwrap();
+    if station_file(&station.name)?.exists() {
+        return Err(format!("{} already exists", station.name));
+    }
+    write_station(&station)
+}
+
+// Replaces the station called name, which may also rename it
+pub fn update_station(name: String, station: RadioStation) -> Result<(), String> {
+    let _guard = STATION_LOCK.lock().unwrap();
+    let old = station_file(&name)?;
+    if !old.exists() {
+        return Err(format!("There is no station called {name}"));
+    }
+    let renamed = station.name != name;
+    if renamed && station_file(&station.name)?.exists() {
+        return Err(format!("{} already exists", station.name));
+    }
+    write_station(&station)?;
+    if renamed {
+        fs::remove_file(&old).map_err(|e| format!("Failed to remove the old {name}: {e}"))?;
+    }
+    Ok(())
+}
+
+pub fn delete_station(name: String) -> Result<(), String> {
+    let _guard = STATION_LOCK.lock().unwrap();
+    fs::remove_file(station_file(&name)?).map_err(|e| format!("Failed to delete {name}: {e}"))
+}
+
+pub fn play_station(name: String) -> Result<bool, String> {
+    let station = get_station(name)?;
+    Ok(play_song(station.url))
+}
+
+// Names the current song after the station while the stream has not said what is playing
+#[flutter_rust_bridge::frb(ignore)]
+pub fn station_name_for_url(url: &str) -> Option<String> {
+    list_stations()
+        .into_iter()
+        .find(|s| s.url == url)
+        .map(|s| s.name)
+}
@@ -0,0 +1,235 @@
+use crate::api::cue_sheet::reject_virtual_track;
+use anyhow::Context;
+use id3::{ErrorKind, TagLike, Version, frame::ExtendedText};
+use mp4ameta::{Data, FreeformIdent};
+use std::{f64::consts::PI, path::Path};
+
+// ReplayGain 2.0 aims every track at -18 LUFS
+const REFERENCE_LUFS: f64 = -18.0;
+const ABSOLUTE_GATE_LUFS: f64 = -70.0;
+const RELATIVE_GATE_LU: f64 = -10.0;
+// Loudness is measured over 400ms blocks overlapping by 75%, so in steps of 100ms
+const BLOCK_STEPS: usize = 4;
+const GAIN_KEY: &str = "REPLAYGAIN_TRACK_GAIN";
+const PEAK_KEY: &str = "REPLAYGAIN_TRACK_PEAK";
+
+#[derive(Debug, Clone, Copy)]
+pub struct ReplayGain {
+    pub loudness_lufs: f64,
+    pub gain_db: f64,
+    // The largest sample, 1.0 is full scale
+    pub peak: f64,
+}
+
+// A biquad in direct form I, one per channel per stage
+#[derive(Clone, Copy, Default)]
+struct Biquad {
+    b: [f64; 3],
+    a: [f64; 3],
+    x: [f64; 2],
+    y: [f64; 2],
+}
+
+impl Biquad {
+    fn process(&mut self, input: f64) -> f64 {
+        let output = self.b[0] * input + self.b[1] * self.x[0] + self.b[2] * self.x[1]
+            - self.a[1] * self.y[0]
+            - self.a[2] * self.y[1];
+        self.x = [input, self.x[0]];
+        self.y = [output, self.y[0]];
+        output
+    }
+}
+
+// The K-weighting filter from ITU-R BS.1770, a high shelf then a high pass, worked out for any
+// sample rate the same way libebur128 does it
+fn k_weighting(sample_rate: f64) -> [Biquad; 2] {
+    let k = (PI * 1681.974450955533 / sample_rate).tan();
+    let q = 0.7071752369554196;
+    let vh = 10f64.powf(3.999843853973347 / 20.0);
+    let vb = vh.powf(0.4996667741545416);
+    let a0 = 1.0 + k / q + k * k;
+    let shelf = Biquad {
+        b: [
+            (vh + vb * k / q + k * k) / a0,
+            2.0 * (k * k - vh) / a0,
+            (vh - vb * k / q + k * k) / a0,
+        ],
+        a: [1.0, 2.0 * (k * k - 1.0) / a0, (1.0 - k / q + k * k) / a0],
+        ..Default::default()
+    };
+
+    let k = (PI * 38.13547087602444 / sample_rate).tan();
+    let q = 0.5003270373238773;
+    let a0 = 1.0 + k / q + k * k;
+    let high_pass = Biquad {
+        b: [1.0, -2.0, 1.0],
+        a: [1.0, 2.0 * (k * k - 1.0) / a0, (1.0 - k / q + k * k) / a0],
+        ..Default::default()
+    };
+    [shelf, high_pass]
+}
+
+fn to_lufs(mean_square: f64) -> f64 {
+    -0.691 + 10.0 * mean_square.log10()
+}
+
+// Integrated loudness with the absolute and relative gates, from the mean square of every 100ms step
+fn integrated_loudness(steps: &[f64]) -> Option<f64> {
+    let blocks: Vec<f64> = steps
+        .windows(BLOCK_STEPS)
+        .map(|w| w.iter().sum::<f64>() / BLOCK_STEPS as f64)
+        .filter(|&z| z > 0.0 && to_lufs(z) > ABSOLUTE_GATE_LUFS)
+        .collect();
+    if blocks.is_empty() {
+        return None;
+    }
+    let relative_gate =
+        to_lufs(blocks.iter().sum::<f64>() / blocks.len() as f64) + RELATIVE_GATE_LU;
+    let gated: Vec<f64> = blocks
+        .into_iter()
+        .filter(|&z| to_lufs(z) > relative_gate)
+        .collect();
+    if gated.is_empty() {
+        return None;
+    }
+    Some(to_lufs(gated.iter().sum::<f64>() / gated.len() as f64))
+}
+
+fn analyse(path: &Path) -> anyhow::Result<ReplayGain> {
+    let src = std::fs::File::open(path).context("Failed to open file")?;
+    let mss = symphonia::core::io::MediaSourceStream::new(Box::new(src), Default::default());
+    let mut hint = symphonia::core::probe::Hint::new();
+    if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+        hint.with_extension(ext);
+    }
+    let probed = symphonia::default::get_probe()
+        .format(&hint, mss, &Default::default(), &Default::default())
+        .context("Unsupported format")?;
+    let mut format = probed.format;
+    let track = format
+        .tracks()
+        .iter()
+        .find(|t| t.codec_params.codec != symphonia::core::codecs::CODEC_TYPE_NULL)
+        .context("No supported audio tracks")?;
+    let track_id = track.id;
+    let sample_rate = track.codec_params.sample_rate.unwrap_or(44100);
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &Default::default())
+        .context("Unsupported codec")?;
+
+    let mut filters = Vec::new();
+    let step_len = (sample_rate as usize / 10).max(1);
+    let mut steps = Vec::new();
+    let mut step_sum = 0.0;
+    let mut step_frames = 0;
+    let mut peak: f64 = 0.0;
+    let mut buffer = None;
+
+    while let Ok(packet) = format.next_packet() {
+        if packet.track_id() != track_id {
+            continue;
+        }
+        let decoded = match decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            Err(symphonia::core::errors::Error::DecodeError(_)) => continue,
+            Err(_) => break,
+        };
+        let buf = buffer.get_or_insert_with(|| {
+            symphonia::core::audio::SampleBuffer::<f32>::new(
+                decoded.capacity() as u64,
+                *decoded.spec(),
+            )
+        });
+        let channels = decoded.spec().channels.count().max(1);
+        // Some streams only reveal their layout once decoding starts
+        if filters.len() != channels {
+            filters = vec![k_weighting(sample_rate as f64); channels];
+        }
+        buf.copy_interleaved_ref(decoded);
+
+        for frame in buf.samples().chunks_exact(channels) {
+            for (channel, &sample) in frame.iter().enumerate() {
+                let sample = sample as f64;
+                peak = peak.max(sample.abs());
+                let [shelf, high_pass] = &mut filters[channel];
+                let weighted = high_pass.process(shelf.process(sample));
+                step_sum += weighted * weighted;
+            }
+            step_frames += 1;
+            if step_frames == step_len {
+                steps.push(step_sum / step_len as f64);
+                step_sum = 0.0;
+                step_frames = 0;
+            }
+        }
+    }
+
+    let loudness = integrated_loudness(&steps).context("The track is silent or too short")?;
+    Ok(ReplayGain {
+        loudness_lufs: loudness,
+        gain_db: REFERENCE_LUFS - loudness,
+        peak,
+    })
+}
+
+// Measures a track's loudness the ReplayGain 2.0 way (EBU R128 gating, -18 LUFS reference)
+pub fn compute_replay_gain(path: String) -> Result<ReplayGain, String> {
+    analyse(Path::new(&path)).map_err(|e| format!("{e:#}"))
+}
+
+// Stores the gain and peak as REPLAYGAIN_TRACK_* tags, which most players read
+pub fn write_replay_gain(path: String, gain: ReplayGain) -> Result<(), String> {
+    reject_virtual_track(&path)?;
+    let path = Path::new(&path);
+    let gain_text = format!("{:.2} dB", gain.gain_db);
+    let peak_text = format!("{:.6}", gain.peak);
+    let ext = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+
+    match ext.as_str() {
+        "mp3" => {
+            let mut tag = match id3::Tag::read_from_path(path) {
+                Ok(tag) => tag,
+                Err(e) if matches!(e.kind, ErrorKind::NoTag) => id3::Tag::new(),
+                Err(e) => return Err(format!("Failed to read the ID3 tag: {e}")),
+            };
+            for (key, value) in [(GAIN_KEY, gain_text), (PEAK_KEY, peak_text)] {
+                tag.remove_extended_text(Some(key), None);
+                tag.add_frame(ExtendedText {
+                    description: key.to_string(),
+                    value,
+                });
+            }
+            tag.write_to_path(path, Version::Id3v24)
+                .map_err(|e| format!("Failed to write the ID3 tag: {e}"))
+        }
+        "flac" => {
+            let mut tag = metaflac::Tag::read_from_path(path)
+                .map_err(|e| format!("Failed to read the FLAC tags: {e}"))?;
+            tag.set_vorbis(GAIN_KEY, vec![gain_text]);
+            tag.set_vorbis(PEAK_KEY, vec![peak_text]);
+            tag.save()
+                .map_err(|e| format!("Failed to write the FLAC tags: {e}"))
+        }
+        "m4a" | "m4b" | "mp4" => {
+            let mut tag = mp4ameta::Tag::read_from_path(path)
+                .map_err(|e| format!("Failed to read the MP4 tags: {e}"))?;
+            // iTunes style freeform atoms, named in lower case by convention
+            for (key, value) in [(GAIN_KEY, gain_text), (PEAK_KEY, peak_text)] {
+                let name = key.to_lowercase();
+                tag.set_data(
+                    FreeformIdent::new("com.apple.iTunes", &name),
+                    Data::Utf8(value),
+                );
+            }
+            tag.write_to_path(path)
+                .map_err(|e| format!("Failed to write the MP4 tags: {e}"))
+        }
+        _ => Err(format!(
+            "ReplayGain tags are not supported for .{ext} files"
+        )),
+    }
+}
@@ -0,0 +1,631 @@
+use crate::api::{
+    library_search::indexed_library,
+    music_handler::{SongMetadata, scan_music_directory},
+    play_stats::{PlayStat, play_stats_snapshot, stat_key},
+    value_store::with_store,
+};
+use audiotags::Tag;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::SystemTime,
+};
+
+// A smart playlist is a normal .adilists/<name>/ folder with this file in it, so everything that
+// lists or opens playlists already finds it
+const SMART_RULE_FILE: &str = ".smart";
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+const FIELDS: [&str; 14] = [
+    "title",
+    "artist",
+    "album",
+    "album_artist",
+    "genre",
+    "year",
+    "track",
+    "disc",
+    "duration",
+    "play_count",
+    "last_played",
+    "added",
+    "path",
+    "ext",
+];
+
+// Path to (size, modified time, tags the SongMetadata does not carry)
+type ExtraTagMemo = HashMap<String, (u64, SystemTime, ExtraTags)>;
+
+static EXTRA_TAG_MEMO: Lazy<Mutex<ExtraTagMemo>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+#[derive(Serialize, Deserialize)]
+struct SmartRule {
+    query: String,
+}
+
+#[derive(Clone, Default)]
+struct ExtraTags {
+    album_artist: Option<String>,
+    year: Option<i32>,
+    track: Option<u16>,
+    disc: Option<u16>,
+    // Days since the file was last modified, the closest thing to when it was added
+    added_at: i64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    Number(f64),
+    Op(String),
+    LParen,
+    RParen,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CmpOp {
+    Eq,
+    Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+    Contains,
+    NotContains,
+}
+
+#[derive(Debug, Clone)]
+enum Value {
+    Text(String),
+    Number(f64),
+}
+
+#[derive(Debug, Clone)]
+enum Expr {
+    All,
+    Cmp(String, CmpOp, Value),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+}
+
+#[derive(Debug, Clone)]
+enum OrderBy {
+    Field(String, bool),
+    Random,
+}
+
+#[derive(Debug, Clone)]
+struct SmartQuery {
+    filter: Expr,
+    order: Option<OrderBy>,
+    limit: Option<usize>,
+}
+
+fn tokenize(query: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let chars: Vec<char> = query.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            c if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            '"' | '\'' => {
+                let mut s = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        None => return Err("Unclosed quote in query".to_string()),
+                        Some('\\') if chars.get(i + 1).is_some() => {
+                            s.push(chars[i + 1]);
+                            i += 2;
+                        }
+                        Some(q) if *q == c => {
+                            i += 1;
+                            break;
+                        }
+                        Some(other) => {
+                            s.push(*other);
+                            i += 1;
+                        }
+                    }
+                }
+                tokens.push(Token::Str(s));
+            }
+            '=' | '!' | '<' | '>' | '~' => {
+                let two: String = chars[i..chars.len().min(i + 2)].iter().collect();
+                let op = if matches!(two.as_str(), "!=" | ">=" | "<=" | "!~" | "==") {
+                    two
+                } else if c == '!' {
+                    return Err("Expected != or !~ in query".to_string());
+                } else {
+                    c.to_string()
+                };
+                i += op.chars().count();
+                tokens.push(Token::Op(if op == "==" { "=".to_string() } else { op }));
+            }
+            _ => {
+                let start = i;
+                while i < chars.len()
+                    && !chars[i].is_whitespace()
+                    && !matches!(
+                        chars[i],
+                        '(' | ')' | '=' | '!' | '<' | '>' | '~' | '"' | '\''
+                    )
+                {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                match word.parse::<f64>() {
+                    Ok(n) => tokens.push(Token::Number(n)),
+                    Err(_) => tokens.push(Token::Ident(word)),
+                }
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Ident(w)) if w.eq_ignore_ascii_case(keyword))
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), String> {
+        if self.peek_keyword(keyword) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(format!("Expected {keyword} in query"))
+        }
+    }
+
+    fn parse_query(&mut self) -> Result<SmartQuery, String> {
+        let filter =
+            if self.peek().is_none() || self.peek_keyword("order") || self.peek_keyword("limit") {
+                Expr::All
+            } else {
+                self.parse_or()?
+            };
+
+        let mut order = None;
+        if self.peek_keyword("order") {
+            self.pos += 1;
+            self.expect_keyword("by")?;
+            order = Some(match self.next() {
+                Some(Token::Ident(w)) if w.eq_ignore_ascii_case("random") => OrderBy::Random,
+                Some(Token::Ident(field)) => {
+                    let field = check_field(&field)?;
+                    let descending = if self.peek_keyword("desc") {
+                        self.pos += 1;
+                        true
+                    } else {
+                        if self.peek_keyword("asc") {
+                            self.pos += 1;
+                        }
+                        false
+                    };
+                    OrderBy::Field(field, descending)
+                }
+                _ => return Err("Expected a field or random after ORDER BY".to_string()),
+            });
+        }
+
+        let mut limit = None;
+        if self.peek_keyword("limit") {
+            self.pos += 1;
+            match self.next() {
+                Some(Token::Number(n)) if n >= 0.0 && n.fract() == 0.0 => limit = Some(n as usize),
+                _ => return Err("Expected a whole number after LIMIT".to_string()),
+            }
+        }
+
+        if let Some(token) = self.peek() {
+            return Err(format!("Unexpected {token:?} in query"));
+        }
+        Ok(SmartQuery {
+            filter,
+            order,
+            limit,
+        })
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, String> {
+        let mut left = self.parse_and()?;
+        while self.peek_keyword("or") {
+            self.pos += 1;
+            left = Expr::Or(Box::new(left), Box::new(self.parse_and()?));
+        }
+        Ok(left)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, String> {
+        let mut left = self.parse_not()?;
+        while self.peek_keyword("and") {
+            self.pos += 1;
+            left = Expr::And(Box::new(left), Box::new(self.parse_not()?));
+        }
+        Ok(left)
+    }
+
+    fn parse_not(&mut self) -> Result<Expr, String> {
+        if self.peek_keyword("not") {
+            self.pos += 1;
+            return Ok(Expr::Not(Box::new(self.parse_not()?)));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(Token::LParen) => {
+                let expr = self.parse_or()?;
+                match self.next() {
+                    Some(Token::RParen) => Ok(expr),
+                    _ => Err("Expected ) in query".to_string()),
+                }
+            }
+            Some(Token::Ident(field)) => {
+                let field = check_field(&field)?;
+                let op = match self.next() {
+                    Some(Token::Op(op)) => match op.as_str() {
+                        "=" => CmpOp::Eq,
+                        "!=" => CmpOp::Ne,
+                        ">" => CmpOp::Gt,
+                        ">=" => CmpOp::Ge,
+                        "<" => CmpOp::Lt,
+                        "<=" => CmpOp::Le,
+                        "~" => CmpOp::Contains,
+                        _ => CmpOp::NotContains,
+                    },
+                    Some(Token::Ident(w)) if w.eq_ignore_ascii_case("contains") => CmpOp::Contains,
+                    _ => return Err(format!("Expected a comparison after {field}")),
+                };
+                let value = match self.next() {
+                    Some(Token::Str(s)) => Value::Text(s),
+                    Some(Token::Number(n)) => Value::Number(n),
+                    Some(Token::Ident(w)) => Value::Text(w),
+                    _ => return Err(format!("Expected a value after {field}")),
+                };
+                Ok(Expr::Cmp(field, op, value))
+            }
+            other => Err(format!("Expected a field or ( but found {other:?}")),
+        }
+    }
+}
+
+fn check_field(field: &str) -> Result<String, String> {
+    let field = field.to_lowercase();
+    if FIELDS.contains(&field.as_str()) {
+        Ok(field)
+    } else {
+        Err(format!(
+            "Unknown field {field}, expected one of {}",
+            FIELDS.join(", ")
+        ))
+    }
+}
+
+fn parse_query(query: &str) -> Result<SmartQuery, String> {
+    Parser {
+        tokens: tokenize(query)?,
+        pos: 0,
+    }
+    .parse_query()
+}
+
+fn extra_tags(path: &str) -> ExtraTags {
+    let Ok(meta) = fs::metadata(path) else {
+        return ExtraTags::default();
+    };
+    let modified = meta.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+    if let Some((len, mtime, tags)) = EXTRA_TAG_MEMO.lock().unwrap().get(path)
+        && *len == meta.len()
+        && *mtime == modified
+    {
+        return tags.clone();
+    }
+
+    let tag = Tag::new().read_from_path(path).ok();
+    let t = tag.as_deref();
+    let tags = ExtraTags {
+        album_artist: t.and_then(|t| t.album_artist()).map(str::to_string),
+        year: t.and_then(|t| t.year()),
+        track: t.and_then(|t| t.track_number()),
+        disc: t.and_then(|t| t.disc_number()),
+        added_at: modified
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0),
+    };
+    EXTRA_TAG_MEMO
+        .lock()
+        .unwrap()
+        .insert(path.to_string(), (meta.len(), modified, tags.clone()));
+    tags
+}
+
+// The year tag of a song, remembered until the file changes
+#[flutter_rust_bridge::frb(ignore)]
+pub fn tag_year(path: &str) -> Option<i32> {
+    extra_tags(path).year
+}
+
+struct Facts<'a> {
+    song: &'a SongMetadata,
+    extra: ExtraTags,
+    stat: PlayStat,
+    now: i64,
+}
+
+impl Facts<'_> {
+    // last_played and added are in days ago so "added < 7" means added this week
+    fn get(&self, field: &str) -> Value {
+        let days_ago = |ts: i64| Value::Number(((self.now - ts) / SECONDS_PER_DAY) as f64);
+        let number = |n: Option<f64>| Value::Number(n.unwrap_or(0.0));
+        match field {
+            "title" => Value::Text(self.song.title.clone()),
+            "artist" => Value::Text(self.song.artist.clone()),
+            "album" => Value::Text(self.song.album.clone()),
+            "album_artist" => Value::Text(
+                self.extra
+                    .album_artist
+                    .clone()
+                    .unwrap_or_else(|| self.song.artist.clone()),
+            ),
+            "genre" => Value::Text(self.song.genre.clone()),
+            "year" => number(self.extra.year.map(f64::from)),
+            "track" => number(self.extra.track.map(f64::from)),
+            "disc" => number(self.extra.disc.map(f64::from)),
+            "duration" => Value::Number(self.song.duration as f64),
+            "play_count" => Value::Number(self.stat.play_count as f64),
+            // Never played counts as played infinitely long ago
+            "last_played" if self.stat.last_played == 0 => Value::Number(f64::INFINITY),
+            "last_played" => days_ago(self.stat.last_played),
+            "added" => days_ago(self.extra.added_at),
+            "path" => Value::Text(self.song.path.clone()),
+            "ext" => Value::Text(
+                Path::new(&self.song.path)
+                    .extension()
+                    .map(|e| e.to_string_lossy().to_lowercase())
+                    .unwrap_or_default(),
+            ),
+            _ => Value::Text(String::new()),
+        }
+    }
+}
+
+fn compare(actual: &Value, op: CmpOp, expected: &Value) -> bool {
+    let ordering = match (actual, expected) {
+        (Value::Number(a), Value::Number(b)) => a.partial_cmp(b),
+        // "year > abc" can never be true
+        (Value::Number(_), Value::Text(_)) => return op == CmpOp::Ne,
+        (Value::Text(a), expected) => {
+            let a = a.to_lowercase();
+            let b = match expected {
+                Value::Text(b) => b.to_lowercase(),
+                Value::Number(n) => n.to_string(),
+            };
+            match op {
+                CmpOp::Contains => return a.contains(&b),
+                CmpOp::NotContains => return !a.contains(&b),
+                _ => Some(a.cmp(&b)),
+            }
+        }
+    };
+    let Some(ordering) = ordering else {
+        return false;
+    };
+    match op {
+        CmpOp::Eq => ordering == Ordering::Equal,
+        CmpOp::Ne => ordering != Ordering::Equal,
+        CmpOp::Gt => ordering == Ordering::Greater,
+        CmpOp::Ge => ordering != Ordering::Less,
+        CmpOp::Lt => ordering == Ordering::Less,
+        CmpOp::Le => ordering != Ordering::Greater,
+        // Contains on a number compares it as text
+        CmpOp::Contains | CmpOp::NotContains => {
+            let (Value::Number(a), Value::Number(b)) = (actual, expected) else {
+                return false;
+            };
+            a.to_string().contains(&b.to_string()) == (op == CmpOp::Contains)
+        }
+    }
+}
+
+fn matches(expr: &Expr, facts: &Facts) -> bool {
+    match expr {
+        Expr::All => true,
+        Expr::Cmp(field, op, value) => compare(&facts.get(field), *op, value),
+        Expr::And(a, b) => matches(a, facts) && matches(b, facts),
+        Expr::Or(a, b) => matches(a, facts) || matches(b, facts),
+        Expr::Not(e) => !matches(e, facts),
+    }
+}
+
+// Fisher-Yates with a xorshift seeded from the clock, good enough for shuffling a playlist
+fn shuffle<T>(items: &mut [T]) {
+    let mut state = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0x2545F4914F6CDD1D)
+        | 1;
+    for i in (1..items.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        items.swap(i, (state % (i as u64 + 1)) as usize);
+    }
+}
+
+fn evaluate(query: &SmartQuery, library: Vec<SongMetadata>) -> Vec<SongMetadata> {
+    let stats = play_stats_snapshot();
+    let now = chrono::Utc::now().timestamp();
+    let mut matched: Vec<(Facts, usize)> = Vec::new();
+    for (i, song) in library.iter().enumerate() {
+        let facts = Facts {
+            song,
+            extra: extra_tags(&song.path),
+            stat: stats
+                .get(&stat_key(&song.path))
+                .cloned()
+                .unwrap_or_default(),
+            now,
+        };
+        if matches(&query.filter, &facts) {
+            matched.push((facts, i));
+        }
+    }
+
+    match &query.order {
+        Some(OrderBy::Random) => shuffle(&mut matched),
+        Some(OrderBy::Field(field, descending)) => {
+            matched.sort_by(|(a, _), (b, _)| {
+                let ordering = match (a.get(field), b.get(field)) {
+                    (Value::Number(x), Value::Number(y)) => x.total_cmp(&y),
+                    (Value::Text(x), Value::Text(y)) => x.to_lowercase().cmp(&y.to_lowercase()),
+                    _ => Ordering::Equal,
+                };
+                if *descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+        }
+        None => {}
+    }
+
+    let limit = query.limit.unwrap_or(usize::MAX);
+    let keep: Vec<usize> = matched.iter().take(limit).map(|(_, i)| *i).collect();
+    let mut library: Vec<Option<SongMetadata>> = library.into_iter().map(Some).collect();
+    keep.into_iter().filter_map(|i| library[i].take()).collect()
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+pub fn music_folder() -> Result<String, String> {
+    with_store(|store| store.music_folder.clone())
+        .ok_or("The value store is not initialised".to_string())
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+pub fn playlist_dir(name: &str) -> Result<PathBuf, String> {
+    if name.is_empty() || name.contains('/') || name.starts_with('.') {
+        return Err(format!("{name} is not a valid playlist name"));
+    }
+    Ok(PathBuf::from(music_folder()?).join(".adilists").join(name))
+}
+
+fn read_rule(dir: &Path) -> Option<String> {
+    let content = fs::read_to_string(dir.join(SMART_RULE_FILE)).ok()?;
+    serde_json::from_str::<SmartRule>(&content)
+        .ok()
+        .map(|r| r.query)
+}
+
+// The library as last scanned, only scanning it when nothing has been yet
+fn library(auto_convert: bool) -> Result<Vec<SongMetadata>, String> {
+    match indexed_library() {
+        Some(songs) => Ok(songs),
+        None => Ok(scan_music_directory(music_folder()?, auto_convert)),
+    }
+}
+
+// Used by scan_music_directory so opening a smart playlist folder gives its current contents
+#[flutter_rust_bridge::frb(ignore)]
+pub fn songs_for_dir(dir: &Path, auto_convert: bool) -> Option<Vec<SongMetadata>> {
+    let query = read_rule(dir)?;
+    let query = match parse_query(&query) {
+        Ok(q) => q,
+        Err(e) => {
+            eprintln!("Smart playlist {} has a broken rule: {e}", dir.display());
+            return Some(Vec::new());
+        }
+    };
+    Some(evaluate(&query, library(auto_convert).ok()?))
+}
+
+// Checks a query without saving it, the error says what is wrong with it
+pub fn validate_smart_query(query: String) -> Result<(), String> {
+    parse_query(&query).map(|_| ())
+}
+
+// Creates the smart playlist or replaces its rule, for example
+// genre = "Jazz" AND year >= 1990 AND play_count > 3 ORDER BY random LIMIT 50
+pub fn save_smart_playlist(name: String, query: String) -> Result<(), String> {
+    parse_query(&query)?;
+    let dir = playlist_dir(&name)?;
+    if dir.exists()
+        && read_rule(&dir).is_none()
+        && fs::read_dir(&dir)
+            .map(|mut d| d.next().is_some())
+            .unwrap_or(false)
+    {
+        return Err(format!("{name} is already a normal playlist"));
+    }
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create the playlist folder: {e}"))?;
+    let json = serde_json::to_string(&SmartRule { query })
+        .map_err(|e| format!("Failed to serialise the rule: {e}"))?;
+    fs::write(dir.join(SMART_RULE_FILE), json).map_err(|e| format!("Failed to save the rule: {e}"))
+}
+
+pub fn get_smart_playlist_query(name: String) -> Option<String> {
+    read_rule(&playlist_dir(&name).ok()?)
+}
+
+pub fn is_smart_playlist(name: String) -> bool {
+    get_smart_playlist_query(name).is_some()
+}
+
+pub fn list_smart_playlists() -> Vec<String> {
+    let Ok(mf) = music_folder() else {
+        return Vec::new();
+    };
+    let Ok(entries) = fs::read_dir(PathBuf::from(mf).join(".adilists")) else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| read_rule(p).is_some())
+        .filter_map(|p| p.file_name().map(|n| n.to_string_lossy().to_string()))
+        .collect();
+    names.sort();
+    names
+}
+
+// Evaluates the rule against the library as it is right now, so the result is always current
+pub fn load_smart_playlist(name: String) -> Result<Vec<SongMetadata>, String> {
+    let dir = playlist_dir(&name)?;
+    let query = read_rule(&dir).ok_or(format!("{name} is not a smart playlist"))?;
+    let query = parse_query(&query)?;
+    Ok(evaluate(&query, library(false)?))
+}
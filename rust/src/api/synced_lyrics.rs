@@ -0,0 +1,465 @@
+use crate::{
+    api::{lyrics_editor::saved_offset, music_handler::get_playback_position},
+    frb_generated::StreamSink,
+};
+use once_cell::sync::Lazy;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::{
+        RwLock,
+        atomic::{AtomicU64, Ordering},
+    },
+    thread,
+    time::Duration,
+};
+
+const DEFAULT_WATCH_INTERVAL_MS: u32 = 50;
+
+pub(crate) static ACTIVE_LYRICS: Lazy<RwLock<Option<ActiveLyrics>>> =
+    Lazy::new(|| RwLock::new(None));
+// Bumped whenever a watch starts or stops so older watcher threads know to exit
+static WATCH_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+// The lyrics being displayed and, once edited, saved back
+pub(crate) struct ActiveLyrics {
+    pub doc: LrcDocument,
+    // None for lyrics loaded from a string, they can only be saved to an explicit path
+    pub path: Option<PathBuf>,
+    // The next line tap_sync stamps
+    pub tap_cursor: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct LrcWord {
+    // As written in the file, before the offset is applied
+    pub start_ms: u64,
+    pub text: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct LrcLine {
+    // As written in the file, before the offset is applied. 0 for unsynced lyrics
+    pub start_ms: u64,
+    pub text: String,
+    // Only filled in for Enhanced LRC lines with <mm:ss.xx> word timings
+    pub words: Vec<LrcWord>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LrcDocument {
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub genre: Option<String>,
+    // The song the app cached these lyrics for (the #PATH header)
+    pub song_path: Option<String>,
+    // Positive means the lyrics show up earlier, as in [offset:+500]
+    pub offset_ms: i64,
+    // The per-track adjustment saved next to the lyric file, on top of offset_ms
+    pub user_offset_ms: i64,
+    // From [length:mm:ss], used to tell recordings of the same song apart
+    pub length_ms: Option<u64>,
+    // False for plain text lyrics where no line had a timestamp
+    pub synced: bool,
+    pub lines: Vec<LrcLine>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LyricPosition {
+    pub line_index: u32,
+    // The word being sung, for Enhanced LRC lines
+    pub word_index: Option<u32>,
+    // With the offset applied, ready to seek to
+    pub line_start_ms: u64,
+    // When the next line starts, None on the last line
+    pub line_end_ms: Option<u64>,
+    pub text: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct LyricEvent {
+    pub position_ms: u64,
+    // None before the first line, after the lyrics are cleared or when they are not synced
+    pub current: Option<LyricPosition>,
+}
+
+impl LrcDocument {
+    #[flutter_rust_bridge::frb(ignore)]
+    pub fn apply_offset(&self, ms: u64) -> u64 {
+        (ms as i64 - self.offset_ms - self.user_offset_ms).max(0) as u64
+    }
+
+    // The inverse of apply_offset, for times picked while listening
+    #[flutter_rust_bridge::frb(ignore)]
+    pub fn remove_offset(&self, ms: u64) -> u64 {
+        (ms as i64 + self.offset_ms + self.user_offset_ms).max(0) as u64
+    }
+
+    // Writes the lyrics back out as LRC. Cached lyrics keep the app's # header, anything else gets
+    // standard ID tags. The per-track offset lives in its own file and is left out
+    #[flutter_rust_bridge::frb(ignore)]
+    pub fn to_lrc(&self) -> String {
+        let mut out = String::new();
+        if self.song_path.is_some() {
+            let fields = [
+                ("TITLE", &self.title),
+                ("ARTIST", &self.artist),
+                ("PATH", &self.song_path),
+                ("GENRE", &self.genre),
+                ("ALBUM", &self.album),
+            ];
+            for (key, value) in fields {
+                out.push_str(&format!(
+                    "#{key}: {}\n",
+                    value.as_deref().unwrap_or_default()
+                ));
+            }
+        } else {
+            let fields = [
+                ("ti", &self.title),
+                ("ar", &self.artist),
+                ("al", &self.album),
+            ];
+            for (key, value) in fields {
+                if let Some(value) = value {
+                    out.push_str(&format!("[{key}:{value}]\n"));
+                }
+            }
+        }
+        if let Some(length) = self.length_ms {
+            out.push_str(&format!("[length:{}]\n", format_timestamp(length)));
+        }
+        if self.offset_ms != 0 {
+            out.push_str(&format!("[offset:{:+}]\n", self.offset_ms));
+        }
+
+        for line in &self.lines {
+            if !self.synced {
+                out.push_str(&line.text);
+            } else if line.words.is_empty() {
+                out.push_str(&format!(
+                    "[{}]{}",
+                    format_timestamp(line.start_ms),
+                    line.text
+                ));
+            } else {
+                out.push_str(&format!("[{}]", format_timestamp(line.start_ms)));
+                let words: Vec<String> = line
+                    .words
+                    .iter()
+                    .map(|w| format!("<{}>{}", format_timestamp(w.start_ms), w.text))
+                    .collect();
+                out.push_str(&words.join(" "));
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    // The line (and word) being sung at position_ms
+    #[flutter_rust_bridge::frb(ignore)]
+    pub fn position_at(&self, position_ms: u64) -> Option<LyricPosition> {
+        if !self.synced {
+            return None;
+        }
+        let line_index = self
+            .lines
+            .partition_point(|l| self.apply_offset(l.start_ms) <= position_ms)
+            .checked_sub(1)?;
+        let line = &self.lines[line_index];
+        let word_index = line
+            .words
+            .partition_point(|w| self.apply_offset(w.start_ms) <= position_ms)
+            .checked_sub(1)
+            .map(|i| i as u32);
+        Some(LyricPosition {
+            line_index: line_index as u32,
+            word_index,
+            line_start_ms: self.apply_offset(line.start_ms),
+            line_end_ms: self
+                .lines
+                .get(line_index + 1)
+                .map(|l| self.apply_offset(l.start_ms)),
+            text: line.text.clone(),
+        })
+    }
+}
+
+// [mm:ss], [mm:ss.xx], [mm:ss.xxx] and [mm:ss:xx], without the brackets
+#[flutter_rust_bridge::frb(ignore)]
+pub fn parse_timestamp(tag: &str) -> Option<u64> {
+    let (minutes, rest) = tag.split_once(':')?;
+    let minutes: u64 = minutes.trim().parse().ok()?;
+    let (seconds, fraction) = rest.split_once(['.', ':']).unwrap_or((rest, ""));
+    let seconds: u64 = seconds.trim().parse().ok()?;
+    if seconds >= 60 {
+        return None;
+    }
+    let fraction_ms = if fraction.is_empty() {
+        0
+    } else {
+        let digits: String = fraction.trim().chars().take(3).collect();
+        let value: u64 = digits.parse().ok()?;
+        value * 10u64.pow(3 - digits.len() as u32)
+    };
+    Some(minutes * 60_000 + seconds * 1000 + fraction_ms)
+}
+
+// The [mm:ss.xx] form LRC files are written with, without the brackets
+#[flutter_rust_bridge::frb(ignore)]
+pub fn format_timestamp(ms: u64) -> String {
+    format!(
+        "{:02}:{:02}.{:02}",
+        ms / 60_000,
+        ms / 1000 % 60,
+        ms % 1000 / 10
+    )
+}
+
+// Splits the text after a line's timestamps into words at each <mm:ss.xx> tag. Text before the
+// first tag starts with the line
+fn parse_words(text: &str, line_start: u64) -> (String, Vec<LrcWord>) {
+    let mut words = Vec::new();
+    let mut current_start = line_start;
+    let mut current = String::new();
+    let mut timed = false;
+    let mut rest = text;
+
+    while let Some(open) = rest.find('<') {
+        let Some(close) = rest[open..].find('>') else {
+            break;
+        };
+        let Some(ms) = parse_timestamp(&rest[open + 1..open + close]) else {
+            current.push_str(&rest[..=open]);
+            rest = &rest[open + 1..];
+            continue;
+        };
+        current.push_str(&rest[..open]);
+        if !current.trim().is_empty() {
+            words.push(LrcWord {
+                start_ms: current_start,
+                text: current.trim().to_string(),
+            });
+        }
+        current.clear();
+        current_start = ms;
+        timed = true;
+        rest = &rest[open + close + 1..];
+    }
+    current.push_str(rest);
+    if !current.trim().is_empty() {
+        words.push(LrcWord {
+            start_ms: current_start,
+            text: current.trim().to_string(),
+        });
+    }
+
+    let line_text = words
+        .iter()
+        .map(|w| w.text.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+    if !timed {
+        words.clear();
+    }
+    (line_text, words)
+}
+
+// Parses LRC and Enhanced LRC along with the #TITLE style header the app writes in front of cached
+// lyrics. Lines with several timestamps are repeated at each, and lines come back sorted by time
+#[flutter_rust_bridge::frb(ignore)]
+pub fn parse_lrc_document(content: &str) -> LrcDocument {
+    let mut doc = LrcDocument::default();
+    let mut timed = Vec::new();
+    let mut untimed = Vec::new();
+
+    for line in content.lines() {
+        let line = line.trim().trim_start_matches('\u{feff}');
+        if let Some(header) = line.strip_prefix('#') {
+            if let Some((key, value)) = header.split_once(':') {
+                let value = Some(value.trim().to_string()).filter(|v| !v.is_empty());
+                match key.trim() {
+                    "TITLE" => doc.title = value,
+                    "ARTIST" => doc.artist = value,
+                    "ALBUM" => doc.album = value,
+                    "GENRE" => doc.genre = value,
+                    "PATH" => doc.song_path = value,
+                    _ => {}
+                }
+            }
+            continue;
+        }
+
+        let mut rest = line;
+        let mut stamps = Vec::new();
+        let mut is_tag = false;
+        while let Some(inner) = rest.strip_prefix('[') {
+            let Some(end) = inner.find(']') else {
+                break;
+            };
+            let tag = &inner[..end];
+            match parse_timestamp(tag) {
+                Some(ms) => stamps.push(ms),
+                None => {
+                    is_tag = true;
+                    if let Some((key, value)) = tag.split_once(':') {
+                        let value = value.trim();
+                        let text = Some(value.to_string()).filter(|v| !v.is_empty());
+                        match key.trim().to_lowercase().as_str() {
+                            "ti" => doc.title = doc.title.take().or(text),
+                            "ar" => doc.artist = doc.artist.take().or(text),
+                            "al" => doc.album = doc.album.take().or(text),
+                            "offset" => doc.offset_ms = value.parse().unwrap_or(0),
+                            "length" => doc.length_ms = parse_timestamp(value),
+                            _ => {}
+                        }
+                    }
+                }
+            }
+            rest = &inner[end + 1..];
+        }
+        if is_tag && stamps.is_empty() {
+            continue;
+        }
+
+        if stamps.is_empty() {
+            let (text, _) = parse_words(rest, 0);
+            if !text.is_empty() {
+                untimed.push(LrcLine {
+                    start_ms: 0,
+                    text,
+                    words: Vec::new(),
+                });
+            }
+            continue;
+        }
+        // Word timings belong to the first time a repeated line is sung and shift with the rest
+        let first = stamps[0];
+        let (text, words) = parse_words(rest, first);
+        for start in stamps {
+            timed.push(LrcLine {
+                start_ms: start,
+                text: text.clone(),
+                words: words
+                    .iter()
+                    .map(|w| LrcWord {
+                        start_ms: (w.start_ms + start).saturating_sub(first),
+                        text: w.text.clone(),
+                    })
+                    .collect(),
+            });
+        }
+    }
+
+    // Empty timed lines are kept, they mark where the previous line stops being sung
+    doc.synced = !timed.is_empty();
+    doc.lines = if doc.synced {
+        timed.sort_by_key(|l| l.start_ms);
+        timed
+    } else {
+        untimed
+    };
+    doc
+}
+
+pub fn parse_lrc(content: String) -> LrcDocument {
+    parse_lrc_document(&content)
+}
+
+pub fn lyrics_to_lrc(doc: LrcDocument) -> String {
+    doc.to_lrc()
+}
+
+fn set_active(doc: LrcDocument, path: Option<PathBuf>) {
+    *ACTIVE_LYRICS.write().unwrap() = Some(ActiveLyrics {
+        doc,
+        path,
+        tap_cursor: 0,
+    });
+}
+
+// Makes these the lyrics current_lyric_line, watch_lyrics and the timing editor work on
+pub fn load_synced_lyrics(content: String) -> LrcDocument {
+    let doc = parse_lrc_document(&content);
+    set_active(doc.clone(), None);
+    doc
+}
+
+// Like load_synced_lyrics, with the per-track offset saved for the file applied
+pub fn load_synced_lyrics_file(path: String) -> Result<LrcDocument, String> {
+    let content = fs::read_to_string(&path).map_err(|e| format!("Failed to read {path}: {e}"))?;
+    let mut doc = parse_lrc_document(&content);
+    doc.user_offset_ms = saved_offset(Path::new(&path));
+    set_active(doc.clone(), Some(PathBuf::from(path)));
+    Ok(doc)
+}
+
+pub fn get_synced_lyrics() -> Option<LrcDocument> {
+    ACTIVE_LYRICS
+        .read()
+        .unwrap()
+        .as_ref()
+        .map(|active| active.doc.clone())
+}
+
+pub fn clear_synced_lyrics() {
+    *ACTIVE_LYRICS.write().unwrap() = None;
+}
+
+// position is in seconds like get_playback_position
+pub fn current_lyric_line(position: f32) -> Option<LyricPosition> {
+    let position_ms = (position.max(0.0) * 1000.0) as u64;
+    ACTIVE_LYRICS
+        .read()
+        .unwrap()
+        .as_ref()?
+        .doc
+        .position_at(position_ms)
+}
+
+// Follows the player and sends an event every time the current line or word changes, for karaoke
+// style display. Only one watch runs at a time, starting another or calling stop_lyrics_watch ends it
+pub fn watch_lyrics(sink: StreamSink<LyricEvent>, interval_ms: Option<u32>) -> Result<(), String> {
+    let generation = WATCH_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let interval =
+        Duration::from_millis(interval_ms.unwrap_or(DEFAULT_WATCH_INTERVAL_MS).max(10) as u64);
+
+    thread::Builder::new()
+        .name("lyrics-watch".to_string())
+        .spawn(move || {
+            // Some(None) is a sent "no line", None means nothing has been sent yet
+            let mut last: Option<Option<(u32, Option<u32>, u64)>> = None;
+            while WATCH_GENERATION.load(Ordering::SeqCst) == generation {
+                let position_ms = (get_playback_position().max(0.0) * 1000.0) as u64;
+                let current = ACTIVE_LYRICS
+                    .read()
+                    .unwrap()
+                    .as_ref()
+                    .and_then(|active| active.doc.position_at(position_ms));
+                let key = current
+                    .as_ref()
+                    .map(|c| (c.line_index, c.word_index, c.line_start_ms));
+                if last != Some(key) {
+                    last = Some(key);
+                    // Dart closed the stream
+                    if sink
+                        .add(LyricEvent {
+                            position_ms,
+                            current,
+                        })
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+                thread::sleep(interval);
+            }
+        })
+        .map(|_| ())
+        .map_err(|e| format!("Failed to start the lyrics watch: {e}"))
+}
+
+pub fn stop_lyrics_watch() {
+    WATCH_GENERATION.fetch_add(1, Ordering::SeqCst);
+}
@@ -0,0 +1,365 @@
+use crate::api::{cue_sheet::reject_virtual_track, utils::get_data_dir};
+use audiotags::{AudioTag, Tag};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, fs, path::PathBuf, sync::Mutex};
+
+// Older edits are dropped from the journal once there are more than this
+const MAX_JOURNAL_ENTRIES: usize = 50;
+
+// Edits and undos both rewrite the journal so only one may run at a time
+static JOURNAL_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TagField {
+    Title,
+    Artist,
+    Album,
+    AlbumArtist,
+    Genre,
+    Year,
+    TrackNumber,
+    TotalTracks,
+    DiscNumber,
+    TotalDiscs,
+    Composer,
+    Comment,
+}
+
+// A value of None (or an empty string) removes the field from the file
+#[derive(Debug, Clone)]
+pub struct FieldChange {
+    pub field: TagField,
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TagEdit {
+    pub path: String,
+    pub changes: Vec<FieldChange>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TagEditRecord {
+    pub id: i64,
+    pub timestamp: i64,
+    pub files: u32,
+}
+
+// The values a file had before an edit, only for the fields that edit touched
+#[derive(Serialize, Deserialize, Clone)]
+struct JournalFile {
+    path: String,
+    previous: Vec<(TagField, Option<String>)>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct JournalEntry {
+    id: i64,
+    timestamp: i64,
+    files: Vec<JournalFile>,
+}
+
+impl JournalEntry {
+    fn record(&self) -> TagEditRecord {
+        TagEditRecord {
+            id: self.id,
+            timestamp: self.timestamp,
+            files: self.files.len() as u32,
+        }
+    }
+}
+
+struct PlannedEdit {
+    path: String,
+    tag: Box<dyn AudioTag + Send + Sync>,
+    changes: Vec<(TagField, Option<String>)>,
+    previous: Vec<(TagField, Option<String>)>,
+}
+
+fn journal_path() -> PathBuf {
+    get_data_dir("tags").join("undo_journal.json")
+}
+
+fn load_journal() -> Vec<JournalEntry> {
+    fs::read_to_string(journal_path())
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+// Written to a temporary file first so a crash can not leave a half written journal behind
+fn save_journal(journal: &[JournalEntry]) -> Result<(), String> {
+    let path = journal_path();
+    let tmp = path.with_extension("json.tmp");
+    let json = serde_json::to_string(journal)
+        .map_err(|e| format!("Failed to serialise the undo journal: {e}"))?;
+    fs::write(&tmp, json).map_err(|e| format!("Failed to write the undo journal: {e}"))?;
+    fs::rename(&tmp, &path).map_err(|e| format!("Failed to write the undo journal: {e}"))
+}
+
+fn read_field(tag: &dyn AudioTag, field: TagField) -> Option<String> {
+    match field {
+        TagField::Title => tag.title().map(str::to_string),
+        TagField::Artist => tag.artist().map(str::to_string),
+        TagField::Album => tag.album_title().map(str::to_string),
+        TagField::AlbumArtist => tag.album_artist().map(str::to_string),
+        TagField::Genre => tag.genre().map(str::to_string),
+        TagField::Year => tag.year().map(|y| y.to_string()),
+        TagField::TrackNumber => tag.track_number().map(|n| n.to_string()),
+        TagField::TotalTracks => tag.total_tracks().map(|n| n.to_string()),
+        TagField::DiscNumber => tag.disc_number().map(|n| n.to_string()),
+        TagField::TotalDiscs => tag.total_discs().map(|n| n.to_string()),
+        TagField::Composer => tag.composer().map(str::to_string),
+        TagField::Comment => tag.comment().map(str::to_string),
+    }
+}
+
+// Values have been through normalise_change already so the numbers always parse
+fn write_field(tag: &mut dyn AudioTag, field: TagField, value: Option<&str>) {
+    let number = value.and_then(|v| v.parse::<u16>().ok());
+    match field {
+        TagField::Title => match value {
+            Some(v) => tag.set_title(v),
+            None => tag.remove_title(),
+        },
+        TagField::Artist => match value {
+            Some(v) => tag.set_artist(v),
+            None => tag.remove_artist(),
+        },
+        TagField::Album => match value {
+            Some(v) => tag.set_album_title(v),
+            None => tag.remove_album_title(),
+        },
+        TagField::AlbumArtist => match value {
+            Some(v) => tag.set_album_artist(v),
+            None => tag.remove_album_artist(),
+        },
+        TagField::Genre => match value {
+            Some(v) => tag.set_genre(v),
+            None => tag.remove_genre(),
+        },
+        TagField::Year => match value.and_then(|v| v.parse::<i32>().ok()) {
+            Some(y) => tag.set_year(y),
+            None => tag.remove_year(),
+        },
+        TagField::TrackNumber => match number {
+            Some(n) => tag.set_track_number(n),
+            None => tag.remove_track_number(),
+        },
+        TagField::TotalTracks => match number {
+            Some(n) => tag.set_total_tracks(n),
+            None => tag.remove_total_tracks(),
+        },
+        TagField::DiscNumber => match number {
+            Some(n) => tag.set_disc_number(n),
+            None => tag.remove_disc_number(),
+        },
+        TagField::TotalDiscs => match number {
+            Some(n) => tag.set_total_discs(n),
+            None => tag.remove_total_discs(),
+        },
+        TagField::Composer => match value {
+            Some(v) => tag.set_composer(v.to_string()),
+            None => tag.remove_composer(),
+        },
+        TagField::Comment => match value {
+            Some(v) => tag.set_comment(v.to_string()),
+            None => tag.remove_comment(),
+        },
+    }
+}
+
+fn normalise_change(change: FieldChange) -> Result<(TagField, Option<String>), String> {
+    let value = change
+        .value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty());
+    let Some(v) = value else {
+        return Ok((change.field, None));
+    };
+    match change.field {
+        TagField::Year => match v.parse::<i32>() {
+            Ok(y) if (1..=9999).contains(&y) => Ok((change.field, Some(y.to_string()))),
+            _ => Err(format!("{v} is not a valid year")),
+        },
+        TagField::TrackNumber
+        | TagField::TotalTracks
+        | TagField::DiscNumber
+        | TagField::TotalDiscs => match v.parse::<u16>() {
+            Ok(n) if n > 0 => Ok((change.field, Some(n.to_string()))),
+            _ => Err(format!("{v} is not a valid {:?}", change.field)),
+        },
+        _ => Ok((change.field, Some(v))),
+    }
+}
+
+fn restore_file(file: &JournalFile) -> Result<(), String> {
+    let mut tag = Tag::new()
+        .read_from_path(&file.path)
+        .map_err(|e| format!("Error reading tag: {e}"))?;
+    for (field, value) in &file.previous {
+        write_field(&mut *tag, *field, value.as_deref());
+    }
+    tag.write_to_path(&file.path)
+        .map_err(|e| format!("Error writing tag to path: {e}"))
+}
+
+// Checks every change against every file first and only writes anything if all of them are valid.
+// If writing one file fails the files already written are put back, so the library is never left
+// half edited. The previous values are journaled on disk for undo_last_tag_edit
+pub fn batch_edit_tags(edits: Vec<TagEdit>) -> Result<TagEditRecord, String> {
+    let _guard = JOURNAL_LOCK.lock().unwrap();
+
+    let mut errors = Vec::new();
+    let mut planned = Vec::new();
+    for edit in edits {
+        let mut seen = HashSet::new();
+        let mut changes = Vec::new();
+        for change in edit.changes {
+            if !seen.insert(change.field) {
+                errors.push(format!(
+                    "{}: {:?} is changed twice",
+                    edit.path, change.field
+                ));
+                continue;
+            }
+            match normalise_change(change) {
+                Ok(change) => changes.push(change),
+                Err(e) => errors.push(format!("{}: {e}", edit.path)),
+            }
+        }
+
+        if let Err(e) = reject_virtual_track(&edit.path) {
+            errors.push(e);
+            continue;
+        }
+        let tag = match Tag::new().read_from_path(&edit.path) {
+            Ok(tag) => tag,
+            Err(e) => {
+                errors.push(format!("{}: Error reading tag: {e}", edit.path));
+                continue;
+            }
+        };
+        let previous: Vec<(TagField, Option<String>)> = changes
+            .iter()
+            .map(|(field, _)| (*field, read_field(&*tag, *field)))
+            .collect();
+        // Nothing to write or undo for files that already have these values
+        if previous == changes {
+            continue;
+        }
+        planned.push(PlannedEdit {
+            path: edit.path,
+            tag,
+            changes,
+            previous,
+        });
+    }
+
+    if !errors.is_empty() {
+        return Err(format!(
+            "No tags were changed because of these problems:\n{}",
+            errors.join("\n")
+        ));
+    }
+    if planned.is_empty() {
+        return Err("None of the changes differ from the current tags".to_string());
+    }
+
+    let now = chrono::Utc::now();
+    let entry = JournalEntry {
+        id: now.timestamp_millis(),
+        timestamp: now.timestamp(),
+        files: planned
+            .iter()
+            .map(|p| JournalFile {
+                path: p.path.clone(),
+                previous: p.previous.clone(),
+            })
+            .collect(),
+    };
+    let record = entry.record();
+
+    // Saved before any file is touched so even an edit cut short by a crash can be undone
+    let mut journal = load_journal();
+    journal.push(entry);
+    if journal.len() > MAX_JOURNAL_ENTRIES {
+        journal.drain(..journal.len() - MAX_JOURNAL_ENTRIES);
+    }
+    save_journal(&journal)?;
+
+    let mut written: Vec<JournalFile> = Vec::new();
+    for mut edit in planned {
+        for (field, value) in &edit.changes {
+            write_field(&mut *edit.tag, *field, value.as_deref());
+        }
+        if let Err(e) = edit.tag.write_to_path(&edit.path) {
+            let rollback_errors: Vec<String> = written
+                .iter()
+                .rev()
+                .filter_map(|f| restore_file(f).err().map(|e| format!("{}: {e}", f.path)))
+                .collect();
+            if rollback_errors.is_empty() {
+                journal.pop();
+                let _ = save_journal(&journal);
+                return Err(format!(
+                    "Failed to write tags to {}: {e}. All changes were rolled back",
+                    edit.path
+                ));
+            }
+            // The journal entry is kept so undo_last_tag_edit can finish the rollback later
+            return Err(format!(
+                "Failed to write tags to {}: {e}. Some files could not be rolled back, use undo to retry:\n{}",
+                edit.path,
+                rollback_errors.join("\n")
+            ));
+        }
+        written.push(JournalFile {
+            path: edit.path,
+            previous: edit.previous,
+        });
+    }
+
+    Ok(record)
+}
+
+// Puts back the values the most recent batch edit replaced. Files that can not be restored stay in
+// the journal so calling this again retries just those
+pub fn undo_last_tag_edit() -> Result<TagEditRecord, String> {
+    let _guard = JOURNAL_LOCK.lock().unwrap();
+    let mut journal = load_journal();
+    let Some(mut entry) = journal.pop() else {
+        return Err("There is no tag edit to undo".to_string());
+    };
+    let record = entry.record();
+
+    let mut errors = Vec::new();
+    let mut failed = Vec::new();
+    for file in entry.files.into_iter().rev() {
+        if let Err(e) = restore_file(&file) {
+            errors.push(format!("{}: {e}", file.path));
+            failed.push(file);
+        }
+    }
+
+    if failed.is_empty() {
+        save_journal(&journal)?;
+        return Ok(record);
+    }
+    entry.files = failed;
+    journal.push(entry);
+    save_journal(&journal)?;
+    Err(format!(
+        "Some files could not be restored:\n{}",
+        errors.join("\n")
+    ))
+}
+
+// Newest first
+pub fn get_tag_edit_history() -> Vec<TagEditRecord> {
+    load_journal()
+        .iter()
+        .rev()
+        .map(JournalEntry::record)
+        .collect()
+}
@@ -3,7 +3,13 @@ use flutter_rust_bridge::frb;
 use futures::StreamExt;
 use reqwest::header::USER_AGENT;
 use std::os::unix::fs::PermissionsExt;
-use std::{ffi::OsStr, path::Path};
+use std::{
+    ffi::OsStr,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
 use tokio::io::AsyncWriteExt;
 
 #[frb(ignore)]
@@ -42,6 +48,73 @@ pub fn check_dir(folder: impl AsRef<std::path::Path>) -> bool {
     folder.as_ref().exists() && folder.as_ref().is_dir()
 }
 
+// Hands out evenly spaced request slots so that any number of workers sharing it stay under the
+// rate limit of whatever service it guards
+#[frb(ignore)]
+pub struct RateLimiter {
+    interval: Duration,
+    next_slot: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    #[frb(ignore)]
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            next_slot: Mutex::new(Instant::now()),
+        }
+    }
+
+    // Blocks until the caller is allowed to send its request
+    #[frb(ignore)]
+    pub fn wait(&self) {
+        let slot = {
+            let mut next = self.next_slot.lock().unwrap();
+            let now = Instant::now();
+            let slot = (*next).max(now);
+            *next = slot + self.interval;
+            slot
+        };
+        let now = Instant::now();
+        if slot > now {
+            thread::sleep(slot - now);
+        }
+    }
+}
+
+// Returns (and creates if needed) a named directory under the user's cache dir for things that
+// are expensive to compute but safe to throw away
+#[frb(ignore)]
+pub fn get_cache_dir(name: &str) -> PathBuf {
+    let base = std::env::var("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| {
+            PathBuf::from(std::env::var("HOME").unwrap_or("/tmp".to_string())).join(".cache")
+        });
+    let dir = base.join("adiman").join(name);
+    if !dir.exists() {
+        let _ = std::fs::create_dir_all(&dir);
+    }
+    dir
+}
+
+// Like get_cache_dir but under the user's data dir, for things that would be lost if deleted
+#[frb(ignore)]
+pub fn get_data_dir(name: &str) -> PathBuf {
+    let base = std::env::var("XDG_DATA_HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| {
+            PathBuf::from(std::env::var("HOME").unwrap_or("/tmp".to_string()))
+                .join(".local")
+                .join("share")
+        });
+    let dir = base.join("adiman").join(name);
+    if !dir.exists() {
+        let _ = std::fs::create_dir_all(&dir);
+    }
+    dir
+}
+
 #[frb(ignore)]
 pub fn validate_path(name: impl AsRef<str>) -> bool {
     let path = name.as_ref();
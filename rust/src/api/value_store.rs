@@ -13,6 +13,11 @@ pub static STORE_STATE: AtomicBool = AtomicBool::new(false);
 // The actual value store
 pub static VALUE_STORE: RwLock<Option<ValueStore>> = RwLock::new(None);
 
+pub const DEFAULT_ACOUSTID_BASE_URL: &str = "https://api.acoustid.org/v2";
+pub const DEFAULT_MUSICBRAINZ_BASE_URL: &str = "https://musicbrainz.org/ws/2";
+pub const DEFAULT_COVERART_BASE_URL: &str = "https://coverartarchive.org";
+pub const DEFAULT_LRCLIB_BASE_URL: &str = "https://lrclib.net/api";
+
 #[frb(ignore)]
 #[derive(Clone)]
 pub struct ValueStore {
@@ -21,6 +26,17 @@ pub struct ValueStore {
     pub plugins_enabled: bool,
     pub plugin_rw_dir: String,
     pub unsafe_apis: bool,
+    // A user supplied AcoustID client key, takes priority over the one compiled in
+    pub acoustid_api_key: Option<String>,
+    pub acoustid_base_url: String,
+    pub musicbrainz_base_url: String,
+    pub coverart_base_url: String,
+    pub lrclib_base_url: String,
+    // The yt-dlp executable downloads run, None means whichever yt-dlp is on PATH
+    pub ytdlp_path: Option<String>,
+    // Where podcast episodes are downloaded to, kept out of the music folder so they are not
+    // scanned as songs
+    pub podcast_folder: String,
 }
 
 #[derive(Clone)]
@@ -36,6 +52,13 @@ pub struct ValueStoreUpdate {
     pub plugins_enabled: Option<bool>,
     pub plugin_rw_dir: Option<String>,
     pub unsafe_apis: Option<bool>,
+    pub acoustid_api_key: Option<String>,
+    pub acoustid_base_url: Option<String>,
+    pub musicbrainz_base_url: Option<String>,
+    pub coverart_base_url: Option<String>,
+    pub lrclib_base_url: Option<String>,
+    pub ytdlp_path: Option<String>,
+    pub podcast_folder: Option<String>,
 }
 
 impl Default for ValueStore {
@@ -47,6 +70,13 @@ impl Default for ValueStore {
             plugins_enabled: false,
             plugin_rw_dir: home_dir.join("AdiDir").to_string_lossy().to_string(),
             unsafe_apis: false,
+            acoustid_api_key: None,
+            acoustid_base_url: DEFAULT_ACOUSTID_BASE_URL.to_string(),
+            musicbrainz_base_url: DEFAULT_MUSICBRAINZ_BASE_URL.to_string(),
+            coverart_base_url: DEFAULT_COVERART_BASE_URL.to_string(),
+            lrclib_base_url: DEFAULT_LRCLIB_BASE_URL.to_string(),
+            ytdlp_path: None,
+            podcast_folder: home_dir.join("Podcasts").to_string_lossy().to_string(),
         }
     }
 }
@@ -76,6 +106,51 @@ impl ValueStore {
         }
     }
 
+    pub fn update_acoustid_base_url(&mut self, url: String) -> Result<(), String> {
+        self.acoustid_base_url = validate_base_url(url, DEFAULT_ACOUSTID_BASE_URL)?;
+        Ok(())
+    }
+
+    pub fn update_musicbrainz_base_url(&mut self, url: String) -> Result<(), String> {
+        self.musicbrainz_base_url = validate_base_url(url, DEFAULT_MUSICBRAINZ_BASE_URL)?;
+        Ok(())
+    }
+
+    pub fn update_coverart_base_url(&mut self, url: String) -> Result<(), String> {
+        self.coverart_base_url = validate_base_url(url, DEFAULT_COVERART_BASE_URL)?;
+        Ok(())
+    }
+
+    pub fn update_lrclib_base_url(&mut self, url: String) -> Result<(), String> {
+        self.lrclib_base_url = validate_base_url(url, DEFAULT_LRCLIB_BASE_URL)?;
+        Ok(())
+    }
+
+    // Unlike the music folder this one is created if it does not exist yet
+    pub fn update_podcast_folder(&mut self, folder: String) -> Result<(), String> {
+        std::fs::create_dir_all(&folder)
+            .map_err(|e| format!("Failed to create the podcast folder: {e}"))?;
+        if check_dir(&folder) {
+            self.podcast_folder = folder;
+            Ok(())
+        } else {
+            Err("The provided folder is not a folder or does not exist".to_string())
+        }
+    }
+
+    // An empty path goes back to yt-dlp on PATH, a bare name is also looked up on PATH
+    pub fn update_ytdlp_path(&mut self, path: String) -> Result<(), String> {
+        let path = path.trim();
+        if path.is_empty() {
+            self.ytdlp_path = None;
+        } else if path.contains(std::path::MAIN_SEPARATOR) && !PathBuf::from(path).is_file() {
+            return Err(format!("{path} does not exist or is not a file"));
+        } else {
+            self.ytdlp_path = Some(path.to_string());
+        }
+        Ok(())
+    }
+
     #[frb(ignore)]
     pub fn apply_update(&mut self, update: ValueStoreUpdate) -> Result<(), String> {
         if let Some(folder) = update.music_folder {
@@ -94,6 +169,36 @@ impl ValueStore {
             self.unsafe_apis = uapis;
         }
 
+        // An empty key clears the override so the compiled in key is used again
+        if let Some(key) = update.acoustid_api_key {
+            let key = key.trim().to_string();
+            self.acoustid_api_key = if key.is_empty() { None } else { Some(key) };
+        }
+
+        if let Some(url) = update.acoustid_base_url {
+            self.update_acoustid_base_url(url)?;
+        }
+
+        if let Some(url) = update.musicbrainz_base_url {
+            self.update_musicbrainz_base_url(url)?;
+        }
+
+        if let Some(url) = update.coverart_base_url {
+            self.update_coverart_base_url(url)?;
+        }
+
+        if let Some(url) = update.lrclib_base_url {
+            self.update_lrclib_base_url(url)?;
+        }
+
+        if let Some(path) = update.ytdlp_path {
+            self.update_ytdlp_path(path)?;
+        }
+
+        if let Some(folder) = update.podcast_folder {
+            self.update_podcast_folder(folder)?;
+        }
+
         match update.current_song {
             CurrentSongUpdate::NoChange => {}
             CurrentSongUpdate::SetToNone => {
@@ -116,6 +221,13 @@ pub struct ValueStoreUpdater {
     pub plugins_enabled: Option<bool>,
     pub plugin_rw_dir: Option<String>,
     pub unsafe_apis: Option<bool>,
+    pub acoustid_api_key: Option<String>,
+    pub acoustid_base_url: Option<String>,
+    pub musicbrainz_base_url: Option<String>,
+    pub coverart_base_url: Option<String>,
+    pub lrclib_base_url: Option<String>,
+    pub ytdlp_path: Option<String>,
+    pub podcast_folder: Option<String>,
 }
 
 impl Default for ValueStoreUpdater {
@@ -132,6 +244,13 @@ impl ValueStoreUpdater {
             plugins_enabled: None,
             plugin_rw_dir: None,
             unsafe_apis: None,
+            acoustid_api_key: None,
+            acoustid_base_url: None,
+            musicbrainz_base_url: None,
+            coverart_base_url: None,
+            lrclib_base_url: None,
+            ytdlp_path: None,
+            podcast_folder: None,
         }
     }
 
@@ -159,6 +278,54 @@ impl ValueStoreUpdater {
         self
     }
 
+    // Pass an empty string to go back to the compiled in key
+    #[frb]
+    pub fn set_acoustid_api_key(&mut self, key: String) -> &mut Self {
+        self.acoustid_api_key = Some(key);
+        self
+    }
+
+    // Pass an empty string to go back to api.acoustid.org
+    #[frb]
+    pub fn set_acoustid_base_url(&mut self, url: String) -> &mut Self {
+        self.acoustid_base_url = Some(url);
+        self
+    }
+
+    // Pass an empty string to go back to musicbrainz.org
+    #[frb]
+    pub fn set_musicbrainz_base_url(&mut self, url: String) -> &mut Self {
+        self.musicbrainz_base_url = Some(url);
+        self
+    }
+
+    // Pass an empty string to go back to coverartarchive.org
+    #[frb]
+    pub fn set_coverart_base_url(&mut self, url: String) -> &mut Self {
+        self.coverart_base_url = Some(url);
+        self
+    }
+
+    // Pass an empty string to go back to lrclib.net, or point it at a local server
+    #[frb]
+    pub fn set_lrclib_base_url(&mut self, url: String) -> &mut Self {
+        self.lrclib_base_url = Some(url);
+        self
+    }
+
+    // Pass an empty string to go back to the yt-dlp on PATH
+    #[frb]
+    pub fn set_ytdlp_path(&mut self, path: String) -> &mut Self {
+        self.ytdlp_path = Some(path);
+        self
+    }
+
+    #[frb]
+    pub fn set_podcast_folder(&mut self, folder: String) -> &mut Self {
+        self.podcast_folder = Some(folder);
+        self
+    }
+
     #[frb]
     pub fn set_current_song(&mut self, song: SongMetadata) -> &mut Self {
         self.current_song = CurrentSongUpdate::SetToSome(song);
@@ -179,11 +346,30 @@ impl ValueStoreUpdater {
             plugins_enabled: self.plugins_enabled,
             plugin_rw_dir: self.plugin_rw_dir,
             unsafe_apis: self.unsafe_apis,
+            acoustid_api_key: self.acoustid_api_key,
+            acoustid_base_url: self.acoustid_base_url,
+            musicbrainz_base_url: self.musicbrainz_base_url,
+            coverart_base_url: self.coverart_base_url,
+            lrclib_base_url: self.lrclib_base_url,
+            ytdlp_path: self.ytdlp_path,
+            podcast_folder: self.podcast_folder,
         };
         update_value_store(update)
     }
 }
 
+// Trims a service base url, an empty url means going back to the default server
+fn validate_base_url(url: String, default: &str) -> Result<String, String> {
+    let url = url.trim().trim_end_matches('/');
+    if url.is_empty() {
+        Ok(default.to_string())
+    } else if url.starts_with("http://") || url.starts_with("https://") {
+        Ok(url.to_string())
+    } else {
+        Err(format!("The url {url} must start with http:// or https://"))
+    }
+}
+
 pub fn init_value_store() -> Result<(), String> {
     let mut store = VALUE_STORE.write().map_err(|e| {
         STORE_STATE.store(false, Ordering::SeqCst);
@@ -218,6 +404,12 @@ pub fn acquire_read_lock() -> Result<std::sync::RwLockReadGuard<'static, Option<
     Ok(store)
 }
 
+// Runs f against the store, giving None if it has not been initialised or the lock is poisoned
+#[frb(ignore)]
+pub fn with_store<T>(f: impl FnOnce(&ValueStore) -> T) -> Option<T> {
+    acquire_read_lock().ok()?.as_ref().map(f)
+}
+
 pub fn update_value_store(update: ValueStoreUpdate) -> Result<(), String> {
     let mut store = VALUE_STORE.write().map_err(|e| {
         STORE_STATE.store(false, Ordering::SeqCst);
@@ -0,0 +1,259 @@
+// Just enough XML for feeds and playlists: elements, attributes, text, CDATA and entities.
+// Namespaces are kept as written ("itunes:duration") since feeds stick to the usual prefixes, and
+// unbalanced tags are closed rather than rejected because plenty of real feeds have them
+#[derive(Debug, Default)]
+pub(crate) struct Element {
+    name: String,
+    attrs: Vec<(String, String)>,
+    children: Vec<Element>,
+    // Only the element's own text, see deep_text
+    text: String,
+    // Where in the parent's text the element sits
+    at: usize,
+}
+
+impl Element {
+    pub(crate) fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub(crate) fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|c| c.name == name)
+    }
+
+    pub(crate) fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> {
+        self.children.iter().filter(move |c| c.name == name)
+    }
+
+    // The text of the element and everything in it, in document order. Descriptions often hold
+    // HTML that was not escaped, which would otherwise leave only the text between the tags
+    pub(crate) fn deep_text(&self) -> String {
+        let mut out = String::new();
+        let mut from = 0;
+        for child in &self.children {
+            out.push_str(&self.text[from..child.at]);
+            out.push_str(&child.deep_text());
+            from = child.at;
+        }
+        out.push_str(&self.text[from..]);
+        out
+    }
+
+    pub(crate) fn child_text(&self, name: &str) -> Option<String> {
+        self.children_named(name)
+            .map(|c| c.deep_text().trim().to_string())
+            .find(|t| !t.is_empty())
+    }
+}
+
+fn xml_unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        // Entities are short, a lone & is just an ampersand
+        let Some((end, _)) = rest.char_indices().take(12).find(|&(_, c)| c == ';') else {
+            out.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+        let entity = &rest[1..end];
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some('\u{a0}'),
+            _ => entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn attach(parent: &mut Element, mut element: Element) {
+    element.at = parent.text.len();
+    parent.children.push(element);
+}
+
+fn close_top(stack: &mut Vec<Element>) {
+    if stack.len() > 1 {
+        let element = stack.pop().unwrap();
+        attach(stack.last_mut().unwrap(), element);
+    }
+}
+
+pub(crate) fn parse_xml(text: &str) -> Element {
+    let mut stack = vec![Element::default()];
+    let mut i = 0;
+
+    while i < text.len() {
+        let rest = &text[i..];
+        if !rest.starts_with('<') {
+            let end = rest.find('<').unwrap_or(rest.len());
+            stack
+                .last_mut()
+                .unwrap()
+                .text
+                .push_str(&xml_unescape(&rest[..end]));
+            i += end;
+            continue;
+        }
+
+        let skip_to = |pattern: &str, from: usize| {
+            rest[from..]
+                .find(pattern)
+                .map(|p| from + p + pattern.len())
+                .unwrap_or(rest.len())
+        };
+        if rest.starts_with("<!--") {
+            i += skip_to("-->", 4);
+        } else if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+            let end = cdata.find("]]>").unwrap_or(cdata.len());
+            stack.last_mut().unwrap().text.push_str(&cdata[..end]);
+            i += skip_to("]]>", 9);
+        } else if rest.starts_with("<?") {
+            i += skip_to("?>", 2);
+        } else if rest.starts_with("<!") {
+            // A DOCTYPE, whose internal subset may contain > of its own
+            let mut depth = 0;
+            let end = rest
+                .char_indices()
+                .find(|&(_, c)| {
+                    match c {
+                        '[' => depth += 1,
+                        ']' => depth -= 1,
+                        '>' if depth <= 0 => return true,
+                        _ => {}
+                    }
+                    false
+                })
+                .map(|(p, _)| p + 1)
+                .unwrap_or(rest.len());
+            i += end;
+        } else if let Some(closing) = rest.strip_prefix("</") {
+            let end = closing.find('>').unwrap_or(closing.len());
+            let name = closing[..end].trim();
+            if let Some(depth) = stack.iter().rposition(|e| e.name == name)
+                && depth > 0
+            {
+                while stack.len() > depth {
+                    close_top(&mut stack);
+                }
+            }
+            i += 2 + (end + 1).min(closing.len());
+        } else if let Some((element, self_closing, len)) = parse_start_tag(rest) {
+            i += len;
+            if self_closing {
+                attach(stack.last_mut().unwrap(), element);
+            } else {
+                stack.push(element);
+            }
+        } else {
+            // A bare < in text
+            stack.last_mut().unwrap().text.push('<');
+            i += 1;
+        }
+    }
+    while stack.len() > 1 {
+        close_top(&mut stack);
+    }
+    stack.pop().unwrap()
+}
+
+// Parses "<name attr="value" ...>" at the start of tag, returning the element, whether it closed
+// itself and how many bytes the tag took. None when the < does not start a tag
+fn parse_start_tag(tag: &str) -> Option<(Element, bool, usize)> {
+    let bytes = tag.as_bytes();
+    let is_name_end = |b: u8| b.is_ascii_whitespace() || b == b'/' || b == b'>' || b == b'=';
+    let mut i = 1;
+    while i < bytes.len() && !is_name_end(bytes[i]) {
+        i += 1;
+    }
+    let mut element = Element {
+        name: tag[1..i].to_string(),
+        ..Default::default()
+    };
+    if element.name.is_empty() {
+        return None;
+    }
+
+    loop {
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        match bytes.get(i) {
+            None => return Some((element, false, i)),
+            Some(b'>') => return Some((element, false, i + 1)),
+            Some(b'/') => {
+                let end = tag[i..].find('>').map(|p| i + p + 1).unwrap_or(tag.len());
+                return Some((element, true, end));
+            }
+            _ => {}
+        }
+        let name_start = i;
+        while i < bytes.len() && !is_name_end(bytes[i]) {
+            i += 1;
+        }
+        let name = tag[name_start..i].to_string();
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        let mut value = String::new();
+        if bytes.get(i) == Some(&b'=') {
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+                i += 1;
+            }
+            match bytes.get(i) {
+                Some(&quote) if quote == b'"' || quote == b'\'' => {
+                    let end = tag[i + 1..]
+                        .find(quote as char)
+                        .map(|p| i + 1 + p)
+                        .unwrap_or(tag.len());
+                    value = xml_unescape(&tag[i + 1..end]);
+                    i = (end + 1).min(tag.len());
+                }
+                _ => {
+                    let start = i;
+                    while i < bytes.len() && !bytes[i].is_ascii_whitespace() && bytes[i] != b'>' {
+                        i += 1;
+                    }
+                    value = xml_unescape(&tag[start..i]);
+                }
+            }
+        }
+        // A stray = has no name to go with, skipping past it was enough
+        if !name.is_empty() {
+            element.attrs.push((name, value));
+        }
+    }
+}
+
+pub(crate) fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}